@@ -386,11 +386,6 @@ export class CallableBodyEmitter {
         const hasReturnValue = shape.hasOriginalReturn;
         const hasRefHandleHidden = shape.hiddenOuts.some((h) => h.kind === "ref-handle");
 
-        if (options.throws) {
-            this.imports.addImport("@gtkx/native", ["createRef"]);
-            this.setupGErrorImports();
-        }
-
         if (hasRefHandleHidden) {
             this.imports.addImport("../../registry.js", ["getNativeObject"]);
         }
@@ -416,24 +411,20 @@ export class CallableBodyEmitter {
         const shape = options.shape;
         this.writeCallbackWrapperDeclarations(writer, callArguments);
 
-        if (options.throws) {
-            writer.writeLine("const error = createRef(null);");
-        }
-
         for (const hidden of shape.hiddenOuts) {
             this.writeHiddenOutDeclaration(writer, hidden);
         }
 
         if (options.throws) {
             callArguments.push({
-                type: this.ffiTypeWriter.createGErrorRefTypeDescriptor(),
-                value: "error",
+                type: this.ffiTypeWriter.createGErrorTypeDescriptor(),
+                value: "null",
             });
         }
 
         const returnTupleNeedsBuild = shape.returnTupleEntries.length > 0;
         const hasRefHandleHidden = shape.hiddenOuts.some((h) => h.kind === "ref-handle");
-        if (returnTupleNeedsBuild || options.throws || hasRefHandleHidden) {
+        if (returnTupleNeedsBuild || hasRefHandleHidden) {
             this.emitTupleReturningBody(writer, ctx);
             return;
         }
@@ -563,10 +554,6 @@ export class CallableBodyEmitter {
             tsReturnType,
         });
 
-        if (options.throws) {
-            this.writeErrorCheck(writer);
-        }
-
         const rewrapBindings = this.writeRefHandleRewrap(writer, shape);
 
         const originalReturnExpression = this.buildOriginalReturnExpression(writer, shape, wrapInfo, {
@@ -866,28 +853,6 @@ export class CallableBodyEmitter {
         };
     }
 
-    /**
-     * Sets up GError import tracking and returns the appropriate GError reference.
-     */
-    setupGErrorImports(currentNamespace?: string): string {
-        this.imports.addImport("../../native.js", ["checkError"]);
-
-        const namespace = currentNamespace ?? this.ffiMapper.getCurrentNamespace();
-        const isGLibNamespace = namespace === "GLib";
-
-        if (isGLibNamespace) {
-            this.imports.addImport("./error.js", ["Error"]);
-            return "Error";
-        }
-        this.imports.addNamespaceImport("../glib/glib.js", "GLib");
-        return "GLib.Error";
-    }
-
-    private writeErrorCheck(writer: Writer): void {
-        const gerrorRef = this.setupGErrorImports();
-        writer.writeLine(`checkError(error, ${gerrorRef});`);
-    }
-
     /**
      * Writes a static factory method body.
      *
@@ -912,16 +877,12 @@ export class CallableBodyEmitter {
             options;
         const hiddenOuts = options.hiddenOuts ?? [];
 
-        if (throws) {
-            this.imports.addImport("@gtkx/native", ["createRef"]);
-            this.setupGErrorImports();
-        }
         if (hiddenOuts.length > 0) {
             this.imports.addImport("@gtkx/native", ["createRef"]);
         }
 
         const allArgs = throws
-            ? [...args, { type: this.ffiTypeWriter.createGErrorRefTypeDescriptor(), value: "error" }]
+            ? [...args, { type: this.ffiTypeWriter.createGErrorTypeDescriptor(), value: "null" }]
             : args;
 
         const callWriter = this.callExpression.toWriter({
@@ -934,10 +895,6 @@ export class CallableBodyEmitter {
         return (writer) => {
             this.writeCallbackWrapperDeclarations(writer, args);
 
-            if (throws) {
-                writer.writeLine("const error = createRef(null);");
-            }
-
             for (const hidden of hiddenOuts) {
                 this.writeHiddenOutDeclaration(writer, hidden);
             }
@@ -946,10 +903,6 @@ export class CallableBodyEmitter {
             callWriter(writer);
             writer.writeLine(";");
 
-            if (throws) {
-                this.writeErrorCheck(writer);
-            }
-
             if (useClassInWrap) {
                 writer.writeLine(`return getNativeObject(ptr, ${wrapClassName});`);
             } else {
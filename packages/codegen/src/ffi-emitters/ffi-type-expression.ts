@@ -25,6 +25,7 @@ const PRIMITIVE_TYPES = new Set([
     "uint64",
     "float32",
     "float64",
+    "gerror",
 ]);
 
 const stringify = (value: string): string => JSON.stringify(value);
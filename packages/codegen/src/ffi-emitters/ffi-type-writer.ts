@@ -13,8 +13,6 @@ import type { FfiTypeDescriptor } from "../type-system/ffi-types.js";
 type FfiTypeWriterOptions = {
     /** Current shared library for boxed types without explicit lib */
     currentSharedLibrary?: string;
-    /** GLib shared library for GError types (derived from GIR) */
-    glibLibrary?: string;
 };
 
 /**
@@ -45,23 +43,12 @@ export class FfiTypeWriter {
     }
 
     /**
-     * Creates a GError ref type descriptor for error out parameters.
-     * Requires glibLibrary to be set in options.
+     * Creates the descriptor of the trailing `GError**` out parameter of a
+     * throwing callable. The native module throws a `NativeError` when the
+     * callee sets it.
      */
-    createGErrorRefTypeDescriptor(): FfiTypeDescriptor {
-        if (!this.options.glibLibrary) {
-            throw new Error("glibLibrary must be set in FfiTypeWriterOptions for GError types");
-        }
-        return {
-            type: "ref",
-            innerType: {
-                type: "boxed",
-                ownership: "full",
-                innerType: "GError",
-                library: this.options.glibLibrary,
-                getTypeFn: "g_error_get_type",
-            },
-        };
+    createGErrorTypeDescriptor(): FfiTypeDescriptor {
+        return { type: "gerror" };
     }
 
     /**
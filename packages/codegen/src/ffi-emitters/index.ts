@@ -38,7 +38,6 @@ export const addMethodStructure = (cls: ClassDeclarationBuilder, struct: MethodS
 
 type CreateMethodBodyWriterOptions = {
     sharedLibrary?: string;
-    glibLibrary?: string;
     selfNames?: ReadonlySet<string>;
     /**
      * Per-file FFI descriptor registry. When supplied, non-variadic call
@@ -55,7 +54,6 @@ export const createMethodBodyWriter = (
 ): MethodBodyWriter => {
     const ffiTypeWriter = new FfiTypeWriter({
         currentSharedLibrary: options.sharedLibrary,
-        glibLibrary: options.glibLibrary,
     });
 
     const descriptors = options.descriptors ?? imports.descriptors;
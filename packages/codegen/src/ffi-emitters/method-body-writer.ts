@@ -529,13 +529,6 @@ export class MethodBodyWriter {
         this.bodyEmitter.writeHiddenOutDeclarationFor(writer, hidden);
     }
 
-    /**
-     * Sets up GError import tracking and returns the appropriate GError reference.
-     */
-    setupGErrorImports(currentNamespace?: string): string {
-        return this.bodyEmitter.setupGErrorImports(currentNamespace);
-    }
-
     /**
      * Writes a static factory method body.
      *
@@ -92,7 +92,6 @@ export class FfiGenerator {
         const generatorOptions: FfiGeneratorOptions = {
             namespace: this.options.namespace,
             sharedLibrary: namespace.sharedLibrary,
-            glibLibrary: this.getNamespaceLibrary("GLib"),
             gobjectLibrary: this.getNamespaceLibrary("GObject"),
         };
 
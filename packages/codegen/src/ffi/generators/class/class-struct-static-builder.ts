@@ -61,7 +61,6 @@ export class ClassStructStaticBuilder {
         this.options = options;
         this.methodBody = createMethodBodyWriter(ffiMapper, imports, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
             selfNames,
         });
     }
@@ -85,7 +85,6 @@ export class ConstructorBuilder {
         this.className = normalizeClassName(cls.name);
         this.methodBody = createMethodBodyWriter(ffiMapper, imports, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
             selfNames: this.selfNames,
         });
     }
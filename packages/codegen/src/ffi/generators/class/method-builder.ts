@@ -43,7 +43,6 @@ export class MethodBuilder {
         this.options = options;
         this.methodBody = createMethodBodyWriter(ffiMapper, imports, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
             selfNames,
         });
     }
@@ -79,7 +79,6 @@ export class SignalBuilder {
         this.descriptors = imports.descriptors;
         this.methodBody = createMethodBodyWriter(ffiMapper, imports, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
             selfNames,
         });
     }
@@ -58,7 +58,6 @@ export class StaticFunctionBuilder {
         this.className = normalizeClassName(cls.name);
         this.methodBody = createMethodBodyWriter(ffiMapper, imports, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
             selfNames,
         });
         this.parentStaticFunctionNames = collectParentStaticFunctionNames(cls);
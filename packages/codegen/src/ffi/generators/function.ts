@@ -34,7 +34,6 @@ export class FunctionGenerator {
     ) {
         this.methodBody = createMethodBodyWriter(ffiMapper, file, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
         });
     }
 
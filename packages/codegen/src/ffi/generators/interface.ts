@@ -53,7 +53,6 @@ export class InterfaceGenerator {
     ) {
         this.methodBody = createMethodBodyWriter(ffiMapper, file, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
         });
     }
 
@@ -147,7 +147,6 @@ export class RecordGenerator {
         this.fieldBuilder = new FieldBuilder(ffiMapper, file, repo, options.namespace);
         this.methodBody = createMethodBodyWriter(ffiMapper, file, {
             sharedLibrary: options.sharedLibrary,
-            glibLibrary: options.glibLibrary,
         });
     }
 
@@ -16,8 +16,6 @@ export type FfiGeneratorOptions = {
     namespace: string;
     /** Shared library (e.g., "libgtk-4.so.1") */
     sharedLibrary: string;
-    /** GLib shared library for GError types (derived from GIR) */
-    glibLibrary: string;
     /** GObject shared library for g_object_new (derived from GIR) */
     gobjectLibrary: string;
 };
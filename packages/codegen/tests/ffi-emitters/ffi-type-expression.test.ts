@@ -24,6 +24,7 @@ describe("writeFfiTypeExpression — primitives", () => {
         "uint64",
         "float32",
         "float64",
+        "gerror",
     ] as const)("renders %s as t.<name>", (type) => {
         expect(render({ type } as FfiTypeDescriptor)).toBe(`t.${type}`);
     });
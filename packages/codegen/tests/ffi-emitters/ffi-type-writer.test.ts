@@ -11,35 +11,12 @@ describe("FfiTypeWriter / constructor", () => {
         const writer = new FfiTypeWriter({ currentSharedLibrary: "libgtk-4.so.1" });
         expect(writer).toBeInstanceOf(FfiTypeWriter);
     });
-
-    it("creates writer with glibLibrary option", () => {
-        const writer = new FfiTypeWriter({ glibLibrary: "libglib-2.0.so.0" });
-        expect(writer).toBeInstanceOf(FfiTypeWriter);
-    });
 });
 
-describe("FfiTypeWriter / createGErrorRefTypeDescriptor", () => {
-    it("creates GError ref type descriptor", () => {
-        const writer = new FfiTypeWriter({ glibLibrary: "libglib-2.0.so.0" });
-        const descriptor = writer.createGErrorRefTypeDescriptor();
-
-        expect(descriptor).toEqual({
-            type: "ref",
-            innerType: {
-                type: "boxed",
-                ownership: "full",
-                innerType: "GError",
-                library: "libglib-2.0.so.0",
-                getTypeFn: "g_error_get_type",
-            },
-        });
-    });
-
-    it("throws when glibLibrary is not set", () => {
+describe("FfiTypeWriter / createGErrorTypeDescriptor", () => {
+    it("creates the GError out-parameter descriptor", () => {
         const writer = new FfiTypeWriter();
-        expect(() => writer.createGErrorRefTypeDescriptor()).toThrow(
-            "glibLibrary must be set in FfiTypeWriterOptions for GError types",
-        );
+        expect(writer.createGErrorTypeDescriptor()).toEqual({ type: "gerror" });
     });
 });
 
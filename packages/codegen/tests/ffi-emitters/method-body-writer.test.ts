@@ -26,7 +26,7 @@ function createTestSetup(namespaces: Map<string, ReturnType<typeof createNormali
     const repo = createMockRepository(namespaces);
     const mapper = new FfiMapper(repo as ConstructorParameters<typeof FfiMapper>[0], "Gtk");
     const imports = fileBuilder();
-    const ffiTypeWriter = new FfiTypeWriter({ currentSharedLibrary: "libgtk-4.so.1", glibLibrary: "libglib-2.0.so.0" });
+    const ffiTypeWriter = new FfiTypeWriter({ currentSharedLibrary: "libgtk-4.so.1" });
     const writer = new MethodBodyWriter(mapper, imports, ffiTypeWriter);
     return { repo, mapper, imports, ffiTypeWriter, writer };
 }
@@ -621,8 +621,8 @@ describe("MethodBodyWriter / writeMethodBody (2)", () => {
         })(w);
 
         const output = w.toString();
-        expect(output).toContain("const error = createRef(null)");
-        expect(output).toContain("checkError(error, GLib.Error)");
+        expect(output).toContain("t.gerror");
+        expect(output).not.toContain("checkError");
     });
 
     it("wraps gobject return values", () => {
@@ -732,8 +732,8 @@ describe("MethodBodyWriter / writeFactoryMethodBody (2)", () => {
         })(w);
 
         const output = w.toString();
-        expect(output).toContain("const error = createRef(null)");
-        expect(output).toContain("checkError(error, GLib.Error)");
+        expect(output).toContain("t.gerror");
+        expect(output).not.toContain("checkError");
     });
 });
 
@@ -1229,8 +1229,8 @@ describe("MethodBodyWriter - extended coverage / writeMethodBody - out parameter
         })(w);
 
         const output = w.toString();
-        expect(output).toContain("const error = createRef(null)");
-        expect(output).toContain("checkError");
+        expect(output).toContain("t.gerror");
+        expect(output).not.toContain("const error = createRef(null)");
     });
 });
 
@@ -1442,10 +1442,7 @@ function createAsyncSetup() {
     );
     const mapper = new FfiMapper(repo as ConstructorParameters<typeof FfiMapper>[0], "Gtk");
     const imports = fileBuilder();
-    const ffiTypeWriter = new FfiTypeWriter({
-        currentSharedLibrary: "libgtk-4.so.1",
-        glibLibrary: "libglib-2.0.so.0",
-    });
+    const ffiTypeWriter = new FfiTypeWriter({ currentSharedLibrary: "libgtk-4.so.1" });
     const descriptors = new FfiDescriptorRegistry();
     const writer = new MethodBodyWriter(mapper, imports, ffiTypeWriter, descriptors);
     return { repo, mapper, imports, ffiTypeWriter, writer };
@@ -1676,32 +1673,6 @@ describe("MethodBodyWriter - extended coverage / writeHiddenOutDeclarationFor (3
     });
 });
 
-describe("MethodBodyWriter - extended coverage / setupGErrorImports", () => {
-    it("imports the local Error for the GLib namespace", () => {
-        const ns = createNormalizedNamespace({ name: "GLib" });
-        const repo = createMockRepository(new Map([["GLib", ns]]));
-        const mapper = new FfiMapper(repo as ConstructorParameters<typeof FfiMapper>[0], "GLib");
-        const imports = fileBuilder();
-        const writer = new MethodBodyWriter(mapper, imports);
-
-        expect(writer.setupGErrorImports()).toBe("Error");
-    });
-
-    it("imports the GLib namespace for non-GLib namespaces", () => {
-        const ns = createNormalizedNamespace({ name: "Gtk" });
-        const { writer } = createTestSetup(new Map([["Gtk", ns]]));
-
-        expect(writer.setupGErrorImports()).toBe("GLib.Error");
-    });
-
-    it("respects an explicitly provided namespace", () => {
-        const ns = createNormalizedNamespace({ name: "Gtk" });
-        const { writer } = createTestSetup(new Map([["Gtk", ns]]));
-
-        expect(writer.setupGErrorImports("GLib")).toBe("Error");
-    });
-});
-
 describe("MethodBodyWriter - extended coverage / writeCallbackWrapperDeclarations", () => {
     it("emits nothing when no argument carries a callback wrapper", () => {
         const ns = createNormalizedNamespace({ name: "Gtk" });
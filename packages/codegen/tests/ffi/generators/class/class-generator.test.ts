@@ -401,7 +401,6 @@ describe("ClassGenerator / cross-namespace inheritance", () => {
         const options = {
             namespace: "Adw",
             sharedLibrary: "libadwaita-1.so.0",
-            glibLibrary: "libglib-2.0.so.0",
             gobjectLibrary: "libgobject-2.0.so.0",
         };
 
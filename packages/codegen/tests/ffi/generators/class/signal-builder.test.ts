@@ -387,7 +387,6 @@ describe("SignalBuilder - Extended Coverage / collectOwnSignals", () => {
         const options = {
             namespace: "Gtk",
             sharedLibrary: "libgtk-4.so.1",
-            glibLibrary: "libglib-2.0.so.0",
             gobjectLibrary: "libgobject-2.0.so.0",
         };
 
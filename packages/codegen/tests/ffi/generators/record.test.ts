@@ -25,7 +25,6 @@ function createTestSetup(namespaces: Map<string, ReturnType<typeof createNormali
     const options = {
         namespace: "Gdk",
         sharedLibrary: "libgdk-4.so.1",
-        glibLibrary: "libglib-2.0.so.0",
         gobjectLibrary: "libgobject-2.0.so.0",
     };
     const generator = new RecordGenerator(ffiMapper, file, options);
@@ -42,7 +41,6 @@ function createTestSetupWithRepo(configure: (ns: ReturnType<typeof createNormali
     const options = {
         namespace: "Gdk",
         sharedLibrary: "libgdk-4.so.1",
-        glibLibrary: "libglib-2.0.so.0",
         gobjectLibrary: "libgobject-2.0.so.0",
     };
     const generator = new RecordGenerator(
@@ -146,7 +144,6 @@ describe("RecordGenerator / generateToSourceFile (3)", () => {
         const options = {
             namespace: "GLib",
             sharedLibrary: "libglib-2.0.so.0",
-            glibLibrary: "libglib-2.0.so.0",
             gobjectLibrary: "libgobject-2.0.so.0",
         };
         const generator = new RecordGenerator(ffiMapper, glibFile, options);
@@ -1282,7 +1279,6 @@ describe("RecordGenerator / filtering and edge cases (3)", () => {
         const options = {
             namespace: "GLib",
             sharedLibrary: "libglib-2.0.so.0",
-            glibLibrary: "libglib-2.0.so.0",
             gobjectLibrary: "libgobject-2.0.so.0",
         };
         const generator = new RecordGenerator(ffiMapper, file, options);
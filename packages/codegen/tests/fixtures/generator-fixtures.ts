@@ -7,7 +7,6 @@ import { createMockRepository } from "./mock-repository.js";
 export interface GeneratorOptionsLike {
     namespace: string;
     sharedLibrary: string;
-    glibLibrary: string;
     gobjectLibrary: string;
 }
 
@@ -18,7 +17,6 @@ export interface GeneratorOptionsLike {
 export const GTK_GENERATOR_OPTIONS: GeneratorOptionsLike = {
     namespace: "Gtk",
     sharedLibrary: "libgtk-4.so.1",
-    glibLibrary: "libglib-2.0.so.0",
     gobjectLibrary: "libgobject-2.0.so.0",
 };
 
@@ -29,7 +27,6 @@ export function buildGeneratorOptions(namespace: string): GeneratorOptionsLike {
     return {
         namespace,
         sharedLibrary: namespace === "GObject" ? "libgobject-2.0.so.0" : "libgtk-4.so.1",
-        glibLibrary: "libglib-2.0.so.0",
         gobjectLibrary: "libgobject-2.0.so.0",
     };
 }
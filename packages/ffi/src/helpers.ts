@@ -1,4 +1,4 @@
-import { type Arg, call as nativeCall, type Type } from "@gtkx/native";
+import { type ArgType, callPrepared, prepare, type PreparedCall, type Type } from "@gtkx/native";
 
 export { alloc, call, freeze, getNativeId, read, unfreeze, write } from "@gtkx/native";
 
@@ -14,10 +14,11 @@ export type TrampolineScope = "call" | "notified" | "async" | "forever";
 /**
  * Binds a native function symbol once and returns a callable that dispatches it.
  *
- * Captures the library, symbol, and a pre-built `Arg` array in a closure so
- * the descriptor objects are allocated once at module load. Each invocation
- * mutates only the per-arg `value` slot before dispatching, making calls
- * allocation-free on the hot path.
+ * The first invocation prepares the call site natively — parsing the type
+ * descriptors, building the libffi CIF, and resolving the symbol — and every
+ * invocation after that only marshals the argument values. Preparation is
+ * deferred to the first call so that loading a bindings module never opens
+ * libraries or resolves symbols it does not use.
  *
  * Reentrancy is safe: native marshals all argument values up-front before
  * dispatching, so trampolines that re-enter the same binding during signal
@@ -38,17 +39,13 @@ const fn = (
     argTypes: ReadonlyArray<{ type: Type; optional?: boolean }>,
     returnType: Type,
 ): ((...values: unknown[]) => unknown) => {
-    const args: Arg[] = argTypes.map((argType) =>
-        argType.optional
-            ? { type: argType.type, value: undefined, optional: true }
-            : { type: argType.type, value: undefined },
+    const descriptors: ArgType[] = argTypes.map((argType) =>
+        argType.optional ? { type: argType.type, optional: true } : { type: argType.type },
     );
+    let prepared: PreparedCall | undefined;
     return (...values) => {
-        let i = 0;
-        for (const arg of args) {
-            arg.value = values[i++];
-        }
-        return nativeCall(library, symbol, args, returnType);
+        prepared ??= prepare(library, symbol, descriptors, returnType);
+        return callPrepared(prepared, values);
     };
 };
 
@@ -65,6 +62,7 @@ const float64: Type = Object.freeze({ type: "float64" });
 const booleanT: Type = Object.freeze({ type: "boolean" });
 const voidT: Type = Object.freeze({ type: "void" });
 const unicharT: Type = Object.freeze({ type: "unichar" });
+const gerrorT: Type = Object.freeze({ type: "gerror" });
 
 const stringT = (ownership: Ownership = "borrowed", length?: number): Type =>
     length === undefined ? { type: "string", ownership } : { type: "string", ownership, length };
@@ -223,6 +221,7 @@ export const t = {
     boolean: booleanT,
     void: voidT,
     unichar: unicharT,
+    gerror: gerrorT,
     string: stringT,
     object: objectT,
     boxed: boxedT,
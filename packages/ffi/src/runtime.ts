@@ -27,7 +27,7 @@ export type { NativeClass, NativeHandle } from "./handles.js";
 export { getClassStruct, getHandle, setClassStruct, setHandle, tryGetHandle } from "./handles.js";
 export type { ArrayKind, ArrayOptions, Ownership, TrampolineOptions, TrampolineScope } from "./helpers.js";
 export { alloc, call, freeze, getNativeId, read, t, unfreeze, write } from "./helpers.js";
-export { checkError, makeErrorDomain, NativeError, throwUnsupported } from "./native.js";
+export { makeErrorDomain, NativeError, throwUnsupported } from "./native.js";
 export { registerInterfaceClassStruct } from "./register-class.js";
 export {
     getNativeObject,
@@ -1,5 +1,6 @@
+import { NativeError as NativeModuleError } from "@gtkx/native";
 import { describe, expect, it } from "vitest";
-import { FileError, Error as GError, quarkFromString } from "../src/generated/glib/glib.js";
+import { ConvertError, FileError, filenameToUri, quarkFromString } from "../src/generated/glib/glib.js";
 import type { GType } from "../src/generated/gobject/gobject.js";
 import { typeFromName } from "../src/generated/gobject/gobject.js";
 import * as Gtk from "../src/generated/gtk/gtk.js";
@@ -12,57 +13,32 @@ const orientableGType = (): GType => typeFromName("GtkOrientable");
 const FILE_ERROR_DOMAIN = 0xbe1;
 const FILE_ERROR_NOENT = 5;
 
-describe("NativeError", () => {
-    it("extends Error", () => {
-        const gerror = GError.newLiteral(FILE_ERROR_DOMAIN, FILE_ERROR_NOENT, "missing file");
-        const error = new NativeError(gerror);
-
-        expect(error).toBeInstanceOf(Error);
-        expect(error).toBeInstanceOf(NativeError);
-    });
-
-    it("sets the error name to NativeError", () => {
-        const gerror = GError.newLiteral(FILE_ERROR_DOMAIN, FILE_ERROR_NOENT, "missing file");
-        const error = new NativeError(gerror);
-
-        expect(error.name).toBe("NativeError");
-    });
-
-    it("uses the GError message for the Error message", () => {
-        const gerror = GError.newLiteral(FILE_ERROR_DOMAIN, FILE_ERROR_NOENT, "missing file");
-        const error = new NativeError(gerror);
-
-        expect(error.message).toBe("missing file");
-    });
-
-    it("falls back to 'Unknown error' when the GError has no message", () => {
-        const fakeGerror = { domain: FILE_ERROR_DOMAIN, code: FILE_ERROR_NOENT, message: null } as unknown as GError;
-        const error = new NativeError(fakeGerror);
+const nativeErrorIn = (domainQuark: number): NativeError =>
+    new NativeError("missing file", domainQuark, "g-file-error-quark", FILE_ERROR_NOENT);
 
-        expect(error.message).toBe("Unknown error");
+describe("NativeError", () => {
+    it("is re-exported from @gtkx/native", () => {
+        expect(NativeError).toBe(NativeModuleError);
     });
 
-    it("exposes the GError domain and code as properties", () => {
-        const gerror = GError.newLiteral(FILE_ERROR_DOMAIN, FILE_ERROR_NOENT, "missing file");
-        const error = new NativeError(gerror);
-
-        expect(error.domain).toBe(FILE_ERROR_DOMAIN);
-        expect(error.code).toBe(FILE_ERROR_NOENT);
+    it("is thrown by generated throwing callables", () => {
+        expect(() => filenameToUri("relative/path")).toThrow(NativeError);
     });
 
-    it("captures a stack trace pointing past the NativeError constructor", () => {
-        const gerror = GError.newLiteral(FILE_ERROR_DOMAIN, FILE_ERROR_NOENT, "missing file");
-        const error = new NativeError(gerror);
+    it("carries the GError domain, code, and message", () => {
+        let caught: unknown;
+        try {
+            filenameToUri("relative/path");
+        } catch (error) {
+            caught = error;
+        }
 
-        expect(typeof error.stack).toBe("string");
-        expect(error.stack ?? "").not.toContain("at new NativeError");
+        expect(caught).toMatchObject({ name: "NativeError", domainName: "g_convert_error", code: 5 });
+        expect((caught as NativeError).message).toContain("relative/path");
     });
 });
 
 describe("makeErrorDomain", () => {
-    const nativeErrorIn = (domain: number): NativeError =>
-        new NativeError(GError.newLiteral(domain, FILE_ERROR_NOENT, "missing file"));
-
     it("exposes the enum members", () => {
         const domain = makeErrorDomain(() => FILE_ERROR_DOMAIN, { NOENT: FILE_ERROR_NOENT });
 
@@ -88,11 +64,22 @@ describe("makeErrorDomain", () => {
     });
 
     it("matches a generated error-domain enum by its GLib quark", () => {
-        const gerror = GError.newLiteral(quarkFromString("g-file-error-quark"), FileError.NOENT, "missing file");
-        const error = new NativeError(gerror);
+        const error = nativeErrorIn(quarkFromString("g-file-error-quark"));
 
         expect(error instanceof FileError).toBe(true);
     });
+
+    it("matches errors thrown by a generated throwing callable", () => {
+        let caught: unknown;
+        try {
+            filenameToUri("relative/path");
+        } catch (error) {
+            caught = error;
+        }
+
+        expect(caught instanceof ConvertError).toBe(true);
+        expect(caught instanceof FileError).toBe(false);
+    });
 });
 
 describe("instanceIsA", () => {
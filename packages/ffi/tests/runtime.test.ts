@@ -19,7 +19,6 @@ const EXPECTED_RUNTIME_EXPORTS = [
     "unfreeze",
     "write",
     "resolveClassStructPointer",
-    "checkError",
     "makeErrorDomain",
     "NativeError",
     "throwUnsupported",
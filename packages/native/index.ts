@@ -1,9 +1,16 @@
 import * as native from "./native-binding.cjs";
 import type {
     Arg,
+    ArgType,
     ArrayType,
+    AsyncPairOptions,
+    BatchCall,
+    BitField,
     CallbackType,
+    CallOptions,
     FfiValue,
+    FieldRead,
+    FieldWrite,
     HashTableType,
     Ref,
     RefType,
@@ -26,6 +33,13 @@ type NativeInterfaceVfuncsDefinition = {
 type NativeRegisterClassOptions = {
     readonly vfuncs?: readonly NativeVfuncDefinition[];
     readonly interfaceVfuncs?: readonly NativeInterfaceVfuncsDefinition[];
+    readonly implements?: readonly NativeInterfaceVfuncsDefinition[];
+    readonly properties?: readonly RegisterClassPropertyDefinition[];
+    readonly signals?: readonly RegisterClassSignalDefinition[];
+    readonly instanceInit?: (instance: NativeHandle) => void;
+    readonly constructed?: (instance: NativeHandle) => void;
+    readonly dispose?: (nativeId: number) => void;
+    readonly finalize?: (nativeId: number) => void;
 };
 
 type ExternalHandle = Parameters<typeof native.getNativeId>[0];
@@ -56,6 +70,57 @@ export function getNativeId(handle: NativeHandle): number {
     return native.getNativeId(handle as unknown as ExternalHandle);
 }
 
+/**
+ * Error thrown when a native callable fails by setting its `GError`
+ * out-parameter (a `gerror` type argument).
+ *
+ * Carries the decoded fields of the `GError`; the `GError` itself has
+ * already been freed.
+ */
+export class NativeError extends Error {
+    /** Quark of the GLib error domain. */
+    readonly domain: number;
+    /** Name of the GLib error domain, e.g. `"g-io-error-quark"`. */
+    readonly domainName: string;
+    /** Domain-specific error code. */
+    readonly code: number;
+    /** Index of the failing call when thrown by {@link callBatch}. */
+    readonly batchIndex?: number;
+
+    constructor(message: string, domain: number, domainName: string, code: number, batchIndex?: number) {
+        super(message);
+
+        this.name = "NativeError";
+        this.domain = domain;
+        this.domainName = domainName;
+        this.code = code;
+        if (batchIndex !== undefined) {
+            this.batchIndex = batchIndex;
+        }
+    }
+}
+
+type NativeErrorFields = Error & { domain: number; domainName: string; code: number; batchIndex?: number };
+
+/**
+ * Converts the plain `Error` the native module throws for a `GError` into a
+ * {@link NativeError}, keeping its stack. Other errors pass through.
+ */
+function toNativeError(error: unknown): unknown {
+    if (!(error instanceof Error) || error instanceof NativeError || error.name !== "NativeError") {
+        return error;
+    }
+
+    const { message, domain, domainName, code, batchIndex, stack } = error as NativeErrorFields;
+    const converted = new NativeError(message, domain, domainName, code, batchIndex);
+    converted.stack = stack;
+    return converted;
+}
+
+function rethrowNativeError(error: unknown): never {
+    throw toNativeError(error);
+}
+
 /**
  * Creates a mutable reference wrapper.
  *
@@ -161,19 +226,32 @@ function rewrapRefArg(ref: Ref<unknown>, type: RefType): void {
  * This is the core FFI mechanism. Most code should use the generated
  * bindings in `@gtkx/ffi` instead of calling this directly.
  *
+ * A throwing callable takes a trailing `{ type: "gerror" }` argument; when
+ * the callable sets it, the call throws a {@link NativeError}.
+ *
+ * A C variadic function such as `g_object_new` is called with a
+ * `variadic: { fixedArgs }` option naming how many of `args` are its named
+ * parameters.
+ *
  * @param library - Shared library name (e.g., "libgtk-4.so.1")
  * @param symbol - Function symbol name
  * @param args - Function arguments with type information
  * @param returnType - Expected return type
+ * @param options - Call options, e.g. the variadic signature
  * @returns The function return value
  */
-export function call(library: string, symbol: string, args: Arg[], returnType: Type): FfiValue {
+export function call(library: string, symbol: string, args: Arg[], returnType: Type, options?: CallOptions): FfiValue {
     const unwrapped = args.map((arg) => ({
         ...arg,
         value: unwrapValue(arg.value, arg.type),
     }));
 
-    const result = native.call(library, symbol, unwrapped, returnType);
+    let result: unknown;
+    try {
+        result = native.call(library, symbol, unwrapped, returnType, options);
+    } catch (error) {
+        rethrowNativeError(error);
+    }
 
     for (const arg of args) {
         if (arg.type.type === "ref") {
@@ -184,6 +262,257 @@ export function call(library: string, symbol: string, args: Arg[], returnType: T
     return wrapValue(result, returnType) as FfiValue;
 }
 
+/**
+ * Makes a low-level FFI call without blocking the Node.js event loop.
+ *
+ * Behaves like {@link call}, but returns as soon as the call is queued and
+ * settles the returned promise once it completes on the GLib thread. Timers,
+ * I/O, and native callbacks keep running in the meantime. `Ref` arguments are
+ * updated before the promise resolves. Invalid type descriptors throw
+ * synchronously; failures of the call itself reject the promise.
+ *
+ * @param library - Shared library name (e.g., "libgtk-4.so.1")
+ * @param symbol - Function symbol name
+ * @param args - Function arguments with type information
+ * @param returnType - Expected return type
+ * @returns A promise for the function return value
+ */
+export function callPromise(library: string, symbol: string, args: Arg[], returnType: Type): Promise<FfiValue> {
+    const unwrapped = args.map((arg) => ({
+        ...arg,
+        value: unwrapValue(arg.value, arg.type),
+    }));
+
+    const pending = native.callPromise(library, symbol, unwrapped, returnType) as Promise<unknown>;
+
+    return pending.then((result) => {
+        for (const arg of args) {
+            if (arg.type.type === "ref") {
+                rewrapRefArg(arg.value as Ref<unknown>, arg.type);
+            }
+        }
+
+        return wrapValue(result, returnType) as FfiValue;
+    }, rethrowNativeError);
+}
+
+/**
+ * Runs a GIO-style asynchronous operation natively and returns a promise for
+ * its result.
+ *
+ * Calls the `_async` callable on the GLib thread with a native
+ * `GAsyncReadyCallback`, then calls the `_finish` callable from that callback
+ * with the `GAsyncResult` and a `GError` out-parameter. The promise resolves
+ * with the decoded `_finish` return value. A `GError` rejects it with a
+ * {@link NativeError}; `G_IO_ERROR_CANCELLED` rejects it with an `Error` named
+ * `AbortError` that carries the same fields. `Ref` arguments of the
+ * `_finish` callable are updated before the promise resolves. Invalid type
+ * descriptors throw synchronously.
+ *
+ * @param library - Shared library name (e.g., "libgio-2.0.so.0")
+ * @param startSymbol - Symbol of the `_async` callable
+ * @param finishSymbol - Symbol of the `_finish` callable
+ * @param options - Arguments, cancellable, and return type of the pair
+ * @returns A promise for the `_finish` return value
+ */
+export function callAsyncPair(
+    library: string,
+    startSymbol: string,
+    finishSymbol: string,
+    options: AsyncPairOptions,
+): Promise<FfiValue> {
+    const unwrapArgs = (args: readonly Arg[] | undefined) =>
+        args?.map((arg) => ({ ...arg, value: unwrapValue(arg.value, arg.type) }));
+
+    const pending = native.callAsyncPair(library, startSymbol, finishSymbol, {
+        args: unwrapArgs(options.args),
+        trailingArgs: unwrapArgs(options.trailingArgs),
+        cancellable: options.cancellable ?? null,
+        finishArgs: unwrapArgs(options.finishArgs),
+        finishTrailingArgs: unwrapArgs(options.finishTrailingArgs),
+        returnType: options.returnType,
+    }) as Promise<unknown>;
+
+    return pending.then((result) => {
+        for (const arg of [...(options.finishArgs ?? []), ...(options.finishTrailingArgs ?? [])]) {
+            if (arg.type.type === "ref") {
+                rewrapRefArg(arg.value as Ref<unknown>, arg.type);
+            }
+        }
+
+        return wrapValue(result, options.returnType) as FfiValue;
+    }, rethrowNativeError);
+}
+
+/**
+ * Queues a void FFI call on the GLib thread without waiting for it to run.
+ *
+ * Calls keep their order relative to every other call made from JavaScript.
+ * Since nothing waits on the result, the call must return `void` and take no
+ * `Ref` out-parameters. Failures are reported asynchronously as uncaught
+ * errors tagged with the symbol name. Use {@link flush} to wait for queued
+ * calls to finish.
+ *
+ * @param library - Shared library name (e.g., "libgtk-4.so.1")
+ * @param symbol - Function symbol name
+ * @param args - Function arguments with type information
+ * @param returnType - Declared return type; anything but `void` is rejected
+ */
+export function enqueue(library: string, symbol: string, args: Arg[], returnType: Type = { type: "void" }): void {
+    const unwrapped = args.map((arg) => ({
+        ...arg,
+        value: unwrapValue(arg.value, arg.type),
+    }));
+
+    native.enqueue(library, symbol, unwrapped, returnType);
+}
+
+/**
+ * Blocks until every call queued with {@link enqueue} has run.
+ *
+ * Inside a native callback, only calls queued from within that callback are
+ * awaited; calls queued before the callback started run after it returns.
+ */
+export function flush(): void {
+    native.flush();
+}
+
+/**
+ * Makes several low-level FFI calls with a single round-trip to the GLib
+ * thread.
+ *
+ * Calls run in order. The batch stops at the first failing call and throws an
+ * error naming its index, or a {@link NativeError} whose `batchIndex` holds it
+ * if the call set a `GError`; calls after it are not made. `Ref` arguments of the
+ * calls that completed before the failure are not updated.
+ *
+ * @param calls - Calls to make, in order
+ * @returns The return value of each call, in order
+ */
+export function callBatch(calls: readonly BatchCall[]): FfiValue[] {
+    const unwrapped = calls.map((entry) => ({
+        ...entry,
+        args: entry.args.map((arg) => ({ ...arg, value: unwrapValue(arg.value, arg.type) })),
+    }));
+
+    let results: unknown[];
+    try {
+        results = native.callBatch(unwrapped) as unknown[];
+    } catch (error) {
+        rethrowNativeError(error);
+    }
+
+    for (const entry of calls) {
+        for (const arg of entry.args) {
+            if (arg.type.type === "ref") {
+                rewrapRefArg(arg.value as Ref<unknown>, arg.type);
+            }
+        }
+    }
+
+    return calls.map((entry, i) => wrapValue(results[i], entry.returnType) as FfiValue);
+}
+
+type ExternalPreparedCall = Parameters<typeof native.callPrepared>[0];
+
+declare const __preparedCallBrand: unique symbol;
+
+/**
+ * Opaque handle to a call site whose argument types, libffi CIF, and resolved
+ * symbol were built once by {@link prepare}.
+ */
+export type PreparedCall = { readonly [__preparedCallBrand]: never };
+
+type PreparedCallState = {
+    readonly handle: ExternalPreparedCall;
+    readonly argTypes: readonly ArgType[];
+    readonly returnType: Type;
+    readonly needsUnwrap: boolean;
+};
+
+function needsUnwrap(type: Type): boolean {
+    switch (type.type) {
+        case "array":
+        case "hashtable":
+        case "ref":
+        case "callback":
+        case "trampoline":
+            return true;
+        default:
+            return false;
+    }
+}
+
+/**
+ * Parses a call signature once and caches its libffi CIF and resolved symbol.
+ *
+ * The returned handle is invoked through {@link callPrepared}, which skips
+ * type-descriptor parsing, CIF construction, and symbol lookup on every call.
+ * Most code should use the generated bindings in `@gtkx/ffi` instead of
+ * calling this directly.
+ *
+ * @param library - Shared library name (e.g., "libgtk-4.so.1")
+ * @param symbol - Function symbol name
+ * @param argTypes - Argument type descriptors in positional order
+ * @param returnType - Expected return type
+ * @returns Handle to pass to {@link callPrepared}
+ */
+export function prepare(
+    library: string,
+    symbol: string,
+    argTypes: readonly ArgType[],
+    returnType: Type,
+): PreparedCall {
+    const state: PreparedCallState = {
+        handle: native.prepare(library, symbol, [...argTypes], returnType) as ExternalPreparedCall,
+        argTypes,
+        returnType,
+        needsUnwrap: argTypes.some((argType) => needsUnwrap(argType.type)),
+    };
+    return state as unknown as PreparedCall;
+}
+
+/**
+ * Calls a function prepared with {@link prepare}.
+ *
+ * @param prepared - Handle returned by {@link prepare}
+ * @param values - Argument values in positional order
+ * @returns The function return value
+ */
+export function callPrepared(prepared: PreparedCall, values: readonly unknown[]): FfiValue {
+    const state = prepared as unknown as PreparedCallState;
+    const { argTypes, returnType } = state;
+
+    if (!state.needsUnwrap) {
+        try {
+            return wrapValue(native.callPrepared(state.handle, values as unknown[]), returnType) as FfiValue;
+        } catch (error) {
+            rethrowNativeError(error);
+        }
+    }
+
+    const unwrapped = values.map((value, i) => {
+        const argType = argTypes[i];
+        return argType ? unwrapValue(value, argType.type) : value;
+    });
+
+    let result: unknown;
+    try {
+        result = native.callPrepared(state.handle, unwrapped);
+    } catch (error) {
+        rethrowNativeError(error);
+    }
+
+    for (let i = 0; i < argTypes.length; i++) {
+        const argType = argTypes[i]?.type;
+        if (argType?.type === "ref") {
+            rewrapRefArg(values[i] as Ref<unknown>, argType);
+        }
+    }
+
+    return wrapValue(result, returnType) as FfiValue;
+}
+
 /**
  * Handle to the `GLib` main loop spawned automatically when this module is
  * first loaded. Stored so {@link stop} can quit the loop without callers
@@ -210,10 +539,11 @@ export function stop(): void {
  * @param handle - Native handle pointing to the memory
  * @param type - Type of value to read
  * @param offset - Byte offset from the handle pointer
+ * @param bits - Bitfield to read instead of the whole integer
  * @returns The read value
  */
-export function read(handle: NativeHandle, type: Type, offset: number): FfiValue {
-    const result = native.read(handle as unknown as ExternalHandle, type, offset);
+export function read(handle: NativeHandle, type: Type, offset: number, bits?: BitField): FfiValue {
+    const result = native.read(handle as unknown as ExternalHandle, type, offset, bits);
     return wrapValue(result, type) as FfiValue;
 }
 
@@ -224,9 +554,40 @@ export function read(handle: NativeHandle, type: Type, offset: number): FfiValue
  * @param type - Type of value to write
  * @param offset - Byte offset from the handle pointer
  * @param value - Value to write
+ * @param bits - Bitfield to write instead of the whole integer
+ */
+export function write(handle: NativeHandle, type: Type, offset: number, value: unknown, bits?: BitField): void {
+    native.write(handle as unknown as ExternalHandle, type, offset, unwrapValue(value, type), bits);
+}
+
+/**
+ * Reads several values from native memory in a single native round trip.
+ *
+ * @param handle - Native handle pointing to the memory
+ * @param fields - Offset and type of each value to read
+ * @returns The read values, in order
  */
-export function write(handle: NativeHandle, type: Type, offset: number, value: unknown): void {
-    native.write(handle as unknown as ExternalHandle, type, offset, unwrapValue(value, type));
+export function readMany(handle: NativeHandle, fields: readonly FieldRead[]): FfiValue[] {
+    const results = native.readMany(handle as unknown as ExternalHandle, fields) as unknown[];
+    return fields.map((field, i) => wrapValue(results[i], field.type) as FfiValue);
+}
+
+/**
+ * Writes several values to native memory in a single native round trip.
+ *
+ * Every value is validated before any byte is written: if one is rejected,
+ * the call throws and the memory is left unchanged. Fields are written in
+ * order, so a later field wins where two overlap. String, bytes and `GValue`
+ * fields, which allocate memory, are rejected; write them with {@link write}.
+ *
+ * @param handle - Native handle pointing to the memory
+ * @param fields - Offset, type, and value of each field to write
+ */
+export function writeMany(handle: NativeHandle, fields: readonly FieldWrite[]): void {
+    native.writeMany(
+        handle as unknown as ExternalHandle,
+        fields.map((field) => ({ ...field, value: unwrapValue(field.value, field.type) })),
+    );
 }
 
 /**
@@ -259,6 +620,147 @@ export function findObjectProperty(handle: NativeHandle, propertyName: string):
     return result == null ? null : (result as NativeHandle);
 }
 
+/**
+ * Reads a `GObject` property by name.
+ *
+ * The property's `GParamSpec` is looked up on the GLib thread and the value is
+ * decoded by the type it holds: numbers, enums and flags as numbers, strings,
+ * string arrays, variants as plain values, and objects and boxed values as
+ * handles. Pass `type` to decode it through a descriptor instead.
+ *
+ * @param handle - Handle to a live `GObject` instance
+ * @param propertyName - Property name in dashed form (e.g. `"label"`)
+ * @param type - Optional descriptor to decode the value with
+ * @throws When the object has no such property or it is not readable
+ */
+export function getProperty(handle: NativeHandle, propertyName: string, type?: Type): FfiValue {
+    return native.getProperty(handle as unknown as ExternalHandle, propertyName, type) as FfiValue;
+}
+
+/**
+ * Sets several `GObject` properties by name in a single native round trip.
+ *
+ * Each value is converted to its property's type from the `GParamSpec`.
+ * Every value is converted and validated before any is set, so a rejected
+ * value leaves the object unchanged. Change notifications are held back until
+ * all properties are set. A property listed in `types` is built through that
+ * descriptor instead, which is how a variant property gets its `signature`.
+ *
+ * @param handle - Handle to a live `GObject` instance
+ * @param properties - Values keyed by property name
+ * @param types - Optional descriptors keyed by property name
+ * @throws When a property is unknown, not writable, or rejects its value
+ */
+export function setProperties(
+    handle: NativeHandle,
+    properties: Readonly<Record<string, unknown>>,
+    types?: Readonly<Record<string, Type>>,
+): void {
+    const values = Object.fromEntries(
+        Object.entries(properties).map(([name, value]) => {
+            const type = types?.[name];
+            return [name, type ? unwrapValue(value, type) : value];
+        }),
+    );
+    native.setProperties(handle as unknown as ExternalHandle, values, types);
+}
+
+/**
+ * Options for {@link connectSignal}.
+ */
+export type ConnectSignalOptions = {
+    /** Run the handler after the signal's default handler. */
+    readonly after?: boolean;
+};
+
+/**
+ * Connects a handler to a signal without a generated trampoline.
+ *
+ * The signal's parameter and return types are looked up with
+ * `g_signal_query`, so any signal on the instance's type can be connected,
+ * including those of types registered at run time. The handler receives the
+ * emitting instance followed by the signal's parameters, each decoded by the
+ * type it holds. Its return value is converted to the signal's return type;
+ * a handler that throws or returns nothing yields that type's default.
+ *
+ * @param handle - Handle to a live `GObject` instance
+ * @param detailedSignal - Signal name, optionally with a detail (e.g. `"notify::label"`)
+ * @param handler - Function invoked on each emission
+ * @param options - Connection options
+ * @returns Handler id for {@link disconnectSignal}, {@link blockSignal} and {@link unblockSignal}
+ * @throws When the instance's type has no such signal
+ */
+export function connectSignal(
+    handle: NativeHandle,
+    detailedSignal: string,
+    handler: (...args: unknown[]) => unknown,
+    options?: ConnectSignalOptions,
+): number {
+    return native.connectSignal(handle as unknown as ExternalHandle, detailedSignal, handler, options) as number;
+}
+
+/**
+ * Emits a signal by name in a single native round trip.
+ *
+ * The signal's parameter types are looked up with `g_signal_query` and each
+ * argument is converted to its parameter's type. An argument with a
+ * descriptor at the same index in `types` is built through it instead, which
+ * is how a variant parameter gets its `signature`.
+ *
+ * @param handle - Handle to a live `GObject` instance
+ * @param detailedSignal - Signal name, optionally with a detail (e.g. `"notify::label"`)
+ * @param args - Signal arguments, not counting the instance
+ * @param types - Optional descriptors for the arguments, by index
+ * @returns The signal's return value, or `undefined` for a signal without one
+ * @throws When the signal is unknown or an argument does not fit its parameter
+ */
+export function emitSignal(
+    handle: NativeHandle,
+    detailedSignal: string,
+    args: readonly unknown[] = [],
+    types?: readonly (Type | undefined)[],
+): FfiValue {
+    const values = args.map((value, index) => {
+        const type = types?.[index];
+        return type ? unwrapValue(value, type) : value;
+    });
+    return native.emitSignal(handle as unknown as ExternalHandle, detailedSignal, values, types) as FfiValue;
+}
+
+/**
+ * Disconnects a signal handler.
+ *
+ * @param handle - Handle to the instance the handler is connected on
+ * @param handlerId - Id returned by {@link connectSignal}
+ * @throws When no handler with that id is connected
+ */
+export function disconnectSignal(handle: NativeHandle, handlerId: number): void {
+    native.disconnectSignal(handle as unknown as ExternalHandle, handlerId);
+}
+
+/**
+ * Blocks a signal handler so emissions skip it until {@link unblockSignal}.
+ * Blocks nest: a handler blocked twice must be unblocked twice.
+ *
+ * @param handle - Handle to the instance the handler is connected on
+ * @param handlerId - Id returned by {@link connectSignal}
+ * @throws When no handler with that id is connected
+ */
+export function blockSignal(handle: NativeHandle, handlerId: number): void {
+    native.blockSignal(handle as unknown as ExternalHandle, handlerId);
+}
+
+/**
+ * Undoes one {@link blockSignal} on a signal handler.
+ *
+ * @param handle - Handle to the instance the handler is connected on
+ * @param handlerId - Id returned by {@link connectSignal}
+ * @throws When no handler with that id is connected
+ */
+export function unblockSignal(handle: NativeHandle, handlerId: number): void {
+    native.unblockSignal(handle as unknown as ExternalHandle, handlerId);
+}
+
 /**
  * Returns the runtime GType of a `GTypeInstance`-compatible handle.
  *
@@ -277,7 +779,8 @@ export function getInstanceGType(handle: NativeHandle): number {
  * `byteOffset` is the offset (in bytes) of the function pointer slot inside
  * the class struct relative to the class struct base; the JavaScript function
  * is wrapped in a libffi trampoline whose generated C function pointer is
- * written at that offset during class initialization.
+ * written at that offset during class initialization. The implementation the
+ * slot held before stays callable through {@link chainUp}.
  */
 export type RegisterClassVfuncDefinition = {
     /** Byte offset of the vfunc slot within the class struct. */
@@ -291,43 +794,141 @@ export type RegisterClassVfuncDefinition = {
 };
 
 /**
- * Vfunc overrides targeting one interface that the registered class inherits
- * from its parent.
+ * Vfunc overrides targeting one interface of the registered class.
  *
- * `gtype` is the GType of the inherited interface. `vfuncs` are the overrides,
- * with `byteOffset` relative to the interface struct base (not the class
- * struct). Each vfunc is wrapped in a libffi trampoline whose function pointer
- * is written into the new class's own copy of the inherited interface vtable.
+ * `gtype` is the GType of the interface. `vfuncs` are the overrides, with
+ * `byteOffset` relative to the interface struct base (not the class struct).
+ * Each vfunc is wrapped in a libffi trampoline whose function pointer is
+ * written into the new class's own copy of the interface vtable: the one
+ * inherited from the parent for `interfaceVfuncs`, or a fresh copy of the
+ * interface's default vtable for `implements`.
  */
 export type RegisterClassInterfaceVfuncsDefinition = {
-    /** GType of the inherited interface whose vfuncs are overridden. */
+    /** GType of the interface whose vfuncs are overridden. */
     readonly gtype: number;
     /** Vfunc overrides relative to the interface struct base. */
     readonly vfuncs: readonly RegisterClassVfuncDefinition[];
 };
 
 /**
- * Optional payload for {@link registerClass} carrying class vfunc overrides and
- * inherited-interface vfunc overrides.
+ * Value type of a property declared by {@link registerClass}, with its range
+ * and default value.
+ *
+ * Ranges default to the full range of the type. Without a `default`, a number
+ * defaults to `0` (or `minimum` when the range excludes it) and an enum to its
+ * first value. `gtype` is the enum, object or boxed GType the property holds.
+ */
+export type RegisterClassPropertyType =
+    | { readonly type: "int"; readonly minimum?: number; readonly maximum?: number; readonly default?: number }
+    | { readonly type: "double"; readonly minimum?: number; readonly maximum?: number; readonly default?: number }
+    | { readonly type: "string"; readonly default?: string | null }
+    | { readonly type: "boolean"; readonly default?: boolean }
+    | { readonly type: "enum"; readonly gtype: number; readonly default?: number }
+    | { readonly type: "object"; readonly gtype: number }
+    | { readonly type: "boxed"; readonly gtype: number }
+    | { readonly type: "variant"; readonly signature: string; readonly default?: unknown };
+
+/**
+ * GObject property installed on a registered class.
+ *
+ * The property is a real `GParamSpec`, visible to `g_object_get`,
+ * `g_object_bind_property`, `GtkBuilder` and `notify::` signals. Reads and
+ * writes are routed to `get` and `set`; a property without `get` reads as its
+ * default value, and a writable property must have `set`.
+ */
+export type RegisterClassPropertyDefinition = RegisterClassPropertyType & {
+    /** Canonical property name, e.g. `"max-count"`. */
+    readonly name: string;
+    readonly nick?: string;
+    readonly blurb?: string;
+    /** `GParamFlags` bitmask. Defaults to `G_PARAM_READWRITE`. */
+    readonly flags?: number;
+    /** Returns the property's value for `instance`. */
+    readonly get?: (instance: NativeHandle) => unknown;
+    /** Stores `value` as the property's value for `instance`. */
+    readonly set?: (instance: NativeHandle, value: unknown) => void;
+};
+
+/**
+ * Signal created on a registered class with `g_signal_newv`.
+ *
+ * The signal can be connected and emitted like any native one, including
+ * from `GtkBuilder` templates. `classHandler` becomes the signal's default
+ * handler; like a handler passed to {@link connectSignal}, it receives the
+ * emitting instance followed by the signal's parameters, and its return value
+ * is converted to `returnType`.
+ */
+export type RegisterClassSignalDefinition = {
+    /** Signal name, e.g. `"item-activated"`. */
+    readonly name: string;
+    /** `GSignalFlags` bitmask, e.g. `G_SIGNAL_RUN_LAST | G_SIGNAL_DETAILED`. Defaults to `G_SIGNAL_RUN_LAST`. */
+    readonly flags?: number;
+    /** GTypes of the parameters, not counting the instance. */
+    readonly paramTypes?: readonly number[];
+    /** GType of the return value. Defaults to `G_TYPE_NONE`. */
+    readonly returnType?: number;
+    /**
+     * Combines the handlers' return values: `"first-wins"` keeps the first
+     * handler's value and `"true-handled"` stops the emission at the first
+     * handler returning `true`.
+     */
+    readonly accumulator?: "first-wins" | "true-handled";
+    /** Default handler, run at the stage selected by `flags`. */
+    readonly classHandler?: (instance: NativeHandle, ...args: unknown[]) => unknown;
+};
+
+/**
+ * Optional payload for {@link registerClass} carrying class vfunc overrides,
+ * inherited-interface vfunc overrides, newly implemented interfaces, declared
+ * properties, signals and lifecycle hooks.
+ *
+ * The `constructed`, `dispose` and `finalize` hooks replace the matching
+ * `GObjectClass` vfuncs and chain up automatically: the parent's
+ * implementation runs before `constructed` and after `dispose` and
+ * `finalize`. A vfunc in `vfuncs` must not override the same slot.
  */
 export type RegisterClassNativeOptions = {
     readonly vfuncs?: readonly RegisterClassVfuncDefinition[];
+    /** Overrides for interfaces the parent already implements. */
     readonly interfaceVfuncs?: readonly RegisterClassInterfaceVfuncsDefinition[];
+    /**
+     * Interfaces the parent does not implement, added with
+     * `g_type_add_interface_static`. An interface must come after any of its
+     * prerequisite interfaces the parent lacks.
+     */
+    readonly implements?: readonly RegisterClassInterfaceVfuncsDefinition[];
+    readonly properties?: readonly RegisterClassPropertyDefinition[];
+    readonly signals?: readonly RegisterClassSignalDefinition[];
+    /** Runs for each new instance, after the `instanceInit` of every ancestor type. */
+    readonly instanceInit?: (instance: NativeHandle) => void;
+    /** Runs once construct properties are set and the parent's `constructed` has returned. */
+    readonly constructed?: (instance: NativeHandle) => void;
+    /**
+     * Runs when the instance drops its references to other objects. Receives
+     * the instance's {@link getNativeId} value instead of a handle, so that
+     * the instance is not kept alive; `dispose` may run more than once.
+     */
+    readonly dispose?: (nativeId: number) => void;
+    /** Runs before the instance is freed. Receives its {@link getNativeId} value. */
+    readonly finalize?: (nativeId: number) => void;
 };
 
 /**
  * Registers a new `GType` derived from `parentGtype` under `name`.
  *
  * Wraps `g_type_register_static`, sizing the new class so it matches the
- * parent's class and instance struct sizes. Class vfunc overrides are installed
- * inside `class_init`; inherited-interface vfunc overrides are written into the
- * new class's interface vtables once the class is initialized. Higher-level
+ * parent's class and instance struct sizes. Class vfunc overrides, lifecycle
+ * hooks, declared properties and signals are installed inside `class_init`
+ * (`instanceInit` becomes the type's `instance_init`), and newly
+ * implemented interfaces are added before the class is first referenced;
+ * inherited-interface vfunc overrides are written into the new class's
+ * interface vtables once the class is initialized. Higher-level
  * orchestration (resolving the parent class, walking JS prototypes, updating
  * the JS class registry) lives in `@gtkx/ffi`'s `registerClass`.
  *
  * @param name - Globally-unique GType name (must not already be registered)
  * @param parentGtype - Numeric GType of the parent class
- * @param options - Optional class and inherited-interface vfunc overrides
+ * @param options - Optional vfunc overrides, implemented interfaces, properties, signals and lifecycle hooks
  * @returns Numeric GType of the newly registered subclass
  */
 export function registerClass(name: string, parentGtype: number, options?: RegisterClassNativeOptions): number {
@@ -338,10 +939,21 @@ export function registerClass(name: string, parentGtype: number, options?: Regis
 function buildNativeOptions(options: RegisterClassNativeOptions): NativeRegisterClassOptions {
     return {
         vfuncs: options.vfuncs?.map(toNativeVfunc),
-        interfaceVfuncs: options.interfaceVfuncs?.map((iface) => ({
-            gtype: iface.gtype,
-            vfuncs: iface.vfuncs.map(toNativeVfunc),
-        })),
+        interfaceVfuncs: options.interfaceVfuncs?.map(toNativeInterface),
+        implements: options.implements?.map(toNativeInterface),
+        properties: options.properties,
+        signals: options.signals,
+        instanceInit: options.instanceInit,
+        constructed: options.constructed,
+        dispose: options.dispose,
+        finalize: options.finalize,
+    };
+}
+
+function toNativeInterface(iface: RegisterClassInterfaceVfuncsDefinition): NativeInterfaceVfuncsDefinition {
+    return {
+        gtype: iface.gtype,
+        vfuncs: iface.vfuncs.map(toNativeVfunc),
     };
 }
 
@@ -354,6 +966,24 @@ function toNativeVfunc(vfunc: RegisterClassVfuncDefinition): NativeVfuncDefiniti
     };
 }
 
+/**
+ * Calls the implementation that a class vfunc override of a registered class
+ * replaced, i.e. the parent class's version of the vfunc.
+ *
+ * The call goes through a CIF built once, during class initialization, from
+ * the override's `argTypes` and `returnType`, so `args` are typically the
+ * arguments the override itself received.
+ *
+ * @param gtype - GType returned by {@link registerClass} for the overriding class
+ * @param byteOffset - Byte offset of the overridden vfunc slot
+ * @param args - Argument values in positional order
+ * @returns The parent implementation's return value
+ * @throws When the class has no override at `byteOffset` or its parent leaves the slot empty
+ */
+export function chainUp(gtype: number, byteOffset: number, args: readonly unknown[]): FfiValue {
+    return native.chainUp(gtype, byteOffset, [...args]) as FfiValue;
+}
+
 /**
  * Suspends GTK frame-clock dispatch while a batch of mutations is applied.
  *
@@ -375,4 +1005,20 @@ export function unfreeze(): void {
     native.unfreeze();
 }
 
-export type { Arg, CallbackType, FfiValue, Ref, Type } from "./types.js";
+export type {
+    Arg,
+    ArgType,
+    AsyncPairOptions,
+    BatchCall,
+    BitField,
+    CallbackType,
+    CallOptions,
+    FfiValue,
+    FieldRead,
+    FieldWrite,
+    NumericTypedArray,
+    Ref,
+    TaggedUnion,
+    TaggedVariant,
+    Type,
+} from "./types.js";
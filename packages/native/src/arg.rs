@@ -46,7 +46,7 @@ impl Arg {
         let type_prop: Unknown<'_> = obj.get_named_property("type")?;
         let value_prop: Unknown<'_> = obj.get_named_property("value")?;
         let ty = Type::from_js_value(env, type_prop)?;
-        let value = Value::from_js_value(env, value_prop)?;
+        let value = Value::from_js_value_typed(env, value_prop, &ty)?;
 
         let optional = obj
             .get_named_property::<Option<bool>>("optional")
@@ -49,6 +49,7 @@ use gtk4::glib;
 use napi::threadsafe_function::ThreadsafeFunction;
 use napi::{JsFunction, Status};
 
+use crate::types::Type;
 use crate::value::{JsRef, Value};
 use crate::wait_signal::WaitSignal;
 
@@ -61,10 +62,23 @@ type DepthTaggedTask = (usize, GlibTask);
 
 pub type WakeJsTsfn = ThreadsafeFunction<(), (), (), Status, false, true>;
 
+/// How the JS thread converts the value a node callback returns.
+#[derive(Debug)]
+pub enum CallbackResult {
+    /// The return value is discarded.
+    Discard,
+    /// The return value is converted by its JavaScript type.
+    Untyped,
+    /// The return value is converted as a value of this type, so variant and
+    /// record descriptors can build it from a plain JavaScript value. Shared
+    /// with the trampoline that declared it rather than cloned per call.
+    Typed(Arc<Type>),
+}
+
 struct NodeCallback {
     callback: Arc<JsRef<JsFunction>>,
     args: Vec<Value>,
-    capture_result: bool,
+    result: CallbackResult,
     result_tx: mpsc::Sender<anyhow::Result<Value>>,
 }
 
@@ -13,7 +13,7 @@ use napi::bindgen_prelude::{FromNapiValue, Unknown};
 use napi::threadsafe_function::ThreadsafeFunctionCallMode;
 use napi::{Env, JsFunction};
 
-use super::{GlibDisconnectedError, Mailbox, NodeCallback, WakeJsTsfn};
+use super::{CallbackResult, GlibDisconnectedError, Mailbox, NodeCallback, WakeJsTsfn};
 use crate::error_reporter::NativeErrorReporter;
 use crate::value::{JsRef, Value};
 
@@ -97,7 +97,7 @@ impl Mailbox {
         &self,
         callback: &Arc<JsRef<JsFunction>>,
         args: Vec<Value>,
-        capture_result: bool,
+        result: CallbackResult,
     ) -> anyhow::Result<Value> {
         let callback_depth = self.callback_depth.load(Ordering::Acquire) + 1;
         let (tx, rx) = mpsc::channel();
@@ -105,7 +105,7 @@ impl Mailbox {
         self.push_node_callback(NodeCallback {
             callback: callback.clone(),
             args,
-            capture_result,
+            result,
             result_tx: tx,
         });
 
@@ -148,11 +148,11 @@ impl Mailbox {
             let NodeCallback {
                 callback,
                 args,
-                capture_result,
+                result,
                 result_tx,
             } = pending;
             self.enter_callback();
-            let result = Self::execute_callback(env, &callback, args, capture_result);
+            let result = Self::execute_callback(env, &callback, args, &result);
             self.leave_callback();
             if result_tx.send(result).is_err() {
                 NativeErrorReporter::global()
@@ -167,7 +167,7 @@ impl Mailbox {
         env: Env,
         callback: &Arc<JsRef<JsFunction>>,
         args: Vec<Value>,
-        capture_result: bool,
+        result: &CallbackResult,
     ) -> anyhow::Result<Value> {
         use napi::sys;
 
@@ -220,13 +220,13 @@ impl Mailbox {
             return Err(anyhow::anyhow!("napi_call_function failed: {status:?}"));
         }
 
-        if capture_result {
-            let unknown = unsafe { Unknown::from_raw_unchecked(env.raw(), return_value) };
-            Value::from_js_value(&env, unknown)
-                .map_err(|e| anyhow::anyhow!("converting callback result: {e}"))
-        } else {
-            Ok(Value::Undefined)
-        }
+        let unknown = unsafe { Unknown::from_raw_unchecked(env.raw(), return_value) };
+        let converted = match result {
+            CallbackResult::Discard => return Ok(Value::Undefined),
+            CallbackResult::Untyped => Value::from_js_value(&env, unknown),
+            CallbackResult::Typed(ty) => Value::from_js_value_typed(&env, unknown, ty),
+        };
+        converted.map_err(|e| anyhow::anyhow!("converting callback result: {e}"))
     }
 
     #[cfg_attr(coverage_nightly, coverage(off))]
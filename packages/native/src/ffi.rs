@@ -8,6 +8,7 @@
 //!
 //! - [`FfiValue`]: Raw FFI-compatible value representation
 //! - [`FfiStorage`]: Temporary storage for FFI call arguments
+//! - [`AggregateBuffer`]: The bytes of a struct passed or returned by value
 
 mod storage;
 mod value;
@@ -16,7 +17,7 @@ pub use storage::{
     FfiStorage, FfiStorageKind, GArrayData, GByteArrayData, GListData, GSListData, HashTableData,
     StringGListData, StringGSListData,
 };
-pub use value::{FfiValue, TrampolineValue};
+pub use value::{AggregateBuffer, FfiValue, TrampolineValue};
 
 use crate::arg::Arg;
 use crate::types::FfiEncoder as _;
@@ -72,6 +72,18 @@ pub enum FfiStorageKind {
     I64Vec(Vec<i64>),
     F32Vec(Vec<f32>),
     F64Vec(Vec<f64>),
+    /// A JS typed array's contents, shared with the [`crate::value::Value`]
+    /// it was read from so the call can use its buffer without copying it.
+    TypedBuffer(crate::value::TypedBuffer),
+    /// A `GBytes` lent to a call. Its reference is released when the storage
+    /// drops.
+    GBytes(glib::Bytes),
+    /// The bytes of a struct lent to a call by pointer, or of a contiguous
+    /// array of structs.
+    Aggregate(super::AggregateBuffer),
+    /// `GValue`s lent to a call, laid out back to back. Each is unset when
+    /// the storage drops.
+    GValues(Vec<glib::Value>),
     StringArray(Vec<std::ffi::CString>, Vec<*mut c_void>),
     ObjectArray(Vec<crate::managed::NativeHandle>, Vec<*mut c_void>),
     GList(GListData),
@@ -130,6 +142,26 @@ impl FfiStorage {
         with_integer_kinds!(dispatch)
     }
 
+    /// The element count of a numeric vector or typed-array buffer, or `None`
+    /// for every non-numeric storage kind.
+    #[must_use]
+    pub fn numeric_len(&self) -> Option<usize> {
+        match &self.kind {
+            FfiStorageKind::U8Vec(v) => Some(v.len()),
+            FfiStorageKind::I8Vec(v) => Some(v.len()),
+            FfiStorageKind::U16Vec(v) => Some(v.len()),
+            FfiStorageKind::I16Vec(v) => Some(v.len()),
+            FfiStorageKind::U32Vec(v) => Some(v.len()),
+            FfiStorageKind::I32Vec(v) => Some(v.len()),
+            FfiStorageKind::U64Vec(v) => Some(v.len()),
+            FfiStorageKind::I64Vec(v) => Some(v.len()),
+            FfiStorageKind::F32Vec(v) => Some(v.len()),
+            FfiStorageKind::F64Vec(v) => Some(v.len()),
+            FfiStorageKind::TypedBuffer(buffer) => Some(buffer.len()),
+            _ => None,
+        }
+    }
+
     pub fn as_f32_slice(&self) -> anyhow::Result<&[f32]> {
         match &self.kind {
             FfiStorageKind::F32Vec(v) => Ok(v),
@@ -280,6 +312,10 @@ impl Drop for FfiStorage {
             | FfiStorageKind::I64Vec(_)
             | FfiStorageKind::F32Vec(_)
             | FfiStorageKind::F64Vec(_)
+            | FfiStorageKind::TypedBuffer(_)
+            | FfiStorageKind::GBytes(_)
+            | FfiStorageKind::Aggregate(_)
+            | FfiStorageKind::GValues(_)
             | FfiStorageKind::StringArray(_, _)
             | FfiStorageKind::ObjectArray(_, _)
             | FfiStorageKind::CString(_)
@@ -306,6 +342,33 @@ macro_rules! impl_ffi_storage_from_integer_vecs {
 }
 with_integer_kinds!(impl_ffi_storage_from_integer_vecs);
 
+impl From<crate::value::TypedBuffer> for FfiStorage {
+    fn from(buffer: crate::value::TypedBuffer) -> Self {
+        Self {
+            ptr: buffer.as_ptr().cast_mut(),
+            kind: FfiStorageKind::TypedBuffer(buffer),
+        }
+    }
+}
+
+impl From<super::AggregateBuffer> for FfiStorage {
+    fn from(buffer: super::AggregateBuffer) -> Self {
+        Self {
+            ptr: buffer.as_ptr().cast_mut(),
+            kind: FfiStorageKind::Aggregate(buffer),
+        }
+    }
+}
+
+impl From<Vec<glib::Value>> for FfiStorage {
+    fn from(gvalues: Vec<glib::Value>) -> Self {
+        Self {
+            ptr: gvalues.as_ptr() as *mut c_void,
+            kind: FfiStorageKind::GValues(gvalues),
+        }
+    }
+}
+
 impl From<Vec<f32>> for FfiStorage {
     fn from(vec: Vec<f32>) -> Self {
         Self {
@@ -21,9 +21,89 @@ pub enum FfiValue {
     Ptr(*mut c_void),
     Storage(FfiStorage),
     Trampoline(TrampolineValue),
+    /// A struct passed or returned by value.
+    Aggregate(AggregateBuffer),
     Void,
 }
 
+/// One alignment unit of an [`AggregateBuffer`], as strictly aligned as any C
+/// scalar libffi can place in a struct.
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct AlignedChunk([u8; 16]);
+
+/// The bytes of a struct passed or returned by value.
+///
+/// The storage is aligned for every field type, so libffi can read an
+/// argument from it or write a return value into it directly.
+#[derive(Clone)]
+pub struct AggregateBuffer {
+    chunks: Vec<AlignedChunk>,
+    len: usize,
+}
+
+impl AggregateBuffer {
+    /// Allocates `len` zeroed bytes.
+    #[must_use]
+    pub fn zeroed(len: usize) -> Self {
+        Self {
+            chunks: vec![AlignedChunk([0; 16]); len.div_ceil(16).max(1)],
+            len,
+        }
+    }
+
+    /// Copies `len` bytes starting at `src`.
+    ///
+    /// # Safety
+    /// `src` must be valid for reads of `len` bytes.
+    #[must_use]
+    pub unsafe fn copy_from(src: *const c_void, len: usize) -> Self {
+        let mut buffer = Self::zeroed(len);
+        unsafe {
+            std::ptr::copy_nonoverlapping(src.cast::<u8>(), buffer.as_mut_ptr().cast(), len);
+        }
+        buffer
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[must_use]
+    pub fn as_ptr(&self) -> *const c_void {
+        self.chunks.as_ptr().cast()
+    }
+
+    #[must_use]
+    pub fn as_mut_ptr(&mut self) -> *mut c_void {
+        self.chunks.as_mut_ptr().cast()
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.as_ptr().cast(), self.len) }
+    }
+
+    /// The slot libffi writes a by-value return into.
+    pub fn as_ret(&mut self) -> libffi::Ret<'_> {
+        libffi::Ret::new(self.chunks.as_mut_slice())
+    }
+}
+
+impl std::fmt::Debug for AggregateBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AggregateBuffer")
+            .field("bytes", &self.as_bytes())
+            .finish()
+    }
+}
+
 pub struct TrampolineValue {
     fn_ptr: *mut c_void,
     state_ptr: *mut c_void,
@@ -105,6 +185,7 @@ impl FfiValue {
             Self::F64(value) => value as *const f64 as *mut c_void,
             Self::Ptr(ptr) => ptr as *const *mut c_void as *mut c_void,
             Self::Storage(storage) => storage.ptr(),
+            Self::Aggregate(buffer) => buffer.as_ptr().cast_mut(),
             Self::Trampoline(_) => {
                 unreachable!(
                     "Trampoline should not be converted to a single pointer - it requires special handling via append_libffi_args"
@@ -118,7 +199,7 @@ impl FfiValue {
         match self {
             Self::Ptr(ptr) => Ok(*ptr),
             Self::Storage(storage) => Ok(storage.ptr()),
-            ffi_numeric_with!(Self::Trampoline(_) | Self::Void) => {
+            ffi_numeric_with!(Self::Trampoline(_) | Self::Aggregate(_) | Self::Void) => {
                 anyhow::bail!("Expected a pointer FfiValue for {type_name}, got {self:?}")
             }
         }
@@ -141,7 +222,11 @@ impl FfiValue {
             Self::U64(v) => Ok(*v as f64),
             Self::F32(v) => Ok(*v as f64),
             Self::F64(v) => Ok(*v),
-            Self::Ptr(_) | Self::Storage(_) | Self::Trampoline(_) | Self::Void => {
+            Self::Ptr(_)
+            | Self::Storage(_)
+            | Self::Trampoline(_)
+            | Self::Aggregate(_)
+            | Self::Void => {
                 anyhow::bail!("Expected a numeric FfiValue, got {self:?}")
             }
         }
@@ -156,7 +241,9 @@ impl FfiValue {
                     args.push(libffi::arg(destroy_ptr));
                 }
             }
-            ffi_numeric_with!(Self::Ptr(_) | Self::Storage(_) | Self::Void) => {
+            ffi_numeric_with!(
+                Self::Ptr(_) | Self::Storage(_) | Self::Aggregate(_) | Self::Void
+            ) => {
                 args.push(self.into());
             }
         }
@@ -178,6 +265,7 @@ impl<'a> From<&'a FfiValue> for libffi::Arg<'a> {
             FfiValue::F64(value) => libffi::arg(value),
             FfiValue::Ptr(ptr) => libffi::arg(ptr),
             FfiValue::Storage(storage) => libffi::arg(storage.ptr_ref()),
+            FfiValue::Aggregate(buffer) => libffi::arg(buffer.chunks.as_slice()),
             FfiValue::Trampoline(_) => {
                 unreachable!("Trampoline requires append_libffi_args for multiple arguments")
             }
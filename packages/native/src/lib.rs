@@ -10,6 +10,13 @@
 //! | `start` | Spawn the `GLib` thread, run a `MainLoop`, and return its handle |
 //! | `stop` | Quit the `GLib` main loop and drain pending finalizers |
 //! | `call` | Execute FFI function call to native library |
+//! | `prepare` | Build and cache the CIF and symbol pointer for a call signature |
+//! | `callPrepared` | Execute a prepared call, encoding only the argument values |
+//! | `callPromise` | Execute FFI function call without blocking the JS thread, returning a `Promise` |
+//! | `callAsyncPair` | Run a GIO `_async`/`_finish` pair natively, returning a `Promise` |
+//! | `callBatch` | Execute a list of FFI calls in order as a single `GLib` task |
+//! | `enqueue` | Queue a void FFI call on the `GLib` thread without waiting for it |
+//! | `flush` | Wait for every enqueued call to finish |
 //! | `alloc` | Allocate memory for boxed types |
 //! | `read` | Read field from boxed/struct memory |
 //! | `write` | Write primitive field to boxed memory (constructor initialization) |
@@ -3,6 +3,7 @@
 //! This module contains all the functions exported to JavaScript via napi-rs.
 
 mod alloc;
+mod async_call;
 mod call;
 mod field;
 mod freeze;
@@ -11,4 +12,5 @@ pub(crate) mod handler;
 mod init;
 mod object;
 mod register_class;
+mod signal;
 mod stop;
@@ -0,0 +1,442 @@
+//! GIO-style asynchronous call pairs resolved as JavaScript promises.
+//!
+//! GIO models an asynchronous operation as a `foo_async(..., GCancellable*,
+//! GAsyncReadyCallback, gpointer)` callable that starts it and a
+//! `foo_finish(..., GAsyncResult*, ..., GError**)` callable that yields its
+//! result. [`call_async_pair`](napi_export::call_async_pair) drives both
+//! natively: it passes a Rust `GAsyncReadyCallback` to the start callable,
+//! calls the finish callable from that callback on the `GLib` thread, and
+//! settles a JavaScript `Promise` with the decoded return value.
+//!
+//! A `GError` reported by the finish callable rejects the promise with the
+//! same `NativeError` a failed synchronous call throws. `G_IO_ERROR_CANCELLED`
+//! rejects it with an error named `AbortError` instead, matching the rejection
+//! callers expect from an aborted web API.
+
+use std::ffi::c_void;
+
+use gtk4::gio;
+use gtk4::glib::{self, error::ErrorDomain as _};
+use napi::Env;
+use napi::JsObject;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::call::{PreparedCall, parse_return_type};
+use super::handler::{ModuleResponse as _, RefUpdate, create_native_error, request_error};
+use crate::{
+    arg::Arg,
+    dispatch::{GlibDisconnectedError, Mailbox},
+    managed::NativeHandle,
+    types::{GErrorDetails, GErrorType, GObjectType, Ownership, Type, VoidType},
+    value::Value,
+};
+
+type AsyncCallOutcome = anyhow::Result<(Value, Vec<RefUpdate>)>;
+
+/// Whether `error` is the `G_IO_ERROR_CANCELLED` reported by a cancelled
+/// operation.
+fn is_cancelled(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<GErrorDetails>()
+        .is_some_and(|details| {
+            details.domain == gio::IOErrorEnum::domain().as_str()
+                && details.code == gio::IOErrorEnum::Cancelled.code()
+        })
+}
+
+/// Receives the outcome of an asynchronous call pair on the `GLib` thread.
+type Completion = Box<dyn FnOnce(AsyncCallOutcome)>;
+
+#[cfg_attr(test, allow(dead_code))]
+struct AsyncCallRequest {
+    library_name: String,
+    start_symbol: String,
+    /// Start arguments, including the `GCancellable*` slot but not the
+    /// callback and user data, which are appended natively.
+    start_args: Vec<Arg>,
+    finish_symbol: String,
+    /// Finish arguments preceding the `GAsyncResult*`.
+    finish_leading_args: Vec<Arg>,
+    /// Finish arguments between the `GAsyncResult*` and the `GError**`, which
+    /// is appended natively.
+    finish_trailing_args: Vec<Arg>,
+    result_type: Type,
+}
+
+/// The finish half of a started operation, handed to the `GAsyncReadyCallback`
+/// as its user data.
+struct PendingFinish {
+    finish: PreparedCall,
+    leading_args: Vec<Arg>,
+    trailing_args: Vec<Arg>,
+    on_complete: Completion,
+    /// Set when the start callable ran but its outcome could not be decoded,
+    /// e.g. a failed `Ref` write-back. The operation still completes, and the
+    /// promise is rejected with this error once it does.
+    start_error: Option<anyhow::Error>,
+}
+
+impl AsyncCallRequest {
+    /// Starts the operation. Must run on the `GLib` thread.
+    ///
+    /// `on_complete` is called exactly once on the `GLib` thread: from the
+    /// `GAsyncReadyCallback` once the operation finishes, or immediately if
+    /// the start callable is not called.
+    fn start(self, on_complete: Completion) {
+        let Self {
+            library_name,
+            start_symbol,
+            start_args,
+            finish_symbol,
+            finish_leading_args,
+            finish_trailing_args,
+            result_type,
+        } = self;
+
+        let finish_args = finish_leading_args
+            .iter()
+            .chain(std::iter::once(&async_result_arg(std::ptr::null_mut())))
+            .chain(&finish_trailing_args)
+            .chain(std::iter::once(&gerror_arg()))
+            .map(|arg| (arg.ty.clone(), arg.optional))
+            .collect::<Vec<_>>();
+        let (finish_types, finish_optional) = finish_args.into_iter().unzip();
+
+        let prepared = PreparedCall::new(
+            &library_name,
+            finish_symbol,
+            finish_types,
+            finish_optional,
+            result_type,
+        )
+        .and_then(|finish| {
+            let start = PreparedCall::with_trailing_ptrs(
+                &library_name,
+                start_symbol,
+                start_args.iter().map(|arg| arg.ty.clone()).collect(),
+                start_args.iter().map(|arg| arg.optional).collect(),
+                Type::Void(VoidType),
+                2,
+            )?;
+            Ok((start, finish))
+        });
+
+        let (start, finish) = match prepared {
+            Ok(calls) => calls,
+            Err(e) => return on_complete(Err(e)),
+        };
+
+        let pending = Box::into_raw(Box::new(PendingFinish {
+            finish,
+            leading_args: finish_leading_args,
+            trailing_args: finish_trailing_args,
+            on_complete,
+            start_error: None,
+        }));
+
+        let callback = async_ready_trampoline as unsafe extern "C" fn(_, _, _) as *mut c_void;
+        let encoded =
+            match start.encode_with_trailing_ptrs(&start_args, &[callback, pending.cast()]) {
+                Ok(encoded) => encoded,
+                Err(e) => {
+                    let pending = unsafe { Box::from_raw(pending) };
+                    return (pending.on_complete)(Err(e));
+                }
+            };
+
+        // Once the start callable has run, `pending` belongs to the callback
+        // it queued. GIO never invokes that callback before the start
+        // callable returns, so a failure past the call is left for it to
+        // report.
+        if let Err(e) = start.invoke_encoded(&start_args, &encoded) {
+            unsafe { (*pending).start_error = Some(e) };
+        }
+    }
+}
+
+impl PendingFinish {
+    /// Calls the finish callable with `result` and hands its outcome over.
+    fn complete(self, result: *mut c_void) {
+        let Self {
+            finish,
+            leading_args,
+            trailing_args,
+            on_complete,
+            start_error,
+        } = self;
+
+        let mut args = leading_args;
+        args.push(async_result_arg(result));
+        args.extend(trailing_args);
+        args.push(gerror_arg());
+
+        // The finish callable runs either way, to release the operation's
+        // result.
+        let outcome = finish.invoke(&args);
+        on_complete(match start_error {
+            Some(e) => Err(e),
+            None => outcome,
+        });
+    }
+}
+
+fn gerror_arg() -> Arg {
+    Arg::new(Type::GError(GErrorType), Value::Null)
+}
+
+fn async_result_arg(result: *mut c_void) -> Arg {
+    Arg::new(
+        Type::GObject(GObjectType {
+            ownership: Ownership::Borrowed,
+        }),
+        Value::Object(NativeHandle::borrowed(result)),
+    )
+}
+
+/// The `GAsyncReadyCallback` passed to every start callable. Reclaims the
+/// [`PendingFinish`] leaked into `user_data` and completes it.
+unsafe extern "C" fn async_ready_trampoline(
+    _source: *mut glib::gobject_ffi::GObject,
+    result: *mut gio::ffi::GAsyncResult,
+    user_data: glib::ffi::gpointer,
+) {
+    let pending = unsafe { Box::from_raw(user_data.cast::<PendingFinish>()) };
+    pending.complete(result.cast());
+}
+
+/// Converts a failed operation into the JavaScript error the promise is
+/// rejected with: an `AbortError` for a cancelled operation, otherwise the
+/// same error a failed synchronous call throws. Excluded from coverage
+/// instrumentation: it builds the error through a live [`napi::Env`].
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn to_js_error(env: &Env, error: &anyhow::Error) -> napi::Error {
+    let Some(details) = error.downcast_ref::<GErrorDetails>() else {
+        return request_error(env, error, "async FFI call");
+    };
+
+    let build = || -> napi::Result<Object<'_>> {
+        let mut js_error = create_native_error(env, details)?;
+        if is_cancelled(error) {
+            js_error.set_named_property("name", "AbortError")?;
+        }
+        Ok(js_error)
+    };
+
+    match build() {
+        Ok(js_error) => napi::Error::from(napi::JsValue::to_unknown(&js_error)),
+        Err(e) => e,
+    }
+}
+
+/// napi export shim. Excluded from coverage instrumentation: it parses JS
+/// arguments and settles a promise through a live [`napi::Env`]. The
+/// [`AsyncCallRequest::start`] logic it dispatches is exercised directly by
+/// tests.
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[allow(clippy::wildcard_imports)]
+mod napi_export {
+    use super::*;
+
+    fn parse_args(env: &Env, options: &JsObject, key: &str) -> napi::Result<Vec<Arg>> {
+        match options.get_named_property::<Option<Array>>(key)? {
+            Some(args) => Arg::from_js_array(env, &args),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn call_async_pair<'env>(
+        env: &'env Env,
+        library: String,
+        start_symbol: String,
+        finish_symbol: String,
+        options: JsObject,
+    ) -> napi::Result<Object<'env>> {
+        let cancellable: Unknown<'_> = options.get_named_property("cancellable")?;
+        let cancellable_arg = Arg {
+            ty: Type::GObject(GObjectType {
+                ownership: Ownership::Borrowed,
+            }),
+            value: Value::from_js_value(env, cancellable)?,
+            optional: true,
+        };
+
+        let mut start_args = parse_args(env, &options, "args")?;
+        start_args.push(cancellable_arg);
+        start_args.extend(parse_args(env, &options, "trailingArgs")?);
+
+        let return_type: Unknown<'_> = options.get_named_property("returnType")?;
+        let result_type = parse_return_type(env, return_type)?;
+
+        let request = AsyncCallRequest {
+            library_name: library,
+            start_symbol,
+            start_args,
+            finish_symbol,
+            finish_leading_args: parse_args(env, &options, "finishArgs")?,
+            finish_trailing_args: parse_args(env, &options, "finishTrailingArgs")?,
+            result_type,
+        };
+
+        let (deferred, promise) = env.create_deferred()?;
+        let mailbox = Mailbox::global();
+
+        if mailbox.is_stopped() {
+            deferred.reject(napi::Error::new(
+                napi::Status::GenericFailure,
+                GlibDisconnectedError.to_string(),
+            ));
+            return Ok(promise);
+        }
+
+        mailbox.schedule_glib(Box::new(move || {
+            request.start(Box::new(move |outcome| {
+                deferred.resolve(move |env: Env| match outcome {
+                    Ok(response) => {
+                        let value = response.to_js_response(&env)?;
+                        Ok(unsafe {
+                            Unknown::<'static>::from_raw_unchecked(
+                                env.raw(),
+                                napi::JsValue::raw(&value),
+                            )
+                        })
+                    }
+                    Err(error) => Err(to_js_error(&env, &error)),
+                });
+            }));
+        }));
+
+        Ok(promise)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use gtk4::glib::translate::ToGlibPtr as _;
+    use gtk4::prelude::{CancellableExt as _, Cast as _};
+
+    use super::*;
+    use crate::types::{IntegerKind, StringType};
+
+    const GIO_LIB: &str = "libgio-2.0.so.0";
+
+    fn object_value(object: &impl glib::prelude::IsA<glib::Object>) -> Value {
+        let ptr: *mut glib::gobject_ffi::GObject =
+            object.upcast_ref::<glib::Object>().to_glib_none().0;
+        Value::Object(NativeHandle::borrowed(ptr.cast()))
+    }
+
+    fn object_arg(value: Value, optional: bool) -> Arg {
+        Arg {
+            ty: Type::GObject(GObjectType {
+                ownership: Ownership::Borrowed,
+            }),
+            value,
+            optional,
+        }
+    }
+
+    fn query_info_request(
+        file: &gio::File,
+        cancellable: Option<&gio::Cancellable>,
+    ) -> AsyncCallRequest {
+        let file_arg = object_arg(object_value(file), false);
+        AsyncCallRequest {
+            library_name: GIO_LIB.into(),
+            start_symbol: "g_file_query_info_async".into(),
+            start_args: vec![
+                file_arg.clone(),
+                Arg::new(
+                    Type::String(StringType {
+                        ownership: Ownership::Borrowed,
+                        length: None,
+                    }),
+                    Value::String("standard::name".into()),
+                ),
+                Arg::new(Type::Integer(IntegerKind::I32), Value::Number(0.0)),
+                Arg::new(Type::Integer(IntegerKind::I32), Value::Number(0.0)),
+                object_arg(cancellable.map_or(Value::Null, object_value), true),
+            ],
+            finish_symbol: "g_file_query_info_finish".into(),
+            finish_leading_args: vec![file_arg],
+            finish_trailing_args: vec![],
+            result_type: Type::GObject(GObjectType {
+                ownership: Ownership::Full,
+            }),
+        }
+    }
+
+    /// Starts `request` on a private main context and iterates it until the
+    /// operation completes.
+    fn run_to_completion(request: AsyncCallRequest) -> AsyncCallOutcome {
+        let context = glib::MainContext::new();
+        context
+            .with_thread_default(|| {
+                let outcome = Rc::new(RefCell::new(None));
+                let slot = Rc::clone(&outcome);
+                request.start(Box::new(move |result| {
+                    *slot.borrow_mut() = Some(result);
+                }));
+                while outcome.borrow().is_none() {
+                    context.iteration(true);
+                }
+                outcome.take().expect("the operation should have completed")
+            })
+            .expect("the main context should be acquirable")
+    }
+
+    #[test]
+    fn resolves_with_the_finish_return_value() {
+        let file = gio::File::for_path("/");
+        let (value, ref_updates) = run_to_completion(query_info_request(&file, None))
+            .expect("querying an existing path should succeed");
+        assert!(ref_updates.is_empty());
+        assert!(matches!(value, Value::Object(handle) if !handle.ptr().is_null()));
+    }
+
+    #[test]
+    fn reports_the_gerror_from_the_finish_call() {
+        let file = gio::File::for_path("/no/such/path/for/gtkx/tests");
+        let err = run_to_completion(query_info_request(&file, None))
+            .expect_err("querying a missing path should fail");
+        let details = err
+            .downcast_ref::<GErrorDetails>()
+            .expect("the failure should carry the GError");
+        assert_eq!(details.domain, "g-io-error-quark");
+        assert_eq!(details.code, gio::IOErrorEnum::NotFound.code());
+        assert!(!is_cancelled(&err));
+    }
+
+    #[test]
+    fn reports_cancellation() {
+        let file = gio::File::for_path("/");
+        let cancellable = gio::Cancellable::new();
+        cancellable.cancel();
+        let err = run_to_completion(query_info_request(&file, Some(&cancellable)))
+            .expect_err("a cancelled operation should fail");
+        assert!(is_cancelled(&err));
+    }
+
+    #[test]
+    fn reports_an_unknown_start_symbol() {
+        let file = gio::File::for_path("/");
+        let mut request = query_info_request(&file, None);
+        request.start_symbol = "g_no_such_symbol_12345".into();
+        let err = run_to_completion(request).expect_err("an unknown symbol should fail");
+        assert!(err.downcast_ref::<GErrorDetails>().is_none());
+    }
+
+    #[test]
+    fn reports_an_unknown_finish_symbol() {
+        let file = gio::File::for_path("/");
+        let mut request = query_info_request(&file, None);
+        request.finish_symbol = "g_no_such_symbol_12345".into();
+        let err = run_to_completion(request).expect_err("an unknown symbol should fail");
+        assert!(err.downcast_ref::<GErrorDetails>().is_none());
+    }
+}
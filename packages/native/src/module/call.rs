@@ -11,8 +11,45 @@
 //! 3. Build a libffi CIF (Call Interface) with proper type signatures
 //! 4. Load the library and resolve the symbol on the GTK thread
 //! 5. Execute the FFI call with proper type dispatching
-//! 6. Convert the result back to a [`Value`] for JavaScript
-//! 7. Update any `Ref` type out-parameters with modified values
+//! 6. Fail with the `GError` stored in a `gerror` out-parameter, if any
+//! 7. Convert the result back to a [`Value`] for JavaScript
+//! 8. Update any `Ref` type out-parameters with modified values
+//!
+//! ## Prepared Calls
+//!
+//! [`prepare`](napi_export::prepare) runs steps 1, 3 and 4 once and returns an
+//! opaque [`PreparedCall`] handle. [`call_prepared`](napi_export::call_prepared)
+//! then only runs steps 2 and 5-8 per call, which is the path
+//! the generated bindings take for every call.
+//!
+//! ## Promise Calls
+//!
+//! [`call_promise`](napi_export::call_promise) runs the same request without
+//! parking the JS thread, so timers and I/O keep running during long native
+//! operations. The returned `Promise` settles with the same return value and
+//! `Ref` write-backs as a synchronous call.
+//!
+//! ## Batched Calls
+//!
+//! [`call_batch`](napi_export::call_batch) ships a list of calls to the `GLib`
+//! thread as one task, so a React commit that issues hundreds of calls pays
+//! for a single thread handoff. Calls run in order and the batch stops at the
+//! first failure.
+//!
+//! ## Enqueued Calls
+//!
+//! [`enqueue`](napi_export::enqueue) queues a void call with no `Ref` arguments
+//! and returns immediately; failures are reported through
+//! [`NativeErrorReporter`]. [`flush`](napi_export::flush) waits for everything
+//! queued so far.
+//!
+//! ## Variadic Calls
+//!
+//! A [`call`](napi_export::call) with a `variadic: { fixedArgs }` option
+//! targets a C variadic function such as `g_object_new` or
+//! `g_strdup_printf`. The CIF is built with libffi's variadic API, and the
+//! arguments after the first `fixedArgs` undergo the C default argument
+//! promotions before they are encoded.
 //!
 //! ## Callbacks
 //!
@@ -22,82 +59,458 @@
 
 use std::{ffi::c_void, sync::Arc};
 
-use anyhow::Context as _;
+use anyhow::{Context as _, bail};
 use libffi::middle as libffi;
-use napi::Env;
 use napi::bindgen_prelude::*;
+use napi::{Env, JsObject};
 use napi_derive::napi;
 
-use super::handler::{ModuleRequest, RefUpdate};
+use super::handler::{ModuleRequest, ModuleResponse, RefUpdate};
 use crate::{
     arg::Arg,
+    dispatch::Mailbox,
+    error_reporter::NativeErrorReporter,
     ffi,
     state::GtkThreadState,
-    types::{FfiEncoder as _, Type},
+    types::{FfiEncoder as _, GErrorDetails, GErrorType, Type, VoidType},
     value::Value,
 };
 
-#[cfg_attr(test, allow(dead_code))]
-struct CallRequest {
-    library_name: String,
+/// A resolved call site: parsed argument types, a built CIF, and the symbol's
+/// code pointer.
+///
+/// Built once by [`prepare`](napi_export::prepare) and reused by every
+/// [`call_prepared`](napi_export::call_prepared) invocation, so repeated calls
+/// with an identical signature only pay for encoding their argument values.
+/// The one-shot [`call`](napi_export::call) export builds a throwaway instance
+/// per call.
+#[derive(Debug)]
+pub struct PreparedCall {
     symbol_name: String,
-    args: Vec<Arg>,
+    /// Type and optional flag of each argument. The values are placeholders:
+    /// each invocation supplies its own, so the types are never cloned per
+    /// call.
+    params: Vec<Arg>,
     result_type: Type,
+    trailing_ptrs: usize,
+    cif: libffi::Cif,
+    code_ptr: libffi::CodePtr,
 }
 
-impl ModuleRequest for CallRequest {
-    type Output = (Value, Vec<RefUpdate>);
+// SAFETY: the CIF and the code pointer are immutable once built. The CIF only
+// points at its own heap-allocated type arrays, and the code pointer is only
+// ever invoked on the GLib thread, where it was resolved.
+unsafe impl Send for PreparedCall {}
+unsafe impl Sync for PreparedCall {}
 
-    fn execute(self) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
-        let mut arg_types: Vec<libffi::Type> = Vec::with_capacity(self.args.len() + 1);
-        for arg in &self.args {
-            arg.ty.append_ffi_arg_types(&mut arg_types);
+impl PreparedCall {
+    /// Builds the CIF for `arg_types` and resolves `symbol_name` in
+    /// `library_name`. Must run on the `GLib` thread.
+    pub(super) fn new(
+        library_name: &str,
+        symbol_name: String,
+        arg_types: Vec<Type>,
+        optional_args: Vec<bool>,
+        result_type: Type,
+    ) -> anyhow::Result<Self> {
+        Self::build(
+            library_name,
+            symbol_name,
+            arg_types,
+            optional_args,
+            result_type,
+            0,
+            None,
+        )
+    }
+
+    /// Like [`Self::new`], for a variadic function whose first `fixed_args`
+    /// arguments are its named parameters. The remaining `arg_types` are the
+    /// ones passed through `...` and must already be promoted with
+    /// [`Type::variadic_promoted`].
+    pub(super) fn variadic(
+        library_name: &str,
+        symbol_name: String,
+        arg_types: Vec<Type>,
+        optional_args: Vec<bool>,
+        result_type: Type,
+        fixed_args: usize,
+    ) -> anyhow::Result<Self> {
+        Self::build(
+            library_name,
+            symbol_name,
+            arg_types,
+            optional_args,
+            result_type,
+            0,
+            Some(fixed_args),
+        )
+    }
+
+    /// Like [`Self::new`], but the CIF takes `trailing_ptrs` extra pointer
+    /// arguments after `arg_types`. Their values are supplied natively on each
+    /// [`Self::encode_with_trailing_ptrs`] call rather than encoded from
+    /// JavaScript — e.g. a `GAsyncReadyCallback` and its user data.
+    pub(super) fn with_trailing_ptrs(
+        library_name: &str,
+        symbol_name: String,
+        arg_types: Vec<Type>,
+        optional_args: Vec<bool>,
+        result_type: Type,
+        trailing_ptrs: usize,
+    ) -> anyhow::Result<Self> {
+        Self::build(
+            library_name,
+            symbol_name,
+            arg_types,
+            optional_args,
+            result_type,
+            trailing_ptrs,
+            None,
+        )
+    }
+
+    /// Builds the CIF for a function known only by its address, such as an
+    /// inherited vfunc read from a class struct. `label` stands in for the
+    /// symbol name in error messages.
+    pub(super) fn from_code_ptr(
+        label: String,
+        arg_types: Vec<Type>,
+        optional_args: Vec<bool>,
+        result_type: Type,
+        code_ptr: *mut c_void,
+    ) -> anyhow::Result<Self> {
+        Self::assemble(
+            label,
+            arg_types,
+            optional_args,
+            result_type,
+            0,
+            None,
+            libffi::CodePtr(code_ptr),
+        )
+    }
+
+    fn build(
+        library_name: &str,
+        symbol_name: String,
+        arg_types: Vec<Type>,
+        optional_args: Vec<bool>,
+        result_type: Type,
+        trailing_ptrs: usize,
+        fixed_args: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        let code_ptr = unsafe {
+            GtkThreadState::with::<_, anyhow::Result<libffi::CodePtr>>(|state| {
+                let library = state.library(library_name)?;
+                let symbol = library.get::<unsafe extern "C" fn() -> ()>(symbol_name.as_bytes())?;
+
+                let ptr = *symbol as *mut c_void;
+                Ok(libffi::CodePtr(ptr))
+            })?
+        };
+
+        Self::assemble(
+            symbol_name,
+            arg_types,
+            optional_args,
+            result_type,
+            trailing_ptrs,
+            fixed_args,
+            code_ptr,
+        )
+    }
+
+    fn assemble(
+        symbol_name: String,
+        arg_types: Vec<Type>,
+        optional_args: Vec<bool>,
+        result_type: Type,
+        trailing_ptrs: usize,
+        fixed_args: Option<usize>,
+        code_ptr: libffi::CodePtr,
+    ) -> anyhow::Result<Self> {
+        let fixed = fixed_args.unwrap_or(arg_types.len());
+        if fixed > arg_types.len() {
+            bail!(
+                "{symbol_name} declares {fixed} fixed argument(s), got {}",
+                arg_types.len()
+            );
         }
 
-        let cif = libffi::Builder::new()
-            .res(self.result_type.libffi_type())
-            .args(arg_types)
-            .into_cif();
+        let mut ffi_arg_types: Vec<libffi::Type> =
+            Vec::with_capacity(arg_types.len() + trailing_ptrs + 1);
+        for ty in &arg_types[..fixed] {
+            ty.append_ffi_arg_types(&mut ffi_arg_types);
+        }
+        // Callback arguments expand to several libffi arguments, so the fixed
+        // count libffi needs is only known once the fixed part is laid out.
+        let ffi_fixed_args = ffi_arg_types.len();
+        for ty in &arg_types[fixed..] {
+            ty.append_ffi_arg_types(&mut ffi_arg_types);
+        }
+        ffi_arg_types.extend(std::iter::repeat_with(libffi::Type::pointer).take(trailing_ptrs));
 
-        let ffi_values = self
-            .args
+        let cif = if fixed_args.is_some() {
+            libffi::Cif::new_variadic(ffi_arg_types, ffi_fixed_args, result_type.libffi_type())
+        } else {
+            libffi::Builder::new()
+                .res(result_type.libffi_type())
+                .args(ffi_arg_types)
+                .into_cif()
+        };
+
+        let params = arg_types
+            .into_iter()
+            .zip(optional_args)
+            .map(|(ty, optional)| Arg {
+                ty,
+                value: Value::Null,
+                optional,
+            })
+            .collect();
+
+        Ok(Self {
+            symbol_name,
+            params,
+            result_type,
+            trailing_ptrs,
+            cif,
+            code_ptr,
+        })
+    }
+
+    /// Argument types the call was prepared with.
+    pub(super) fn arg_types(&self) -> impl Iterator<Item = &Type> {
+        self.params.iter().map(|param| &param.ty)
+    }
+
+    /// Calls the symbol with `values` as the values of the prepared
+    /// arguments, like [`Self::invoke`].
+    pub(super) fn invoke_values(
+        &self,
+        values: &[Value],
+    ) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        if values.len() != self.params.len() {
+            bail!(
+                "{} expects {} argument(s), got {}",
+                self.symbol_name,
+                self.params.len(),
+                values.len()
+            );
+        }
+
+        self.call(&self.params, &values.iter().collect::<Vec<_>>(), &[])
+    }
+
+    /// Encodes `args`, calls the symbol through the cached CIF, and decodes
+    /// the return value and any `Ref` write-backs. Fails with a
+    /// [`GErrorDetails`] when the callee sets a `GError` out-parameter.
+    pub(super) fn invoke(&self, args: &[Arg]) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        let values: Vec<&Value> = args.iter().map(|arg| &arg.value).collect();
+        self.call(args, &values, &[])
+    }
+
+    /// Encodes `args` followed by `trailing_ptrs` for
+    /// [`Self::invoke_encoded`], without calling the symbol. The pointer count
+    /// must match the one the call was prepared with.
+    pub(super) fn encode_with_trailing_ptrs(
+        &self,
+        args: &[Arg],
+        trailing_ptrs: &[*mut c_void],
+    ) -> anyhow::Result<EncodedArgs> {
+        let values: Vec<&Value> = args.iter().map(|arg| &arg.value).collect();
+        self.encode(args, &values, trailing_ptrs)
+    }
+
+    /// Calls the symbol with `encoded`, the encoding of `args`, and decodes
+    /// the outcome like [`Self::invoke`]. An error means the symbol has run,
+    /// unless the return type cannot be called through the CIF.
+    pub(super) fn invoke_encoded(
+        &self,
+        args: &[Arg],
+        encoded: &EncodedArgs,
+    ) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        let values: Vec<&Value> = args.iter().map(|arg| &arg.value).collect();
+        self.call_encoded(args, &values, encoded)
+    }
+
+    /// Encodes `values`, typed by the matching `params`, and runs the call.
+    /// `params` also serve as the decoding context of the return value and
+    /// `Ref` write-backs; their own values are ignored.
+    fn call(
+        &self,
+        params: &[Arg],
+        values: &[&Value],
+        trailing_ptrs: &[*mut c_void],
+    ) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        let encoded = self.encode(params, values, trailing_ptrs)?;
+        self.call_encoded(params, values, &encoded)
+    }
+
+    fn encode(
+        &self,
+        params: &[Arg],
+        values: &[&Value],
+        trailing_ptrs: &[*mut c_void],
+    ) -> anyhow::Result<EncodedArgs> {
+        if trailing_ptrs.len() != self.trailing_ptrs {
+            bail!(
+                "{} expects {} trailing pointer(s), got {}",
+                self.symbol_name,
+                self.trailing_ptrs,
+                trailing_ptrs.len()
+            );
+        }
+
+        let ffi_values = params
             .iter()
+            .zip(values)
             .enumerate()
-            .map(|(i, arg)| {
-                arg.ty
-                    .encode(&arg.value, arg.optional)
+            .map(|(i, (param, value))| {
+                param
+                    .ty
+                    .encode(value, param.optional)
                     .with_context(|| format!("encoding arg {} of {}", i, self.symbol_name))
             })
             .collect::<anyhow::Result<Vec<ffi::FfiValue>>>()?;
 
-        let mut ffi_args: Vec<libffi::Arg> = Vec::with_capacity(ffi_values.len() + 1);
-        for ffi_value in &ffi_values {
-            ffi_value.append_libffi_args(&mut ffi_args);
-        }
+        let trailing_values: Vec<ffi::FfiValue> = trailing_ptrs
+            .iter()
+            .map(|&ptr| ffi::FfiValue::Ptr(ptr))
+            .collect();
 
-        let symbol_ptr = unsafe {
-            GtkThreadState::with::<_, anyhow::Result<libffi::CodePtr>>(|state| {
-                let library = state.library(&self.library_name)?;
-                let symbol =
-                    library.get::<unsafe extern "C" fn() -> ()>(self.symbol_name.as_bytes())?;
+        Ok(EncodedArgs {
+            ffi_values,
+            trailing_values,
+        })
+    }
 
-                let ptr = *symbol as *mut c_void;
-                Ok(libffi::CodePtr(ptr))
-            })?
-        };
+    /// Runs the call with the `encoded` arguments, then checks the `GError`
+    /// out-parameters and decodes the return value and `Ref` write-backs.
+    fn call_encoded(
+        &self,
+        params: &[Arg],
+        values: &[&Value],
+        encoded: &EncodedArgs,
+    ) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        let EncodedArgs {
+            ffi_values,
+            trailing_values,
+        } = encoded;
+
+        let mut ffi_args: Vec<libffi::Arg> =
+            Vec::with_capacity(ffi_values.len() + trailing_values.len() + 1);
+        for ffi_value in ffi_values.iter().chain(&trailing_values) {
+            ffi_value.append_libffi_args(&mut ffi_args);
+        }
 
         let result = self
             .result_type
-            .call_cif(&cif, symbol_ptr, &ffi_args)
+            .call_cif(&self.cif, self.code_ptr, &ffi_args)
             .with_context(|| format!("calling {}", self.symbol_name))?;
 
-        let ref_updates = self.collect_ref_updates(&ffi_values)?;
+        for (param, ffi_value) in params.iter().zip(ffi_values) {
+            if matches!(param.ty, Type::GError(_))
+                && let Some(error) = GErrorType::take_error(ffi_value)?
+            {
+                return Err(GErrorDetails::from(error).into());
+            }
+        }
+
+        let ref_updates = collect_ref_updates(params, values, ffi_values)?;
 
         let return_value =
-            Value::from_ffi_value_with_args(&result, &self.result_type, &ffi_values, &self.args)
+            Value::from_ffi_value_with_args(&result, &self.result_type, ffi_values, params)
                 .with_context(|| format!("decoding return value of {}", self.symbol_name))?;
         Ok((return_value, ref_updates))
     }
+}
+
+/// Arguments encoded by [`PreparedCall`] ahead of a call, which must outlive
+/// it since they own the memory the passed pointers refer to.
+pub(super) struct EncodedArgs {
+    ffi_values: Vec<ffi::FfiValue>,
+    trailing_values: Vec<ffi::FfiValue>,
+}
+
+/// Collects the out-parameter write-backs for `Ref`-typed arguments.
+///
+/// Excluded from coverage instrumentation: a `Value::Ref` carries an
+/// `Arc<JsRef<JsObject>>`, which only exists when a live JavaScript runtime
+/// produced it, so this path cannot run under `cargo test`.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn collect_ref_updates(
+    params: &[Arg],
+    values: &[&Value],
+    ffi_values: &[ffi::FfiValue],
+) -> anyhow::Result<Vec<RefUpdate>> {
+    let mut ref_updates = Vec::new();
+    for (i, (param, value)) in params.iter().zip(values).enumerate() {
+        if let Value::Ref(ref_val) = value {
+            let new_value =
+                Value::from_ffi_value_with_args(&ffi_values[i], &param.ty, ffi_values, params)?;
+            ref_updates.push((Arc::clone(&ref_val.js_obj), new_value));
+        }
+    }
+    Ok(ref_updates)
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct CallRequest {
+    library_name: String,
+    symbol_name: String,
+    args: Vec<Arg>,
+    result_type: Type,
+    /// Number of named parameters of a variadic callee, or `None` for a
+    /// fixed-arity one.
+    fixed_args: Option<usize>,
+}
+
+impl ModuleRequest for CallRequest {
+    type Output = (Value, Vec<RefUpdate>);
+
+    /// Variadic arguments are encoded as their default-promoted types, so an
+    /// `int8` or `float32` past the fixed ones reaches the callee as the `int`
+    /// or `double` that `va_arg` reads. A number is first checked against,
+    /// and narrowed to, the type it was declared as.
+    fn execute(mut self) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        if let Some(fixed_args) = self.fixed_args {
+            for (i, arg) in self.args.iter_mut().enumerate().skip(fixed_args) {
+                if matches!(arg.ty, Type::Integer(_) | Type::Float(_)) {
+                    let declared = arg
+                        .ty
+                        .encode(&arg.value, arg.optional)
+                        .with_context(|| format!("encoding arg {i} of {}", self.symbol_name))?;
+                    if let ffi::FfiValue::F32(narrowed) = declared {
+                        arg.value = Value::Number(f64::from(narrowed));
+                    }
+                }
+                arg.ty = arg.ty.variadic_promoted();
+            }
+        }
+
+        let arg_types = self.args.iter().map(|arg| arg.ty.clone()).collect();
+        let optional_args = self.args.iter().map(|arg| arg.optional).collect();
+        let prepared = match self.fixed_args {
+            Some(fixed_args) => PreparedCall::variadic(
+                &self.library_name,
+                self.symbol_name,
+                arg_types,
+                optional_args,
+                self.result_type,
+                fixed_args,
+            ),
+            None => PreparedCall::new(
+                &self.library_name,
+                self.symbol_name,
+                arg_types,
+                optional_args,
+                self.result_type,
+            ),
+        }?;
+        prepared.invoke(&self.args)
+    }
 
     fn error_context() -> &'static str {
         "FFI call"
@@ -105,37 +518,205 @@ impl ModuleRequest for CallRequest {
 }
 
 impl CallRequest {
-    /// Collects the out-parameter write-backs for `Ref`-typed arguments.
+    /// Checks that the call can run without the JS thread waiting on it: it
+    /// must return `void` and take no `Ref` out-parameters, since there is
+    /// nobody left to receive either.
+    fn ensure_fire_and_forget(&self) -> anyhow::Result<()> {
+        if !matches!(self.result_type, Type::Void(_)) {
+            bail!(
+                "{} returns '{}', only void calls can be enqueued",
+                self.symbol_name,
+                self.result_type
+            );
+        }
+        if let Some(i) = self
+            .args
+            .iter()
+            .position(|arg| matches!(arg.ty, Type::Ref(_)) || matches!(arg.value, Value::Ref(_)))
+        {
+            bail!(
+                "arg {i} of {} is a ref, calls with out-parameters cannot be enqueued",
+                self.symbol_name
+            );
+        }
+        Ok(())
+    }
+
+    /// Queues the call on the `GLib` thread without waiting for it.
     ///
-    /// Excluded from coverage instrumentation: a `Value::Ref` carries an
-    /// `Arc<JsRef<JsObject>>`, which only exists when a live JavaScript runtime
-    /// produced it, so this path cannot run under `cargo test`.
-    #[cfg_attr(coverage_nightly, coverage(off))]
-    fn collect_ref_updates(&self, ffi_values: &[ffi::FfiValue]) -> anyhow::Result<Vec<RefUpdate>> {
-        let mut ref_updates = Vec::new();
-        for (i, arg) in self.args.iter().enumerate() {
-            if let Value::Ref(ref_val) = &arg.value {
-                let new_value = Value::from_ffi_value_with_args(
-                    &ffi_values[i],
-                    &arg.ty,
-                    ffi_values,
-                    &self.args,
-                )?;
-                ref_updates.push((Arc::clone(&ref_val.js_obj), new_value));
+    /// The task is tagged with the current callback depth like any other
+    /// `GLib` task, so it keeps its place relative to calls made before and
+    /// after it. Failures surface through [`NativeErrorReporter`], tagged with
+    /// the symbol name.
+    fn enqueue(self) {
+        Mailbox::global().schedule_glib(Box::new(move || {
+            let symbol_name = self.symbol_name.clone();
+            if let Err(e) = self.execute() {
+                NativeErrorReporter::global()
+                    .report(&e.context(format!("enqueued call to {symbol_name} failed")));
             }
+        }));
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct CallBatchRequest {
+    calls: Vec<CallRequest>,
+}
+
+/// Context of the error a [`CallBatchRequest`] fails with, naming the call
+/// that failed. [`request_error`](super::handler::request_error) also reads
+/// it off a `GError` failure, whose message does not include it.
+#[derive(Debug)]
+pub(super) struct BatchCallFailed {
+    pub(super) index: usize,
+    symbol_name: String,
+}
+
+impl std::fmt::Display for BatchCallFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "batch call {} ({}) failed", self.index, self.symbol_name)
+    }
+}
+
+impl ModuleRequest for CallBatchRequest {
+    type Output = (Value, Vec<RefUpdate>);
+
+    /// Runs every call in order as a single `GLib` task, stopping at the first
+    /// failure. The error names the index and symbol of the failing call.
+    ///
+    /// A failed batch returns no values, so the `Ref` write-backs of the calls
+    /// that completed before the failing one are discarded: their `Ref`
+    /// objects keep the values they were passed with.
+    fn execute(self) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        let mut results = Vec::with_capacity(self.calls.len());
+        let mut ref_updates = Vec::new();
+
+        for (index, call) in self.calls.into_iter().enumerate() {
+            let symbol_name = call.symbol_name.clone();
+            let (value, updates) = call
+                .execute()
+                .context(BatchCallFailed { index, symbol_name })?;
+            results.push(value);
+            ref_updates.extend(updates);
+        }
+
+        Ok((Value::Array(results), ref_updates))
+    }
+
+    fn error_context() -> &'static str {
+        "batched FFI call"
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct PrepareRequest {
+    library_name: String,
+    symbol_name: String,
+    arg_types: Vec<Type>,
+    optional_args: Vec<bool>,
+    result_type: Type,
+}
+
+impl ModuleRequest for PrepareRequest {
+    type Output = Arc<PreparedCall>;
+
+    fn execute(self) -> anyhow::Result<Arc<PreparedCall>> {
+        PreparedCall::new(
+            &self.library_name,
+            self.symbol_name,
+            self.arg_types,
+            self.optional_args,
+            self.result_type,
+        )
+        .map(Arc::new)
+    }
+
+    fn error_context() -> &'static str {
+        "call preparation"
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct CallPreparedRequest {
+    prepared: Arc<PreparedCall>,
+    values: Vec<Value>,
+}
+
+impl ModuleRequest for CallPreparedRequest {
+    type Output = (Value, Vec<RefUpdate>);
+
+    fn execute(self) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        self.prepared.invoke_values(&self.values)
+    }
+
+    fn error_context() -> &'static str {
+        "FFI call"
+    }
+}
+
+/// Hands the prepared call to JavaScript as an opaque external. Excluded from
+/// coverage instrumentation: it requires a live [`napi::Env`].
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl ModuleResponse for Arc<PreparedCall> {
+    fn to_js_response(self, env: &Env) -> napi::Result<Unknown<'_>> {
+        unsafe {
+            let external = External::new(self);
+            let raw = External::<Self>::to_napi_value(env.raw(), external)?;
+            Ok(Unknown::from_raw_unchecked(env.raw(), raw))
         }
-        Ok(ref_updates)
     }
 }
 
+/// Parses a JS return type descriptor, rejecting types that cannot occupy the
+/// return slot. Excluded from coverage instrumentation: it requires a live
+/// [`napi::Env`].
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(super) fn parse_return_type(env: &Env, return_type: Unknown<'_>) -> napi::Result<Type> {
+    let result_type = Type::from_js_value(env, return_type)?;
+    if !result_type.can_be_return_type() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("'{result_type}' cannot be used as a function return type"),
+        ));
+    }
+    Ok(result_type)
+}
+
 /// napi export shim. Excluded from coverage instrumentation: it parses JS
-/// arguments through a live [`napi::Env`]. The [`CallRequest::execute`] logic
+/// arguments through a live [`napi::Env`]. The `execute` logic of the requests
 /// it dispatches is exercised directly by tests.
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[allow(clippy::wildcard_imports)]
 mod napi_export {
     use super::*;
 
+    /// Parses one `{ type, optional? }` argument descriptor.
+    fn parse_arg_descriptor(env: &Env, value: Unknown<'_>) -> napi::Result<(Type, bool)> {
+        let obj: JsObject = unsafe { JsObject::from_napi_value(env.raw(), value.raw())? };
+        let type_prop: Unknown<'_> = obj.get_named_property("type")?;
+        let ty = Type::from_js_value(env, type_prop)?;
+        let optional = obj
+            .get_named_property::<Option<bool>>("optional")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+        Ok((ty, optional))
+    }
+
+    /// Reads `variadic.fixedArgs` from the options of a [`call`].
+    fn parse_fixed_args(options: Option<&JsObject>) -> napi::Result<Option<usize>> {
+        let Some(variadic) = options
+            .map(|options| options.get_named_property::<Option<JsObject>>("variadic"))
+            .transpose()?
+            .flatten()
+        else {
+            return Ok(None);
+        };
+        let fixed_args: u32 = variadic.get_named_property("fixedArgs")?;
+        Ok(Some(fixed_args as usize))
+    }
+
     #[napi]
     #[cfg_attr(test, allow(dead_code))]
     pub fn call<'env>(
@@ -144,20 +725,146 @@ mod napi_export {
         symbol: String,
         args: Array,
         return_type: Unknown<'_>,
+        options: Option<JsObject>,
     ) -> napi::Result<Unknown<'env>> {
         let parsed_args = Arg::from_js_array(env, &args)?;
-        let result_type = Type::from_js_value(env, return_type)?;
-        if !result_type.can_be_return_type() {
-            return Err(napi::Error::new(
-                napi::Status::InvalidArg,
-                format!("'{result_type}' cannot be used as a function return type"),
-            ));
-        }
+        let result_type = parse_return_type(env, return_type)?;
         let request = CallRequest {
             library_name: library,
             symbol_name: symbol,
             args: parsed_args,
             result_type,
+            fixed_args: parse_fixed_args(options.as_ref())?,
+        };
+        request.dispatch(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn call_promise<'env>(
+        env: &'env Env,
+        library: String,
+        symbol: String,
+        args: Array,
+        return_type: Unknown<'_>,
+    ) -> napi::Result<Object<'env>> {
+        let parsed_args = Arg::from_js_array(env, &args)?;
+        let result_type = parse_return_type(env, return_type)?;
+        let request = CallRequest {
+            library_name: library,
+            symbol_name: symbol,
+            args: parsed_args,
+            result_type,
+            fixed_args: None,
+        };
+        request.dispatch_promise(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn enqueue(
+        env: &Env,
+        library: String,
+        symbol: String,
+        args: Array,
+        return_type: Option<Unknown<'_>>,
+    ) -> napi::Result<()> {
+        let result_type = match return_type {
+            Some(return_type) => parse_return_type(env, return_type)?,
+            None => Type::Void(VoidType),
+        };
+        let request = CallRequest {
+            library_name: library,
+            symbol_name: symbol,
+            args: Arg::from_js_array(env, &args)?,
+            result_type,
+            fixed_args: None,
+        };
+        request
+            .ensure_fire_and_forget()
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+        request.enqueue();
+        Ok(())
+    }
+
+    /// Blocks until every call enqueued so far has run.
+    ///
+    /// Implemented as an empty task queued behind them. Inside a JS callback
+    /// this only waits for work enqueued at that callback's depth or deeper;
+    /// shallower work is held back until the callback returns by design.
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn flush(env: Env) -> napi::Result<()> {
+        Mailbox::global()
+            .dispatch_to_glib_and_wait(env, || ())
+            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))
+    }
+
+    /// Parses one `{ library, symbol, args, returnType }` batch entry.
+    fn parse_batch_call(env: &Env, value: Unknown<'_>) -> napi::Result<CallRequest> {
+        let obj: JsObject = unsafe { JsObject::from_napi_value(env.raw(), value.raw())? };
+        let library_name: String = obj.get_named_property("library")?;
+        let symbol_name: String = obj.get_named_property("symbol")?;
+        let args: Array = obj.get_named_property("args")?;
+        let return_type: Unknown<'_> = obj.get_named_property("returnType")?;
+        Ok(CallRequest {
+            library_name,
+            symbol_name,
+            args: Arg::from_js_array(env, &args)?,
+            result_type: parse_return_type(env, return_type)?,
+            fixed_args: None,
+        })
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn call_batch<'env>(env: &'env Env, calls: Array) -> napi::Result<Unknown<'env>> {
+        let request = CallBatchRequest {
+            calls: crate::value::map_js_array(env, &calls, parse_batch_call)?,
+        };
+        request.dispatch(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn prepare<'env>(
+        env: &'env Env,
+        library: String,
+        symbol: String,
+        arg_types: Array,
+        return_type: Unknown<'_>,
+    ) -> napi::Result<Unknown<'env>> {
+        let (arg_types, optional_args): (Vec<Type>, Vec<bool>) =
+            crate::value::map_js_array(env, &arg_types, parse_arg_descriptor)?
+                .into_iter()
+                .unzip();
+        let result_type = parse_return_type(env, return_type)?;
+        let request = PrepareRequest {
+            library_name: library,
+            symbol_name: symbol,
+            arg_types,
+            optional_args,
+            result_type,
+        };
+        request.dispatch(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn call_prepared<'env>(
+        env: &'env Env,
+        handle: &External<Arc<PreparedCall>>,
+        values: Array,
+    ) -> napi::Result<Unknown<'env>> {
+        let mut arg_types = handle.arg_types();
+        let values =
+            crate::value::map_js_array(env, &values, |env, value| match arg_types.next() {
+                Some(ty) => Value::from_js_value_typed(env, value, ty),
+                None => Value::from_js_value(env, value),
+            })?;
+        let request = CallPreparedRequest {
+            prepared: Arc::clone(handle),
+            values,
         };
         request.dispatch(env)
     }
@@ -165,7 +872,11 @@ mod napi_export {
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{ArrayKind, ArrayType, IntegerKind, Ownership, RefType, StringType};
+    use gtk4::glib;
+
+    use crate::types::{
+        ArrayKind, ArrayType, FloatKind, IntegerKind, Ownership, RefType, StringType,
+    };
 
     use super::*;
 
@@ -180,6 +891,7 @@ mod tests {
             symbol_name: "g_random_int_range".into(),
             args: vec![int_arg(10.0), int_arg(20.0)],
             result_type: Type::Integer(IntegerKind::I32),
+            fixed_args: None,
         };
         let (value, ref_updates) = request.execute().expect("FFI call should succeed");
         assert!(ref_updates.is_empty());
@@ -194,6 +906,7 @@ mod tests {
             symbol_name: "g_no_such_symbol_12345".into(),
             args: vec![],
             result_type: Type::Integer(IntegerKind::I32),
+            fixed_args: None,
         };
         assert!(request.execute().is_err());
     }
@@ -208,6 +921,7 @@ mod tests {
                 Value::String("not a number".into()),
             )],
             result_type: Type::Integer(IntegerKind::I32),
+            fixed_args: None,
         };
         let err = request
             .execute()
@@ -215,6 +929,52 @@ mod tests {
         assert!(err.to_string().contains("encoding arg 0"));
     }
 
+    fn filename_to_uri_call(filename: &str) -> CallRequest {
+        let string = Type::String(StringType {
+            ownership: Ownership::Borrowed,
+            length: None,
+        });
+        CallRequest {
+            library_name: "libglib-2.0.so.0".into(),
+            symbol_name: "g_filename_to_uri".into(),
+            args: vec![
+                Arg::new(string.clone(), Value::String(filename.into())),
+                Arg {
+                    ty: string,
+                    value: Value::Null,
+                    optional: true,
+                },
+                Arg::new(Type::GError(GErrorType), Value::Null),
+            ],
+            result_type: Type::String(StringType {
+                ownership: Ownership::Full,
+                length: None,
+            }),
+            fixed_args: None,
+        }
+    }
+
+    #[test]
+    fn execute_succeeds_when_the_gerror_slot_stays_empty() {
+        let (value, _) = filename_to_uri_call("/tmp")
+            .execute()
+            .expect("an absolute path should convert");
+        assert!(matches!(value, Value::String(uri) if uri == "file:///tmp"));
+    }
+
+    #[test]
+    fn execute_fails_with_the_gerror_set_by_the_callee() {
+        let err = filename_to_uri_call("relative/path")
+            .execute()
+            .expect_err("a relative path should not convert");
+        let details = err
+            .downcast_ref::<GErrorDetails>()
+            .expect("the failure should carry the GError");
+        assert_eq!(details.domain, "g_convert_error");
+        assert_eq!(details.code, glib::ffi::G_CONVERT_ERROR_NOT_ABSOLUTE_PATH);
+        assert!(!details.message.is_empty());
+    }
+
     #[test]
     fn execute_fails_when_result_type_cannot_occupy_return_slot() {
         let request = CallRequest {
@@ -222,6 +982,7 @@ mod tests {
             symbol_name: "g_random_int".into(),
             args: vec![],
             result_type: Type::Ref(RefType::new(Type::Integer(IntegerKind::I32))),
+            fixed_args: None,
         };
         let err = request
             .execute()
@@ -246,7 +1007,9 @@ mod tests {
                 kind: ArrayKind::Sized { size_index: 9 },
                 ownership: Ownership::Borrowed,
                 element_size: None,
+                typed: false,
             }),
+            fixed_args: None,
         };
         let err = request
             .execute()
@@ -257,8 +1020,300 @@ mod tests {
         );
     }
 
+    fn strdup_printf_call(args: Vec<Arg>, fixed_args: usize) -> CallRequest {
+        CallRequest {
+            library_name: "libglib-2.0.so.0".into(),
+            symbol_name: "g_strdup_printf".into(),
+            args,
+            result_type: Type::String(StringType {
+                ownership: Ownership::Full,
+                length: None,
+            }),
+            fixed_args: Some(fixed_args),
+        }
+    }
+
+    fn borrowed_string_arg(value: &str) -> Arg {
+        let string = Type::String(StringType {
+            ownership: Ownership::Borrowed,
+            length: None,
+        });
+        Arg::new(string, Value::String(value.into()))
+    }
+
+    #[test]
+    fn execute_promotes_variadic_arguments() {
+        let request = strdup_printf_call(
+            vec![
+                borrowed_string_arg("%d|%u|%.2f|%s"),
+                Arg::new(Type::Integer(IntegerKind::I8), Value::Number(-5.0)),
+                Arg::new(Type::Integer(IntegerKind::U16), Value::Number(65535.0)),
+                Arg::new(Type::Float(FloatKind::F32), Value::Number(1.5)),
+                borrowed_string_arg("x"),
+            ],
+            1,
+        );
+        let (value, _) = request.execute().expect("variadic call should succeed");
+        assert!(matches!(value, Value::String(s) if s == "-5|65535|1.50|x"));
+    }
+
+    #[test]
+    fn execute_checks_variadic_arguments_against_their_declared_types() {
+        let request = strdup_printf_call(
+            vec![
+                borrowed_string_arg("%u"),
+                Arg::new(Type::Integer(IntegerKind::U8), Value::Number(300.0)),
+            ],
+            1,
+        );
+        let err = request
+            .execute()
+            .expect_err("300 does not fit the declared uint8");
+        assert_eq!(
+            format!("{err:#}"),
+            "encoding arg 1 of g_strdup_printf: Value 300 is out of range for u8 [0, 255]"
+        );
+
+        let request = strdup_printf_call(
+            vec![
+                borrowed_string_arg("%.10f"),
+                Arg::new(Type::Float(FloatKind::F32), Value::Number(0.1)),
+            ],
+            1,
+        );
+        let (value, _) = request.execute().expect("variadic call should succeed");
+        assert!(matches!(value, Value::String(s) if s == "0.1000000015"));
+    }
+
+    #[test]
+    fn execute_accepts_a_variadic_call_without_variadic_arguments() {
+        let request = strdup_printf_call(vec![borrowed_string_arg("plain")], 1);
+        let (value, _) = request.execute().expect("variadic call should succeed");
+        assert!(matches!(value, Value::String(s) if s == "plain"));
+    }
+
+    #[test]
+    fn execute_fails_when_fixed_args_exceed_the_arguments() {
+        let request = strdup_printf_call(vec![borrowed_string_arg("plain")], 2);
+        let err = request
+            .execute()
+            .expect_err("more fixed arguments than arguments should fail");
+        assert!(
+            err.to_string()
+                .contains("g_strdup_printf declares 2 fixed argument(s), got 1")
+        );
+    }
+
     #[test]
     fn error_context_is_ffi_call() {
         assert_eq!(CallRequest::error_context(), "FFI call");
     }
+
+    fn random_int_range_call(min: f64, max: f64) -> CallRequest {
+        CallRequest {
+            library_name: "libglib-2.0.so.0".into(),
+            symbol_name: "g_random_int_range".into(),
+            args: vec![int_arg(min), int_arg(max)],
+            result_type: Type::Integer(IntegerKind::I32),
+            fixed_args: None,
+        }
+    }
+
+    #[test]
+    fn batch_runs_every_call_in_order() {
+        let request = CallBatchRequest {
+            calls: vec![
+                random_int_range_call(0.0, 1.0),
+                random_int_range_call(5.0, 6.0),
+                random_int_range_call(9.0, 10.0),
+            ],
+        };
+        let (value, ref_updates) = request.execute().expect("batch should succeed");
+        assert!(ref_updates.is_empty());
+        let Value::Array(results) = value else {
+            panic!("batch result should be an array");
+        };
+        let numbers: Vec<f64> = results.iter().filter_map(Value::as_number).collect();
+        assert_eq!(numbers, [0.0, 5.0, 9.0]);
+    }
+
+    #[test]
+    fn batch_stops_at_first_failure_and_reports_its_index() {
+        let request = CallBatchRequest {
+            calls: vec![
+                random_int_range_call(0.0, 1.0),
+                CallRequest {
+                    library_name: "libglib-2.0.so.0".into(),
+                    symbol_name: "g_no_such_symbol_12345".into(),
+                    args: vec![],
+                    result_type: Type::Integer(IntegerKind::I32),
+                    fixed_args: None,
+                },
+                random_int_range_call(0.0, 1.0),
+            ],
+        };
+        let err = request
+            .execute()
+            .expect_err("a missing symbol should fail the batch");
+        assert!(
+            err.to_string()
+                .contains("batch call 1 (g_no_such_symbol_12345) failed")
+        );
+    }
+
+    #[test]
+    fn batch_keeps_the_index_of_a_call_that_set_a_gerror() {
+        let request = CallBatchRequest {
+            calls: vec![
+                filename_to_uri_call("/tmp"),
+                filename_to_uri_call("relative/path"),
+                filename_to_uri_call("/tmp"),
+            ],
+        };
+        let err = request
+            .execute()
+            .expect_err("a relative path should fail the batch");
+        assert!(err.downcast_ref::<GErrorDetails>().is_some());
+        let failed = err
+            .downcast_ref::<BatchCallFailed>()
+            .expect("the failure should name the batch call");
+        assert_eq!(failed.index, 1);
+        assert_eq!(err.to_string(), "batch call 1 (g_filename_to_uri) failed");
+    }
+
+    #[test]
+    fn empty_batch_returns_an_empty_array() {
+        let (value, _) = CallBatchRequest { calls: vec![] }
+            .execute()
+            .expect("an empty batch should succeed");
+        assert!(matches!(value, Value::Array(results) if results.is_empty()));
+    }
+
+    #[test]
+    fn error_context_is_batched_ffi_call() {
+        assert_eq!(CallBatchRequest::error_context(), "batched FFI call");
+    }
+
+    fn void_call(args: Vec<Arg>, result_type: Type) -> CallRequest {
+        CallRequest {
+            library_name: "libglib-2.0.so.0".into(),
+            symbol_name: "g_usleep".into(),
+            args,
+            result_type,
+            fixed_args: None,
+        }
+    }
+
+    #[test]
+    fn void_call_without_refs_can_be_enqueued() {
+        let request = void_call(
+            vec![Arg::new(
+                Type::Integer(IntegerKind::U64),
+                Value::Number(0.0),
+            )],
+            Type::Void(VoidType),
+        );
+        assert!(request.ensure_fire_and_forget().is_ok());
+    }
+
+    #[test]
+    fn non_void_call_cannot_be_enqueued() {
+        let request = void_call(vec![], Type::Integer(IntegerKind::I32));
+        let err = request
+            .ensure_fire_and_forget()
+            .expect_err("a call returning a value should be rejected");
+        assert!(err.to_string().contains("only void calls can be enqueued"));
+    }
+
+    #[test]
+    fn call_with_ref_arg_cannot_be_enqueued() {
+        let request = void_call(
+            vec![
+                int_arg(1.0),
+                Arg::new(
+                    Type::Ref(RefType::new(Type::Integer(IntegerKind::I32))),
+                    Value::Null,
+                ),
+            ],
+            Type::Void(VoidType),
+        );
+        let err = request
+            .ensure_fire_and_forget()
+            .expect_err("a ref argument should be rejected");
+        assert!(err.to_string().contains("arg 1 of g_usleep is a ref"));
+    }
+
+    fn prepare_random_int_range() -> Arc<PreparedCall> {
+        PrepareRequest {
+            library_name: "libglib-2.0.so.0".into(),
+            symbol_name: "g_random_int_range".into(),
+            arg_types: vec![
+                Type::Integer(IntegerKind::I32),
+                Type::Integer(IntegerKind::I32),
+            ],
+            optional_args: vec![false, false],
+            result_type: Type::Integer(IntegerKind::I32),
+        }
+        .execute()
+        .expect("preparing a known symbol should succeed")
+    }
+
+    #[test]
+    fn prepared_call_runs_repeatedly() {
+        let prepared = prepare_random_int_range();
+        for _ in 0..3 {
+            let request = CallPreparedRequest {
+                prepared: Arc::clone(&prepared),
+                values: vec![Value::Number(10.0), Value::Number(20.0)],
+            };
+            let (value, ref_updates) = request.execute().expect("prepared call should succeed");
+            assert!(ref_updates.is_empty());
+            let n = value.as_number().expect("result should be a number");
+            assert!((10.0..20.0).contains(&n));
+        }
+    }
+
+    #[test]
+    fn prepare_fails_for_unknown_symbol() {
+        let request = PrepareRequest {
+            library_name: "libglib-2.0.so.0".into(),
+            symbol_name: "g_no_such_symbol_12345".into(),
+            arg_types: vec![],
+            optional_args: vec![],
+            result_type: Type::Integer(IntegerKind::I32),
+        };
+        assert!(request.execute().is_err());
+    }
+
+    #[test]
+    fn prepared_call_rejects_wrong_arity() {
+        let request = CallPreparedRequest {
+            prepared: prepare_random_int_range(),
+            values: vec![Value::Number(10.0)],
+        };
+        let err = request
+            .execute()
+            .expect_err("a missing argument should fail the call");
+        assert!(err.to_string().contains("expects 2 argument(s), got 1"));
+    }
+
+    #[test]
+    fn prepared_call_reports_encoding_failures() {
+        let request = CallPreparedRequest {
+            prepared: prepare_random_int_range(),
+            values: vec![Value::Number(10.0), Value::String("nope".into())],
+        };
+        let err = request
+            .execute()
+            .expect_err("encoding a string as an integer should fail");
+        assert!(
+            err.to_string()
+                .contains("encoding arg 1 of g_random_int_range")
+        );
+    }
+
+    #[test]
+    fn error_context_is_call_preparation() {
+        assert_eq!(PrepareRequest::error_context(), "call preparation");
+    }
 }
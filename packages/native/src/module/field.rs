@@ -7,6 +7,7 @@
 //! ## Read Types
 //!
 //! - `Integer` (all sizes and signs)
+//! - `BigInt` (64-bit integers as JS `BigInt`s)
 //! - `Float` (f32, f64)
 //! - `Boolean`
 //! - `String` (as pointer to C string)
@@ -14,24 +15,55 @@
 //! - `Boxed` (as pointer to boxed value)
 //! - `Fundamental` (as pointer to fundamental value)
 //! - `Struct` (as pointer to struct, copied with known size)
+//! - By-value `Boxed` / `Struct` (embedded in the parent, copied out)
+//! - `Record` (the struct at the offset, decoded to a plain object)
+//! - `Union` (the union at the offset, decoded to a `{ tag, value }` object)
+//! - `inline` arrays (fixed-length C arrays embedded at the offset)
+//! - `GValue` (the `GValue` at the offset, decoded by the type it holds)
 //!
 //! ## Write Types
 //!
 //! - `Integer` (all sizes and signs)
+//! - `BigInt` (64-bit integers, range-checked)
 //! - `Float` (f32, f64)
 //! - `Boolean`
 //! - `String` (copies via `g_strdup`)
 //! - `GObject` / `Boxed` / `Struct` / `Fundamental` (writes pointer value)
+//! - By-value `Boxed` / `Struct` (copies the struct bytes in place)
+//! - `Record` (writes the fields an object holds into the struct at the offset)
+//! - `Union` (writes the tagged variant and its discriminant at the offset)
+//! - `inline` arrays (writes up to `fixedSize` elements in place)
+//! - `GValue` (unsets the `GValue` at the offset and stores the new value)
+//!
+//! ## Bitfields
+//!
+//! A read or write with a `bitWidth` (and optional `bitOffset`) accesses a C
+//! bitfield inside the integer at the offset rather than the whole integer.
+//! `Integer`, `enum`/`flags` and `Boolean` (a `guint` unit) fields can be
+//! bitfields. Writes leave the unit's other bits untouched and reject values
+//! that do not fit the width.
+//!
+//! ## Bulk Access
+//!
+//! `readMany` and `writeMany` access several fields of one handle in a single
+//! `GLib` task. A bulk write is all-or-nothing: the fields are written, in
+//! order, into a copy of the bytes they span, which replaces the original only
+//! once every value has been encoded. A rejected value leaves the memory
+//! untouched. Fields whose writes allocate or release memory — strings,
+//! `GBytes` and `GValue`s, alone or nested in an embedded field — cannot be
+//! written in bulk, since discarding the copy would leak the new values or
+//! free the old ones.
 
 use std::ffi::c_void;
 
-use napi::Env;
 use napi::bindgen_prelude::*;
+use napi::{Env, JsObject};
 use napi_derive::napi;
 
 use super::handler::ModuleRequest;
+use crate::ffi::AggregateBuffer;
 use crate::managed::NativeHandle;
-use crate::types::{RawPtrCodec as _, Type};
+use crate::types::{BoxedType, IntegerKind, RawPtrCodec as _, StructType, Type, embedded_size};
 use crate::value::Value;
 
 /// The address of a field inside a boxed/structured native value: the base
@@ -54,10 +86,130 @@ impl FieldLocation {
     }
 }
 
+/// A C bitfield: `width` bits starting `offset` bits into the integer storage
+/// unit at the field's byte offset. The unit is the field type's own integer
+/// (`guint flag : 1` is a `uint32` unit), and bits are counted the way C
+/// compilers allocate them: from the least significant bit on little-endian
+/// targets and from the most significant bit on big-endian ones.
+#[derive(Debug, Clone, Copy)]
+struct BitRange {
+    offset: u32,
+    width: u32,
+}
+
+impl BitRange {
+    /// The storage unit holding a bitfield of `field_type`.
+    fn unit(field_type: &Type) -> anyhow::Result<IntegerKind> {
+        match field_type {
+            Type::Integer(kind) => Ok(*kind),
+            Type::Tagged(tagged) => Ok(tagged.storage),
+            Type::Boolean(_) => Ok(IntegerKind::U32),
+            other => anyhow::bail!("{other} cannot be the type of a bitfield"),
+        }
+    }
+
+    /// The shift and mask that isolate the bitfield within its unit.
+    fn layout(self, unit: IntegerKind) -> anyhow::Result<(u32, u64)> {
+        let unit_bits = unit.byte_size() as u32 * 8;
+        if self.width == 0
+            || self
+                .offset
+                .checked_add(self.width)
+                .is_none_or(|end| end > unit_bits)
+        {
+            anyhow::bail!(
+                "Bitfield of {} bits at bit {} does not fit in a {unit_bits}-bit unit",
+                self.width,
+                self.offset
+            );
+        }
+        let shift = if cfg!(target_endian = "little") {
+            self.offset
+        } else {
+            unit_bits - self.offset - self.width
+        };
+        Ok((shift, u64::MAX >> (64 - self.width)))
+    }
+
+    fn read(self, ptr: *const c_void, field_type: &Type) -> anyhow::Result<Value> {
+        let unit = Self::unit(field_type)?;
+        let (shift, mask) = self.layout(unit)?;
+        let bits = (load_unit(ptr, unit) >> shift) & mask;
+        Ok(match field_type {
+            Type::Boolean(_) => Value::Boolean(bits != 0),
+            _ if unit.is_unsigned() => Value::Number(bits as f64),
+            _ => {
+                let spare = 64 - self.width;
+                Value::Number((((bits << spare) as i64) >> spare) as f64)
+            }
+        })
+    }
+
+    /// Replaces the bitfield's bits, leaving the rest of the unit untouched.
+    fn write(self, ptr: *mut c_void, field_type: &Type, value: &Value) -> anyhow::Result<()> {
+        let unit = Self::unit(field_type)?;
+        let (shift, mask) = self.layout(unit)?;
+        let bits = match (field_type, value) {
+            (Type::Boolean(_), Value::Boolean(b)) => u64::from(*b),
+            (Type::Boolean(_), _) => {
+                anyhow::bail!("Expected a Boolean for boolean bitfield write, got {value:?}")
+            }
+            (_, Value::Number(n)) => self.checked_bits(*n, unit.is_unsigned())?,
+            _ => anyhow::bail!("Expected a Number for bitfield write, got {value:?}"),
+        };
+        let raw = (load_unit(ptr, unit) & !(mask << shift)) | ((bits & mask) << shift);
+        store_unit(ptr, unit, raw);
+        Ok(())
+    }
+
+    /// The two's-complement bits of `n`, failing unless it is an integer in
+    /// the bitfield's range.
+    fn checked_bits(self, n: f64, unsigned: bool) -> anyhow::Result<u64> {
+        let (min, max) = if unsigned {
+            (0.0, ((1u128 << self.width) - 1) as f64)
+        } else {
+            let half = 1u128 << (self.width - 1);
+            (-(half as f64), (half - 1) as f64)
+        };
+        if n.fract() != 0.0 || !(min..=max).contains(&n) {
+            anyhow::bail!(
+                "Value {n} is out of range for a {}-bit bitfield [{min}, {max}]",
+                self.width
+            );
+        }
+        Ok(if unsigned { n as u64 } else { n as i64 as u64 })
+    }
+}
+
+/// Loads the storage unit at `ptr`, zero-extended to 64 bits.
+fn load_unit(ptr: *const c_void, unit: IntegerKind) -> u64 {
+    unsafe {
+        match unit.byte_size() {
+            1 => u64::from(ptr.cast::<u8>().read()),
+            2 => u64::from(ptr.cast::<u16>().read_unaligned()),
+            4 => u64::from(ptr.cast::<u32>().read_unaligned()),
+            _ => ptr.cast::<u64>().read_unaligned(),
+        }
+    }
+}
+
+/// Stores the low bytes of `raw` into the storage unit at `ptr`.
+fn store_unit(ptr: *mut c_void, unit: IntegerKind, raw: u64) {
+    unsafe {
+        match unit.byte_size() {
+            1 => ptr.cast::<u8>().write(raw as u8),
+            2 => ptr.cast::<u16>().write_unaligned(raw as u16),
+            4 => ptr.cast::<u32>().write_unaligned(raw as u32),
+            _ => ptr.cast::<u64>().write_unaligned(raw),
+        }
+    }
+}
+
 #[cfg_attr(test, allow(dead_code))]
 struct ReadRequest {
     location: FieldLocation,
     field_type: Type,
+    bits: Option<BitRange>,
 }
 
 impl ModuleRequest for ReadRequest {
@@ -65,7 +217,15 @@ impl ModuleRequest for ReadRequest {
 
     fn execute(self) -> anyhow::Result<Value> {
         let field_ptr = self.location.resolve()?.cast_const();
-        self.field_type.read_from_raw_ptr(field_ptr, "field read")
+        if let Some(bits) = self.bits {
+            return bits.read(field_ptr, &self.field_type);
+        }
+        match &self.field_type {
+            Type::Record(record) => record.read_inline(field_ptr),
+            Type::Union(union_type) => union_type.read_inline(field_ptr),
+            Type::GValue(gvalue_type) => gvalue_type.read_inline(field_ptr),
+            field_type => field_type.read_from_raw_ptr(field_ptr, "field read"),
+        }
     }
 
     fn error_context() -> &'static str {
@@ -77,6 +237,7 @@ impl ModuleRequest for ReadRequest {
 struct WriteRequest {
     location: FieldLocation,
     field_type: Type,
+    bits: Option<BitRange>,
     value: Value,
 }
 
@@ -85,8 +246,136 @@ impl ModuleRequest for WriteRequest {
 
     fn execute(self) -> anyhow::Result<()> {
         let field_ptr = self.location.resolve()?;
-        self.field_type
-            .write_value_to_raw_ptr(field_ptr, &self.value)
+        if let Some(bits) = self.bits {
+            return bits.write(field_ptr, &self.field_type, &self.value);
+        }
+        match &self.field_type {
+            Type::Record(record) => record.write_inline(field_ptr, &self.value),
+            Type::Union(union_type) => union_type.write_inline(field_ptr, &self.value),
+            Type::GValue(gvalue_type) => gvalue_type.write_inline(field_ptr, &self.value),
+            field_type => field_type.write_value_to_raw_ptr(field_ptr, &self.value),
+        }
+    }
+
+    fn error_context() -> &'static str {
+        "field write"
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct ReadManyRequest {
+    reads: Vec<ReadRequest>,
+}
+
+impl ModuleRequest for ReadManyRequest {
+    type Output = Value;
+
+    /// Reads every field in order, stopping at the first failure. The error
+    /// names the index of the failing field.
+    fn execute(self) -> anyhow::Result<Value> {
+        self.reads
+            .into_iter()
+            .enumerate()
+            .map(|(i, read)| read.execute().map_err(|e| e.context(format!("field {i}"))))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map(Value::Array)
+    }
+
+    fn error_context() -> &'static str {
+        "field read"
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct WriteManyRequest {
+    base_addr: usize,
+    writes: Vec<WriteRequest>,
+}
+
+impl WriteManyRequest {
+    /// The byte range `[start, end)` covered by every write, relative to the
+    /// base address.
+    fn span(&self) -> anyhow::Result<(usize, usize)> {
+        self.writes
+            .iter()
+            .enumerate()
+            .try_fold((usize::MAX, 0), |(start, end), (i, write)| {
+                let size = embedded_size(&write.field_type).ok_or_else(|| {
+                    anyhow::anyhow!("field {i}: {} cannot be written", write.field_type)
+                })?;
+                if owns_memory(&write.field_type) {
+                    anyhow::bail!(
+                        "field {i}: {} fields allocate memory and cannot be written by writeMany",
+                        write.field_type
+                    );
+                }
+                let offset = write.location.offset;
+                Ok((start.min(offset), end.max(offset + size)))
+            })
+    }
+}
+
+/// Whether writing a field of type `ty` in place allocates a value or
+/// releases the one it replaces.
+fn owns_memory(ty: &Type) -> bool {
+    match ty {
+        Type::String(_) | Type::Bytes(_) | Type::GValue(_) => true,
+        Type::Record(record) => record.fields.iter().any(|field| owns_memory(&field.ty)),
+        Type::Union(union_type) => union_type
+            .variants
+            .iter()
+            .any(|variant| owns_memory(&variant.ty)),
+        Type::Array(array) => owns_memory(&array.item_type),
+        Type::Boxed(BoxedType {
+            by_value: Some(layout),
+            ..
+        })
+        | Type::Struct(StructType {
+            by_value: Some(layout),
+            ..
+        }) => layout.fields.iter().any(owns_memory),
+        _ => false,
+    }
+}
+
+impl ModuleRequest for WriteManyRequest {
+    type Output = ();
+
+    /// Applies every write to a scratch copy of the bytes they span, then
+    /// copies the result back over the original in one step.
+    fn execute(self) -> anyhow::Result<()> {
+        if self.writes.is_empty() {
+            return Ok(());
+        }
+        let (start, end) = self.span()?;
+        let target = FieldLocation {
+            base_addr: self.base_addr,
+            offset: start,
+        }
+        .resolve()?;
+
+        let mut scratch = unsafe { AggregateBuffer::copy_from(target, end - start) };
+        let scratch_addr = scratch.as_mut_ptr() as usize;
+        for (i, write) in self.writes.into_iter().enumerate() {
+            WriteRequest {
+                location: FieldLocation {
+                    base_addr: scratch_addr,
+                    offset: write.location.offset - start,
+                },
+                ..write
+            }
+            .execute()
+            .map_err(|e| e.context(format!("field {i}")))?;
+        }
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                scratch.as_ptr().cast::<u8>(),
+                target.cast::<u8>(),
+                end - start,
+            );
+        }
+        Ok(())
     }
 
     fn error_context() -> &'static str {
@@ -110,6 +399,7 @@ mod napi_export {
         handle: &External<NativeHandle>,
         js_type: Unknown<'_>,
         offset: f64,
+        bits: Option<JsObject>,
     ) -> napi::Result<Unknown<'env>> {
         let field_type = Type::from_js_value(env, js_type)?;
         let request = ReadRequest {
@@ -118,6 +408,7 @@ mod napi_export {
                 offset: offset as usize,
             },
             field_type,
+            bits: bits.as_ref().map(parse_bits).transpose()?.flatten(),
         };
         request.dispatch(env)
     }
@@ -130,24 +421,93 @@ mod napi_export {
         js_type: Unknown<'_>,
         offset: f64,
         value: Unknown<'_>,
+        bits: Option<JsObject>,
     ) -> napi::Result<Unknown<'env>> {
         let field_type = Type::from_js_value(env, js_type)?;
-        let parsed_value = Value::from_js_value(env, value)?;
+        let parsed_value = Value::from_js_value_typed(env, value, &field_type)?;
         let request = WriteRequest {
             location: FieldLocation {
                 base_addr: handle.ptr_as_usize(),
                 offset: offset as usize,
             },
             field_type,
+            bits: bits.as_ref().map(parse_bits).transpose()?.flatten(),
             value: parsed_value,
         };
         request.dispatch(env)
     }
+
+    /// Parses the `offset` and `type` of one bulk access entry.
+    fn parse_field(env: &Env, field: &JsObject) -> napi::Result<(usize, Type)> {
+        let offset: f64 = field.get_named_property("offset")?;
+        let js_type: Unknown<'_> = field.get_named_property("type")?;
+        Ok((offset as usize, Type::from_js_value(env, js_type)?))
+    }
+
+    /// Parses the optional `bitOffset` and `bitWidth` that make a field a
+    /// bitfield. `bitOffset` defaults to 0.
+    fn parse_bits(obj: &JsObject) -> napi::Result<Option<BitRange>> {
+        let bit_offset: Option<f64> = obj.get_named_property("bitOffset")?;
+        let bit_width: Option<f64> = obj.get_named_property("bitWidth")?;
+        match (bit_offset, bit_width) {
+            (_, Some(width)) => Ok(Some(BitRange {
+                offset: bit_offset.unwrap_or(0.0) as u32,
+                width: width as u32,
+            })),
+            (Some(_), None) => Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                "'bitWidth' is required when 'bitOffset' is set",
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn read_many<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        fields: Array,
+    ) -> napi::Result<Unknown<'env>> {
+        let base_addr = handle.ptr_as_usize();
+        let reads = crate::value::map_js_array(env, &fields, |env, field| {
+            let field: JsObject = unsafe { JsObject::from_napi_value(env.raw(), field.raw())? };
+            let (offset, field_type) = parse_field(env, &field)?;
+            Ok(ReadRequest {
+                location: FieldLocation { base_addr, offset },
+                field_type,
+                bits: parse_bits(&field)?,
+            })
+        })?;
+        ReadManyRequest { reads }.dispatch(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn write_many<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        fields: Array,
+    ) -> napi::Result<Unknown<'env>> {
+        let base_addr = handle.ptr_as_usize();
+        let writes = crate::value::map_js_array(env, &fields, |env, field| {
+            let field: JsObject = unsafe { JsObject::from_napi_value(env.raw(), field.raw())? };
+            let (offset, field_type) = parse_field(env, &field)?;
+            let value: Unknown<'_> = field.get_named_property("value")?;
+            Ok(WriteRequest {
+                location: FieldLocation { base_addr, offset },
+                value: Value::from_js_value_typed(env, value, &field_type)?,
+                field_type,
+                bits: parse_bits(&field)?,
+            })
+        })?;
+        WriteManyRequest { base_addr, writes }.dispatch(env)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::types::IntegerKind;
+    use crate::types::{BigIntKind, GValueType, IntegerKind, Ownership, RecordField, RecordType};
 
     use super::*;
 
@@ -184,6 +544,7 @@ mod tests {
                 offset: 8,
             },
             field_type: Type::Integer(IntegerKind::I32),
+            bits: None,
             value: Value::Number(1234.0),
         };
         write.execute().expect("write should succeed");
@@ -194,12 +555,342 @@ mod tests {
                 offset: 8,
             },
             field_type: Type::Integer(IntegerKind::I32),
+            bits: None,
         };
         let value = read.execute().expect("read should succeed");
         let n = value.as_number().expect("read result should be a number");
         assert!((n - 1234.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn write_then_read_round_trips_a_bigint_losslessly() {
+        let mut buffer = [0u8; 32];
+        let base_addr = buffer.as_mut_ptr() as usize;
+        let write = WriteRequest {
+            location: FieldLocation {
+                base_addr,
+                offset: 8,
+            },
+            field_type: Type::BigInt(BigIntKind::U64),
+            bits: None,
+            value: Value::BigInt(u64::MAX.into()),
+        };
+        write.execute().expect("write should succeed");
+
+        let read = ReadRequest {
+            location: FieldLocation {
+                base_addr,
+                offset: 8,
+            },
+            field_type: Type::BigInt(BigIntKind::U64),
+            bits: None,
+        };
+        let value = read.execute().expect("read should succeed");
+        assert!(matches!(value, Value::BigInt(n) if n == u64::MAX.into()));
+    }
+
+    #[test]
+    fn record_fields_round_trip_in_place() {
+        let mut buffer = [0u8; 32];
+        let base_addr = buffer.as_mut_ptr() as usize;
+        let record = RecordType::new(
+            Ownership::Borrowed,
+            vec![
+                RecordField {
+                    name: "x".into(),
+                    offset: 0,
+                    ty: Type::Integer(IntegerKind::I32),
+                },
+                RecordField {
+                    name: "y".into(),
+                    offset: 4,
+                    ty: Type::Integer(IntegerKind::I32),
+                },
+            ],
+            None,
+        )
+        .expect("record should be valid");
+
+        let write = WriteRequest {
+            location: FieldLocation {
+                base_addr,
+                offset: 8,
+            },
+            field_type: Type::Record(record.clone()),
+            bits: None,
+            value: Value::Record(vec![
+                ("x".into(), Value::Number(3.0)),
+                ("y".into(), Value::Number(-4.0)),
+            ]),
+        };
+        write.execute().expect("write should succeed");
+        assert_eq!(i32::from_ne_bytes(buffer[12..16].try_into().unwrap()), -4);
+
+        let read = ReadRequest {
+            location: FieldLocation {
+                base_addr,
+                offset: 8,
+            },
+            field_type: Type::Record(record),
+            bits: None,
+        };
+        let Value::Record(fields) = read.execute().expect("read should succeed") else {
+            panic!("read result should be a record");
+        };
+        assert_eq!(fields[0].0, "x");
+        assert!(matches!(fields[0].1, Value::Number(n) if (n - 3.0).abs() < f64::EPSILON));
+    }
+
+    fn write_at(base_addr: usize, offset: usize, field_type: Type, value: Value) -> WriteRequest {
+        WriteRequest {
+            location: FieldLocation { base_addr, offset },
+            field_type,
+            bits: None,
+            value,
+        }
+    }
+
+    #[test]
+    fn write_many_then_read_many_round_trips() {
+        let mut buffer = [0u8; 32];
+        let base_addr = buffer.as_mut_ptr() as usize;
+
+        WriteManyRequest {
+            base_addr,
+            writes: vec![
+                write_at(
+                    base_addr,
+                    4,
+                    Type::Integer(IntegerKind::U16),
+                    Value::Number(7.0),
+                ),
+                write_at(
+                    base_addr,
+                    16,
+                    Type::BigInt(BigIntKind::I64),
+                    Value::BigInt(-9),
+                ),
+            ],
+        }
+        .execute()
+        .expect("write should succeed");
+
+        let reads = [
+            (4, Type::Integer(IntegerKind::U16)),
+            (16, Type::BigInt(BigIntKind::I64)),
+        ]
+        .into_iter()
+        .map(|(offset, field_type)| ReadRequest {
+            location: FieldLocation { base_addr, offset },
+            field_type,
+            bits: None,
+        })
+        .collect();
+        let Value::Array(values) = ReadManyRequest { reads }
+            .execute()
+            .expect("read should succeed")
+        else {
+            panic!("read result should be an array");
+        };
+        assert!(matches!(values[0], Value::Number(n) if (n - 7.0).abs() < f64::EPSILON));
+        assert!(matches!(values[1], Value::BigInt(-9)));
+    }
+
+    #[test]
+    fn write_many_applies_overlapping_writes_in_order() {
+        let mut buffer = [0u8; 8];
+        let base_addr = buffer.as_mut_ptr() as usize;
+
+        WriteManyRequest {
+            base_addr,
+            writes: vec![
+                write_at(
+                    base_addr,
+                    0,
+                    Type::Integer(IntegerKind::U32),
+                    Value::Number(f64::from(0x0101_0101_u32)),
+                ),
+                write_at(
+                    base_addr,
+                    2,
+                    Type::Integer(IntegerKind::U8),
+                    Value::Number(9.0),
+                ),
+            ],
+        }
+        .execute()
+        .expect("write should succeed");
+
+        assert_eq!(buffer[..4], [1, 1, 9, 1]);
+    }
+
+    #[test]
+    fn write_many_leaves_memory_untouched_when_a_value_is_rejected() {
+        let mut buffer = [0u8; 16];
+        let base_addr = buffer.as_mut_ptr() as usize;
+
+        let err = WriteManyRequest {
+            base_addr,
+            writes: vec![
+                write_at(
+                    base_addr,
+                    0,
+                    Type::Integer(IntegerKind::I32),
+                    Value::Number(5.0),
+                ),
+                write_at(
+                    base_addr,
+                    8,
+                    Type::BigInt(BigIntKind::U64),
+                    Value::BigInt(-1),
+                ),
+            ],
+        }
+        .execute()
+        .expect_err("out-of-range value should fail");
+
+        assert!(format!("{err:#}").contains("field 1"));
+        assert_eq!(buffer, [0u8; 16]);
+    }
+
+    #[test]
+    fn write_many_rejects_gvalue_fields_before_writing() {
+        let mut buffer = [0u8; 32];
+        let base_addr = buffer.as_mut_ptr() as usize;
+
+        let err = WriteManyRequest {
+            base_addr,
+            writes: vec![
+                write_at(
+                    base_addr,
+                    0,
+                    Type::GValue(GValueType::new(
+                        Ownership::Borrowed,
+                        Some(Type::Integer(IntegerKind::I32)),
+                        None,
+                    )),
+                    Value::Number(1.0),
+                ),
+                write_at(
+                    base_addr,
+                    24,
+                    Type::Integer(IntegerKind::I32),
+                    Value::Boolean(true),
+                ),
+            ],
+        }
+        .execute()
+        .expect_err("a GValue field cannot be written in bulk");
+
+        assert_eq!(
+            err.to_string(),
+            "field 0: GValue fields allocate memory and cannot be written by writeMany"
+        );
+        assert_eq!(buffer, [0u8; 32]);
+    }
+
+    #[test]
+    fn read_many_names_the_failing_field() {
+        let read = ReadManyRequest {
+            reads: vec![ReadRequest {
+                location: FieldLocation {
+                    base_addr: 0,
+                    offset: 0,
+                },
+                field_type: Type::Integer(IntegerKind::I32),
+                bits: None,
+            }],
+        };
+        let err = read.execute().expect_err("null base read should fail");
+        assert!(format!("{err:#}").contains("field 0"));
+    }
+
+    fn bitfield(base_addr: usize, field_type: Type, offset: u32, width: u32) -> ReadRequest {
+        ReadRequest {
+            location: FieldLocation {
+                base_addr,
+                offset: 0,
+            },
+            field_type,
+            bits: Some(BitRange { offset, width }),
+        }
+    }
+
+    fn write_bits(read: &ReadRequest, value: Value) -> anyhow::Result<()> {
+        WriteRequest {
+            location: FieldLocation {
+                base_addr: read.location.base_addr,
+                offset: read.location.offset,
+            },
+            field_type: read.field_type.clone(),
+            bits: read.bits,
+            value,
+        }
+        .execute()
+    }
+
+    #[test]
+    fn bitfields_round_trip_and_preserve_neighbouring_bits() {
+        let mut unit = u32::MAX;
+        let base_addr = (&raw mut unit) as usize;
+        let field = bitfield(base_addr, Type::Integer(IntegerKind::U32), 4, 3);
+
+        write_bits(&field, Value::Number(5.0)).expect("write should succeed");
+        let n = field.execute().unwrap().as_number().unwrap();
+        assert!((n - 5.0).abs() < f64::EPSILON);
+
+        let expected = if cfg!(target_endian = "little") {
+            !(0b010 << 4)
+        } else {
+            !(0b010 << 25)
+        };
+        assert_eq!(unit, expected);
+    }
+
+    #[test]
+    fn signed_bitfields_are_sign_extended() {
+        let mut unit = 0i16;
+        let base_addr = (&raw mut unit) as usize;
+        let field = bitfield(base_addr, Type::Integer(IntegerKind::I16), 0, 4);
+
+        write_bits(&field, Value::Number(-3.0)).expect("write should succeed");
+        let n = field.execute().unwrap().as_number().unwrap();
+        assert!((n + 3.0).abs() < f64::EPSILON);
+
+        let err = write_bits(&field, Value::Number(8.0)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("out of range for a 4-bit bitfield")
+        );
+    }
+
+    #[test]
+    fn boolean_bitfields_use_a_guint_unit() {
+        let mut unit = 0u32;
+        let base_addr = (&raw mut unit) as usize;
+        let field = bitfield(base_addr, Type::Boolean(crate::types::BooleanType), 31, 1);
+
+        write_bits(&field, Value::Boolean(true)).expect("write should succeed");
+        assert!(matches!(field.execute().unwrap(), Value::Boolean(true)));
+        assert_eq!(unit.count_ones(), 1);
+    }
+
+    #[test]
+    fn bitfields_must_fit_their_unit() {
+        let mut unit = 0u8;
+        let base_addr = (&raw mut unit) as usize;
+
+        let err = bitfield(base_addr, Type::Integer(IntegerKind::U8), 6, 3)
+            .execute()
+            .unwrap_err();
+        assert!(err.to_string().contains("does not fit in a 8-bit unit"));
+
+        let err = bitfield(base_addr, Type::Float(crate::types::FloatKind::F32), 0, 1)
+            .execute()
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot be the type of a bitfield"));
+    }
+
     #[test]
     fn read_rejects_null_base() {
         let read = ReadRequest {
@@ -208,6 +899,7 @@ mod tests {
                 offset: 0,
             },
             field_type: Type::Integer(IntegerKind::I32),
+            bits: None,
         };
         let err = read.execute().expect_err("null base read should fail");
         assert!(err.to_string().contains("null pointer"));
@@ -221,6 +913,7 @@ mod tests {
                 offset: 0,
             },
             field_type: Type::Integer(IntegerKind::I32),
+            bits: None,
             value: Value::Number(0.0),
         };
         let err = write.execute().expect_err("null base write should fail");
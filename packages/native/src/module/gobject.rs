@@ -3,16 +3,55 @@
 //! Provides direct access to `GObject` class metadata so that JavaScript does
 //! not need to traverse the `GTypeInstance` → `GTypeClass` → `GObjectClass`
 //! chain through several individual FFI dispatches.
+//!
+//! Properties are read and written by name the same way: the `GParamSpec` is
+//! looked up on the `GLib` thread and its value type decides how the value is
+//! marshalled into or out of a `GValue`.
 
-use std::ffi::{CString, c_void};
+use std::ffi::{CStr, CString, c_void};
 
-use gtk4::glib::gobject_ffi;
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{FromGlib as _, ToGlibPtr as _, ToGlibPtrMut as _},
+};
 use napi::Env;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
 use super::handler::ModuleRequest;
 use crate::managed::NativeHandle;
+use crate::types::{GlibValueCodec as _, Type, gvalue_to_value, value_to_gvalue_as};
+use crate::value::Value;
+
+/// Resolves the `GObjectClass` of the instance at `instance_addr`.
+fn object_class(instance_addr: usize) -> anyhow::Result<*mut gobject_ffi::GObjectClass> {
+    if instance_addr == 0 {
+        anyhow::bail!("instance handle has a null pointer");
+    }
+
+    let instance = instance_addr as *mut gobject_ffi::GTypeInstance;
+    let object_class = unsafe { (*instance).g_class.cast::<gobject_ffi::GObjectClass>() };
+    if object_class.is_null() {
+        anyhow::bail!("instance has no resolved class");
+    }
+    Ok(object_class)
+}
+
+/// Looks up the `GParamSpec` of the property `name` on the instance at
+/// `instance_addr`, failing when the class has no such property.
+fn find_pspec(instance_addr: usize, name: &CStr) -> anyhow::Result<*mut gobject_ffi::GParamSpec> {
+    let object_class = object_class(instance_addr)?;
+    let pspec = unsafe { gobject_ffi::g_object_class_find_property(object_class, name.as_ptr()) };
+    if pspec.is_null() {
+        let gtype = unsafe { glib::Type::from_glib((*object_class).g_type_class.g_type) };
+        anyhow::bail!(
+            "{} has no property '{}'",
+            gtype.name(),
+            name.to_string_lossy()
+        );
+    }
+    Ok(pspec)
+}
 
 #[cfg_attr(test, allow(dead_code))]
 struct FindObjectPropertyRequest {
@@ -24,15 +63,7 @@ impl ModuleRequest for FindObjectPropertyRequest {
     type Output = Option<NativeHandle>;
 
     fn execute(self) -> anyhow::Result<Option<NativeHandle>> {
-        if self.instance_addr == 0 {
-            anyhow::bail!("instance handle has a null pointer");
-        }
-
-        let instance = self.instance_addr as *mut gobject_ffi::GTypeInstance;
-        let object_class = unsafe { (*instance).g_class.cast::<gobject_ffi::GObjectClass>() };
-        if object_class.is_null() {
-            anyhow::bail!("instance has no resolved class");
-        }
+        let object_class = object_class(self.instance_addr)?;
 
         let pspec = unsafe {
             gobject_ffi::g_object_class_find_property(object_class, self.property_name.as_ptr())
@@ -50,6 +81,123 @@ impl ModuleRequest for FindObjectPropertyRequest {
     }
 }
 
+#[cfg_attr(test, allow(dead_code))]
+struct GetPropertyRequest {
+    instance_addr: usize,
+    property_name: CString,
+    /// Decodes the value instead of its runtime `GType` when set.
+    value_type: Option<Type>,
+}
+
+impl ModuleRequest for GetPropertyRequest {
+    type Output = Value;
+
+    fn execute(self) -> anyhow::Result<Value> {
+        let pspec = find_pspec(self.instance_addr, &self.property_name)?;
+        let (flags, value_type) = unsafe { ((*pspec).flags, (*pspec).value_type) };
+        if flags & gobject_ffi::G_PARAM_READABLE == 0 {
+            anyhow::bail!(
+                "property '{}' is not readable",
+                self.property_name.to_string_lossy()
+            );
+        }
+
+        let mut gvalue = glib::Value::from_type(unsafe { glib::Type::from_glib(value_type) });
+        unsafe {
+            gobject_ffi::g_object_get_property(
+                self.instance_addr as *mut gobject_ffi::GObject,
+                self.property_name.as_ptr(),
+                gvalue.to_glib_none_mut().0,
+            );
+        }
+
+        self.value_type.as_ref().map_or_else(
+            || gvalue_to_value(&gvalue),
+            |ty| ty.from_glib_value(&gvalue),
+        )
+    }
+
+    fn error_context() -> &'static str {
+        "get_property"
+    }
+}
+
+/// One property of a [`SetPropertiesRequest`].
+#[cfg_attr(test, allow(dead_code))]
+struct PropertyWrite {
+    name: CString,
+    value: Value,
+    /// Builds the `GValue` instead of the property's `GType` when set.
+    value_type: Option<Type>,
+}
+
+impl PropertyWrite {
+    /// Builds the `GValue` stored into the property `pspec` describes,
+    /// converted to the property's type and checked against its constraints.
+    fn to_gvalue(&self, pspec: *mut gobject_ffi::GParamSpec) -> anyhow::Result<glib::Value> {
+        let name = self.name.to_string_lossy();
+        let (flags, value_type) = unsafe { ((*pspec).flags, (*pspec).value_type) };
+        if flags & gobject_ffi::G_PARAM_WRITABLE == 0 {
+            anyhow::bail!("property '{name}' is not writable");
+        }
+        if flags & gobject_ffi::G_PARAM_CONSTRUCT_ONLY != 0 {
+            anyhow::bail!("property '{name}' can only be set at construction");
+        }
+
+        let gtype = unsafe { glib::Type::from_glib(value_type) };
+        let mut gvalue = value_to_gvalue_as(&self.value, self.value_type.as_ref(), gtype)
+            .map_err(|e| e.context(format!("property '{name}'")))?;
+        let modified =
+            unsafe { gobject_ffi::g_param_value_validate(pspec, gvalue.to_glib_none_mut().0) };
+        if modified != glib::ffi::GFALSE {
+            anyhow::bail!("value for property '{name}' is out of range");
+        }
+        Ok(gvalue)
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct SetPropertiesRequest {
+    instance_addr: usize,
+    properties: Vec<PropertyWrite>,
+}
+
+impl ModuleRequest for SetPropertiesRequest {
+    type Output = ();
+
+    /// Converts every value before setting any, so a rejected value leaves
+    /// the object untouched. Change notifications are held back until all
+    /// properties are set.
+    fn execute(self) -> anyhow::Result<()> {
+        let gvalues = self
+            .properties
+            .iter()
+            .map(|property| {
+                let pspec = find_pspec(self.instance_addr, &property.name)?;
+                property.to_gvalue(pspec)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let object = self.instance_addr as *mut gobject_ffi::GObject;
+        unsafe {
+            gobject_ffi::g_object_freeze_notify(object);
+            for (property, gvalue) in self.properties.iter().zip(&gvalues) {
+                gobject_ffi::g_object_set_property(
+                    object,
+                    property.name.as_ptr(),
+                    gvalue.to_glib_none().0,
+                );
+            }
+            gobject_ffi::g_object_thaw_notify(object);
+        }
+        Ok(())
+    }
+
+    fn error_context() -> &'static str {
+        "set_properties"
+    }
+}
+
 #[cfg_attr(test, allow(dead_code))]
 struct GetInstanceGtypeRequest {
     instance_addr: usize,
@@ -76,10 +224,10 @@ impl ModuleRequest for GetInstanceGtypeRequest {
     }
 }
 
-/// napi export shims for `GObject` metadata access. Excluded from coverage
-/// instrumentation: both dispatch through a live [`napi::Env`]. The
-/// [`FindObjectPropertyRequest`] and [`GetInstanceGtypeRequest`] `execute`
-/// logic they dispatch is exercised directly by tests.
+/// napi export shims for `GObject` metadata and property access. Excluded
+/// from coverage instrumentation: all dispatch through a live [`napi::Env`].
+/// The `execute` logic of the requests they dispatch is exercised directly by
+/// tests.
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[allow(clippy::wildcard_imports)]
 mod napi_export {
@@ -92,11 +240,69 @@ mod napi_export {
         handle: &External<NativeHandle>,
         property_name: String,
     ) -> napi::Result<Unknown<'env>> {
-        let property_name = CString::new(property_name)
-            .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))?;
         FindObjectPropertyRequest {
             instance_addr: handle.ptr_as_usize(),
-            property_name,
+            property_name: to_cstring(property_name)?,
+        }
+        .dispatch(env)
+    }
+
+    fn to_cstring(name: String) -> napi::Result<CString> {
+        CString::new(name)
+            .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn get_property<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        property_name: String,
+        js_type: Option<Unknown<'_>>,
+    ) -> napi::Result<Unknown<'env>> {
+        GetPropertyRequest {
+            instance_addr: handle.ptr_as_usize(),
+            property_name: to_cstring(property_name)?,
+            value_type: js_type
+                .map(|js_type| Type::from_js_value(env, js_type))
+                .transpose()?,
+        }
+        .dispatch(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn set_properties<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        properties: Object<'_>,
+        types: Option<Object<'_>>,
+    ) -> napi::Result<Unknown<'env>> {
+        let properties = Object::keys(&properties)?
+            .into_iter()
+            .map(|name| {
+                let value: Unknown<'_> = properties.get_named_property(&name)?;
+                let value_type = types
+                    .as_ref()
+                    .map(|types| types.get_named_property::<Option<Unknown<'_>>>(&name))
+                    .transpose()?
+                    .flatten()
+                    .map(|js_type| Type::from_js_value(env, js_type))
+                    .transpose()?;
+                let value = match &value_type {
+                    Some(ty) => Value::from_js_value_typed(env, value, ty)?,
+                    None => Value::from_js_value(env, value)?,
+                };
+                Ok(PropertyWrite {
+                    name: to_cstring(name)?,
+                    value,
+                    value_type,
+                })
+            })
+            .collect::<napi::Result<Vec<_>>>()?;
+        SetPropertiesRequest {
+            instance_addr: handle.ptr_as_usize(),
+            properties,
         }
         .dispatch(env)
     }
@@ -119,7 +325,9 @@ mod tests {
     use gtk4::gio;
     use gtk4::glib;
     use gtk4::glib::translate::{IntoGlib as _, ToGlibPtr as _};
-    use gtk4::prelude::{Cast as _, StaticType as _};
+    use gtk4::prelude::{
+        ActionExt as _, Cast as _, ObjectExt as _, StaticType as _, ToVariant as _,
+    };
 
     use super::*;
 
@@ -182,6 +390,117 @@ mod tests {
         );
     }
 
+    fn get_property(object: &glib::Object, name: &str) -> anyhow::Result<Value> {
+        GetPropertyRequest {
+            instance_addr: object_addr(object),
+            property_name: CString::new(name).unwrap(),
+            value_type: None,
+        }
+        .execute()
+    }
+
+    fn set_properties(object: &glib::Object, properties: Vec<(&str, Value)>) -> anyhow::Result<()> {
+        SetPropertiesRequest {
+            instance_addr: object_addr(object),
+            properties: properties
+                .into_iter()
+                .map(|(name, value)| PropertyWrite {
+                    name: CString::new(name).unwrap(),
+                    value,
+                    value_type: None,
+                })
+                .collect(),
+        }
+        .execute()
+    }
+
+    #[test]
+    fn get_property_decodes_by_the_pspec_type() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let object = action.upcast_ref::<glib::Object>();
+        assert!(matches!(
+            get_property(object, "enabled").unwrap(),
+            Value::Boolean(true)
+        ));
+        assert!(matches!(
+            get_property(object, "name").unwrap(),
+            Value::String(name) if name == "test-action"
+        ));
+    }
+
+    #[test]
+    fn get_property_rejects_unknown_property() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let err = get_property(action.upcast_ref(), "no-such-property").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("GSimpleAction has no property 'no-such-property'")
+        );
+    }
+
+    #[test]
+    fn set_properties_converts_to_the_pspec_type() {
+        let action = gio::SimpleAction::new_stateful("test-action", None, &1i32.to_variant());
+        set_properties(
+            action.upcast_ref(),
+            vec![
+                ("enabled", Value::Boolean(false)),
+                ("state", Value::Variant(7i32.to_variant())),
+            ],
+        )
+        .unwrap();
+        assert!(!action.is_enabled());
+        assert_eq!(action.state().and_then(|state| state.get::<i32>()), Some(7));
+    }
+
+    #[test]
+    fn set_properties_leaves_the_object_untouched_on_error() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let err = set_properties(
+            action.upcast_ref(),
+            vec![
+                ("enabled", Value::Boolean(false)),
+                ("name", Value::String("renamed".into())),
+            ],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("can only be set at construction"));
+        assert!(action.is_enabled());
+    }
+
+    #[test]
+    fn set_properties_rejects_mismatched_values() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let err = set_properties(
+            action.upcast_ref(),
+            vec![("enabled", Value::String("yes".into()))],
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("gboolean"), "{err:#}");
+    }
+
+    #[test]
+    fn set_properties_holds_notifications_until_all_are_set() {
+        let action = gio::SimpleAction::new_stateful("test-action", None, &1i32.to_variant());
+        let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = seen.clone();
+        action.connect_notify_local(None, move |action, _| {
+            let state = action.state().and_then(|state| state.get::<i32>());
+            log.borrow_mut().push((action.is_enabled(), state));
+        });
+
+        set_properties(
+            action.upcast_ref(),
+            vec![
+                ("enabled", Value::Boolean(false)),
+                ("state", Value::Variant(7i32.to_variant())),
+            ],
+        )
+        .unwrap();
+        assert!(!seen.borrow().is_empty());
+        assert!(seen.borrow().iter().all(|seen| *seen == (false, Some(7))));
+    }
+
     #[test]
     fn get_instance_gtype_returns_real_gtype() {
         let object = glib::Object::new::<glib::Object>();
@@ -12,8 +12,10 @@ use std::sync::Arc;
 use napi::bindgen_prelude::*;
 use napi::{Env, JsObject};
 
+use super::call::BatchCallFailed;
 use crate::dispatch;
 use crate::managed::NativeHandle;
+use crate::types::GErrorDetails;
 use crate::value::{JsRef, Value};
 
 #[cfg_attr(test, allow(dead_code))]
@@ -28,14 +30,96 @@ pub trait ModuleRequest: Sized + Send + 'static {
         let result = dispatch::Mailbox::global()
             .dispatch_to_glib_and_wait(*env, move || self.execute())
             .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?
-            .map_err(|e| {
-                napi::Error::new(
-                    napi::Status::GenericFailure,
-                    format!("Error during {}: {e}", Self::error_context()),
-                )
-            })?;
+            .map_err(|e| request_error(env, &e, Self::error_context()))?;
         result.to_js_response(env)
     }
+
+    /// Dispatches the request onto the `GLib` thread without blocking the JS
+    /// thread and returns a `Promise` for its outcome.
+    ///
+    /// The promise is settled on the JS thread through the deferred's
+    /// threadsafe function, using the same [`ModuleResponse`] conversion as
+    /// [`Self::dispatch`]. Because the JS thread is not parked meanwhile,
+    /// callbacks the request triggers are delivered through the wake
+    /// threadsafe function and run on the event loop as usual.
+    fn dispatch_promise(self, env: &Env) -> napi::Result<Object<'_>> {
+        let (deferred, promise) = env.create_deferred()?;
+        let mailbox = dispatch::Mailbox::global();
+
+        if mailbox.is_stopped() {
+            deferred.reject(napi::Error::new(
+                napi::Status::GenericFailure,
+                dispatch::GlibDisconnectedError.to_string(),
+            ));
+            return Ok(promise);
+        }
+
+        mailbox.schedule_glib(Box::new(move || {
+            let outcome = self.execute();
+            deferred.resolve(move |env: Env| match outcome {
+                Ok(output) => {
+                    let value = output.to_js_response(&env)?;
+                    Ok(unsafe {
+                        Unknown::<'static>::from_raw_unchecked(
+                            env.raw(),
+                            napi::JsValue::raw(&value),
+                        )
+                    })
+                }
+                Err(e) => Err(request_error(&env, &e, Self::error_context())),
+            });
+        }));
+
+        Ok(promise)
+    }
+}
+
+/// Converts a failed request into the error thrown to JavaScript.
+///
+/// A [`GErrorDetails`] anywhere in the error chain becomes a `NativeError`
+/// carrying the `GError`'s fields (see [`create_native_error`]), plus a
+/// `batchIndex` when a call of a batch failed; any other failure becomes a
+/// plain `Error` prefixed with `context`, with the messages of the whole
+/// error chain.
+pub(super) fn request_error(env: &Env, error: &anyhow::Error, context: &str) -> napi::Error {
+    let Some(details) = error.downcast_ref::<GErrorDetails>() else {
+        return napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Error during {context}: {error:#}"),
+        );
+    };
+
+    let build = || -> napi::Result<Object<'_>> {
+        let mut js_error = create_native_error(env, details)?;
+        if let Some(failed) = error.downcast_ref::<BatchCallFailed>() {
+            js_error.set_named_property("batchIndex", failed.index as u32)?;
+        }
+        Ok(js_error)
+    };
+
+    match build() {
+        Ok(js_error) => napi::Error::from(napi::JsValue::to_unknown(&js_error)),
+        Err(e) => e,
+    }
+}
+
+/// Builds the JS `Error` for a `GError`: its message is the `GError`
+/// message, its `name` is `NativeError`, and it carries `domain` (the
+/// domain quark), `domainName`, and `code` properties. The JS wrappers re-throw it as an
+/// instance of the `NativeError` class.
+pub(super) fn create_native_error<'env>(
+    env: &'env Env,
+    details: &GErrorDetails,
+) -> napi::Result<Object<'env>> {
+    let mut js_error = env.create_error(napi::Error::new(
+        napi::Status::GenericFailure,
+        details.message.clone(),
+    ))?;
+    js_error.set_named_property("name", "NativeError")?;
+    js_error.set_named_property("domain", details.domain_quark)?;
+    js_error.set_named_property("domainName", details.domain.as_str())?;
+    js_error.set_named_property("code", details.code)?;
+    Ok(js_error)
 }
 
 #[cfg_attr(test, allow(dead_code))]
@@ -1,10 +1,16 @@
 //! Dynamic `GType` registration.
 //!
 //! Registers new `GObject` subclasses at runtime from a JavaScript class
-//! descriptor: parses vfunc and inherited-interface overrides, builds a libffi
+//! descriptor: parses vfunc and interface overrides, builds a libffi
 //! trampoline for each handler, and writes the resulting function pointers into
-//! the new class's vtable (via [`class_init_trampoline`]) and its copies of any
-//! inherited interface vtables (via [`PreparedInterface::install`]).
+//! the new class's vtable (via [`class_init_trampoline`]), its copies of any
+//! inherited interface vtables (via [`PreparedInterface::install`]) and the
+//! vtables of interfaces it newly implements (via
+//! [`PreparedInterface::add_to`]). Declared
+//! properties and signals are created during class initialization too; see
+//! [`property`] and [`signal`]. Named lifecycle hooks are installed alongside
+//! the vfuncs; see [`lifecycle`]. The implementation each class vfunc override
+//! replaces stays callable from JS; see [`chain_up`].
 //!
 //! The functions that parse the JS descriptor or build trampolines around a
 //! captured JS callback are excluded from coverage instrumentation — they
@@ -13,11 +19,16 @@
 //! [`RegisterClassRequest::query_parent_gtype`], [`RegisterClassRequest::validate_vfunc_offset`])
 //! is exercised directly by tests.
 
+mod chain_up;
+mod lifecycle;
+mod property;
+mod signal;
+
 use std::ffi::{CString, c_char, c_void};
 use std::sync::Arc;
 use std::sync::atomic::AtomicPtr;
 
-use gtk4::glib::gobject_ffi;
+use gtk4::glib::{self, gobject_ffi, translate::FromGlib as _};
 use napi::bindgen_prelude::*;
 use napi::{Env, JsFunction, JsObject, NapiValue as _};
 use napi_derive::napi;
@@ -27,6 +38,10 @@ use crate::error_reporter::NativeErrorReporter;
 use crate::trampoline::{TrampolineData, TrampolineState};
 use crate::types::Type;
 use crate::value::{JsRef, map_js_array};
+use chain_up::ParentVfunc;
+use lifecycle::{PreparedClassHook, RawHook, install_instance_init};
+use property::{PreparedProperty, RawProperty};
+use signal::{PreparedSignal, RawSignal};
 
 /// JS-thread parse output for a vfunc override.
 ///
@@ -40,8 +55,8 @@ struct RawVfunc {
     return_type: Type,
 }
 
-/// JS-thread parse output for the vfunc overrides of one interface that the
-/// new class inherits from its parent.
+/// JS-thread parse output for the vfunc overrides of one interface, either
+/// inherited from the parent or newly implemented by the new class.
 #[cfg_attr(test, allow(dead_code))]
 struct RawInterface {
     gtype: usize,
@@ -93,15 +108,7 @@ impl RawVfunc {
             arg_types,
             return_type,
         } = self;
-        let data = TrampolineData {
-            js_func,
-            arg_types,
-            return_type,
-            user_data_index: None,
-            is_oneshot: false,
-            oneshot_state_ptr: AtomicPtr::new(std::ptr::null_mut()),
-        };
-        let state = Box::new(TrampolineState::create(data));
+        let state = build_trampoline(js_func, arg_types, return_type);
         let code_ptr = state.code_ptr;
         PreparedVfunc {
             byte_offset,
@@ -143,6 +150,25 @@ impl RawInterface {
     }
 }
 
+/// Builds a persistent trampoline calling `js_func` with the native signature
+/// `arg_types -> return_type`.
+#[cfg_attr(test, allow(dead_code))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn build_trampoline(
+    js_func: Arc<JsRef<JsFunction>>,
+    arg_types: Vec<Type>,
+    return_type: Type,
+) -> Box<TrampolineState> {
+    Box::new(TrampolineState::create(TrampolineData {
+        js_func,
+        arg_types,
+        return_type: Arc::new(return_type),
+        user_data_index: None,
+        is_oneshot: false,
+        oneshot_state_ptr: AtomicPtr::new(std::ptr::null_mut()),
+    }))
+}
+
 /// Built vfunc trampoline waiting to be written into a vtable.
 ///
 /// `code_ptr` is the libffi-generated C function pointer; `state` retains the
@@ -154,11 +180,13 @@ struct PreparedVfunc {
     state: Box<TrampolineState>,
 }
 
-/// Built interface vfunc overrides for one inherited interface.
+/// Built interface vfunc overrides for one interface.
 ///
 /// `gtype` identifies the interface; each vfunc's `byte_offset` is relative to
-/// the interface struct base. The overrides are written into the new class's
-/// own copy of the inherited interface vtable by [`PreparedInterface::install`].
+/// the interface struct base. Overrides of an inherited interface are written
+/// into the new class's own copy of its vtable by [`PreparedInterface::install`];
+/// a newly implemented interface gets them through
+/// [`PreparedInterface::add_to`].
 #[cfg_attr(test, allow(dead_code))]
 struct PreparedInterface {
     gtype: usize,
@@ -206,6 +234,42 @@ impl PreparedInterface {
         }
         PreparedVfunc::install_all(iface_vtable, self.vfuncs);
     }
+
+    /// Adds this interface to `instance_gtype`, which must not have been
+    /// referenced yet. `GLib` hands the vfuncs to
+    /// [`interface_init_trampoline`] when it initializes the class, after
+    /// copying the interface's default vtable.
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn add_to(self, instance_gtype: usize) {
+        let info = gobject_ffi::GInterfaceInfo {
+            interface_init: Some(interface_init_trampoline),
+            interface_finalize: None,
+            interface_data: Box::into_raw(Box::new(self.vfuncs)).cast::<c_void>(),
+        };
+        unsafe { gobject_ffi::g_type_add_interface_static(instance_gtype, self.gtype, &info) };
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+unsafe extern "C" fn interface_init_trampoline(g_iface: *mut c_void, iface_data: *mut c_void) {
+    if iface_data.is_null() {
+        return;
+    }
+    let vfuncs = unsafe { Box::from_raw(iface_data.cast::<Vec<PreparedVfunc>>()) };
+    PreparedVfunc::install_all(g_iface, *vfuncs);
+}
+
+/// Everything [`class_init_trampoline`] installs into the new class, passed
+/// to it as the type's `class_data`.
+#[cfg_attr(test, allow(dead_code))]
+struct PreparedClass {
+    parent_vfuncs: Vec<ParentVfunc>,
+    vfuncs: Vec<PreparedVfunc>,
+    hooks: Vec<PreparedClassHook>,
+    properties: Vec<PreparedProperty>,
+    signals: Vec<PreparedSignal>,
 }
 
 #[cfg_attr(test, allow(dead_code))]
@@ -214,8 +278,20 @@ unsafe extern "C" fn class_init_trampoline(g_class: *mut c_void, class_data: *mu
     if class_data.is_null() {
         return;
     }
-    let vfuncs = unsafe { Box::from_raw(class_data.cast::<Vec<PreparedVfunc>>()) };
-    PreparedVfunc::install_all(g_class, *vfuncs);
+    let class = unsafe { Box::from_raw(class_data.cast::<PreparedClass>()) };
+    let PreparedClass {
+        parent_vfuncs,
+        vfuncs,
+        hooks,
+        properties,
+        signals,
+    } = *class;
+    let gtype = unsafe { (*g_class.cast::<gobject_ffi::GTypeClass>()).g_type };
+    ParentVfunc::record_all(gtype, g_class, parent_vfuncs);
+    PreparedVfunc::install_all(g_class, vfuncs);
+    PreparedClassHook::install_all(g_class, hooks);
+    PreparedProperty::install_all(g_class, properties);
+    PreparedSignal::install_all(gtype, signals);
 }
 
 #[cfg_attr(test, allow(dead_code))]
@@ -224,6 +300,10 @@ struct RegisterClassRequest {
     parent_gtype: usize,
     vfuncs: Vec<RawVfunc>,
     interfaces: Vec<RawInterface>,
+    implements: Vec<RawInterface>,
+    hooks: Vec<RawHook>,
+    properties: Vec<RawProperty>,
+    signals: Vec<RawSignal>,
 }
 
 impl RegisterClassRequest {
@@ -289,18 +369,100 @@ impl RegisterClassRequest {
         }
 
         for iface in &self.interfaces {
-            if iface.gtype == 0 {
-                anyhow::bail!("interface gtype is invalid (G_TYPE_INVALID)");
+            Self::validate_interface_vfuncs(iface, None)?;
+        }
+        for iface in &self.implements {
+            let class_size = Self::query_interface_size(iface.gtype)?;
+            Self::validate_interface_vfuncs(iface, Some(class_size))?;
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(test, allow(dead_code))]
+    fn validate_interface_vfuncs(
+        iface: &RawInterface,
+        class_size: Option<u32>,
+    ) -> anyhow::Result<()> {
+        if iface.gtype == 0 {
+            anyhow::bail!("interface gtype is invalid (G_TYPE_INVALID)");
+        }
+        for vfunc in &iface.vfuncs {
+            Self::validate_vfunc_offset(
+                vfunc.byte_offset,
+                std::mem::align_of::<*mut c_void>(),
+                std::mem::size_of::<*mut c_void>(),
+                class_size,
+                "interface vfunc",
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Size of the vtable of an interface in `implements`, which bounds its
+    /// vfunc offsets the way the parent's class size bounds the class vfuncs.
+    fn query_interface_size(gtype: usize) -> anyhow::Result<u32> {
+        if gtype == 0 {
+            anyhow::bail!("interface gtype is invalid (G_TYPE_INVALID)");
+        }
+        let mut query: gobject_ffi::GTypeQuery = unsafe { std::mem::zeroed() };
+        unsafe { gobject_ffi::g_type_query(gtype, &mut query) };
+        if query.type_ == 0 {
+            anyhow::bail!("interface gtype could not be queried");
+        }
+        Ok(query.class_size)
+    }
+
+    /// Checks that each interface in `implements` is an interface the parent
+    /// does not conform to yet, listed once and after those of its
+    /// prerequisites the parent lacks. `g_type_add_interface_static` would
+    /// otherwise only report these as criticals.
+    fn validate_implements(&self) -> anyhow::Result<()> {
+        if self.implements.is_empty() {
+            return Ok(());
+        }
+        let parent = unsafe { glib::Type::from_glib(self.parent_gtype) };
+        let instantiatable = unsafe {
+            gobject_ffi::g_type_test_flags(
+                self.parent_gtype,
+                gobject_ffi::G_TYPE_FLAG_INSTANTIATABLE,
+            )
+        };
+        if instantiatable == glib::ffi::GFALSE {
+            anyhow::bail!(
+                "{} is not an instantiatable type and cannot implement interfaces",
+                parent.name()
+            );
+        }
+
+        let mut added: Vec<glib::Type> = Vec::with_capacity(self.implements.len());
+        for iface in &self.implements {
+            let iface = unsafe { glib::Type::from_glib(iface.gtype) };
+            if !iface.is_a(glib::Type::INTERFACE) {
+                anyhow::bail!("{} is not an interface type", iface.name());
+            }
+            if parent.is_a(iface) {
+                anyhow::bail!(
+                    "{} already implements {}; override its vfuncs through interfaceVfuncs",
+                    parent.name(),
+                    iface.name()
+                );
+            }
+            if added.contains(&iface) {
+                anyhow::bail!("interface {} is implemented more than once", iface.name());
             }
-            for vfunc in &iface.vfuncs {
-                Self::validate_vfunc_offset(
-                    vfunc.byte_offset,
-                    pointer_align,
-                    pointer_size,
-                    None,
-                    "interface vfunc",
-                )?;
+            let prerequisites = iface.interface_prerequisites();
+            let missing = prerequisites.iter().find(|&&prerequisite| {
+                !parent.is_a(prerequisite) && !added.contains(&prerequisite)
+            });
+            if let Some(missing) = missing {
+                anyhow::bail!(
+                    "interface {} requires {}, which {} does not implement",
+                    iface.name(),
+                    missing.name(),
+                    parent.name()
+                );
             }
+            added.push(iface);
         }
         Ok(())
     }
@@ -308,34 +470,38 @@ impl RegisterClassRequest {
     #[cfg_attr(test, allow(dead_code))]
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn register_type(
-        parent_gtype: usize,
+        query: &gobject_ffi::GTypeQuery,
         name_ptr: *const c_char,
-        class_vfuncs_ptr: *mut c_void,
+        class_data_ptr: *mut c_void,
+        instance_init: Option<Box<TrampolineState>>,
         interfaces: Vec<PreparedInterface>,
-        class_size: u16,
-        instance_size: u16,
+        implements: Vec<PreparedInterface>,
     ) -> anyhow::Result<usize> {
         let info = gobject_ffi::GTypeInfo {
-            class_size,
+            class_size: query.class_size as u16,
             base_init: None,
             base_finalize: None,
             class_init: Some(class_init_trampoline),
             class_finalize: None,
-            class_data: class_vfuncs_ptr,
-            instance_size,
+            class_data: class_data_ptr,
+            instance_size: query.instance_size as u16,
             n_preallocs: 0,
-            instance_init: None,
+            instance_init: instance_init.map(install_instance_init),
             value_table: std::ptr::null(),
         };
 
         let new_gtype =
-            unsafe { gobject_ffi::g_type_register_static(parent_gtype, name_ptr, &info, 0) };
+            unsafe { gobject_ffi::g_type_register_static(query.type_, name_ptr, &info, 0) };
 
         if new_gtype == 0 {
-            drop(unsafe { Box::from_raw(class_vfuncs_ptr.cast::<Vec<PreparedVfunc>>()) });
+            drop(unsafe { Box::from_raw(class_data_ptr.cast::<PreparedClass>()) });
             anyhow::bail!("g_type_register_static returned G_TYPE_INVALID");
         }
 
+        for iface in implements {
+            iface.add_to(new_gtype);
+        }
+
         let class_ptr = unsafe { gobject_ffi::g_type_class_ref(new_gtype) };
 
         for iface in interfaces {
@@ -357,25 +523,49 @@ impl ModuleRequest for RegisterClassRequest {
     fn execute(self) -> anyhow::Result<u64> {
         let query = self.query_parent_gtype()?;
         self.validate_layout(&query)?;
+        self.validate_implements()?;
+
+        let vfunc_offsets: Vec<usize> = self.vfuncs.iter().map(|vfunc| vfunc.byte_offset).collect();
+        let properties =
+            RawProperty::prepare_all(self.parent_gtype, self.properties, &vfunc_offsets)?;
+        let signals = RawSignal::prepare_all(self.parent_gtype, self.signals)?;
+        let hooks = RawHook::prepare_all(self.parent_gtype, self.hooks, &vfunc_offsets)?;
 
-        let class_size = query.class_size as u16;
-        let instance_size = query.instance_size as u16;
-        let class_vfuncs: Vec<PreparedVfunc> =
-            self.vfuncs.into_iter().map(RawVfunc::into_built).collect();
+        let parent_vfuncs = self
+            .vfuncs
+            .iter()
+            .map(|vfunc| ParentVfunc {
+                byte_offset: vfunc.byte_offset,
+                arg_types: vfunc.arg_types.clone(),
+                return_type: vfunc.return_type.clone(),
+            })
+            .collect();
+        let class = PreparedClass {
+            parent_vfuncs,
+            vfuncs: self.vfuncs.into_iter().map(RawVfunc::into_built).collect(),
+            hooks: hooks.class_hooks,
+            properties,
+            signals,
+        };
         let interfaces: Vec<PreparedInterface> = self
             .interfaces
             .into_iter()
             .map(RawInterface::into_built)
             .collect();
-        let class_vfuncs_ptr = Box::into_raw(Box::new(class_vfuncs)).cast::<c_void>();
+        let implements: Vec<PreparedInterface> = self
+            .implements
+            .into_iter()
+            .map(RawInterface::into_built)
+            .collect();
+        let class_data_ptr = Box::into_raw(Box::new(class)).cast::<c_void>();
 
         let new_gtype = Self::register_type(
-            self.parent_gtype,
+            &query,
             self.name.as_ptr(),
-            class_vfuncs_ptr,
+            class_data_ptr,
+            hooks.instance_init,
             interfaces,
-            class_size,
-            instance_size,
+            implements,
         )?;
 
         Ok(new_gtype as u64)
@@ -412,6 +602,46 @@ fn parse_type_array(env: &Env, prop: Unknown<'_>) -> napi::Result<Vec<Type>> {
     parse_js_array(env, prop, "types", Type::from_js_value)
 }
 
+/// Mirrors `g_param_spec_is_valid_name` and `g_signal_is_valid_name`: a letter
+/// followed by letters, digits, `-` or `_`.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[cfg_attr(test, allow(dead_code))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn optional_cstring(obj: &JsObject, key: &str) -> napi::Result<Option<CString>> {
+    obj.get_named_property::<Option<String>>(key)?
+        .map(CString::new)
+        .transpose()
+        .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+#[cfg_attr(test, allow(dead_code))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn handler_property(
+    env: &Env,
+    obj: &JsObject,
+    key: &str,
+) -> napi::Result<Option<Arc<JsRef<JsFunction>>>> {
+    let prop: Unknown<'_> = obj.get_named_property(key)?;
+    match prop.get_type()? {
+        napi::ValueType::Undefined | napi::ValueType::Null => Ok(None),
+        napi::ValueType::Function => {
+            let handler: JsFunction =
+                unsafe { JsFunction::from_raw_unchecked(env.raw(), prop.raw()) };
+            Ok(Some(Arc::new(JsRef::from_js_value(env, &handler)?)))
+        }
+        _ => Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("register_class: '{key}' handler must be a function"),
+        )),
+    }
+}
+
 #[allow(clippy::trivially_copy_pass_by_ref)]
 #[cfg_attr(test, allow(dead_code))]
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -437,23 +667,41 @@ fn parse_array_property<T>(
 #[allow(clippy::trivially_copy_pass_by_ref)]
 #[cfg_attr(test, allow(dead_code))]
 #[cfg_attr(coverage_nightly, coverage(off))]
-fn parse_register_options(
+fn parse_register_request(
     env: &Env,
+    name: CString,
+    parent_gtype: usize,
     options: Option<JsObject>,
-) -> napi::Result<(Vec<RawVfunc>, Vec<RawInterface>)> {
+) -> napi::Result<RegisterClassRequest> {
+    let mut request = RegisterClassRequest {
+        name,
+        parent_gtype,
+        vfuncs: Vec::new(),
+        interfaces: Vec::new(),
+        implements: Vec::new(),
+        hooks: Vec::new(),
+        properties: Vec::new(),
+        signals: Vec::new(),
+    };
     let Some(options) = options else {
-        return Ok((Vec::new(), Vec::new()));
+        return Ok(request);
     };
 
-    let vfuncs = parse_array_property(env, &options, "vfuncs", RawVfunc::from_js_value)?;
-    let interfaces = parse_array_property(
+    request.vfuncs = parse_array_property(env, &options, "vfuncs", RawVfunc::from_js_value)?;
+    request.interfaces = parse_array_property(
         env,
         &options,
         "interfaceVfuncs",
         RawInterface::from_js_value,
     )?;
+    request.implements =
+        parse_array_property(env, &options, "implements", RawInterface::from_js_value)?;
+    request.hooks = RawHook::parse_all(env, &options)?;
+    request.properties =
+        parse_array_property(env, &options, "properties", RawProperty::from_js_value)?;
+    request.signals = parse_array_property(env, &options, "signals", RawSignal::from_js_value)?;
 
-    Ok((vfuncs, interfaces))
+    Ok(request)
 }
 
 /// napi export shim. Excluded from coverage instrumentation: it parses the JS
@@ -476,14 +724,7 @@ mod napi_export {
     ) -> napi::Result<Unknown<'_>> {
         let name = CString::new(name)
             .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))?;
-        let (vfuncs, interfaces) = parse_register_options(env, options)?;
-        RegisterClassRequest {
-            name,
-            parent_gtype: parent_gtype as usize,
-            vfuncs,
-            interfaces,
-        }
-        .dispatch(env)
+        parse_register_request(env, name, parent_gtype as usize, options)?.dispatch(env)
     }
 }
 
@@ -491,10 +732,12 @@ mod napi_export {
 mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
-    use gtk4::glib;
-    use gtk4::glib::translate::IntoGlib as _;
-    use gtk4::prelude::StaticType as _;
+    use gtk4::glib::translate::{FromGlib as _, IntoGlib as _};
+    use gtk4::prelude::{ObjectExt as _, StaticType as _, ToValue as _};
+    use gtk4::{gio, glib};
 
+    use super::property::PropertyKind;
+    use super::signal::SignalAccumulator;
     use super::*;
 
     const POINTER_ALIGN: usize = 8;
@@ -511,16 +754,192 @@ mod tests {
         glib::Object::static_type().into_glib()
     }
 
+    /// A request registering `name` under `parent_gtype` with no options.
+    fn empty_request(name: CString, parent_gtype: usize) -> RegisterClassRequest {
+        RegisterClassRequest {
+            name,
+            parent_gtype,
+            vfuncs: vec![],
+            interfaces: vec![],
+            implements: vec![],
+            hooks: vec![],
+            properties: vec![],
+            signals: vec![],
+        }
+    }
+
     #[test]
     fn execute_registers_a_new_gtype() {
+        let request = empty_request(unique_name("GtkxTestExecuteType"), object_parent_gtype());
+        let gtype = request.execute().expect("registration should succeed");
+        assert_ne!(gtype, 0);
+    }
+
+    fn count_property(default: i32) -> RawProperty {
+        RawProperty {
+            name: CString::new("count").unwrap(),
+            nick: None,
+            blurb: None,
+            flags: gobject_ffi::G_PARAM_READABLE,
+            kind: PropertyKind::Int {
+                minimum: 0,
+                maximum: 10,
+                default: Some(default),
+            },
+            get: None,
+            set: None,
+        }
+    }
+
+    #[test]
+    fn execute_installs_declared_properties() {
         let request = RegisterClassRequest {
-            name: unique_name("GtkxTestExecuteType"),
-            parent_gtype: object_parent_gtype(),
-            vfuncs: vec![],
-            interfaces: vec![],
+            properties: vec![count_property(4)],
+            ..empty_request(unique_name("GtkxTestProperties"), object_parent_gtype())
         };
         let gtype = request.execute().expect("registration should succeed");
-        assert_ne!(gtype, 0);
+        let gtype = unsafe { glib::Type::from_glib(gtype as usize) };
+
+        let object = glib::Object::with_type(gtype);
+        let pspec = object
+            .find_property("count")
+            .expect("the property should be installed");
+        assert_eq!(pspec.owner_type(), gtype);
+        assert_eq!(object.property::<i32>("count"), 4);
+    }
+
+    #[test]
+    fn execute_rejects_invalid_properties_before_registering() {
+        let name = unique_name("GtkxTestInvalidProperties");
+        let request = RegisterClassRequest {
+            properties: vec![count_property(1), count_property(2)],
+            ..empty_request(name.clone(), object_parent_gtype())
+        };
+        let err = request
+            .execute()
+            .expect_err("duplicate properties should fail");
+        assert!(err.to_string().contains("declared more than once"));
+        assert_eq!(unsafe { gobject_ffi::g_type_from_name(name.as_ptr()) }, 0);
+    }
+
+    #[test]
+    fn execute_creates_declared_signals() {
+        let request = RegisterClassRequest {
+            signals: vec![RawSignal {
+                name: CString::new("activate-item").unwrap(),
+                flags: gobject_ffi::G_SIGNAL_RUN_LAST,
+                param_types: vec![glib::Type::I32.into_glib()],
+                return_type: glib::Type::BOOL.into_glib(),
+                accumulator: Some(SignalAccumulator::TrueHandled),
+                class_handler: None,
+            }],
+            ..empty_request(unique_name("GtkxTestSignals"), object_parent_gtype())
+        };
+        let gtype = request.execute().expect("registration should succeed");
+        let gtype = unsafe { glib::Type::from_glib(gtype as usize) };
+
+        let object = glib::Object::with_type(gtype);
+        let calls = Arc::new(AtomicUsize::new(0));
+        for handled in [true, false] {
+            let calls = calls.clone();
+            object.connect_local("activate-item", false, move |args| {
+                assert_eq!(args[1].get::<i32>().unwrap(), 3);
+                calls.fetch_add(1, Ordering::Relaxed);
+                Some(handled.to_value())
+            });
+        }
+
+        assert!(object.emit_by_name::<bool>("activate-item", &[&3i32]));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    fn implementing(parent: glib::Type, interfaces: &[glib::Type]) -> RegisterClassRequest {
+        RegisterClassRequest {
+            implements: interfaces
+                .iter()
+                .map(|iface| RawInterface {
+                    gtype: iface.into_glib(),
+                    vfuncs: vec![],
+                })
+                .collect(),
+            ..empty_request(unique_name("GtkxTestImplements"), parent.into_glib())
+        }
+    }
+
+    fn implements_err(parent: glib::Type, interfaces: &[glib::Type]) -> String {
+        implementing(parent, interfaces)
+            .validate_implements()
+            .expect_err("the interfaces should be rejected")
+            .to_string()
+    }
+
+    #[test]
+    fn execute_adds_implemented_interfaces() {
+        let request = implementing(
+            glib::Object::static_type(),
+            &[gio::ListModel::static_type()],
+        );
+        let gtype = request.execute().expect("registration should succeed");
+        let gtype = unsafe { glib::Type::from_glib(gtype as usize) };
+
+        assert!(gtype.is_a(gio::ListModel::static_type()));
+        assert!(glib::Object::with_type(gtype).is::<gio::ListModel>());
+    }
+
+    #[test]
+    fn validate_implements_accepts_prerequisites_listed_first() {
+        let request = implementing(
+            glib::Object::static_type(),
+            &[
+                gio::DatagramBased::static_type(),
+                gio::DtlsConnection::static_type(),
+            ],
+        );
+        assert!(request.validate_implements().is_ok());
+    }
+
+    #[test]
+    fn validate_implements_rejects_unusable_interfaces() {
+        let object = glib::Object::static_type();
+
+        let err = implements_err(object, &[gio::ListStore::static_type()]);
+        assert!(err.contains("GListStore is not an interface type"), "{err}");
+
+        let list_model = gio::ListModel::static_type();
+        let err = implements_err(gio::ListStore::static_type(), &[list_model]);
+        assert!(
+            err.contains("GListStore already implements GListModel"),
+            "{err}"
+        );
+
+        let err = implements_err(object, &[list_model, list_model]);
+        assert!(err.contains("implemented more than once"), "{err}");
+
+        let err = implements_err(object, &[gio::PollableInputStream::static_type()]);
+        assert!(
+            err.contains(
+                "interface GPollableInputStream requires GInputStream, which GObject does not implement"
+            ),
+            "{err}"
+        );
+
+        let err = implements_err(
+            object,
+            &[
+                gio::DtlsConnection::static_type(),
+                gio::DatagramBased::static_type(),
+            ],
+        );
+        assert!(err.contains("requires GDatagramBased"), "{err}");
+    }
+
+    #[test]
+    fn is_valid_name_follows_the_glib_rules() {
+        assert!(is_valid_name("max-width-chars"));
+        assert!(is_valid_name("use_underline"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("-leading"));
+        assert!(!is_valid_name("has space"));
     }
 
     #[test]
@@ -530,12 +949,7 @@ mod tests {
 
     #[test]
     fn query_parent_gtype_rejects_invalid_parent() {
-        let request = RegisterClassRequest {
-            name: unique_name("GtkxTestInvalidParent"),
-            parent_gtype: 0,
-            vfuncs: vec![],
-            interfaces: vec![],
-        };
+        let request = empty_request(unique_name("GtkxTestInvalidParent"), 0);
         let err = request
             .query_parent_gtype()
             .expect_err("invalid parent should fail");
@@ -544,12 +958,10 @@ mod tests {
 
     #[test]
     fn query_parent_gtype_rejects_non_classed_parent() {
-        let request = RegisterClassRequest {
-            name: unique_name("GtkxTestNonClassedParent"),
-            parent_gtype: glib::Type::I64.into_glib(),
-            vfuncs: vec![],
-            interfaces: vec![],
-        };
+        let request = empty_request(
+            unique_name("GtkxTestNonClassedParent"),
+            glib::Type::I64.into_glib(),
+        );
         let err = request
             .query_parent_gtype()
             .expect_err("non-classed parent should fail");
@@ -559,20 +971,10 @@ mod tests {
     #[test]
     fn query_parent_gtype_rejects_already_registered_name() {
         let name = unique_name("GtkxTestDuplicateName");
-        let first = RegisterClassRequest {
-            name: name.clone(),
-            parent_gtype: object_parent_gtype(),
-            vfuncs: vec![],
-            interfaces: vec![],
-        };
+        let first = empty_request(name.clone(), object_parent_gtype());
         first.execute().expect("first registration should succeed");
 
-        let second = RegisterClassRequest {
-            name,
-            parent_gtype: object_parent_gtype(),
-            vfuncs: vec![],
-            interfaces: vec![],
-        };
+        let second = empty_request(name, object_parent_gtype());
         let err = second
             .query_parent_gtype()
             .expect_err("duplicate name should fail");
@@ -644,4 +1046,18 @@ mod tests {
         let err = result.expect_err("usize overflow should fail validation");
         assert!(err.to_string().contains("overflow"));
     }
+
+    #[test]
+    fn query_interface_size_returns_the_vtable_size() {
+        let list_model = gio::ListModel::static_type().into_glib();
+        let size = RegisterClassRequest::query_interface_size(list_model)
+            .expect("GListModel should be queryable");
+        assert_eq!(
+            size as usize,
+            std::mem::size_of::<gio::ffi::GListModelInterface>()
+        );
+
+        let err = RegisterClassRequest::query_interface_size(0).expect_err("zero gtype");
+        assert!(err.to_string().contains("G_TYPE_INVALID"));
+    }
 }
@@ -0,0 +1,321 @@
+//! Chaining up from a class vfunc override to the implementation it replaced.
+//!
+//! While a registered class is initialized, [`ParentVfunc::record_all`] reads
+//! the function pointer each overridden slot still holds — the one inherited
+//! from the parent class — and caches a [`PreparedCall`] for it, built from
+//! the override's own `argTypes` and `returnType`. [`chain_up`](napi_export::chain_up)
+//! looks the call up by the class's `GType` and the slot's byte offset and
+//! invokes it, so an override such as `snapshot` or `measure` can run the
+//! parent's version without peeking the parent class struct.
+
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use anyhow::bail;
+use gtk4::glib::{self, translate::FromGlib as _};
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::error_reporter::NativeErrorReporter;
+use crate::module::call::PreparedCall;
+use crate::module::handler::{ModuleRequest, RefUpdate};
+use crate::types::Type;
+use crate::value::Value;
+
+/// Signature of a class vfunc override, kept to call the implementation it
+/// replaces.
+pub(super) struct ParentVfunc {
+    pub(super) byte_offset: usize,
+    pub(super) arg_types: Vec<Type>,
+    pub(super) return_type: Type,
+}
+
+/// What an overridden class vfunc slot held in the parent class.
+#[derive(Clone)]
+enum ParentSlot {
+    Implemented(Arc<PreparedCall>),
+    /// The slot was empty in the parent class.
+    Empty,
+    /// The call to the parent implementation could not be built, for the
+    /// reason held.
+    Unusable(String),
+}
+
+/// The inherited implementation of every overridden class vfunc, keyed by the
+/// overriding class and the slot's byte offset.
+#[derive(Default)]
+struct ParentVfuncs {
+    slots: Mutex<HashMap<(usize, usize), ParentSlot>>,
+}
+
+static PARENT_VFUNCS: OnceLock<ParentVfuncs> = OnceLock::new();
+
+impl ParentVfuncs {
+    fn global() -> &'static Self {
+        PARENT_VFUNCS.get_or_init(Self::default)
+    }
+
+    fn insert(&self, gtype: usize, byte_offset: usize, slot: ParentSlot) {
+        self.slots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert((gtype, byte_offset), slot);
+    }
+
+    /// Returns the implementation that the override of `gtype` at
+    /// `byte_offset` replaced.
+    fn get(&self, gtype: usize, byte_offset: usize) -> anyhow::Result<Arc<PreparedCall>> {
+        let slot = self
+            .slots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(gtype, byte_offset))
+            .cloned();
+        // A recorded key means `gtype` is registered, so it can be named.
+        let type_name = || unsafe { glib::Type::from_glib(gtype) }.name();
+        match slot {
+            Some(ParentSlot::Implemented(call)) => Ok(call),
+            Some(ParentSlot::Empty) => bail!(
+                "the parent class of {} has no implementation at byte offset {byte_offset}",
+                type_name()
+            ),
+            Some(ParentSlot::Unusable(reason)) => bail!(
+                "cannot chain up from {} at byte offset {byte_offset}: {reason}",
+                type_name()
+            ),
+            None => bail!("GType {gtype} has no vfunc override at byte offset {byte_offset}"),
+        }
+    }
+
+    /// Caches a call to the implementation each slot overridden by `vfuncs`
+    /// holds in `g_class`, the class of `gtype`.
+    ///
+    /// Every argument of the call is optional, since a chain-up passes on
+    /// whatever the override received, `NULL` included.
+    fn record_all(&self, gtype: usize, g_class: *mut c_void, vfuncs: Vec<ParentVfunc>) {
+        let type_name = unsafe { glib::Type::from_glib(gtype) }.name();
+        for ParentVfunc {
+            byte_offset,
+            arg_types,
+            return_type,
+        } in vfuncs
+        {
+            let code_ptr = unsafe {
+                g_class
+                    .cast::<u8>()
+                    .add(byte_offset)
+                    .cast::<*mut c_void>()
+                    .read()
+            };
+            if code_ptr.is_null() {
+                self.insert(gtype, byte_offset, ParentSlot::Empty);
+                continue;
+            }
+            let optional_args = vec![true; arg_types.len()];
+            match PreparedCall::from_code_ptr(
+                format!("the parent {type_name} vfunc at byte offset {byte_offset}"),
+                arg_types,
+                optional_args,
+                return_type,
+                code_ptr,
+            ) {
+                Ok(call) => {
+                    self.insert(gtype, byte_offset, ParentSlot::Implemented(Arc::new(call)))
+                }
+                Err(e) => {
+                    let reason = format!("{e:#}");
+                    NativeErrorReporter::global().report(&e.context(format!(
+                        "register_class: cannot chain up from {type_name} at byte offset {byte_offset}"
+                    )));
+                    self.insert(gtype, byte_offset, ParentSlot::Unusable(reason));
+                }
+            }
+        }
+    }
+}
+
+impl ParentVfunc {
+    /// Records the implementation each slot overridden by `vfuncs` holds in
+    /// the class of `gtype` being initialized, for [`chain_up`](napi_export::chain_up).
+    /// Must run before the overrides are written into the class.
+    pub(super) fn record_all(gtype: usize, g_class: *mut c_void, vfuncs: Vec<Self>) {
+        ParentVfuncs::global().record_all(gtype, g_class, vfuncs);
+    }
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct ChainUpRequest {
+    parent: Arc<PreparedCall>,
+    values: Vec<Value>,
+}
+
+impl ModuleRequest for ChainUpRequest {
+    type Output = (Value, Vec<RefUpdate>);
+
+    fn execute(self) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+        self.parent.invoke_values(&self.values)
+    }
+
+    fn error_context() -> &'static str {
+        "chain-up"
+    }
+}
+
+/// napi export shim. Excluded from coverage instrumentation: it parses JS
+/// arguments through a live [`napi::Env`]. The [`ChainUpRequest::execute`]
+/// logic it dispatches is exercised directly by tests.
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[allow(clippy::wildcard_imports)]
+mod napi_export {
+    use super::*;
+    use crate::module::handler::request_error;
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn chain_up<'env>(
+        env: &'env Env,
+        gtype: f64,
+        byte_offset: f64,
+        args: Array,
+    ) -> napi::Result<Unknown<'env>> {
+        let parent = ParentVfuncs::global()
+            .get(gtype as usize, byte_offset as usize)
+            .map_err(|e| request_error(env, &e, ChainUpRequest::error_context()))?;
+        let mut arg_types = parent.arg_types();
+        let values = crate::value::map_js_array(env, &args, |env, value| match arg_types.next() {
+            Some(ty) => Value::from_js_value_typed(env, value, ty),
+            None => Value::from_js_value(env, value),
+        })?;
+        ChainUpRequest { parent, values }.dispatch(env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gtk4::glib::translate::IntoGlib as _;
+    use gtk4::prelude::StaticType as _;
+
+    use super::*;
+    use crate::types::IntegerKind;
+
+    extern "C" fn subtract(a: i32, b: i32) -> i32 {
+        a - b
+    }
+
+    fn object_gtype() -> usize {
+        glib::Object::static_type().into_glib()
+    }
+
+    fn int_signature(byte_offset: usize) -> ParentVfunc {
+        ParentVfunc {
+            byte_offset,
+            arg_types: vec![
+                Type::Integer(IntegerKind::I32),
+                Type::Integer(IntegerKind::I32),
+            ],
+            return_type: Type::Integer(IntegerKind::I32),
+        }
+    }
+
+    /// Records, in a registry of its own, the two slots following the first
+    /// one of a fake class struct: one holding `subtract` and an empty one.
+    fn record_fake_class() -> ParentVfuncs {
+        let size = std::mem::size_of::<usize>();
+        let mut class = [0usize; 4];
+        class[1] = subtract as usize;
+        let registry = ParentVfuncs::default();
+        registry.record_all(
+            object_gtype(),
+            class.as_mut_ptr().cast(),
+            vec![int_signature(size), int_signature(2 * size)],
+        );
+        registry
+    }
+
+    #[test]
+    fn execute_calls_the_recorded_parent_implementation() {
+        let size = std::mem::size_of::<usize>();
+        let request = ChainUpRequest {
+            parent: record_fake_class()
+                .get(object_gtype(), size)
+                .expect("the slot should be recorded"),
+            values: vec![Value::Number(50.0), Value::Number(8.0)],
+        };
+
+        let (value, ref_updates) = request.execute().expect("chain-up should succeed");
+        assert!(ref_updates.is_empty());
+        assert_eq!(value.as_number(), Some(42.0));
+    }
+
+    #[test]
+    fn execute_rejects_the_wrong_number_of_arguments() {
+        let size = std::mem::size_of::<usize>();
+        let request = ChainUpRequest {
+            parent: record_fake_class()
+                .get(object_gtype(), size)
+                .expect("the slot should be recorded"),
+            values: vec![Value::Number(1.0)],
+        };
+
+        let err = request
+            .execute()
+            .expect_err("a missing argument should fail");
+        assert_eq!(
+            err.to_string(),
+            format!("the parent GObject vfunc at byte offset {size} expects 2 argument(s), got 1")
+        );
+    }
+
+    #[test]
+    fn get_reports_an_empty_parent_slot() {
+        let size = std::mem::size_of::<usize>();
+        let err = record_fake_class()
+            .get(object_gtype(), 2 * size)
+            .expect_err("an empty slot has nothing to chain up to");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "the parent class of GObject has no implementation at byte offset {}",
+                2 * size
+            )
+        );
+    }
+
+    #[test]
+    fn get_reports_why_a_parent_call_could_not_be_built() {
+        let registry = ParentVfuncs::default();
+        registry.insert(
+            object_gtype(),
+            8,
+            ParentSlot::Unusable("unsupported argument type".to_owned()),
+        );
+        let err = registry
+            .get(object_gtype(), 8)
+            .expect_err("an unusable slot cannot be chained up to");
+        assert_eq!(
+            err.to_string(),
+            "cannot chain up from GObject at byte offset 8: unsupported argument type"
+        );
+    }
+
+    #[test]
+    fn get_reports_a_slot_without_override() {
+        let err = record_fake_class()
+            .get(object_gtype(), 4096)
+            .expect_err("no override was recorded at that offset");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "GType {} has no vfunc override at byte offset 4096",
+                object_gtype()
+            )
+        );
+    }
+
+    #[test]
+    fn error_context_is_chain_up() {
+        assert_eq!(ChainUpRequest::error_context(), "chain-up");
+    }
+}
@@ -0,0 +1,406 @@
+//! Named instance lifecycle hooks of a registered class.
+//!
+//! `constructed`, `dispose` and `finalize` replace the matching `GObjectClass`
+//! vfuncs and chain up to the implementation the class inherited, read from
+//! the class struct just before it is overwritten. The parent runs after the
+//! JS handler, except for `constructed`, whose handler sees the instance once
+//! the parent has finished constructing it. `instanceInit` becomes the type's
+//! `GTypeInfo.instance_init`, which `GLib` itself runs after those of the
+//! parent types, so it needs no chaining.
+//!
+//! `instanceInit` and `constructed` run before `g_object_new` returns, while a
+//! `GInitiallyUnowned` instance is still floating. Turning the instance into a
+//! handle calls `g_object_ref_sink`, so the handle is given a reference of its
+//! own and the instance is made floating again once the handler returns; the
+//! caller of `g_object_new` then sinks it as usual.
+//!
+//! Each vfunc hook is a libffi closure bound to the class that declared it, so
+//! a registered subclass chaining up to it reaches that class's parent instead
+//! of looping back into itself.
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use ::libffi::low as libffi_low;
+use ::libffi::middle as libffi;
+use anyhow::bail;
+use gtk4::glib::{self, gobject_ffi, translate::FromGlib as _};
+use napi::{Env, JsFunction, JsObject};
+
+use super::{build_trampoline, handler_property};
+use crate::trampoline::TrampolineState;
+use crate::types::{GObjectType, IntegerKind, Ownership, Type, VoidType};
+use crate::value::JsRef;
+
+/// An instance lifecycle stage a registered class can hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum LifecycleHook {
+    InstanceInit,
+    Constructed,
+    Dispose,
+    Finalize,
+}
+
+/// JS-thread parse output for one lifecycle hook of the new class.
+#[cfg_attr(test, allow(dead_code))]
+pub(super) struct RawHook {
+    hook: LifecycleHook,
+    js_func: Arc<JsRef<JsFunction>>,
+}
+
+/// Built trampolines of the lifecycle hooks, waiting to be installed.
+#[derive(Default)]
+pub(super) struct PreparedHooks {
+    /// Passed as the type's `instance_init`.
+    pub(super) instance_init: Option<Box<TrampolineState>>,
+    /// Installed into the class struct during class initialization.
+    pub(super) class_hooks: Vec<PreparedClassHook>,
+}
+
+/// A built `GObjectClass` vfunc hook.
+pub(super) struct PreparedClassHook {
+    hook: LifecycleHook,
+    byte_offset: usize,
+    handler: Box<TrampolineState>,
+}
+
+/// State of an installed hook, read by [`hook_handler`].
+struct HookData {
+    parent_first: bool,
+    keeps_floating: bool,
+    handler: Box<TrampolineState>,
+    parent: Option<unsafe extern "C" fn(*mut gobject_ffi::GObject)>,
+}
+
+impl LifecycleHook {
+    const ALL: [Self; 4] = [
+        Self::InstanceInit,
+        Self::Constructed,
+        Self::Dispose,
+        Self::Finalize,
+    ];
+
+    /// The option declaring the hook in the JS class descriptor.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::InstanceInit => "instanceInit",
+            Self::Constructed => "constructed",
+            Self::Dispose => "dispose",
+            Self::Finalize => "finalize",
+        }
+    }
+
+    /// Offset of the `GObjectClass` vfunc the hook replaces, if any.
+    const fn class_offset(self) -> Option<usize> {
+        match self {
+            Self::InstanceInit => None,
+            Self::Constructed => Some(std::mem::offset_of!(gobject_ffi::GObjectClass, constructed)),
+            Self::Dispose => Some(std::mem::offset_of!(gobject_ffi::GObjectClass, dispose)),
+            Self::Finalize => Some(std::mem::offset_of!(gobject_ffi::GObjectClass, finalize)),
+        }
+    }
+
+    /// Whether the JS handler receives a handle to the instance. `dispose`
+    /// and `finalize` pass the instance's address instead, since a handle
+    /// would take a reference on an instance being torn down.
+    const fn passes_handle(self) -> bool {
+        matches!(self, Self::InstanceInit | Self::Constructed)
+    }
+
+    /// Native arguments of the hook's JS trampoline.
+    fn arg_types(self) -> Vec<Type> {
+        if self.passes_handle() {
+            vec![Type::GObject(GObjectType {
+                ownership: Ownership::Borrowed,
+            })]
+        } else {
+            vec![Type::Integer(IntegerKind::U64)]
+        }
+    }
+
+    /// Checks that a subclass of `parent_gtype` can have `hooks`, none of
+    /// which may replace a slot that a vfunc at one of `vfunc_offsets`
+    /// overrides too.
+    fn check_all(
+        parent_gtype: usize,
+        hooks: &[Self],
+        vfunc_offsets: &[usize],
+    ) -> anyhow::Result<()> {
+        if hooks.is_empty() {
+            return Ok(());
+        }
+        let parent = unsafe { glib::Type::from_glib(parent_gtype) };
+        if !parent.is_a(glib::Type::OBJECT) {
+            bail!(
+                "{} is not a GObject type and cannot have lifecycle hooks",
+                parent.name()
+            );
+        }
+        for hook in hooks {
+            if let Some(offset) = hook.class_offset()
+                && vfunc_offsets.contains(&offset)
+            {
+                bail!(
+                    "the '{}' hook and a vfunc both override byte offset {offset}",
+                    hook.name()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RawHook {
+    /// Parses the lifecycle hooks declared in the class descriptor.
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub(super) fn parse_all(env: &Env, options: &JsObject) -> napi::Result<Vec<Self>> {
+        let mut hooks = Vec::new();
+        for hook in LifecycleHook::ALL {
+            if let Some(js_func) = handler_property(env, options, hook.name())? {
+                hooks.push(Self { hook, js_func });
+            }
+        }
+        Ok(hooks)
+    }
+
+    /// Checks the hooks against the parent type and the class vfunc overrides
+    /// at `vfunc_offsets`, then builds their JS trampolines.
+    pub(super) fn prepare_all(
+        parent_gtype: usize,
+        hooks: Vec<Self>,
+        vfunc_offsets: &[usize],
+    ) -> anyhow::Result<PreparedHooks> {
+        let kinds: Vec<LifecycleHook> = hooks.iter().map(|raw| raw.hook).collect();
+        LifecycleHook::check_all(parent_gtype, &kinds, vfunc_offsets)?;
+
+        let mut prepared = PreparedHooks::default();
+        for Self { hook, js_func } in hooks {
+            let handler = build_trampoline(js_func, hook.arg_types(), Type::Void(VoidType));
+            match hook.class_offset() {
+                None => prepared.instance_init = Some(handler),
+                Some(byte_offset) => prepared.class_hooks.push(PreparedClassHook {
+                    hook,
+                    byte_offset,
+                    handler,
+                }),
+            }
+        }
+        Ok(prepared)
+    }
+}
+
+impl PreparedClassHook {
+    /// Replaces each hooked vfunc of the class being initialized with a
+    /// closure that runs the JS handler and the implementation the slot held
+    /// before. The closures and their state are leaked, like vfunc
+    /// trampolines, since the class of a static type is never finalized.
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub(super) fn install_all(g_class: *mut c_void, hooks: Vec<Self>) {
+        for Self {
+            hook,
+            byte_offset,
+            handler,
+        } in hooks
+        {
+            let slot = unsafe {
+                g_class
+                    .cast::<u8>()
+                    .add(byte_offset)
+                    .cast::<Option<unsafe extern "C" fn(*mut gobject_ffi::GObject)>>()
+            };
+            let data: &'static HookData = Box::leak(Box::new(HookData {
+                parent_first: hook == LifecycleHook::Constructed,
+                keeps_floating: hook.passes_handle(),
+                handler,
+                parent: unsafe { slot.read() },
+            }));
+            let cif = libffi::Cif::new(vec![libffi::Type::pointer()], libffi::Type::void());
+            let closure = libffi::Closure::new(cif, hook_handler, data);
+            unsafe {
+                slot.cast::<*mut c_void>()
+                    .write(*closure.code_ptr() as *mut c_void)
+            };
+            std::mem::forget(closure);
+        }
+    }
+}
+
+/// Wraps the `instanceInit` handler in a closure to use as the type's
+/// `GTypeInfo.instance_init`, which also receives the class pointer. The
+/// closure and its state are leaked, like those of the class hooks.
+#[cfg_attr(test, allow(dead_code))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(super) fn install_instance_init(
+    handler: Box<TrampolineState>,
+) -> unsafe extern "C" fn(*mut gobject_ffi::GTypeInstance, glib::ffi::gpointer) {
+    let data: &'static HookData = Box::leak(Box::new(HookData {
+        parent_first: false,
+        keeps_floating: true,
+        handler,
+        parent: None,
+    }));
+    let cif = libffi::Cif::new(
+        vec![libffi::Type::pointer(), libffi::Type::pointer()],
+        libffi::Type::void(),
+    );
+    let closure = libffi::Closure::new(cif, hook_handler, data);
+    let code_ptr = *closure.code_ptr() as *mut c_void;
+    std::mem::forget(closure);
+    unsafe {
+        std::mem::transmute::<
+            *mut c_void,
+            unsafe extern "C" fn(*mut gobject_ffi::GTypeInstance, glib::ffi::gpointer),
+        >(code_ptr)
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+unsafe extern "C" fn hook_handler(
+    _cif: &libffi_low::ffi_cif,
+    _result: &mut u64,
+    args: *const *const c_void,
+    data: &HookData,
+) {
+    let object = unsafe { *(*args).cast::<*mut gobject_ffi::GObject>() };
+    let run_handler = || unsafe {
+        let handler = std::mem::transmute::<
+            *mut c_void,
+            unsafe extern "C" fn(*mut gobject_ffi::GObject),
+        >(data.handler.code_ptr);
+        if data.keeps_floating {
+            run_keeping_floating(object, || handler(object));
+        } else {
+            handler(object);
+        }
+    };
+    let run_parent = || {
+        if let Some(parent) = data.parent {
+            unsafe { parent(object) };
+        }
+    };
+    if data.parent_first {
+        run_parent();
+        run_handler();
+    } else {
+        run_handler();
+        run_parent();
+    }
+}
+
+/// Runs `handler`, which turns the instance into a handle, without leaving
+/// a floating instance sunk: the handle takes a reference added here, and the
+/// floating flag it cleared is set again afterwards.
+unsafe fn run_keeping_floating(object: *mut gobject_ffi::GObject, handler: impl FnOnce()) {
+    if unsafe { gobject_ffi::g_object_is_floating(object) } == 0 {
+        handler();
+        return;
+    }
+    unsafe { gobject_ffi::g_object_ref(object) };
+    handler();
+    if unsafe { gobject_ffi::g_object_is_floating(object) } == 0 {
+        unsafe { gobject_ffi::g_object_force_floating(object) };
+    } else {
+        // No handle was made, so nothing took the added reference.
+        unsafe { gobject_ffi::g_object_unref(object) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gtk4::glib::prelude::*;
+    use gtk4::glib::translate::{FromGlibPtrNone as _, IntoGlib as _};
+
+    use super::*;
+
+    fn object_gtype() -> usize {
+        glib::Object::static_type().into_glib()
+    }
+
+    #[test]
+    fn check_all_accepts_hooks_on_a_gobject_parent() {
+        let result = LifecycleHook::check_all(
+            object_gtype(),
+            &LifecycleHook::ALL,
+            &[std::mem::offset_of!(gobject_ffi::GObjectClass, notify)],
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn check_all_rejects_a_parent_that_is_not_a_gobject() {
+        let err = LifecycleHook::check_all(
+            glib::Type::VARIANT.into_glib(),
+            &[LifecycleHook::InstanceInit],
+            &[],
+        )
+        .expect_err("a non-object parent should be rejected");
+        assert!(err.to_string().contains("GVariant is not a GObject type"));
+    }
+
+    #[test]
+    fn check_all_rejects_a_hook_overridden_by_a_vfunc() {
+        let dispose = std::mem::offset_of!(gobject_ffi::GObjectClass, dispose);
+        let err = LifecycleHook::check_all(
+            object_gtype(),
+            &[LifecycleHook::Constructed, LifecycleHook::Dispose],
+            &[dispose],
+        )
+        .expect_err("the conflicting vfunc should be rejected");
+        assert_eq!(
+            err.to_string(),
+            format!("the 'dispose' hook and a vfunc both override byte offset {dispose}")
+        );
+    }
+
+    #[test]
+    fn check_all_ignores_a_parent_without_hooks() {
+        assert!(LifecycleHook::check_all(glib::Type::VARIANT.into_glib(), &[], &[]).is_ok());
+    }
+
+    fn new_floating_object() -> *mut gobject_ffi::GObject {
+        let object = unsafe {
+            gobject_ffi::g_object_new_with_properties(
+                glib::InitiallyUnowned::static_type().into_glib(),
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null(),
+            )
+        };
+        assert_ne!(unsafe { gobject_ffi::g_object_is_floating(object) }, 0);
+        object
+    }
+
+    fn ref_count(object: *mut gobject_ffi::GObject) -> u32 {
+        unsafe { (*object).ref_count }
+    }
+
+    #[test]
+    fn run_keeping_floating_gives_the_handle_its_own_reference() {
+        let object = new_floating_object();
+        let mut handle = None;
+        unsafe {
+            run_keeping_floating(object, || {
+                handle = Some(glib::Object::from_glib_none(object));
+            });
+        }
+        assert_ne!(unsafe { gobject_ffi::g_object_is_floating(object) }, 0);
+        assert_eq!(ref_count(object), 2);
+
+        drop(handle);
+        assert_eq!(ref_count(object), 1);
+        unsafe { gobject_ffi::g_object_ref_sink(object) };
+        unsafe { gobject_ffi::g_object_unref(object) };
+    }
+
+    #[test]
+    fn run_keeping_floating_drops_the_reference_when_no_handle_is_made() {
+        let object = new_floating_object();
+        unsafe { run_keeping_floating(object, || {}) };
+        assert_ne!(unsafe { gobject_ffi::g_object_is_floating(object) }, 0);
+        assert_eq!(ref_count(object), 1);
+        unsafe { gobject_ffi::g_object_ref_sink(object) };
+        unsafe { gobject_ffi::g_object_unref(object) };
+    }
+}
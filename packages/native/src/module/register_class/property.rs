@@ -0,0 +1,862 @@
+//! `GObject` properties declared by a registered class.
+//!
+//! Each property descriptor becomes a `GParamSpec` that `class_init` installs
+//! with `g_object_class_install_properties`, so `g_object_get`,
+//! `g_object_bind_property`, `GtkBuilder` and `notify::` signals see it like
+//! any native property. The param specs are built and validated on the `GLib`
+//! thread before the type is registered, so a bad descriptor fails the
+//! request instead of surfacing as a `GLib` critical during class
+//! initialization.
+//!
+//! The class's `get_property` and `set_property` vfuncs are shared by every
+//! property: they look up the [`PropertyAccessors`] attached to the param spec
+//! and call its `get` or `set` handler through a [`TrampolineState`]. A getter
+//! returns its value as a `GValue` of the property's type; a property without
+//! one reads as its default value.
+
+use std::collections::HashSet;
+use std::ffi::{CStr, CString, c_uint, c_void};
+use std::mem::offset_of;
+use std::sync::Arc;
+
+use anyhow::bail;
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{FromGlib as _, FromGlibPtrNone as _, IntoGlib as _},
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsFunction, JsObject, NapiValue as _, ValueType};
+
+use super::{build_trampoline, handler_property, is_valid_name, optional_cstring};
+use crate::trampoline::TrampolineState;
+use crate::types::{GObjectType, GValueType, Ownership, Type, VariantType, VoidType};
+use crate::value::{self, JsRef};
+
+/// Value type of a declared property, with its range and default value.
+#[derive(Debug)]
+pub(super) enum PropertyKind {
+    Int {
+        minimum: i32,
+        maximum: i32,
+        default: Option<i32>,
+    },
+    Double {
+        minimum: f64,
+        maximum: f64,
+        default: Option<f64>,
+    },
+    String {
+        default: Option<CString>,
+    },
+    Boolean {
+        default: bool,
+    },
+    Enum {
+        gtype: usize,
+        default: Option<i32>,
+    },
+    Object {
+        gtype: usize,
+    },
+    Boxed {
+        gtype: usize,
+    },
+    Variant {
+        signature: glib::VariantType,
+        default: Option<glib::Variant>,
+    },
+}
+
+/// JS-thread parse output for a property declared by the new class.
+#[cfg_attr(test, allow(dead_code))]
+pub(super) struct RawProperty {
+    pub(super) name: CString,
+    pub(super) nick: Option<CString>,
+    pub(super) blurb: Option<CString>,
+    pub(super) flags: gobject_ffi::GParamFlags,
+    pub(super) kind: PropertyKind,
+    pub(super) get: Option<Arc<JsRef<JsFunction>>>,
+    pub(super) set: Option<Arc<JsRef<JsFunction>>>,
+}
+
+/// The `get` and `set` trampolines of one property, attached to its param
+/// spec as qdata for the class's `get_property` and `set_property` vfuncs.
+struct PropertyAccessors {
+    getter: Option<Box<TrampolineState>>,
+    setter: Option<Box<TrampolineState>>,
+}
+
+/// Built param spec and accessors waiting to be installed into a class.
+pub(super) struct PreparedProperty {
+    pspec: glib::ParamSpec,
+    accessors: PropertyAccessors,
+}
+
+impl PropertyKind {
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let kind: String = obj.get_named_property("type")?;
+        Ok(match kind.as_str() {
+            "int" => Self::Int {
+                minimum: integer_property(obj, "minimum")?.unwrap_or(i32::MIN),
+                maximum: integer_property(obj, "maximum")?.unwrap_or(i32::MAX),
+                default: integer_property(obj, "default")?,
+            },
+            "double" => Self::Double {
+                minimum: obj
+                    .get_named_property::<Option<f64>>("minimum")?
+                    .unwrap_or(f64::MIN),
+                maximum: obj
+                    .get_named_property::<Option<f64>>("maximum")?
+                    .unwrap_or(f64::MAX),
+                default: obj.get_named_property("default")?,
+            },
+            "string" => Self::String {
+                default: obj
+                    .get_named_property::<Option<String>>("default")?
+                    .map(CString::new)
+                    .transpose()
+                    .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))?,
+            },
+            "boolean" => Self::Boolean {
+                default: obj
+                    .get_named_property::<Option<bool>>("default")?
+                    .unwrap_or(false),
+            },
+            "enum" => Self::Enum {
+                gtype: obj.get_named_property::<f64>("gtype")? as usize,
+                default: integer_property(obj, "default")?,
+            },
+            "object" => Self::Object {
+                gtype: obj.get_named_property::<f64>("gtype")? as usize,
+            },
+            "boxed" => Self::Boxed {
+                gtype: obj.get_named_property::<f64>("gtype")? as usize,
+            },
+            "variant" => Self::variant_from_js(env, obj)?,
+            other => {
+                return Err(napi::Error::new(
+                    napi::Status::InvalidArg,
+                    format!("register_class: unknown property type '{other}'"),
+                ));
+            }
+        })
+    }
+
+    /// Parses a variant property, building its default value from a plain JS
+    /// value of the property's `signature`.
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn variant_from_js(env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let signature: String = obj.get_named_property("signature")?;
+        let signature = glib::VariantType::new(&signature).map_err(|_| {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("register_class: invalid variant signature '{signature}'"),
+            )
+        })?;
+        let default_prop: Unknown<'_> = obj.get_named_property("default")?;
+        let default = match default_prop.get_type()? {
+            ValueType::Undefined | ValueType::Null => None,
+            _ => match VariantType::new(Ownership::Borrowed, Some(signature.clone()))
+                .value_from_js(env, default_prop)?
+            {
+                value::Value::Variant(variant) => Some(variant),
+                _ => None,
+            },
+        };
+        Ok(Self::Variant { signature, default })
+    }
+
+    /// The type a getter's return value is built through on the JS thread,
+    /// for property types a plain JS value does not map onto directly.
+    fn js_value_type(&self) -> Option<Type> {
+        match self {
+            Self::Variant { signature, .. } => Some(Type::Variant(VariantType::new(
+                Ownership::Borrowed,
+                Some(signature.clone()),
+            ))),
+            _ => None,
+        }
+    }
+
+    /// Creates the param spec, checking the range, default value and `GType`
+    /// that the `g_param_spec_*` constructors would otherwise reject with a
+    /// critical warning.
+    fn param_spec(
+        &self,
+        name: &CStr,
+        nick: Option<&CStr>,
+        blurb: Option<&CStr>,
+        flags: gobject_ffi::GParamFlags,
+    ) -> anyhow::Result<*mut gobject_ffi::GParamSpec> {
+        let label = name.to_string_lossy();
+        let name = name.as_ptr();
+        let nick = nick.map_or(std::ptr::null(), CStr::as_ptr);
+        let blurb = blurb.map_or(std::ptr::null(), CStr::as_ptr);
+
+        Ok(match self {
+            Self::Int {
+                minimum,
+                maximum,
+                default,
+            } => {
+                let default = checked_default(&label, *minimum, *maximum, *default, 0)?;
+                unsafe {
+                    gobject_ffi::g_param_spec_int(
+                        name, nick, blurb, *minimum, *maximum, default, flags,
+                    )
+                }
+            }
+            Self::Double {
+                minimum,
+                maximum,
+                default,
+            } => {
+                let default = checked_default(&label, *minimum, *maximum, *default, 0.0)?;
+                unsafe {
+                    gobject_ffi::g_param_spec_double(
+                        name, nick, blurb, *minimum, *maximum, default, flags,
+                    )
+                }
+            }
+            Self::String { default } => unsafe {
+                gobject_ffi::g_param_spec_string(
+                    name,
+                    nick,
+                    blurb,
+                    default.as_deref().map_or(std::ptr::null(), CStr::as_ptr),
+                    flags,
+                )
+            },
+            Self::Boolean { default } => unsafe {
+                gobject_ffi::g_param_spec_boolean(name, nick, blurb, (*default).into_glib(), flags)
+            },
+            Self::Enum { gtype, default } => {
+                let gtype = unsafe { glib::Type::from_glib(*gtype) };
+                let Some(enum_class) = glib::EnumClass::with_type(gtype) else {
+                    bail!("property '{label}' type {} is not an enum", gtype.name());
+                };
+                let default = match default {
+                    Some(value) if enum_class.value(*value).is_none() => {
+                        bail!(
+                            "property '{label}' default {value} is not a value of {}",
+                            gtype.name()
+                        );
+                    }
+                    Some(value) => *value,
+                    None => match enum_class.values().first() {
+                        Some(first) => first.value(),
+                        None => bail!("property '{label}' enum {} has no values", gtype.name()),
+                    },
+                };
+                unsafe {
+                    gobject_ffi::g_param_spec_enum(
+                        name,
+                        nick,
+                        blurb,
+                        gtype.into_glib(),
+                        default,
+                        flags,
+                    )
+                }
+            }
+            Self::Object { gtype } => {
+                let gtype = checked_gtype(&label, *gtype, glib::Type::OBJECT, "an object")?;
+                unsafe { gobject_ffi::g_param_spec_object(name, nick, blurb, gtype, flags) }
+            }
+            Self::Boxed { gtype } => {
+                let gtype = checked_gtype(&label, *gtype, glib::Type::BOXED, "a boxed")?;
+                unsafe { gobject_ffi::g_param_spec_boxed(name, nick, blurb, gtype, flags) }
+            }
+            Self::Variant { signature, default } => {
+                if let Some(variant) = default
+                    && !variant.is_type(signature)
+                {
+                    bail!(
+                        "property '{label}' default has type '{}', expected '{}'",
+                        variant.type_(),
+                        signature
+                    );
+                }
+                unsafe {
+                    gobject_ffi::g_param_spec_variant(
+                        name,
+                        nick,
+                        blurb,
+                        signature.as_ptr(),
+                        default
+                            .as_ref()
+                            .map_or(std::ptr::null_mut(), |v| v.as_ptr()),
+                        flags,
+                    )
+                }
+            }
+        })
+    }
+}
+
+impl RawProperty {
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub(super) fn from_js_value(env: &Env, item: Unknown<'_>) -> napi::Result<Self> {
+        let obj = unsafe { JsObject::from_napi_value(env.raw(), item.raw())? };
+        let name = optional_cstring(&obj, "name")?.ok_or_else(|| {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                "register_class: property 'name' must be a string",
+            )
+        })?;
+        Ok(Self {
+            name,
+            nick: optional_cstring(&obj, "nick")?,
+            blurb: optional_cstring(&obj, "blurb")?,
+            flags: obj
+                .get_named_property::<Option<u32>>("flags")?
+                .unwrap_or(gobject_ffi::G_PARAM_READWRITE),
+            kind: PropertyKind::from_js_value(env, &obj)?,
+            get: handler_property(env, &obj, "get")?,
+            set: handler_property(env, &obj, "set")?,
+        })
+    }
+
+    /// Checks the descriptor and builds its param spec and accessor
+    /// trampolines. The static-string flags are cleared, since the names
+    /// passed to `GLib` do not outlive the request.
+    pub(super) fn into_prepared(self) -> anyhow::Result<PreparedProperty> {
+        let label = self.name.to_string_lossy().into_owned();
+        if !is_valid_name(&label) {
+            bail!("'{label}' is not a valid property name");
+        }
+        let flags = self.flags
+            & !(gobject_ffi::G_PARAM_STATIC_NAME
+                | gobject_ffi::G_PARAM_STATIC_NICK
+                | gobject_ffi::G_PARAM_STATIC_BLURB);
+        let writable = flags & gobject_ffi::G_PARAM_WRITABLE != 0;
+        if !writable
+            && flags & (gobject_ffi::G_PARAM_CONSTRUCT | gobject_ffi::G_PARAM_CONSTRUCT_ONLY) != 0
+        {
+            bail!("property '{label}' must be writable to be set at construction");
+        }
+        if writable && self.set.is_none() {
+            bail!("property '{label}' is writable but has no 'set' handler");
+        }
+
+        let ptr = self.kind.param_spec(
+            &self.name,
+            self.nick.as_deref(),
+            self.blurb.as_deref(),
+            flags,
+        )?;
+        if ptr.is_null() {
+            bail!("property '{label}' could not be created");
+        }
+        let pspec = unsafe { glib::ParamSpec::from_glib_none(ptr) };
+
+        let instance = || {
+            Type::GObject(GObjectType {
+                ownership: Ownership::Borrowed,
+            })
+        };
+        let value_type = Type::GValue(GValueType::new(
+            Ownership::Full,
+            self.kind.js_value_type(),
+            Some(pspec.value_type().name().to_owned()),
+        ));
+        let getter = self
+            .get
+            .map(|js_func| build_trampoline(js_func, vec![instance()], value_type));
+        let setter = self.set.map(|js_func| {
+            let value = Type::GValue(GValueType::new(Ownership::Borrowed, None, None));
+            build_trampoline(js_func, vec![instance(), value], Type::Void(VoidType))
+        });
+
+        Ok(PreparedProperty {
+            pspec,
+            accessors: PropertyAccessors { getter, setter },
+        })
+    }
+
+    /// Prepares every property declared on a subclass of `parent_gtype`,
+    /// rejecting a name declared twice and a vfunc at one of `vfunc_offsets`
+    /// that overrides the `get_property` or `set_property` slot the
+    /// properties are routed through.
+    pub(super) fn prepare_all(
+        parent_gtype: usize,
+        properties: Vec<Self>,
+        vfunc_offsets: &[usize],
+    ) -> anyhow::Result<Vec<PreparedProperty>> {
+        if properties.is_empty() {
+            return Ok(Vec::new());
+        }
+        let parent = unsafe { glib::Type::from_glib(parent_gtype) };
+        if !parent.is_a(glib::Type::OBJECT) {
+            bail!(
+                "{} is not a GObject type and cannot have properties",
+                parent.name()
+            );
+        }
+        for (slot, offset) in [
+            (
+                "get_property",
+                offset_of!(gobject_ffi::GObjectClass, get_property),
+            ),
+            (
+                "set_property",
+                offset_of!(gobject_ffi::GObjectClass, set_property),
+            ),
+        ] {
+            if vfunc_offsets.contains(&offset) {
+                bail!("the properties and a vfunc both override {slot} at byte offset {offset}");
+            }
+        }
+        let mut names = HashSet::new();
+        properties
+            .into_iter()
+            .map(|property| {
+                if !names.insert(property.name.clone()) {
+                    bail!(
+                        "property '{}' is declared more than once",
+                        property.name.to_string_lossy()
+                    );
+                }
+                property.into_prepared()
+            })
+            .collect()
+    }
+}
+
+impl PreparedProperty {
+    /// Installs the properties into the class being initialized, routing its
+    /// `get_property` and `set_property` vfuncs to the property accessors. The
+    /// accessors are leaked, like vfunc trampolines, since the class of a
+    /// static type is never finalized.
+    #[cfg_attr(test, allow(dead_code))]
+    pub(super) fn install_all(g_class: *mut c_void, properties: Vec<Self>) {
+        if properties.is_empty() {
+            return;
+        }
+        let object_class = g_class.cast::<gobject_ffi::GObjectClass>();
+        unsafe {
+            (*object_class).get_property = Some(get_property);
+            (*object_class).set_property = Some(set_property);
+        }
+
+        let pspecs: Vec<glib::ParamSpec> = properties
+            .into_iter()
+            .map(|Self { pspec, accessors }| {
+                let accessors = Box::into_raw(Box::new(accessors));
+                unsafe {
+                    gobject_ffi::g_param_spec_set_qdata(
+                        pspec.as_ptr(),
+                        accessors_quark(),
+                        accessors.cast(),
+                    );
+                }
+                pspec
+            })
+            .collect();
+        let mut raw: Vec<*mut gobject_ffi::GParamSpec> = std::iter::once(std::ptr::null_mut())
+            .chain(pspecs.iter().map(glib::ParamSpec::as_ptr))
+            .collect();
+        unsafe {
+            gobject_ffi::g_object_class_install_properties(
+                object_class,
+                raw.len() as c_uint,
+                raw.as_mut_ptr(),
+            );
+        }
+    }
+}
+
+impl PropertyAccessors {
+    /// The accessors attached to `pspec` by [`PreparedProperty::install_all`].
+    ///
+    /// # Safety
+    ///
+    /// `pspec` must be a param spec installed by a registered class.
+    unsafe fn of<'a>(pspec: *mut gobject_ffi::GParamSpec) -> Option<&'a Self> {
+        let ptr = unsafe { gobject_ffi::g_param_spec_get_qdata(pspec, accessors_quark()) };
+        unsafe { ptr.cast::<Self>().as_ref() }
+    }
+}
+
+fn accessors_quark() -> glib::ffi::GQuark {
+    glib::Quark::from_static_str(glib::gstr!("gtkx-property-accessors")).into_glib()
+}
+
+/// Checks that `minimum..=maximum` is a range and holds `default`. Without a
+/// default, `fallback` is used when the range holds it and `minimum`
+/// otherwise.
+fn checked_default<T: PartialOrd + Copy + std::fmt::Display>(
+    label: &str,
+    minimum: T,
+    maximum: T,
+    default: Option<T>,
+    fallback: T,
+) -> anyhow::Result<T> {
+    if minimum
+        .partial_cmp(&maximum)
+        .is_none_or(std::cmp::Ordering::is_gt)
+    {
+        bail!("property '{label}' minimum {minimum} is greater than maximum {maximum}");
+    }
+    let range = minimum..=maximum;
+    let Some(default) = default else {
+        return Ok(if range.contains(&fallback) {
+            fallback
+        } else {
+            minimum
+        });
+    };
+    if !range.contains(&default) {
+        bail!("property '{label}' default {default} is outside {minimum}..={maximum}");
+    }
+    Ok(default)
+}
+
+/// Resolves `gtype`, checking that it derives from `base`.
+fn checked_gtype(
+    label: &str,
+    gtype: usize,
+    base: glib::Type,
+    description: &str,
+) -> anyhow::Result<glib::ffi::GType> {
+    let gtype = unsafe { glib::Type::from_glib(gtype) };
+    if !gtype.is_a(base) {
+        bail!(
+            "property '{label}' type {} is not {description} type",
+            gtype.name()
+        );
+    }
+    Ok(gtype.into_glib())
+}
+
+#[cfg_attr(test, allow(dead_code))]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn integer_property(obj: &JsObject, key: &str) -> napi::Result<Option<i32>> {
+    obj.get_named_property::<Option<f64>>(key)?
+        .map(|n| {
+            if n.fract() == 0.0 && (f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&n) {
+                Ok(n as i32)
+            } else {
+                Err(napi::Error::new(
+                    napi::Status::InvalidArg,
+                    format!(
+                        "register_class: property '{key}' value must be a 32-bit integer, got {n}"
+                    ),
+                ))
+            }
+        })
+        .transpose()
+}
+
+/// `GObjectClass.get_property` of a class with declared properties.
+///
+/// Calls the property's getter trampoline, which returns a `g_malloc`ed
+/// `GValue` of the property's type, and moves its contents into `value`.
+#[cfg_attr(coverage_nightly, coverage(off))]
+unsafe extern "C" fn get_property(
+    object: *mut gobject_ffi::GObject,
+    _property_id: c_uint,
+    value: *mut gobject_ffi::GValue,
+    pspec: *mut gobject_ffi::GParamSpec,
+) {
+    let getter = unsafe { PropertyAccessors::of(pspec) }.and_then(|a| a.getter.as_deref());
+    let result = getter.map_or(std::ptr::null_mut(), |getter| unsafe {
+        let get = std::mem::transmute::<
+            *mut c_void,
+            unsafe extern "C" fn(*mut gobject_ffi::GObject) -> *mut gobject_ffi::GValue,
+        >(getter.code_ptr);
+        get(object)
+    });
+    unsafe {
+        if result.is_null() {
+            gobject_ffi::g_param_value_set_default(pspec, value);
+            return;
+        }
+        gobject_ffi::g_value_copy(result, value);
+        gobject_ffi::g_boxed_free(gobject_ffi::g_value_get_type(), result.cast());
+    }
+}
+
+/// `GObjectClass.set_property` of a class with declared properties.
+#[cfg_attr(coverage_nightly, coverage(off))]
+unsafe extern "C" fn set_property(
+    object: *mut gobject_ffi::GObject,
+    _property_id: c_uint,
+    value: *const gobject_ffi::GValue,
+    pspec: *mut gobject_ffi::GParamSpec,
+) {
+    let Some(setter) = unsafe { PropertyAccessors::of(pspec) }.and_then(|a| a.setter.as_deref())
+    else {
+        return;
+    };
+    unsafe {
+        let set = std::mem::transmute::<
+            *mut c_void,
+            unsafe extern "C" fn(*mut gobject_ffi::GObject, *const gobject_ffi::GValue),
+        >(setter.code_ptr);
+        set(object, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gtk4::glib::prelude::*;
+    use gtk4::glib::translate::IntoGlib as _;
+
+    use super::*;
+
+    fn property(name: &str, flags: gobject_ffi::GParamFlags, kind: PropertyKind) -> RawProperty {
+        RawProperty {
+            name: CString::new(name).unwrap(),
+            nick: None,
+            blurb: None,
+            flags,
+            kind,
+            get: None,
+            set: None,
+        }
+    }
+
+    fn read_only(name: &str, kind: PropertyKind) -> RawProperty {
+        property(name, gobject_ffi::G_PARAM_READABLE, kind)
+    }
+
+    fn prepare_err(property: RawProperty) -> String {
+        property
+            .into_prepared()
+            .err()
+            .expect("property should be rejected")
+            .to_string()
+    }
+
+    #[test]
+    fn into_prepared_builds_an_int_param_spec() {
+        let mut raw = read_only(
+            "count",
+            PropertyKind::Int {
+                minimum: -5,
+                maximum: 10,
+                default: Some(3),
+            },
+        );
+        raw.nick = Some(CString::new("Count").unwrap());
+        let prepared = raw.into_prepared().expect("property should be prepared");
+
+        let pspec = prepared
+            .pspec
+            .downcast_ref::<glib::ParamSpecInt>()
+            .expect("an int param spec");
+        assert_eq!(pspec.name(), "count");
+        assert_eq!(pspec.nick(), "Count");
+        assert_eq!(pspec.minimum(), -5);
+        assert_eq!(pspec.maximum(), 10);
+        assert_eq!(pspec.default_value(), 3);
+        assert!(prepared.accessors.getter.is_none());
+    }
+
+    #[test]
+    fn into_prepared_clamps_the_implicit_default_into_range() {
+        let prepared = read_only(
+            "ratio",
+            PropertyKind::Double {
+                minimum: 1.0,
+                maximum: 2.0,
+                default: None,
+            },
+        )
+        .into_prepared()
+        .unwrap();
+        let pspec = prepared
+            .pspec
+            .downcast_ref::<glib::ParamSpecDouble>()
+            .unwrap();
+        assert_eq!(pspec.default_value(), 1.0);
+    }
+
+    #[test]
+    fn into_prepared_builds_string_enum_object_and_variant_param_specs() {
+        let string = read_only(
+            "title",
+            PropertyKind::String {
+                default: Some(CString::new("untitled").unwrap()),
+            },
+        )
+        .into_prepared()
+        .unwrap();
+        assert_eq!(
+            string
+                .pspec
+                .downcast_ref::<glib::ParamSpecString>()
+                .unwrap()
+                .default_value(),
+            Some("untitled")
+        );
+
+        let enum_gtype = glib::UnicodeType::static_type();
+        let enumeration = read_only(
+            "kind",
+            PropertyKind::Enum {
+                gtype: enum_gtype.into_glib(),
+                default: None,
+            },
+        )
+        .into_prepared()
+        .unwrap();
+        assert_eq!(enumeration.pspec.value_type(), enum_gtype);
+
+        let object = read_only(
+            "target",
+            PropertyKind::Object {
+                gtype: glib::Object::static_type().into_glib(),
+            },
+        )
+        .into_prepared()
+        .unwrap();
+        assert_eq!(object.pspec.value_type(), glib::Object::static_type());
+
+        let variant = read_only(
+            "state",
+            PropertyKind::Variant {
+                signature: glib::VariantType::new("i").unwrap(),
+                default: Some(7.to_variant()),
+            },
+        )
+        .into_prepared()
+        .unwrap();
+        let pspec = variant
+            .pspec
+            .downcast_ref::<glib::ParamSpecVariant>()
+            .unwrap();
+        let default = pspec.default_value().get::<glib::Variant>().unwrap();
+        assert_eq!(default.get::<i32>(), Some(7));
+    }
+
+    #[test]
+    fn into_prepared_rejects_invalid_ranges_and_defaults() {
+        let err = prepare_err(read_only(
+            "count",
+            PropertyKind::Int {
+                minimum: 10,
+                maximum: 0,
+                default: None,
+            },
+        ));
+        assert!(
+            err.contains("minimum 10 is greater than maximum 0"),
+            "{err}"
+        );
+
+        let err = prepare_err(read_only(
+            "count",
+            PropertyKind::Int {
+                minimum: 0,
+                maximum: 10,
+                default: Some(11),
+            },
+        ));
+        assert!(err.contains("default 11 is outside 0..=10"), "{err}");
+
+        let err = prepare_err(read_only(
+            "kind",
+            PropertyKind::Enum {
+                gtype: glib::UnicodeType::static_type().into_glib(),
+                default: Some(-1),
+            },
+        ));
+        assert!(err.contains("is not a value of GUnicodeType"), "{err}");
+
+        let err = prepare_err(read_only(
+            "state",
+            PropertyKind::Variant {
+                signature: glib::VariantType::new("s").unwrap(),
+                default: Some(7.to_variant()),
+            },
+        ));
+        assert!(err.contains("default has type 'i', expected 's'"), "{err}");
+    }
+
+    #[test]
+    fn into_prepared_rejects_mismatched_gtypes() {
+        let err = prepare_err(read_only(
+            "target",
+            PropertyKind::Object {
+                gtype: glib::Type::STRING.into_glib(),
+            },
+        ));
+        assert!(
+            err.contains("type gchararray is not an object type"),
+            "{err}"
+        );
+
+        let err = prepare_err(read_only(
+            "kind",
+            PropertyKind::Enum {
+                gtype: glib::Type::I32.into_glib(),
+                default: None,
+            },
+        ));
+        assert!(err.contains("type gint is not an enum"), "{err}");
+    }
+
+    #[test]
+    fn into_prepared_checks_the_name_and_flags() {
+        let err = prepare_err(read_only("2fast", PropertyKind::Boolean { default: false }));
+        assert!(err.contains("not a valid property name"), "{err}");
+
+        let err = prepare_err(property(
+            "enabled",
+            gobject_ffi::G_PARAM_READABLE | gobject_ffi::G_PARAM_CONSTRUCT_ONLY,
+            PropertyKind::Boolean { default: false },
+        ));
+        assert!(
+            err.contains("must be writable to be set at construction"),
+            "{err}"
+        );
+
+        let err = prepare_err(property(
+            "enabled",
+            gobject_ffi::G_PARAM_READWRITE,
+            PropertyKind::Boolean { default: false },
+        ));
+        assert!(err.contains("writable but has no 'set' handler"), "{err}");
+    }
+
+    #[test]
+    fn prepare_all_rejects_a_duplicate_name() {
+        let boolean = || read_only("enabled", PropertyKind::Boolean { default: false });
+        let object = glib::Object::static_type().into_glib();
+        let err = RawProperty::prepare_all(object, vec![boolean(), boolean()], &[])
+            .err()
+            .expect("duplicate names should be rejected");
+        assert!(err.to_string().contains("declared more than once"));
+    }
+
+    #[test]
+    fn prepare_all_rejects_a_parent_without_properties() {
+        let boolean = read_only("enabled", PropertyKind::Boolean { default: false });
+        let err = RawProperty::prepare_all(glib::Type::VARIANT.into_glib(), vec![boolean], &[])
+            .err()
+            .expect("a non-object parent should be rejected");
+        assert!(err.to_string().contains("GVariant is not a GObject type"));
+    }
+
+    #[test]
+    fn prepare_all_rejects_a_vfunc_over_the_property_accessors() {
+        let boolean = read_only("enabled", PropertyKind::Boolean { default: false });
+        let object = glib::Object::static_type().into_glib();
+        let get_property = offset_of!(gobject_ffi::GObjectClass, get_property);
+        let err = RawProperty::prepare_all(object, vec![boolean], &[get_property])
+            .err()
+            .expect("a get_property vfunc should be rejected");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "the properties and a vfunc both override get_property at byte offset {get_property}"
+            )
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! Signals declared by a registered class.
+//!
+//! Each descriptor is created with `g_signal_newv` while the class is
+//! initialized, so the signal can be connected and emitted like any native
+//! one, including from `GtkBuilder` templates. The descriptors are checked on
+//! the `GLib` thread before the type is registered, mirroring the
+//! preconditions `g_signal_newv` would otherwise only report as criticals.
+//!
+//! An optional class handler becomes the signal's class closure, built with
+//! the same [`signal_closure`] as handlers connected through `connect_signal`,
+//! so it receives the instance followed by each parameter decoded by its type.
+
+use std::collections::HashSet;
+use std::ffi::{CString, c_uint};
+use std::sync::Arc;
+
+use anyhow::bail;
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{FromGlib as _, IntoGlib as _, ToGlibPtr as _},
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsFunction, JsObject, NapiValue as _};
+
+use super::{handler_property, is_valid_name, optional_cstring};
+use crate::error_reporter::NativeErrorReporter;
+use crate::types::signal_closure;
+use crate::value::{Callback, JsRef};
+
+/// `G_SIGNAL_ACCUMULATOR_FIRST_RUN`, which `G_SIGNAL_FLAGS_MASK` leaves out
+/// and `gobject-sys` only exposes behind its `v2_68` feature.
+const G_SIGNAL_ACCUMULATOR_FIRST_RUN: gobject_ffi::GSignalFlags = 1 << 17;
+
+/// Built-in `GLib` accumulator combining the handlers' return values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SignalAccumulator {
+    /// `g_signal_accumulator_first_wins`: the first handler's value is kept.
+    FirstWins,
+    /// `g_signal_accumulator_true_handled`: emission stops at the first
+    /// handler returning `TRUE`.
+    TrueHandled,
+}
+
+/// JS-thread parse output for a signal declared by the new class.
+#[cfg_attr(test, allow(dead_code))]
+pub(super) struct RawSignal {
+    pub(super) name: CString,
+    pub(super) flags: gobject_ffi::GSignalFlags,
+    pub(super) param_types: Vec<usize>,
+    pub(super) return_type: usize,
+    pub(super) accumulator: Option<SignalAccumulator>,
+    pub(super) class_handler: Option<Arc<JsRef<JsFunction>>>,
+}
+
+/// Checked signal waiting to be created in the class being initialized.
+pub(super) struct PreparedSignal {
+    name: CString,
+    flags: gobject_ffi::GSignalFlags,
+    param_types: Vec<glib::ffi::GType>,
+    return_type: glib::ffi::GType,
+    accumulator: Option<SignalAccumulator>,
+    class_closure: Option<glib::Closure>,
+}
+
+impl SignalAccumulator {
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn from_js_value(name: &str) -> napi::Result<Self> {
+        match name {
+            "first-wins" => Ok(Self::FirstWins),
+            "true-handled" => Ok(Self::TrueHandled),
+            other => Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("register_class: unknown signal accumulator '{other}'"),
+            )),
+        }
+    }
+
+    fn function(self) -> gobject_ffi::GSignalAccumulator {
+        Some(match self {
+            Self::FirstWins => gobject_ffi::g_signal_accumulator_first_wins,
+            Self::TrueHandled => gobject_ffi::g_signal_accumulator_true_handled,
+        })
+    }
+}
+
+impl RawSignal {
+    #[cfg_attr(test, allow(dead_code))]
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub(super) fn from_js_value(env: &Env, item: Unknown<'_>) -> napi::Result<Self> {
+        let obj = unsafe { JsObject::from_napi_value(env.raw(), item.raw())? };
+        let name = optional_cstring(&obj, "name")?.ok_or_else(|| {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                "register_class: signal 'name' must be a string",
+            )
+        })?;
+        Ok(Self {
+            name,
+            flags: obj
+                .get_named_property::<Option<u32>>("flags")?
+                .unwrap_or(gobject_ffi::G_SIGNAL_RUN_LAST),
+            param_types: obj
+                .get_named_property::<Option<Vec<f64>>>("paramTypes")?
+                .unwrap_or_default()
+                .into_iter()
+                .map(|gtype| gtype as usize)
+                .collect(),
+            return_type: obj
+                .get_named_property::<Option<f64>>("returnType")?
+                .map_or_else(|| glib::Type::UNIT.into_glib(), |gtype| gtype as usize),
+            accumulator: obj
+                .get_named_property::<Option<String>>("accumulator")?
+                .as_deref()
+                .map(SignalAccumulator::from_js_value)
+                .transpose()?,
+            class_handler: handler_property(env, &obj, "classHandler")?,
+        })
+    }
+
+    /// Checks the descriptor against the rules of `g_signal_newv` and builds
+    /// the class closure around the class handler.
+    pub(super) fn into_prepared(self) -> anyhow::Result<PreparedSignal> {
+        let label = self.name.to_string_lossy().into_owned();
+        if !is_valid_name(&label) {
+            bail!("'{label}' is not a valid signal name");
+        }
+        let known_flags = gobject_ffi::G_SIGNAL_FLAGS_MASK as gobject_ffi::GSignalFlags
+            | G_SIGNAL_ACCUMULATOR_FIRST_RUN;
+        if self.flags & !known_flags != 0 {
+            bail!("signal '{label}' has unknown flags {:#x}", self.flags);
+        }
+
+        let param_types = self
+            .param_types
+            .iter()
+            .enumerate()
+            .map(|(i, &gtype)| {
+                let gtype = unsafe { glib::Type::from_glib(gtype) };
+                if !is_value_type(gtype) {
+                    bail!(
+                        "signal '{label}' parameter {i} type {} is not a value type",
+                        gtype.name()
+                    );
+                }
+                Ok(gtype)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let return_type = unsafe { glib::Type::from_glib(self.return_type) };
+        let returns_value = return_type != glib::Type::UNIT;
+        if returns_value && !is_value_type(return_type) {
+            bail!(
+                "signal '{label}' return type {} is not a value type",
+                return_type.name()
+            );
+        }
+        let stages = gobject_ffi::G_SIGNAL_RUN_FIRST
+            | gobject_ffi::G_SIGNAL_RUN_LAST
+            | gobject_ffi::G_SIGNAL_RUN_CLEANUP;
+        if returns_value && self.flags & stages == gobject_ffi::G_SIGNAL_RUN_FIRST {
+            bail!("signal '{label}' has a return value and must not be only RUN_FIRST");
+        }
+        match self.accumulator {
+            Some(SignalAccumulator::FirstWins) if !returns_value => {
+                bail!("signal '{label}' has no return value to accumulate");
+            }
+            Some(SignalAccumulator::TrueHandled) if return_type != glib::Type::BOOL => {
+                bail!("signal '{label}' must return gboolean to use the true-handled accumulator");
+            }
+            _ => {}
+        }
+
+        let class_closure = self.class_handler.map(|js_func| {
+            signal_closure(&Callback::new(js_func), param_types.len() + 1, return_type)
+        });
+
+        Ok(PreparedSignal {
+            name: self.name,
+            flags: self.flags,
+            param_types: param_types.into_iter().map(glib::Type::into_glib).collect(),
+            return_type: return_type.into_glib(),
+            accumulator: self.accumulator,
+            class_closure,
+        })
+    }
+
+    /// Prepares every signal declared on a subclass of `parent_gtype`,
+    /// rejecting a name declared twice or already used by the parent.
+    /// Signal names treat `-` and `_` alike.
+    pub(super) fn prepare_all(
+        parent_gtype: usize,
+        signals: Vec<Self>,
+    ) -> anyhow::Result<Vec<PreparedSignal>> {
+        if signals.is_empty() {
+            return Ok(Vec::new());
+        }
+        let instantiatable = unsafe {
+            gobject_ffi::g_type_test_flags(parent_gtype, gobject_ffi::G_TYPE_FLAG_INSTANTIATABLE)
+        };
+        if instantiatable == glib::ffi::GFALSE {
+            bail!(
+                "{} is not an instantiatable type and cannot have signals",
+                unsafe { glib::Type::from_glib(parent_gtype) }.name()
+            );
+        }
+        // `g_signal_lookup` warns about a type whose class was never initialized.
+        let parent_class = unsafe { gobject_ffi::g_type_class_ref(parent_gtype) };
+        let result = Self::prepare_each(parent_gtype, signals);
+        unsafe { gobject_ffi::g_type_class_unref(parent_class) };
+        result
+    }
+
+    fn prepare_each(
+        parent_gtype: usize,
+        signals: Vec<Self>,
+    ) -> anyhow::Result<Vec<PreparedSignal>> {
+        let mut names = HashSet::new();
+        signals
+            .into_iter()
+            .map(|signal| {
+                let label = signal.name.to_string_lossy().replace('_', "-");
+                if !names.insert(label.clone()) {
+                    bail!("signal '{label}' is declared more than once");
+                }
+                if is_valid_name(&label)
+                    && unsafe { gobject_ffi::g_signal_lookup(signal.name.as_ptr(), parent_gtype) }
+                        != 0
+                {
+                    bail!("signal '{label}' already exists on the parent type");
+                }
+                signal.into_prepared()
+            })
+            .collect()
+    }
+}
+
+impl PreparedSignal {
+    /// Creates the signals on `gtype`, the type whose class is being
+    /// initialized. `g_signal_newv` takes its own reference to each class
+    /// closure.
+    #[cfg_attr(test, allow(dead_code))]
+    pub(super) fn install_all(gtype: glib::ffi::GType, signals: Vec<Self>) {
+        for mut signal in signals {
+            let signal_id = unsafe {
+                gobject_ffi::g_signal_newv(
+                    signal.name.as_ptr(),
+                    gtype,
+                    signal.flags,
+                    signal
+                        .class_closure
+                        .as_ref()
+                        .map_or(std::ptr::null_mut(), |closure| closure.to_glib_none().0),
+                    signal.accumulator.and_then(SignalAccumulator::function),
+                    std::ptr::null_mut(),
+                    None,
+                    signal.return_type,
+                    signal.param_types.len() as c_uint,
+                    signal.param_types.as_mut_ptr(),
+                )
+            };
+            if signal_id == 0 {
+                NativeErrorReporter::global().report_str(&format!(
+                    "register_class: signal '{}' could not be created",
+                    signal.name.to_string_lossy()
+                ));
+            }
+        }
+    }
+}
+
+fn is_value_type(gtype: glib::Type) -> bool {
+    unsafe { gobject_ffi::g_type_check_is_value_type(gtype.into_glib()) != glib::ffi::GFALSE }
+}
+
+#[cfg(test)]
+mod tests {
+    use gtk4::glib::prelude::*;
+
+    use super::*;
+
+    fn signal(name: &str) -> RawSignal {
+        RawSignal {
+            name: CString::new(name).unwrap(),
+            flags: gobject_ffi::G_SIGNAL_RUN_LAST,
+            param_types: vec![],
+            return_type: glib::Type::UNIT.into_glib(),
+            accumulator: None,
+            class_handler: None,
+        }
+    }
+
+    fn prepare_err(signal: RawSignal) -> String {
+        signal
+            .into_prepared()
+            .err()
+            .expect("the signal should be rejected")
+            .to_string()
+    }
+
+    fn object_gtype() -> usize {
+        glib::Object::static_type().into_glib()
+    }
+
+    #[test]
+    fn into_prepared_keeps_the_declared_types() {
+        let prepared = RawSignal {
+            param_types: vec![glib::Type::I32.into_glib(), glib::Type::STRING.into_glib()],
+            return_type: glib::Type::BOOL.into_glib(),
+            accumulator: Some(SignalAccumulator::TrueHandled),
+            ..signal("activate-item")
+        }
+        .into_prepared()
+        .expect("the signal should be valid");
+
+        assert_eq!(
+            prepared.param_types,
+            vec![glib::Type::I32.into_glib(), glib::Type::STRING.into_glib()]
+        );
+        assert_eq!(prepared.return_type, glib::Type::BOOL.into_glib());
+        assert_eq!(prepared.accumulator, Some(SignalAccumulator::TrueHandled));
+        assert!(prepared.class_closure.is_none());
+    }
+
+    #[test]
+    fn into_prepared_checks_the_name_and_flags() {
+        let err = prepare_err(signal("2fast"));
+        assert!(err.contains("not a valid signal name"), "{err}");
+
+        let err = prepare_err(RawSignal {
+            flags: gobject_ffi::G_SIGNAL_RUN_LAST | 0x1000,
+            ..signal("changed")
+        });
+        assert!(err.contains("unknown flags 0x1002"), "{err}");
+
+        let first_run = RawSignal {
+            flags: gobject_ffi::G_SIGNAL_RUN_LAST | G_SIGNAL_ACCUMULATOR_FIRST_RUN,
+            ..signal("changed")
+        };
+        assert!(first_run.into_prepared().is_ok());
+
+        let err = prepare_err(RawSignal {
+            flags: gobject_ffi::G_SIGNAL_RUN_FIRST,
+            return_type: glib::Type::BOOL.into_glib(),
+            ..signal("changed")
+        });
+        assert!(err.contains("must not be only RUN_FIRST"), "{err}");
+    }
+
+    #[test]
+    fn into_prepared_rejects_types_without_values() {
+        let err = prepare_err(RawSignal {
+            param_types: vec![
+                glib::Type::I32.into_glib(),
+                glib::Type::INTERFACE.into_glib(),
+            ],
+            ..signal("changed")
+        });
+        assert!(
+            err.contains("parameter 1 type GInterface is not a value type"),
+            "{err}"
+        );
+
+        let err = prepare_err(RawSignal {
+            return_type: glib::Type::INTERFACE.into_glib(),
+            ..signal("changed")
+        });
+        assert!(
+            err.contains("return type GInterface is not a value type"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn into_prepared_checks_the_accumulator_against_the_return_type() {
+        let err = prepare_err(RawSignal {
+            accumulator: Some(SignalAccumulator::FirstWins),
+            ..signal("changed")
+        });
+        assert!(err.contains("no return value to accumulate"), "{err}");
+
+        let err = prepare_err(RawSignal {
+            return_type: glib::Type::I32.into_glib(),
+            accumulator: Some(SignalAccumulator::TrueHandled),
+            ..signal("changed")
+        });
+        assert!(err.contains("must return gboolean"), "{err}");
+    }
+
+    #[test]
+    fn prepare_all_rejects_a_duplicate_or_inherited_name() {
+        let err = RawSignal::prepare_all(
+            object_gtype(),
+            vec![signal("value-changed"), signal("value_changed")],
+        )
+        .err()
+        .expect("duplicate names should be rejected");
+        assert!(
+            err.to_string()
+                .contains("signal 'value-changed' is declared more than once")
+        );
+
+        let err = RawSignal::prepare_all(object_gtype(), vec![signal("notify")])
+            .err()
+            .expect("an inherited name should be rejected");
+        assert!(
+            err.to_string()
+                .contains("already exists on the parent type")
+        );
+    }
+
+    #[test]
+    fn prepare_all_rejects_a_parent_without_instances() {
+        let err = RawSignal::prepare_all(glib::Type::VARIANT.into_glib(), vec![signal("changed")])
+            .err()
+            .expect("a non-instantiatable parent should be rejected");
+        assert!(
+            err.to_string()
+                .contains("GVariant is not an instantiatable type")
+        );
+    }
+}
@@ -0,0 +1,543 @@
+//! Signal connection and emission without generated trampolines.
+//!
+//! Both resolve a detailed signal name against the instance's type and ask
+//! `g_signal_query` for the signal's parameter and return `GType`s, so signals
+//! of types without introspection data work the same way as any other.
+//!
+//! [`ConnectSignalRequest`] connects a `GClosure` that decodes each argument by
+//! the type its `GValue` holds and stores the JS result as the return type.
+//! Handlers are then disconnected, blocked, and unblocked by the id the
+//! connection returns. [`EmitSignalRequest`] builds the argument `GValue`s
+//! from the parameter types, emits, and decodes the return value in a single
+//! `GLib`-thread task.
+//!
+//! Building the closure needs the captured JS callback, so connecting is
+//! excluded from coverage instrumentation; resolving, emitting, and managing
+//! handlers are exercised directly by tests.
+
+use std::ffi::{CStr, CString, c_ulong, c_void};
+
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{FromGlib as _, IntoGlib as _, ToGlibPtr as _, ToGlibPtrMut as _},
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject, ValueType};
+use napi_derive::napi;
+
+use super::handler::ModuleRequest;
+use crate::managed::NativeHandle;
+use crate::types::{Type, gvalue_to_value, signal_closure, value_to_gvalue_as};
+use crate::value::{Callback, Value, map_js_array};
+
+/// `G_SIGNAL_TYPE_STATIC_SCOPE`, the flag bit `g_signal_query` may set on a
+/// return or parameter `GType`. The C macro has no binding in `gobject-sys`.
+const G_SIGNAL_TYPE_STATIC_SCOPE: glib::ffi::GType = 1;
+
+/// A signal resolved against the type of the instance it is connected on or
+/// emitted from.
+#[cfg_attr(test, allow(dead_code))]
+struct ResolvedSignal {
+    instance_type: glib::Type,
+    signal_id: u32,
+    detail: glib::ffi::GQuark,
+    /// Parameter types, not counting the instance.
+    param_types: Vec<glib::Type>,
+    return_type: glib::Type,
+}
+
+/// Resolves `detailed_signal` (`"name"` or `"name::detail"`) on the instance
+/// at `instance_addr`.
+fn resolve_signal(instance_addr: usize, detailed_signal: &CStr) -> anyhow::Result<ResolvedSignal> {
+    if instance_addr == 0 {
+        anyhow::bail!("instance handle has a null pointer");
+    }
+    let instance = instance_addr as *mut gobject_ffi::GTypeInstance;
+    let g_class = unsafe { (*instance).g_class };
+    if g_class.is_null() {
+        anyhow::bail!("instance has no resolved class");
+    }
+    let itype = unsafe { (*g_class).g_type };
+
+    let mut signal_id = 0;
+    let mut detail = 0;
+    let found = unsafe {
+        gobject_ffi::g_signal_parse_name(
+            detailed_signal.as_ptr(),
+            itype,
+            &raw mut signal_id,
+            &raw mut detail,
+            glib::ffi::GTRUE,
+        )
+    };
+    if found == glib::ffi::GFALSE {
+        anyhow::bail!(
+            "{} has no signal '{}'",
+            unsafe { glib::Type::from_glib(itype) }.name(),
+            detailed_signal.to_string_lossy()
+        );
+    }
+
+    let mut query: gobject_ffi::GSignalQuery = unsafe { std::mem::zeroed() };
+    unsafe { gobject_ffi::g_signal_query(signal_id, &raw mut query) };
+    let without_scope =
+        |gtype| unsafe { glib::Type::from_glib(gtype & !G_SIGNAL_TYPE_STATIC_SCOPE) };
+    let param_types = if query.n_params == 0 {
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(query.param_types, query.n_params as usize) }
+            .iter()
+            .map(|&gtype| without_scope(gtype))
+            .collect()
+    };
+
+    Ok(ResolvedSignal {
+        instance_type: unsafe { glib::Type::from_glib(itype) },
+        signal_id,
+        detail,
+        param_types,
+        return_type: without_scope(query.return_type),
+    })
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct ConnectSignalRequest {
+    instance_addr: usize,
+    detailed_signal: CString,
+    callback: Callback,
+    after: bool,
+}
+
+impl ModuleRequest for ConnectSignalRequest {
+    type Output = u64;
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn execute(self) -> anyhow::Result<u64> {
+        let signal = resolve_signal(self.instance_addr, &self.detailed_signal)?;
+        let closure = signal_closure(
+            &self.callback,
+            signal.param_types.len() + 1,
+            signal.return_type,
+        );
+        let handler_id = unsafe {
+            gobject_ffi::g_signal_connect_closure_by_id(
+                self.instance_addr as *mut gobject_ffi::GObject,
+                signal.signal_id,
+                signal.detail,
+                closure.to_glib_none().0,
+                self.after.into_glib(),
+            )
+        };
+        Ok(u64::from(handler_id))
+    }
+
+    fn error_context() -> &'static str {
+        "connect_signal"
+    }
+}
+
+/// One argument of an [`EmitSignalRequest`].
+#[cfg_attr(test, allow(dead_code))]
+struct SignalArg {
+    value: Value,
+    /// Builds the `GValue` instead of the parameter's `GType` when set.
+    value_type: Option<Type>,
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct EmitSignalRequest {
+    instance_addr: usize,
+    detailed_signal: CString,
+    args: Vec<SignalArg>,
+}
+
+impl ModuleRequest for EmitSignalRequest {
+    type Output = Value;
+
+    /// Builds the instance and argument `GValue`s back to back, as
+    /// `g_signal_emitv` expects, and decodes the return value by its type.
+    /// All of them are unset when the request completes.
+    fn execute(self) -> anyhow::Result<Value> {
+        let signal = resolve_signal(self.instance_addr, &self.detailed_signal)?;
+        if self.args.len() != signal.param_types.len() {
+            anyhow::bail!(
+                "signal '{}' takes {} arguments, got {}",
+                self.detailed_signal.to_string_lossy(),
+                signal.param_types.len(),
+                self.args.len()
+            );
+        }
+
+        let mut instance = glib::Value::from_type(signal.instance_type);
+        unsafe {
+            gobject_ffi::g_value_set_instance(
+                instance.to_glib_none_mut().0,
+                self.instance_addr as *mut c_void,
+            );
+        }
+        let mut values = Vec::with_capacity(self.args.len() + 1);
+        values.push(instance);
+        for (i, (arg, &gtype)) in self.args.iter().zip(&signal.param_types).enumerate() {
+            let gvalue = value_to_gvalue_as(&arg.value, arg.value_type.as_ref(), gtype)
+                .map_err(|e| e.context(format!("signal argument {i}")))?;
+            values.push(gvalue);
+        }
+
+        let mut return_value = (signal.return_type != glib::Type::UNIT)
+            .then(|| glib::Value::from_type(signal.return_type));
+        let return_ptr = return_value
+            .as_mut()
+            .map_or(std::ptr::null_mut(), |value| value.to_glib_none_mut().0);
+        unsafe {
+            gobject_ffi::g_signal_emitv(
+                values.as_ptr().cast::<gobject_ffi::GValue>(),
+                signal.signal_id,
+                signal.detail,
+                return_ptr,
+            );
+        }
+
+        return_value
+            .as_ref()
+            .map_or(Ok(Value::Undefined), gvalue_to_value)
+    }
+
+    fn error_context() -> &'static str {
+        "emit_signal"
+    }
+}
+
+/// What a [`SignalHandlerRequest`] does to its handler.
+#[cfg_attr(test, allow(dead_code))]
+#[derive(Debug, Clone, Copy)]
+enum HandlerAction {
+    Disconnect,
+    Block,
+    Unblock,
+}
+
+#[cfg_attr(test, allow(dead_code))]
+struct SignalHandlerRequest {
+    instance_addr: usize,
+    handler_id: c_ulong,
+    action: HandlerAction,
+}
+
+impl ModuleRequest for SignalHandlerRequest {
+    type Output = ();
+
+    fn execute(self) -> anyhow::Result<()> {
+        if self.instance_addr == 0 {
+            anyhow::bail!("instance handle has a null pointer");
+        }
+        let instance = self.instance_addr as *mut gobject_ffi::GObject;
+        let handler_id = self.handler_id;
+        if handler_id == 0
+            || unsafe { gobject_ffi::g_signal_handler_is_connected(instance, handler_id) }
+                == glib::ffi::GFALSE
+        {
+            anyhow::bail!("no signal handler {handler_id} is connected");
+        }
+
+        unsafe {
+            match self.action {
+                HandlerAction::Disconnect => {
+                    gobject_ffi::g_signal_handler_disconnect(instance, handler_id);
+                }
+                HandlerAction::Block => gobject_ffi::g_signal_handler_block(instance, handler_id),
+                HandlerAction::Unblock => {
+                    gobject_ffi::g_signal_handler_unblock(instance, handler_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn error_context() -> &'static str {
+        "signal_handler"
+    }
+}
+
+/// napi export shims for signal connection. Excluded from coverage
+/// instrumentation: all dispatch through a live [`napi::Env`].
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[allow(clippy::wildcard_imports)]
+mod napi_export {
+    use super::*;
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn connect_signal<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        detailed_signal: String,
+        callback: Unknown<'_>,
+        options: Option<JsObject>,
+    ) -> napi::Result<Unknown<'env>> {
+        let detailed_signal = CString::new(detailed_signal)
+            .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))?;
+        if callback.get_type()? != ValueType::Function {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                "connect_signal: handler must be a function",
+            ));
+        }
+        let after = options
+            .map(|options| options.get_named_property::<Option<bool>>("after"))
+            .transpose()?
+            .flatten()
+            .unwrap_or(false);
+        ConnectSignalRequest {
+            instance_addr: handle.ptr_as_usize(),
+            detailed_signal,
+            callback: Callback::from_js_value(env, callback)?,
+            after,
+        }
+        .dispatch(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn emit_signal<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        detailed_signal: String,
+        args: Array,
+        types: Option<Array>,
+    ) -> napi::Result<Unknown<'env>> {
+        let detailed_signal = CString::new(detailed_signal)
+            .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))?;
+        let mut index = 0;
+        let args = map_js_array(env, &args, |env, value| {
+            let value_type = types
+                .as_ref()
+                .map(|types| types.get::<Option<Unknown<'_>>>(index))
+                .transpose()?
+                .flatten()
+                .flatten()
+                .map(|js_type| Type::from_js_value(env, js_type))
+                .transpose()?;
+            index += 1;
+            let value = match &value_type {
+                Some(ty) => Value::from_js_value_typed(env, value, ty)?,
+                None => Value::from_js_value(env, value)?,
+            };
+            Ok(SignalArg { value, value_type })
+        })?;
+        EmitSignalRequest {
+            instance_addr: handle.ptr_as_usize(),
+            detailed_signal,
+            args,
+        }
+        .dispatch(env)
+    }
+
+    fn handler_request<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        handler_id: f64,
+        action: HandlerAction,
+    ) -> napi::Result<Unknown<'env>> {
+        SignalHandlerRequest {
+            instance_addr: handle.ptr_as_usize(),
+            handler_id: handler_id as c_ulong,
+            action,
+        }
+        .dispatch(env)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn disconnect_signal<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        handler_id: f64,
+    ) -> napi::Result<Unknown<'env>> {
+        handler_request(env, handle, handler_id, HandlerAction::Disconnect)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn block_signal<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        handler_id: f64,
+    ) -> napi::Result<Unknown<'env>> {
+        handler_request(env, handle, handler_id, HandlerAction::Block)
+    }
+
+    #[napi]
+    #[cfg_attr(test, allow(dead_code))]
+    pub fn unblock_signal<'env>(
+        env: &'env Env,
+        handle: &External<NativeHandle>,
+        handler_id: f64,
+    ) -> napi::Result<Unknown<'env>> {
+        handler_request(env, handle, handler_id, HandlerAction::Unblock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gtk4::gio;
+    use gtk4::prelude::{ActionExt as _, Cast as _, StaticType as _, ToVariant as _};
+
+    use super::*;
+
+    fn object_addr(object: &impl glib::prelude::IsA<glib::Object>) -> usize {
+        let ptr: *const gobject_ffi::GObject = object.upcast_ref().to_glib_none().0;
+        ptr as usize
+    }
+
+    fn handler_request(
+        object: &glib::Object,
+        handler_id: c_ulong,
+        action: HandlerAction,
+    ) -> anyhow::Result<()> {
+        SignalHandlerRequest {
+            instance_addr: object_addr(object),
+            handler_id,
+            action,
+        }
+        .execute()
+    }
+
+    #[test]
+    fn resolve_signal_queries_params_and_return_type() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let signal = resolve_signal(object_addr(&action), c"activate").unwrap();
+        assert_eq!(signal.instance_type, gio::SimpleAction::static_type());
+        assert_eq!(signal.param_types, vec![glib::Type::VARIANT]);
+        assert_eq!(signal.return_type, glib::Type::UNIT);
+        assert_eq!(signal.detail, 0);
+    }
+
+    #[test]
+    fn resolve_signal_parses_the_detail() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let signal = resolve_signal(object_addr(&action), c"notify::enabled").unwrap();
+        assert_eq!(signal.param_types, vec![glib::Type::PARAM_SPEC]);
+        assert_ne!(signal.detail, 0);
+    }
+
+    #[test]
+    fn resolve_signal_rejects_unknown_signal() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let err = resolve_signal(object_addr(&action), c"no-such-signal").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("GSimpleAction has no signal 'no-such-signal'")
+        );
+    }
+
+    #[test]
+    fn resolve_signal_rejects_null_instance() {
+        let err = resolve_signal(0, c"activate").unwrap_err();
+        assert!(err.to_string().contains("null pointer"));
+    }
+
+    fn emit(
+        object: &glib::Object,
+        detailed_signal: &CStr,
+        args: Vec<Value>,
+    ) -> anyhow::Result<Value> {
+        EmitSignalRequest {
+            instance_addr: object_addr(object),
+            detailed_signal: detailed_signal.to_owned(),
+            args: args
+                .into_iter()
+                .map(|value| SignalArg {
+                    value,
+                    value_type: None,
+                })
+                .collect(),
+        }
+        .execute()
+    }
+
+    #[test]
+    fn emit_signal_converts_arguments_to_the_param_types() {
+        let action = gio::SimpleAction::new("test-action", Some(glib::VariantTy::STRING));
+        let received = Rc::new(std::cell::RefCell::new(None));
+        let log = received.clone();
+        action.connect_activate(move |_, parameter| {
+            *log.borrow_mut() = parameter.and_then(glib::Variant::str).map(str::to_owned);
+        });
+
+        let result = emit(
+            action.upcast_ref(),
+            c"activate",
+            vec![Value::Variant("hello".to_variant())],
+        )
+        .unwrap();
+        assert!(matches!(result, Value::Undefined));
+        assert_eq!(received.borrow().as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn emit_signal_decodes_the_return_value() {
+        let application = gio::Application::new(None, gio::ApplicationFlags::default());
+        let options = glib::VariantDict::new(None);
+        let options_ptr: *mut glib::ffi::GVariantDict = options.to_glib_none().0;
+
+        let result = emit(
+            application.upcast_ref(),
+            c"handle-local-options",
+            vec![Value::Object(NativeHandle::borrowed(options_ptr.cast()))],
+        )
+        .unwrap();
+        assert!(matches!(result, Value::Number(n) if n == -1.0));
+    }
+
+    #[test]
+    fn emit_signal_rejects_wrong_argument_count_and_types() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let err = emit(action.upcast_ref(), c"activate", vec![]).unwrap_err();
+        assert!(err.to_string().contains("takes 1 arguments, got 0"));
+
+        let err = emit(action.upcast_ref(), c"activate", vec![Value::Number(1.0)]).unwrap_err();
+        assert!(format!("{err:#}").contains("signal argument 0"), "{err:#}");
+    }
+
+    #[test]
+    fn handler_requests_block_unblock_and_disconnect() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let count = Rc::new(Cell::new(0));
+        let counter = count.clone();
+        let handler = action.connect_activate(move |_, _| counter.set(counter.get() + 1));
+        let handler_id = unsafe { handler.as_raw() };
+        let object = action.upcast_ref::<glib::Object>();
+
+        handler_request(object, handler_id, HandlerAction::Block).unwrap();
+        action.activate(None);
+        assert_eq!(count.get(), 0);
+
+        handler_request(object, handler_id, HandlerAction::Unblock).unwrap();
+        action.activate(None);
+        assert_eq!(count.get(), 1);
+
+        handler_request(object, handler_id, HandlerAction::Disconnect).unwrap();
+        action.activate(None);
+        assert_eq!(count.get(), 1);
+
+        let err = handler_request(object, handler_id, HandlerAction::Disconnect).unwrap_err();
+        assert!(err.to_string().contains("no signal handler"));
+    }
+
+    #[test]
+    fn handler_requests_reject_unknown_handler() {
+        let action = gio::SimpleAction::new("test-action", None);
+        let err = handler_request(action.upcast_ref(), 0, HandlerAction::Block).unwrap_err();
+        assert!(err.to_string().contains("no signal handler 0"));
+    }
+
+    #[test]
+    fn error_contexts_are_stable() {
+        assert_eq!(ConnectSignalRequest::error_context(), "connect_signal");
+        assert_eq!(SignalHandlerRequest::error_context(), "signal_handler");
+        assert_eq!(EmitSignalRequest::error_context(), "emit_signal");
+    }
+}
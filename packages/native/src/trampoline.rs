@@ -19,7 +19,7 @@ use ::libffi::low as libffi_low;
 use ::libffi::middle as libffi;
 use napi::JsFunction;
 
-use crate::dispatch::Mailbox;
+use crate::dispatch::{CallbackResult, Mailbox};
 use crate::error_reporter::NativeErrorReporter;
 use crate::types::{FfiEncoder as _, RawPtrCodec as _, Type};
 use crate::value::{JsRef, Value};
@@ -27,7 +27,7 @@ use crate::value::{JsRef, Value};
 pub struct TrampolineData {
     pub js_func: Arc<JsRef<JsFunction>>,
     pub arg_types: Vec<Type>,
-    pub return_type: Type,
+    pub return_type: Arc<Type>,
     pub user_data_index: Option<usize>,
     pub is_oneshot: bool,
     pub oneshot_state_ptr: AtomicPtr<TrampolineState>,
@@ -133,7 +133,11 @@ impl TrampolineData {
             }
         }
 
-        let capture_result = !matches!(self.return_type, Type::Void(_));
+        let callback_result = if matches!(*self.return_type, Type::Void(_)) {
+            CallbackResult::Discard
+        } else {
+            CallbackResult::Typed(Arc::clone(&self.return_type))
+        };
 
         let state_ptr = if self.is_oneshot {
             let ptr = self
@@ -145,7 +149,7 @@ impl TrampolineData {
         };
 
         let js_result =
-            Mailbox::global().invoke_node_and_wait(&self.js_func, values, capture_result);
+            Mailbox::global().invoke_node_and_wait(&self.js_func, values, callback_result);
 
         if let Err(ref e) = js_result {
             NativeErrorReporter::global().report(&anyhow::anyhow!(
@@ -9,6 +9,7 @@
 //! ```text
 //! Type
 //! ├── Integer(IntegerKind)    - Sized integers (i8..i64, u8..u64)
+//! ├── BigInt(BigIntKind)      - 64-bit integers carried as JS `BigInt`s
 //! ├── Float(FloatKind)        - Floating point (f32, f64)
 //! ├── String(StringType)      - UTF-8 strings (owned or borrowed)
 //! ├── Boolean                 - Boolean values
@@ -16,9 +17,15 @@
 //! ├── GObject(GObjectType)    - GObject instances
 //! ├── Boxed(BoxedType)        - GObject boxed types (e.g., GdkRGBA)
 //! ├── Fundamental(FundamentalType) - Fundamental types (GVariant, GParamSpec, etc.)
+//! ├── Variant(VariantType)    - GVariants converted to and from plain JS values
+//! ├── Bytes(BytesType)        - GBytes converted to and from Node `Buffer`s
+//! ├── GValue(GValueType)      - GValues passed by pointer, decoded by their runtime type
+//! ├── Record(RecordType)      - C structs converted to and from plain JS objects
+//! ├── Union(UnionType)        - C unions converted to and from `{ tag, value }` objects
 //! ├── Array(ArrayType)        - Arrays, GLists, GSLists
 //! ├── Callback(CallbackType)  - JavaScript callback functions
-//! └── Ref(RefType)            - Pointers to values (out parameters)
+//! ├── Ref(RefType)            - Pointers to values (out parameters)
+//! └── GError                  - Trailing `GError**` out-parameter of throwing callables
 //! ```
 //!
 //! ## Ownership
@@ -75,32 +82,49 @@ pub(crate) fn parse_callback_arg_and_return_types(
 mod array;
 mod boolean;
 mod boxed;
+mod bytes;
 mod callback;
 mod fundamental;
+mod gerror;
 mod gobject;
+mod gvalue;
 mod hashtable;
 mod numeric;
 mod prelude;
 mod raw_ptr;
+mod record;
 mod ref_type;
 mod string;
 mod trampoline;
 mod unichar;
+mod union_type;
+mod variant;
 mod void;
 
 pub use array::ArrayKind;
 pub use array::ArrayType;
 pub use boolean::BooleanType;
-pub use boxed::{BoxedType, StructType};
+pub use boxed::{BoxedType, StructLayout, StructType};
+pub use bytes::BytesType;
+pub(crate) use bytes::bytes_to_js;
 pub use callback::CallbackType;
+pub(crate) use callback::signal_closure;
 pub use fundamental::FundamentalType;
+pub use gerror::{GErrorDetails, GErrorType};
 pub use gobject::GObjectType;
+pub use gvalue::GValueType;
+pub(crate) use gvalue::{gvalue_to_value, value_to_gvalue, value_to_gvalue_as};
 pub use hashtable::{HashTableEntryEncoder, HashTableType};
-pub use numeric::{FloatKind, IntegerKind, TaggedKind, TaggedType};
+pub use numeric::{BigIntKind, FloatKind, IntegerKind, TaggedKind, TaggedType};
+pub(crate) use record::embedded_size;
+pub use record::{RecordField, RecordType};
 pub use ref_type::RefType;
 pub use string::StringType;
 pub use trampoline::{TrampolineScope, TrampolineType};
 pub use unichar::UnicharType;
+pub use union_type::{UnionDiscriminant, UnionType, UnionVariant};
+pub use variant::VariantType;
+pub(crate) use variant::variant_to_js;
 pub use void::VoidType;
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -267,6 +291,7 @@ impl<T: FfiEncoder + FfiDecoder + RawPtrCodec + GlibValueCodec> FfiCodec for T {
 #[non_exhaustive]
 pub enum Type {
     Integer(IntegerKind),
+    BigInt(BigIntKind),
     Float(FloatKind),
     Tagged(TaggedType),
     String(StringType),
@@ -276,18 +301,25 @@ pub enum Type {
     Boxed(BoxedType),
     Struct(StructType),
     Fundamental(FundamentalType),
+    Variant(VariantType),
+    Bytes(BytesType),
+    GValue(GValueType),
+    Record(RecordType),
+    Union(UnionType),
     Array(ArrayType),
     HashTable(HashTableType),
     Callback(CallbackType),
     Trampoline(TrampolineType),
     Ref(RefType),
     Unichar(UnicharType),
+    GError(GErrorType),
 }
 
 impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Integer(kind) => write!(f, "Integer({kind:?})"),
+            Self::BigInt(kind) => write!(f, "BigInt({kind:?})"),
             Self::Float(kind) => write!(f, "Float({kind:?})"),
             Self::Tagged(t) => match t.kind {
                 TaggedKind::Enum => write!(f, "Enum({})", t.get_type_fn),
@@ -300,12 +332,21 @@ impl std::fmt::Display for Type {
             Self::Boxed(t) => write!(f, "Boxed({})", t.type_name),
             Self::Struct(t) => write!(f, "Struct({})", t.type_name),
             Self::Fundamental(t) => write!(f, "Fundamental({})", t.unref_func),
+            Self::Variant(t) => match &t.signature {
+                Some(signature) => write!(f, "Variant({})", signature.as_str()),
+                None => write!(f, "Variant"),
+            },
+            Self::Bytes(_) => write!(f, "Bytes"),
+            Self::GValue(_) => write!(f, "GValue"),
+            Self::Record(_) => write!(f, "Record"),
+            Self::Union(_) => write!(f, "Union"),
             Self::Array(_) => write!(f, "Array"),
             Self::HashTable(_) => write!(f, "HashTable"),
             Self::Callback(_) => write!(f, "Callback"),
             Self::Trampoline(_) => write!(f, "Trampoline"),
             Self::Ref(t) => write!(f, "Ref({})", t.inner_type),
             Self::Unichar(_) => write!(f, "Unichar"),
+            Self::GError(_) => write!(f, "GError"),
         }
     }
 }
@@ -323,6 +364,8 @@ impl Type {
             "uint16" => Ok(Self::Integer(IntegerKind::U16)),
             "int32" => Ok(Self::Integer(IntegerKind::I32)),
             "uint32" => Ok(Self::Integer(IntegerKind::U32)),
+            "int64" if Self::wants_bigint(&obj)? => Ok(Self::BigInt(BigIntKind::I64)),
+            "uint64" if Self::wants_bigint(&obj)? => Ok(Self::BigInt(BigIntKind::U64)),
             "int64" => Ok(Self::Integer(IntegerKind::I64)),
             "uint64" => Ok(Self::Integer(IntegerKind::U64)),
             "float32" => Ok(Self::Float(FloatKind::F32)),
@@ -349,9 +392,15 @@ impl Type {
             "trampoline" => Ok(Self::Trampoline(TrampolineType::from_js_value(env, &obj)?)),
             "ref" => Ok(Self::Ref(RefType::from_js_value(env, &obj)?)),
             "unichar" => Ok(Self::Unichar(UnicharType)),
+            "gerror" => Ok(Self::GError(GErrorType)),
             "fundamental" => Ok(Self::Fundamental(FundamentalType::from_js_value(
                 env, &obj,
             )?)),
+            "variant" => Ok(Self::Variant(VariantType::from_js_value(env, &obj)?)),
+            "bytes" => Ok(Self::Bytes(BytesType::from_js_value(env, &obj)?)),
+            "gvalue" => Ok(Self::GValue(GValueType::from_js_value(env, &obj)?)),
+            "record" => Ok(Self::Record(RecordType::from_js_value(env, &obj)?)),
+            "union" => Ok(Self::Union(UnionType::from_js_value(env, &obj)?)),
             other => Err(napi::Error::new(
                 napi::Status::InvalidArg,
                 format!("Unknown type: {other}"),
@@ -359,16 +408,43 @@ impl Type {
         }
     }
 
+    /// Reads the opt-in `bigint` flag of an `int64`/`uint64` descriptor.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn wants_bigint(obj: &JsObject) -> napi::Result<bool> {
+        Ok(obj
+            .get_named_property::<Option<bool>>("bigint")?
+            .unwrap_or(false))
+    }
+
     /// Whether this type may occupy a function's return slot.
     ///
-    /// `Callback`, `Trampoline`, and `Ref` describe argument-only shapes — a
-    /// callback handler or an out-parameter — and have no return-slot codec
-    /// (their [`FfiEncoder::call_cif`] implementations bail). Callers consult
+    /// `Callback`, `Trampoline`, `Ref`, and `GError` describe argument-only
+    /// shapes — a callback handler or an out-parameter — and have no
+    /// return-slot codec (their [`FfiEncoder::call_cif`] implementations
+    /// bail). Callers consult
     /// this at the descriptor-parsing boundary to reject a malformed return
     /// type with a precise `InvalidArg` error.
     #[must_use]
     pub fn can_be_return_type(&self) -> bool {
-        !matches!(self, Self::Callback(_) | Self::Trampoline(_) | Self::Ref(_))
+        !matches!(
+            self,
+            Self::Callback(_) | Self::Trampoline(_) | Self::Ref(_) | Self::GError(_)
+        )
+    }
+
+    /// The type this one is passed as through the `...` of a variadic
+    /// function, after the C default argument promotions: integers narrower
+    /// than `int` widen to `int`, and `float` widens to `double`. Every other
+    /// type is passed unchanged.
+    #[must_use]
+    pub fn variadic_promoted(&self) -> Self {
+        match self {
+            Self::Integer(
+                IntegerKind::I8 | IntegerKind::U8 | IntegerKind::I16 | IntegerKind::U16,
+            ) => Self::Integer(IntegerKind::I32),
+            Self::Float(FloatKind::F32) => Self::Float(FloatKind::F64),
+            other => other.clone(),
+        }
     }
 }
 
@@ -410,4 +486,48 @@ mod tests {
         let ref_type = RefType::new(Type::Integer(IntegerKind::I32));
         assert!(!Type::Ref(ref_type).can_be_return_type());
     }
+
+    #[test]
+    fn gerror_cannot_be_return_type() {
+        assert!(!Type::GError(GErrorType).can_be_return_type());
+    }
+
+    #[test]
+    fn narrow_numbers_are_promoted_for_varargs() {
+        for kind in [
+            IntegerKind::I8,
+            IntegerKind::U8,
+            IntegerKind::I16,
+            IntegerKind::U16,
+        ] {
+            assert!(matches!(
+                Type::Integer(kind).variadic_promoted(),
+                Type::Integer(IntegerKind::I32)
+            ));
+        }
+        assert!(matches!(
+            Type::Float(FloatKind::F32).variadic_promoted(),
+            Type::Float(FloatKind::F64)
+        ));
+    }
+
+    #[test]
+    fn wide_and_pointer_types_are_not_promoted_for_varargs() {
+        assert!(matches!(
+            Type::Integer(IntegerKind::U32).variadic_promoted(),
+            Type::Integer(IntegerKind::U32)
+        ));
+        assert!(matches!(
+            Type::Integer(IntegerKind::I64).variadic_promoted(),
+            Type::Integer(IntegerKind::I64)
+        ));
+        assert!(matches!(
+            Type::Float(FloatKind::F64).variadic_promoted(),
+            Type::Float(FloatKind::F64)
+        ));
+        assert!(matches!(
+            Type::Boolean(BooleanType).variadic_promoted(),
+            Type::Boolean(BooleanType)
+        ));
+    }
 }
@@ -7,8 +7,9 @@ use napi::{Env, JsObject};
 
 use super::prelude::*;
 use crate::arg::Arg;
-use crate::ffi::{FfiStorage, FfiStorageKind};
-use crate::types::{FloatKind, IntegerKind, Type};
+use crate::ffi::{AggregateBuffer, FfiStorage, FfiStorageKind};
+use crate::types::{BigIntKind, FloatKind, IntegerKind, Type};
+use crate::value::TypedBuffer;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
@@ -19,8 +20,19 @@ pub enum ArrayKind {
     GPtrArray,
     GArray,
     GByteArray,
-    Sized { size_index: usize },
-    Fixed { size: usize },
+    Sized {
+        size_index: usize,
+    },
+    Fixed {
+        size: usize,
+    },
+    /// `size` elements embedded in place, like `double matrix[6]` inside a
+    /// struct. Field reads and writes access the elements at the field's own
+    /// address instead of following a pointer; as a call argument it behaves
+    /// like [`ArrayKind::Fixed`].
+    Inline {
+        size: usize,
+    },
 }
 
 impl std::str::FromStr for ArrayKind {
@@ -36,8 +48,9 @@ impl std::str::FromStr for ArrayKind {
             "gbytearray" => Ok(Self::GByteArray),
             "sized" => Ok(Self::Sized { size_index: 0 }),
             "fixed" => Ok(Self::Fixed { size: 0 }),
+            "inline" => Ok(Self::Inline { size: 0 }),
             _ => Err(format!(
-                "'kind' must be 'array', 'glist', 'gslist', 'gptrarray', 'garray', 'gbytearray', 'sized', or 'fixed'; got '{s}'"
+                "'kind' must be 'array', 'glist', 'gslist', 'gptrarray', 'garray', 'gbytearray', 'sized', 'fixed', or 'inline'; got '{s}'"
             )),
         }
     }
@@ -49,6 +62,9 @@ pub struct ArrayType {
     pub kind: ArrayKind,
     pub ownership: Ownership,
     pub element_size: Option<usize>,
+    /// Decode numeric elements into a JS `TypedArray` instead of an array of
+    /// numbers.
+    pub typed: bool,
 }
 
 impl ArrayType {
@@ -84,7 +100,7 @@ impl ArrayType {
                     size_index: size_index as usize,
                 }
             }
-            ArrayKind::Fixed { .. } => {
+            ArrayKind::Fixed { .. } | ArrayKind::Inline { .. } => {
                 let fixed_size: f64 = obj
                     .get_named_property::<Option<f64>>("fixedSize")
                     .ok()
@@ -92,11 +108,14 @@ impl ArrayType {
                     .ok_or_else(|| {
                         napi::Error::new(
                             napi::Status::InvalidArg,
-                            "'fixedSize' is required for fixed arrays",
+                            format!("'fixedSize' is required for {kind_str} arrays"),
                         )
                     })?;
-                ArrayKind::Fixed {
-                    size: fixed_size as usize,
+                let size = fixed_size as usize;
+                if matches!(kind, ArrayKind::Inline { .. }) {
+                    ArrayKind::Inline { size }
+                } else {
+                    ArrayKind::Fixed { size }
                 }
             }
             other => other,
@@ -108,6 +127,19 @@ impl ArrayType {
             .flatten()
             .map(|n| n as usize);
 
+        let typed = obj
+            .get_named_property::<Option<bool>>("typed")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+
+        if typed && !ItemCodec::resolve(&item_type).is_some_and(ItemCodec::is_numeric) {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("'typed' requires a numeric item type, got {item_type:?}"),
+            ));
+        }
+
         let ownership = Ownership::from_js_value(obj, "array")?;
 
         Ok(Self {
@@ -115,6 +147,7 @@ impl ArrayType {
             kind,
             ownership,
             element_size,
+            typed,
         })
     }
 }
@@ -141,6 +174,16 @@ impl FfiDecoder for ArrayType {
 }
 
 impl RawPtrCodec for ArrayType {
+    /// Reads an [`ArrayKind::Inline`] array in place; every other kind is
+    /// stored as a pointer to its elements.
+    fn read_from_raw_ptr(&self, ptr: *const c_void, context: &str) -> anyhow::Result<value::Value> {
+        if let ArrayKind::Inline { size } = self.kind {
+            return self.read_inline(ptr, size);
+        }
+        let inner_ptr = unsafe { *(ptr as *const *mut c_void) };
+        RawPtrCodec::ptr_to_value(self, inner_ptr, context)
+    }
+
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     fn ptr_to_value(
         &self,
@@ -149,6 +192,14 @@ impl RawPtrCodec for ArrayType {
     ) -> anyhow::Result<value::Value> {
         unsafe { Self::ptr_to_value(self, ptr) }
     }
+
+    /// Writes the elements of an [`ArrayKind::Inline`] array in place.
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        let ArrayKind::Inline { size } = self.kind else {
+            bail!("Only inline arrays can be written to a raw pointer");
+        };
+        self.write_inline(ptr, size, value)
+    }
 }
 
 impl GlibValueCodec for ArrayType {}
@@ -167,6 +218,8 @@ enum ItemCodec {
     /// `Type::Tagged`: an integer-storage element encoded without range
     /// checking, since the tag already constrains its value range.
     Tagged(IntegerKind),
+    /// `Type::BigInt`: a 64-bit integer element decoded to a `BigInt`.
+    BigInt(BigIntKind),
     /// `Type::Float`.
     Float(FloatKind),
     /// `Type::Boolean`, stored as a C `int`.
@@ -175,6 +228,10 @@ enum ItemCodec {
     Pointer,
     /// A null-terminated C string element.
     String,
+    /// `Type::Record`: a struct of `size` bytes embedded in the array.
+    Record { size: usize },
+    /// `Type::GValue`: a `GValue` embedded in the array.
+    GValue,
 }
 
 impl ItemCodec {
@@ -184,19 +241,26 @@ impl ItemCodec {
         Some(match item_type {
             Type::Integer(kind) => Self::Integer(*kind),
             Type::Tagged(tagged) => Self::Tagged(tagged.storage),
+            Type::BigInt(kind) => Self::BigInt(*kind),
             Type::Float(kind) => Self::Float(*kind),
             Type::Boolean(_) => Self::Boolean,
             Type::GObject(_) | Type::Boxed(_) | Type::Struct(_) | Type::Fundamental(_) => {
                 Self::Pointer
             }
             Type::String(_) => Self::String,
+            Type::Record(record) => Self::Record { size: record.size },
+            Type::GValue(_) => Self::GValue,
             Type::Void(_)
+            | Type::Union(_)
             | Type::Array(_)
             | Type::HashTable(_)
             | Type::Callback(_)
             | Type::Trampoline(_)
             | Type::Ref(_)
-            | Type::Unichar(_) => return None,
+            | Type::Unichar(_)
+            | Type::Variant(_)
+            | Type::Bytes(_)
+            | Type::GError(_) => return None,
         })
     }
 
@@ -204,10 +268,33 @@ impl ItemCodec {
     fn element_size(self) -> usize {
         match self {
             Self::Integer(kind) | Self::Tagged(kind) => kind.byte_size(),
+            Self::BigInt(_) => size_of::<u64>(),
             Self::Float(FloatKind::F32) => size_of::<f32>(),
             Self::Float(FloatKind::F64) => size_of::<f64>(),
             Self::Boolean => size_of::<i32>(),
             Self::Pointer | Self::String => size_of::<*mut c_void>(),
+            Self::Record { size } => size,
+            Self::GValue => size_of::<glib::gobject_ffi::GValue>(),
+        }
+    }
+
+    /// Whether elements are plain numbers that a JS `TypedArray` can hold.
+    fn is_numeric(self) -> bool {
+        matches!(
+            self,
+            Self::Integer(_) | Self::Tagged(_) | Self::BigInt(_) | Self::Float(_)
+        )
+    }
+
+    /// Whether `buffer` already holds elements in this codec's native layout.
+    fn accepts(self, buffer: &TypedBuffer) -> bool {
+        match self {
+            Self::Integer(kind) | Self::Tagged(kind) => buffer.integer_kind() == Some(kind),
+            Self::BigInt(kind) => buffer.integer_kind() == Some(kind.integer_kind()),
+            Self::Float(kind) => buffer.float_kind() == Some(kind),
+            Self::Boolean | Self::Pointer | Self::String | Self::Record { .. } | Self::GValue => {
+                false
+            }
         }
     }
 }
@@ -446,6 +533,10 @@ impl ArrayType {
             .collect()
     }
 
+    fn extract_bigints(array: &[value::Value], kind: BigIntKind) -> anyhow::Result<Vec<i128>> {
+        array.iter().map(|v| kind.value_to_i128(v)).collect()
+    }
+
     fn extract_booleans(array: &[value::Value]) -> anyhow::Result<Vec<i32>> {
         array
             .iter()
@@ -482,6 +573,56 @@ impl ArrayType {
         ItemCodec::resolve(&self.item_type).map(ItemCodec::element_size)
     }
 
+    /// Reads `size` elements laid out in place at `ptr`.
+    fn read_inline(&self, ptr: *const c_void, size: usize) -> anyhow::Result<value::Value> {
+        let stride = self.inline_stride()?;
+        (0..size)
+            .map(|i| {
+                super::record::read_embedded(&self.item_type, unsafe { ptr.byte_add(i * stride) })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map(value::Value::Array)
+    }
+
+    /// Writes the elements of `value` in place at `ptr`, leaving the slots
+    /// past the end of an array shorter than `size` untouched.
+    fn write_inline(
+        &self,
+        ptr: *mut c_void,
+        size: usize,
+        value: &value::Value,
+    ) -> anyhow::Result<()> {
+        let value::Value::Array(items) = value else {
+            bail!("Expected an Array for an inline array of {size}, got {value:?}");
+        };
+        if items.len() > size {
+            bail!(
+                "An inline array of {size} cannot hold {} elements",
+                items.len()
+            );
+        }
+        let stride = self.inline_stride()?;
+        for (i, item) in items.iter().enumerate() {
+            let item_ptr = unsafe { ptr.byte_add(i * stride) };
+            super::record::write_embedded(&self.item_type, item_ptr, item)
+                .map_err(|e| e.context(format!("array element {i}")))?;
+        }
+        Ok(())
+    }
+
+    /// The distance between consecutive elements of an inline array: the
+    /// `elementSize` override, or the size the item type occupies in place.
+    pub(crate) fn inline_stride(&self) -> anyhow::Result<usize> {
+        self.element_size
+            .or_else(|| super::embedded_size(&self.item_type))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{} cannot be the item type of an inline array",
+                    self.item_type
+                )
+            })
+    }
+
     /// Resolves the [`ItemCodec`] for this array's element type, failing with a
     /// `context`-specific message when the type cannot appear as an element.
     fn item_codec(&self, context: &str) -> anyhow::Result<ItemCodec> {
@@ -508,6 +649,11 @@ impl ArrayType {
                 .into_iter()
                 .map(value::Value::Number)
                 .collect(),
+            ItemCodec::BigInt(kind) => kind
+                .read_slice(data, len)
+                .into_iter()
+                .map(value::Value::BigInt)
+                .collect(),
             ItemCodec::Float(FloatKind::F32) => {
                 unsafe { std::slice::from_raw_parts(data.cast::<f32>(), len) }
                     .iter()
@@ -532,13 +678,74 @@ impl ArrayType {
                     .map(|&item_ptr| self.item_type.decode(&ffi::FfiValue::Ptr(item_ptr)))
                     .collect();
             }
+            ItemCodec::Record { .. } | ItemCodec::GValue => {
+                let size = codec.element_size();
+                return (0..len)
+                    .map(|i| {
+                        let item_ptr = unsafe { data.add(i * size) };
+                        self.item_type
+                            .ptr_to_value(item_ptr.cast_mut().cast(), "array item")
+                    })
+                    .collect();
+            }
         };
         Ok(values)
     }
 
+    /// Copies `len` contiguous elements into a [`TypedBuffer`] when this array
+    /// opted into typed decoding, or returns `None` so the caller falls back to
+    /// one [`value::Value`] per element.
+    fn typed_buffer(&self, codec: ItemCodec, data: *const u8, len: usize) -> Option<TypedBuffer> {
+        if !self.typed {
+            return None;
+        }
+        let data = data.cast::<c_void>();
+        Some(unsafe {
+            match codec {
+                ItemCodec::Integer(kind) | ItemCodec::Tagged(kind) => {
+                    TypedBuffer::from_integers(kind, data, len)
+                }
+                ItemCodec::BigInt(kind) => {
+                    TypedBuffer::from_integers(kind.integer_kind(), data, len)
+                }
+                ItemCodec::Float(kind) => TypedBuffer::from_floats(kind, data, len),
+                ItemCodec::Boolean
+                | ItemCodec::Pointer
+                | ItemCodec::String
+                | ItemCodec::Record { .. }
+                | ItemCodec::GValue => return None,
+            }
+        })
+    }
+
+    /// Decodes `len` contiguous elements as a [`value::Value::TypedArray`] or
+    /// a [`value::Value::Array`], depending on [`ArrayType::typed`].
+    fn decode_contiguous_value(
+        &self,
+        codec: ItemCodec,
+        data: *const u8,
+        len: usize,
+    ) -> anyhow::Result<value::Value> {
+        if let Some(buffer) = self.typed_buffer(codec, data, len) {
+            return Ok(value::Value::TypedArray(buffer));
+        }
+        Ok(value::Value::Array(
+            self.decode_contiguous(codec, data, len)?,
+        ))
+    }
+
+    /// The value a null array pointer decodes to: an empty typed array when
+    /// [`ArrayType::typed`] is set, an empty array otherwise.
+    fn empty_value(&self) -> value::Value {
+        ItemCodec::resolve(&self.item_type)
+            .and_then(|codec| self.typed_buffer(codec, std::ptr::null(), 0))
+            .map_or_else(|| value::Value::Array(vec![]), value::Value::TypedArray)
+    }
+
     pub fn encode(&self, val: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
         let array = match val {
             value::Value::Array(arr) => arr,
+            value::Value::TypedArray(buffer) => return self.encode_typed(buffer),
             value::Value::Null | value::Value::Undefined if optional => {
                 return Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()));
             }
@@ -566,6 +773,9 @@ impl ArrayType {
             ItemCodec::Tagged(kind) => Ok(ffi::FfiValue::Storage(
                 kind.to_ffi_storage(&Self::extract_numbers(array)?),
             )),
+            ItemCodec::BigInt(kind) => Ok(ffi::FfiValue::Storage(
+                kind.checked_to_ffi_storage(&Self::extract_bigints(array, kind)?)?,
+            )),
             ItemCodec::Float(kind) => {
                 Self::encode_float_array(&Self::extract_numbers(array)?, kind)
             }
@@ -600,9 +810,45 @@ impl ArrayType {
 
                 encoder.encode_handles(&handles, &self.item_type, self.ownership)
             }
+            ItemCodec::Record { .. } => {
+                if matches!(
+                    self.kind,
+                    ArrayKind::GList | ArrayKind::GSList | ArrayKind::GPtrArray
+                ) {
+                    bail!("Records cannot be encoded into a {:?} array", self.kind);
+                }
+                Ok(ffi::FfiValue::Storage(self.record_buffer(array)?.into()))
+            }
+            ItemCodec::GValue => {
+                let Type::GValue(gvalue_type) = &*self.item_type else {
+                    bail!("Expected a GValue item type, got {:?}", self.item_type);
+                };
+                if matches!(
+                    self.kind,
+                    ArrayKind::GList | ArrayKind::GSList | ArrayKind::GPtrArray
+                ) {
+                    bail!("GValues cannot be encoded into a {:?} array", self.kind);
+                }
+                Ok(ffi::FfiValue::Storage(gvalue_type.alloc_array(array)?))
+            }
         }
     }
 
+    /// Lays `array`'s records out back to back, as a C array of structs.
+    fn record_buffer(&self, array: &[value::Value]) -> anyhow::Result<AggregateBuffer> {
+        let Type::Record(record) = &*self.item_type else {
+            bail!("Expected a record item type, got {:?}", self.item_type);
+        };
+        let mut buffer = AggregateBuffer::zeroed(array.len() * record.size);
+        for (i, item) in array.iter().enumerate() {
+            let item_ptr = unsafe { buffer.as_mut_ptr().byte_add(i * record.size) };
+            record
+                .write_inline(item_ptr, item)
+                .map_err(|e| e.context(format!("array element {i}")))?;
+        }
+        Ok(buffer)
+    }
+
     fn encode_gbytearray(&self, array: &[value::Value]) -> anyhow::Result<ffi::FfiValue> {
         let bytes: Vec<u8> = array
             .iter()
@@ -618,6 +864,10 @@ impl ArrayType {
             })
             .collect::<anyhow::Result<Vec<u8>>>()?;
 
+        Ok(self.gbytearray_from_bytes(&bytes))
+    }
+
+    fn gbytearray_from_bytes(&self, bytes: &[u8]) -> ffi::FfiValue {
         let byte_array = unsafe {
             let ba = glib::ffi::g_byte_array_sized_new(bytes.len() as u32);
             glib::ffi::g_byte_array_append(ba, bytes.as_ptr(), bytes.len() as u32);
@@ -625,13 +875,59 @@ impl ArrayType {
         };
 
         let should_free = self.ownership.is_borrowed();
-        Ok(ffi::FfiValue::Storage(FfiStorage::new(
+        ffi::FfiValue::Storage(FfiStorage::new(
             byte_array as *mut c_void,
             FfiStorageKind::GByteArray(ffi::GByteArrayData {
                 array_ptr: byte_array,
                 should_free,
             }),
-        )))
+        ))
+    }
+
+    /// Encodes a JS typed array, whose contents are already laid out the way
+    /// the element type expects, without visiting individual elements.
+    ///
+    /// The buffer is copied once, on its way out of the JS heap; plain and
+    /// `sized`/`fixed` arrays then point native code straight at that copy,
+    /// while `GArray`/`GByteArray` append it in a single call.
+    fn encode_typed(&self, buffer: &TypedBuffer) -> anyhow::Result<ffi::FfiValue> {
+        if self.kind == ArrayKind::GByteArray {
+            let TypedBuffer::U8(bytes) = buffer else {
+                bail!(
+                    "Expected a Uint8Array or ArrayBuffer for GByteArray, got a {}",
+                    buffer.js_type_name()
+                );
+            };
+            return Ok(self.gbytearray_from_bytes(bytes));
+        }
+
+        let codec = self.item_codec("array")?;
+        if !codec.accepts(buffer) {
+            bail!(
+                "Cannot pass a {} as an array of {:?}",
+                buffer.js_type_name(),
+                self.item_type
+            );
+        }
+
+        if self.kind == ArrayKind::GArray {
+            if self
+                .element_size
+                .is_some_and(|size| size != codec.element_size())
+            {
+                bail!(
+                    "GArray 'elementSize' does not match the {} element size",
+                    buffer.js_type_name()
+                );
+            }
+            let g_array = self.new_garray(buffer.len())?;
+            unsafe {
+                glib::ffi::g_array_append_vals(g_array, buffer.as_ptr(), buffer.len() as u32)
+            };
+            return Ok(self.garray_storage(g_array));
+        }
+
+        Ok(ffi::FfiValue::Storage(buffer.clone().into()))
     }
 
     fn append_integer_values_to_garray(
@@ -649,6 +945,19 @@ impl ArrayType {
         Ok(())
     }
 
+    fn append_bigint_values_to_garray(
+        g_array: *mut glib::ffi::GArray,
+        kind: BigIntKind,
+        array: &[value::Value],
+    ) -> anyhow::Result<()> {
+        let values = Self::extract_bigints(array, kind)?;
+        let storage = kind.checked_to_ffi_storage(&values)?;
+        unsafe {
+            glib::ffi::g_array_append_vals(g_array, storage.ptr(), values.len() as u32);
+        }
+        Ok(())
+    }
+
     fn append_float_values_to_garray(
         g_array: *mut glib::ffi::GArray,
         float_kind: super::FloatKind,
@@ -705,6 +1014,7 @@ impl ArrayType {
             ItemCodec::Integer(kind) | ItemCodec::Tagged(kind) => {
                 Self::append_integer_values_to_garray(g_array, kind, array)
             }
+            ItemCodec::BigInt(kind) => Self::append_bigint_values_to_garray(g_array, kind, array),
             ItemCodec::Float(kind) => Self::append_float_values_to_garray(g_array, kind, array),
             ItemCodec::Boolean => {
                 for b in Self::extract_booleans(array)? {
@@ -732,10 +1042,18 @@ impl ArrayType {
                 }
                 Ok(())
             }
+            ItemCodec::Record { .. } => {
+                let buffer = self.record_buffer(array)?;
+                unsafe {
+                    glib::ffi::g_array_append_vals(g_array, buffer.as_ptr(), array.len() as u32);
+                }
+                Ok(())
+            }
+            ItemCodec::GValue => bail!("GValues cannot be encoded into a GArray"),
         }
     }
 
-    fn encode_garray(&self, array: &[value::Value]) -> anyhow::Result<ffi::FfiValue> {
+    fn new_garray(&self, capacity: usize) -> anyhow::Result<*mut glib::ffi::GArray> {
         let element_size = self
             .element_size
             .or_else(|| self.item_element_size())
@@ -746,22 +1064,29 @@ impl ArrayType {
                 )
             })?;
 
-        let g_array =
-            unsafe { glib::ffi::g_array_sized_new(0, 0, element_size as u32, array.len() as u32) };
-
-        if let Err(err) = self.append_items_to_garray(g_array, array) {
-            unsafe { glib::ffi::g_array_unref(g_array) };
-            return Err(err);
-        }
+        Ok(unsafe { glib::ffi::g_array_sized_new(0, 0, element_size as u32, capacity as u32) })
+    }
 
+    fn garray_storage(&self, g_array: *mut glib::ffi::GArray) -> ffi::FfiValue {
         let should_free = self.ownership.is_borrowed();
-        Ok(ffi::FfiValue::Storage(FfiStorage::new(
+        ffi::FfiValue::Storage(FfiStorage::new(
             g_array as *mut c_void,
             FfiStorageKind::GArray(ffi::GArrayData {
                 array_ptr: g_array,
                 should_free,
             }),
-        )))
+        ))
+    }
+
+    fn encode_garray(&self, array: &[value::Value]) -> anyhow::Result<ffi::FfiValue> {
+        let g_array = self.new_garray(array.len())?;
+
+        if let Err(err) = self.append_items_to_garray(g_array, array) {
+            unsafe { glib::ffi::g_array_unref(g_array) };
+            return Err(err);
+        }
+
+        Ok(self.garray_storage(g_array))
     }
 
     pub fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
@@ -770,7 +1095,10 @@ impl ArrayType {
             ArrayKind::GArray => return self.decode_garray(ffi_value),
             ArrayKind::GPtrArray => return self.decode_gptrarray(ffi_value),
             ArrayKind::GByteArray => return self.decode_gbytearray(ffi_value),
-            ArrayKind::Array | ArrayKind::Sized { .. } | ArrayKind::Fixed { .. } => {}
+            ArrayKind::Array
+            | ArrayKind::Sized { .. }
+            | ArrayKind::Fixed { .. }
+            | ArrayKind::Inline { .. } => {}
         }
 
         if let ffi::FfiValue::Ptr(ptr) = ffi_value {
@@ -782,6 +1110,16 @@ impl ArrayType {
                 return Ok(self.decode_null_terminated_string_array(*ptr));
             }
 
+            if matches!(&*self.item_type, Type::Record(_) | Type::GValue(_)) {
+                let (ArrayKind::Fixed { size } | ArrayKind::Inline { size }) = self.kind else {
+                    bail!(
+                        "An array of {} needs a 'sized' or 'fixed' kind to be decoded",
+                        self.item_type
+                    );
+                };
+                return self.decode_sized_array(*ptr, size);
+            }
+
             return self.decode_null_terminated_ptr_array(*ptr);
         }
 
@@ -804,16 +1142,16 @@ impl ArrayType {
 
                 if let ffi::FfiValue::Ptr(ptr) = ffi_value {
                     if ptr.is_null() {
-                        return Ok(value::Value::Array(vec![]));
+                        return Ok(self.empty_value());
                     }
 
                     return self.decode_sized_array(*ptr, length);
                 }
             }
-            ArrayKind::Fixed { size } => {
+            ArrayKind::Fixed { size } | ArrayKind::Inline { size } => {
                 if let ffi::FfiValue::Ptr(ptr) = ffi_value {
                     if ptr.is_null() {
-                        return Ok(value::Value::Array(vec![]));
+                        return Ok(self.empty_value());
                     }
 
                     return self.decode_sized_array(*ptr, *size);
@@ -861,14 +1199,14 @@ impl ArrayType {
 
     pub(crate) fn decode_garray(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
         let Some(array_ptr) = ffi_value.as_non_null_ptr("GArray")? else {
-            return Ok(value::Value::Array(vec![]));
+            return Ok(self.empty_value());
         };
 
         let codec = self.item_codec("GArray")?;
         let g_array = array_ptr as *const glib::ffi::GArray;
         let data = unsafe { (*g_array).data as *const u8 };
         let len = unsafe { (*g_array).len as usize };
-        let values = self.decode_contiguous(codec, data, len)?;
+        let result = self.decode_contiguous_value(codec, data, len)?;
 
         if self.ownership.is_full() {
             let storage_owns = matches!(ffi_value, ffi::FfiValue::Storage(_));
@@ -877,7 +1215,7 @@ impl ArrayType {
             }
         }
 
-        Ok(value::Value::Array(values))
+        Ok(result)
     }
 
     fn decode_gptrarray(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
@@ -905,20 +1243,26 @@ impl ArrayType {
 
     fn decode_gbytearray(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
         let Some(ptr) = ffi_value.as_non_null_ptr("GByteArray")? else {
-            return Ok(value::Value::Array(vec![]));
+            return Ok(self.empty_value());
         };
 
         let byte_array = ptr as *const glib::ffi::GByteArray;
         let data = unsafe { (*byte_array).data };
         let len = unsafe { (*byte_array).len as usize };
 
-        let values: Vec<value::Value> = if data.is_null() || len == 0 {
-            vec![]
+        let result = if self.typed {
+            value::Value::TypedArray(unsafe {
+                TypedBuffer::from_integers(IntegerKind::U8, data.cast_const().cast(), len)
+            })
+        } else if data.is_null() || len == 0 {
+            value::Value::Array(vec![])
         } else {
-            unsafe { std::slice::from_raw_parts(data, len) }
-                .iter()
-                .map(|&b| value::Value::Number(b as f64))
-                .collect()
+            value::Value::Array(
+                unsafe { std::slice::from_raw_parts(data, len) }
+                    .iter()
+                    .map(|&b| value::Value::Number(b as f64))
+                    .collect(),
+            )
         };
 
         let storage_owns = matches!(ffi_value, ffi::FfiValue::Storage(_));
@@ -926,7 +1270,7 @@ impl ArrayType {
             unsafe { glib::ffi::g_byte_array_unref(ptr as *mut glib::ffi::GByteArray) };
         }
 
-        Ok(value::Value::Array(values))
+        Ok(result)
     }
 
     fn decode_null_terminated_ptr_array(&self, ptr: *mut c_void) -> anyhow::Result<value::Value> {
@@ -972,12 +1316,51 @@ impl ArrayType {
     }
 
     fn decode_storage(&self, storage: &FfiStorage) -> anyhow::Result<value::Value> {
-        let values = match self.item_codec("array")? {
+        let codec = self.item_codec("array")?;
+        let data = storage.ptr().cast_const().cast::<u8>();
+
+        if let Some(len) = storage.numeric_len()
+            && let Some(buffer) = self.typed_buffer(codec, data, len)
+        {
+            return Ok(value::Value::TypedArray(buffer));
+        }
+
+        if let ItemCodec::Record { size } = codec
+            && let FfiStorageKind::Aggregate(buffer) = storage.kind()
+        {
+            let len = buffer.len().checked_div(size).unwrap_or(0);
+            return Ok(value::Value::Array(
+                self.decode_contiguous(codec, data, len)?,
+            ));
+        }
+
+        if let FfiStorageKind::GValues(gvalues) = storage.kind() {
+            return Ok(value::Value::Array(self.decode_contiguous(
+                codec,
+                data,
+                gvalues.len(),
+            )?));
+        }
+
+        if let FfiStorageKind::TypedBuffer(buffer) = storage.kind() {
+            return Ok(value::Value::Array(self.decode_contiguous(
+                codec,
+                data,
+                buffer.len(),
+            )?));
+        }
+
+        let values = match codec {
             ItemCodec::Integer(kind) | ItemCodec::Tagged(kind) => kind
                 .vec_to_f64(storage)?
                 .into_iter()
                 .map(value::Value::Number)
                 .collect(),
+            ItemCodec::BigInt(kind) => kind
+                .storage_to_vec(storage)?
+                .into_iter()
+                .map(value::Value::BigInt)
+                .collect(),
             ItemCodec::Float(FloatKind::F32) => storage
                 .as_f32_slice()?
                 .iter()
@@ -1003,6 +1386,12 @@ impl ArrayType {
                 .iter()
                 .map(|cstr| Ok(value::Value::String(cstr.to_str()?.to_string())))
                 .collect::<anyhow::Result<Vec<value::Value>>>()?,
+            ItemCodec::Record { .. } => {
+                bail!("Expected record storage for an array of records, got {storage:?}")
+            }
+            ItemCodec::GValue => {
+                bail!("Expected GValue storage for an array of GValues, got {storage:?}")
+            }
         };
 
         Ok(value::Value::Array(values))
@@ -1010,8 +1399,7 @@ impl ArrayType {
 
     fn decode_sized_array(&self, ptr: *mut c_void, length: usize) -> anyhow::Result<value::Value> {
         let codec = self.item_codec("sized array")?;
-        let values = self.decode_contiguous(codec, ptr.cast::<u8>(), length)?;
-        Ok(value::Value::Array(values))
+        self.decode_contiguous_value(codec, ptr.cast::<u8>(), length)
     }
 
     /// # Safety
@@ -1045,7 +1433,10 @@ impl ArrayType {
                 let ffi_value = ffi::FfiValue::Ptr(ptr);
                 self.decode_glist(&ffi_value)
             }
-            ArrayKind::Array | ArrayKind::Sized { .. } | ArrayKind::Fixed { .. } => {
+            ArrayKind::Array
+            | ArrayKind::Sized { .. }
+            | ArrayKind::Fixed { .. }
+            | ArrayKind::Inline { .. } => {
                 let ffi_value = ffi::FfiValue::Ptr(ptr);
                 self.decode(&ffi_value)
             }
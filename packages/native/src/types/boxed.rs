@@ -4,30 +4,182 @@
 //! managed by `GLib`. Struct types are similar but may be stack-allocated
 //! or have fixed sizes. This module provides [`BoxedType`] and [`StructType`]
 //! descriptors that handle encoding/decoding these types for FFI calls.
+//!
+//! Both are passed by pointer unless the descriptor carries a
+//! [`StructLayout`], in which case the struct itself is passed and returned
+//! by value: arguments are copied out of the JS handle's memory and returned
+//! structs are copied into a freshly allocated [`Boxed`].
 
 use anyhow::bail;
 use gtk4::glib::{
     self,
     translate::{FromGlib as _, IntoGlib as _, ToGlibPtr as _, ToGlibPtrMut as _},
 };
+use libffi::middle as libffi;
+use napi::bindgen_prelude::*;
 use napi::{Env, JsObject};
 
+use super::Type;
 use super::prelude::*;
 use crate::error_reporter::NativeErrorReporter;
+use crate::ffi::AggregateBuffer;
 use crate::managed::{Boxed, NativeValue};
 use crate::state::GtkThreadState;
 
+/// Field layout of a struct or boxed type passed by value.
+///
+/// `fields` lists the member types in declaration order; libffi derives the
+/// size, alignment and padding from them the same way the C compiler does.
+#[derive(Debug, Clone)]
+pub struct StructLayout {
+    pub fields: Vec<Type>,
+    size: usize,
+}
+
+impl StructLayout {
+    pub fn new(fields: Vec<Type>) -> anyhow::Result<Self> {
+        if fields.is_empty() {
+            bail!("A by-value struct needs at least one field");
+        }
+        if let Some(field) = fields
+            .iter()
+            .find(|field| matches!(field, Type::Void(_) | Type::Trampoline(_)))
+        {
+            bail!("{field} cannot be a field of a by-value struct");
+        }
+
+        let cif = libffi::Cif::new(Vec::new(), Self::ffi_type_of(&fields));
+        let size = unsafe { (*(*cif.as_raw_ptr()).rtype).size };
+        Ok(Self { fields, size })
+    }
+
+    /// Reads the `byValue` flag and `fields` list of a `kind` descriptor.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value(env: &Env, obj: &JsObject, kind: &str) -> napi::Result<Option<Self>> {
+        let by_value = obj
+            .get_named_property::<Option<bool>>("byValue")?
+            .unwrap_or(false);
+        if !by_value {
+            return Ok(None);
+        }
+
+        let fields_prop: Unknown<'_> = obj.get_named_property("fields")?;
+        if !fields_prop.is_array()? {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("'fields' property is required for by-value {kind} types"),
+            ));
+        }
+        let fields_arr: Array = unsafe { Array::from_napi_value(env.raw(), fields_prop.raw())? };
+        let fields = crate::value::map_js_array(env, &fields_arr, Type::from_js_value)?;
+
+        Self::new(fields)
+            .map(Some)
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))
+    }
+
+    /// Size of the struct in bytes, including trailing padding.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    #[must_use]
+    pub fn libffi_type(&self) -> libffi::Type {
+        Self::ffi_type_of(&self.fields)
+    }
+
+    fn ffi_type_of(fields: &[Type]) -> libffi::Type {
+        libffi::Type::structure(fields.iter().map(FfiEncoder::libffi_type))
+    }
+
+    /// Copies the struct `value` points at into an argument buffer.
+    fn encode(&self, value: &value::Value, type_name: &str) -> anyhow::Result<ffi::FfiValue> {
+        let ptr = value.object_ptr(type_name)?;
+        if ptr.is_null() {
+            bail!("A by-value {type_name} cannot be null");
+        }
+        Ok(ffi::FfiValue::Aggregate(unsafe {
+            AggregateBuffer::copy_from(ptr, self.size)
+        }))
+    }
+
+    fn call_cif(
+        &self,
+        cif: &libffi::Cif,
+        ptr: libffi::CodePtr,
+        args: &[libffi::Arg],
+    ) -> ffi::FfiValue {
+        let mut buffer = AggregateBuffer::zeroed(self.size);
+        unsafe { cif.call_return_into(ptr, args, buffer.as_ret()) };
+        ffi::FfiValue::Aggregate(buffer)
+    }
+
+    /// Copies the struct at `ptr` into a new [`Boxed`], through
+    /// `g_boxed_copy` when `gtype` is known.
+    fn copy_out(
+        &self,
+        gtype: Option<glib::Type>,
+        ptr: *const c_void,
+        type_name: &str,
+    ) -> anyhow::Result<value::Value> {
+        let boxed = Boxed::from_glib_none_with_size(
+            gtype,
+            ptr.cast_mut(),
+            Some(self.size),
+            Some(type_name),
+        )?;
+        Ok(value::Value::Object(NativeValue::Boxed(boxed).into()))
+    }
+
+    /// Copies the struct `value` points at into `dest`, zero-filling it when
+    /// there is no struct to copy.
+    fn write_to(&self, dest: *mut c_void, value: Option<&value::Value>) -> anyhow::Result<()> {
+        let src = value.map_or(Ok(std::ptr::null_mut()), |v| {
+            v.object_ptr("by-value struct")
+        })?;
+        unsafe {
+            if src.is_null() {
+                std::ptr::write_bytes(dest.cast::<u8>(), 0, self.size);
+            } else {
+                std::ptr::copy_nonoverlapping(src.cast::<u8>(), dest.cast::<u8>(), self.size);
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BoxedType {
     pub ownership: Ownership,
     pub type_name: String,
     pub library: Option<String>,
     pub get_type_fn: Option<String>,
+    /// Set when the type is passed and returned by value. Ownership does not
+    /// apply then: the bytes are always copied.
+    pub by_value: Option<StructLayout>,
 }
 
 impl BoxedType {
+    /// A boxed type passed by pointer.
+    #[must_use]
+    pub fn new(
+        ownership: Ownership,
+        type_name: String,
+        library: Option<String>,
+        get_type_fn: Option<String>,
+    ) -> Self {
+        Self {
+            ownership,
+            type_name,
+            library,
+            get_type_fn,
+            by_value: None,
+        }
+    }
+
     #[cfg_attr(coverage_nightly, coverage(off))]
-    pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
+    pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
         let ownership = Ownership::from_js_value(obj, "boxed")?;
 
         let type_name: String = obj.get_named_property("innerType")?;
@@ -42,11 +194,14 @@ impl BoxedType {
             .ok()
             .flatten();
 
+        let by_value = StructLayout::from_js_value(env, obj, "boxed")?;
+
         Ok(Self {
             ownership,
             type_name,
             library,
             get_type_fn,
+            by_value,
         })
     }
 
@@ -89,6 +244,10 @@ impl BoxedType {
 
 impl FfiEncoder for BoxedType {
     fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        if let Some(layout) = &self.by_value {
+            return layout.encode(value, "Boxed object");
+        }
+
         let ptr = value.object_ptr("Boxed object")?;
 
         if let Some(gtype) = self.gtype()
@@ -103,6 +262,26 @@ impl FfiEncoder for BoxedType {
         Ok(ffi::FfiValue::Ptr(ptr))
     }
 
+    fn libffi_type(&self) -> libffi::Type {
+        self.by_value
+            .as_ref()
+            .map_or_else(libffi::Type::pointer, StructLayout::libffi_type)
+    }
+
+    fn call_cif(
+        &self,
+        cif: &libffi::Cif,
+        ptr: libffi::CodePtr,
+        args: &[libffi::Arg],
+    ) -> anyhow::Result<ffi::FfiValue> {
+        if let Some(layout) = &self.by_value {
+            return Ok(layout.call_cif(cif, ptr, args));
+        }
+        Ok(ffi::FfiValue::Ptr(unsafe {
+            cif.call::<*mut c_void>(ptr, args)
+        }))
+    }
+
     fn ref_for_transfer(&self, ptr: *mut c_void) -> anyhow::Result<*mut c_void> {
         if self.ownership.is_full()
             && !ptr.is_null()
@@ -118,6 +297,10 @@ impl FfiEncoder for BoxedType {
 
 impl FfiDecoder for BoxedType {
     fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        if let (Some(layout), ffi::FfiValue::Aggregate(buffer)) = (&self.by_value, ffi_value) {
+            return layout.copy_out(self.gtype(), buffer.as_ptr(), &self.type_name);
+        }
+
         let Some(boxed_ptr) = ffi_value.as_non_null_ptr("Boxed")? else {
             return Ok(value::Value::Null);
         };
@@ -139,6 +322,16 @@ impl FfiDecoder for BoxedType {
 }
 
 impl RawPtrCodec for BoxedType {
+    /// A by-value boxed type is stored inline, so `ptr` is the struct itself
+    /// rather than a pointer to it.
+    fn read_from_raw_ptr(&self, ptr: *const c_void, context: &str) -> anyhow::Result<value::Value> {
+        if let Some(layout) = &self.by_value {
+            return layout.copy_out(self.gtype(), ptr, &self.type_name);
+        }
+        let inner_ptr = unsafe { *(ptr as *const *mut c_void) };
+        self.ptr_to_value(inner_ptr, context)
+    }
+
     fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
         null_guarded(ptr, |ptr| {
             let gtype = self.gtype();
@@ -147,7 +340,17 @@ impl RawPtrCodec for BoxedType {
         })
     }
 
-    fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
+    fn write_return_to_raw_ptr(
+        &self,
+        ret: *mut c_void,
+        value: &std::result::Result<value::Value, ()>,
+    ) {
+        if let Some(layout) = &self.by_value {
+            if let Err(e) = layout.write_to(ret, value.as_ref().ok()) {
+                NativeErrorReporter::global().report(&e.context("by-value boxed return"));
+            }
+            return;
+        }
         write_return_object_ptr(ret, value, |ptr| {
             self.gtype().map_or(ptr, |gtype| unsafe {
                 glib::gobject_ffi::g_boxed_copy(gtype.into_glib(), ptr as *const _)
@@ -156,6 +359,9 @@ impl RawPtrCodec for BoxedType {
     }
 
     fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        if let Some(layout) = &self.by_value {
+            return layout.write_to(ptr, Some(value));
+        }
         write_object_ptr(ptr, value, "Boxed field write")
     }
 }
@@ -218,11 +424,24 @@ pub struct StructType {
     pub ownership: Ownership,
     pub type_name: String,
     pub size: Option<usize>,
+    /// Set when the struct is passed and returned by value.
+    pub by_value: Option<StructLayout>,
 }
 
 impl StructType {
+    /// A plain struct passed by pointer.
+    #[must_use]
+    pub fn new(ownership: Ownership, type_name: String, size: Option<usize>) -> Self {
+        Self {
+            ownership,
+            type_name,
+            size,
+            by_value: None,
+        }
+    }
+
     #[cfg_attr(coverage_nightly, coverage(off))]
-    pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
+    pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
         let ownership = Ownership::from_js_value(obj, "struct")?;
 
         let type_name: String = obj.get_named_property("innerType")?;
@@ -232,24 +451,53 @@ impl StructType {
             .ok()
             .flatten()
             .map(|n| n as usize);
+        let by_value = StructLayout::from_js_value(env, obj, "struct")?;
 
         Ok(Self {
             ownership,
             type_name,
-            size,
+            size: size.or_else(|| by_value.as_ref().map(StructLayout::size)),
+            by_value,
         })
     }
 }
 
 impl FfiEncoder for StructType {
     fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        if let Some(layout) = &self.by_value {
+            return layout.encode(value, "Struct object");
+        }
         let ptr = value.object_ptr("Struct object")?;
         Ok(ffi::FfiValue::Ptr(ptr))
     }
+
+    fn libffi_type(&self) -> libffi::Type {
+        self.by_value
+            .as_ref()
+            .map_or_else(libffi::Type::pointer, StructLayout::libffi_type)
+    }
+
+    fn call_cif(
+        &self,
+        cif: &libffi::Cif,
+        ptr: libffi::CodePtr,
+        args: &[libffi::Arg],
+    ) -> anyhow::Result<ffi::FfiValue> {
+        if let Some(layout) = &self.by_value {
+            return Ok(layout.call_cif(cif, ptr, args));
+        }
+        Ok(ffi::FfiValue::Ptr(unsafe {
+            cif.call::<*mut c_void>(ptr, args)
+        }))
+    }
 }
 
 impl FfiDecoder for StructType {
     fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        if let (Some(layout), ffi::FfiValue::Aggregate(buffer)) = (&self.by_value, ffi_value) {
+            return layout.copy_out(None, buffer.as_ptr(), &self.type_name);
+        }
+
         let Some(struct_ptr) = ffi_value.as_non_null_ptr("Struct")? else {
             return Ok(value::Value::Null);
         };
@@ -274,6 +522,16 @@ impl FfiDecoder for StructType {
 }
 
 impl RawPtrCodec for StructType {
+    /// A by-value struct is stored inline, so `ptr` is the struct itself
+    /// rather than a pointer to it.
+    fn read_from_raw_ptr(&self, ptr: *const c_void, context: &str) -> anyhow::Result<value::Value> {
+        if let Some(layout) = &self.by_value {
+            return layout.copy_out(None, ptr, &self.type_name);
+        }
+        let inner_ptr = unsafe { *(ptr as *const *mut c_void) };
+        self.ptr_to_value(inner_ptr, context)
+    }
+
     fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
         null_guarded(ptr, |ptr| {
             let boxed =
@@ -282,11 +540,24 @@ impl RawPtrCodec for StructType {
         })
     }
 
-    fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
+    fn write_return_to_raw_ptr(
+        &self,
+        ret: *mut c_void,
+        value: &std::result::Result<value::Value, ()>,
+    ) {
+        if let Some(layout) = &self.by_value {
+            if let Err(e) = layout.write_to(ret, value.as_ref().ok()) {
+                NativeErrorReporter::global().report(&e.context("by-value struct return"));
+            }
+            return;
+        }
         write_return_object_ptr(ret, value, std::convert::identity);
     }
 
     fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        if let Some(layout) = &self.by_value {
+            return layout.write_to(ptr, Some(value));
+        }
         write_object_ptr(ptr, value, "Struct field write")
     }
 }
@@ -0,0 +1,206 @@
+//! `GBytes` codec.
+//!
+//! [`BytesType`] marshals `GBytes*` values as Node `Buffer`s instead of the
+//! opaque handles a [`BoxedType`](super::BoxedType) produces. An argument may
+//! be a `Uint8Array`, `Buffer`, `ArrayBuffer`, or a native `GBytes` handle;
+//! JavaScript memory is read once into a [`value::TypedBuffer`] and wrapped in
+//! a new `GBytes` without a second copy.
+//!
+//! A returned `GBytes` is copied into a fresh `Buffer` unless the descriptor
+//! sets `zeroCopy`, in which case the `Buffer` is an external view of the
+//! `GBytes` memory that keeps a reference alive until the garbage collector
+//! frees it. `GBytes` are immutable, so zero-copy is opt-in: JavaScript must
+//! not write to such a `Buffer`.
+//!
+//! [`bytes_to_js`] is bound to a live [`napi::Env`] and is excluded from
+//! coverage instrumentation.
+
+use std::sync::Arc;
+
+use anyhow::bail;
+use gtk4::glib::{
+    self,
+    translate::{FromGlibPtrFull as _, FromGlibPtrNone as _, ToGlibPtr as _},
+    value::ToValue as _,
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject};
+
+use super::prelude::*;
+use crate::error_reporter::NativeErrorReporter;
+use crate::ffi::{FfiStorage, FfiStorageKind};
+use crate::value::TypedBuffer;
+
+#[derive(Debug, Clone)]
+pub struct BytesType {
+    pub ownership: Ownership,
+    /// Whether a decoded `GBytes` is exposed to JavaScript as an external
+    /// `Buffer` over its own memory rather than copied.
+    pub zero_copy: bool,
+}
+
+/// The contents of a JavaScript byte array, shared with the `GBytes` that
+/// wraps them.
+struct SharedData(Arc<Vec<u8>>);
+
+impl AsRef<[u8]> for SharedData {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl BytesType {
+    #[must_use]
+    pub fn new(ownership: Ownership, zero_copy: bool) -> Self {
+        Self {
+            ownership,
+            zero_copy,
+        }
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let ownership = Ownership::from_js_value(obj, "bytes")?;
+        let zero_copy = obj
+            .get_named_property::<Option<bool>>("zeroCopy")?
+            .unwrap_or(false);
+
+        Ok(Self::new(ownership, zero_copy))
+    }
+
+    /// Resolves the `GBytes` an argument value stands for, or `None` for
+    /// `null` and `undefined`.
+    pub fn bytes_from_value(value: &value::Value) -> anyhow::Result<Option<glib::Bytes>> {
+        match value {
+            value::Value::TypedArray(TypedBuffer::U8(data)) => {
+                Ok(Some(glib::Bytes::from_owned(SharedData(Arc::clone(data)))))
+            }
+            value::Value::Bytes { bytes, .. } => Ok(Some(bytes.clone())),
+            value::Value::Object(handle) if !handle.ptr().is_null() => {
+                let ptr = handle.ptr().cast::<glib::ffi::GBytes>();
+                Ok(Some(unsafe { glib::Bytes::from_glib_none(ptr) }))
+            }
+            value::Value::Object(_) | value::Value::Null | value::Value::Undefined => Ok(None),
+            value::Value::TypedArray(buffer) => bail!(
+                "Expected a Uint8Array, Buffer, or ArrayBuffer for GBytes, got a {}",
+                buffer.js_type_name()
+            ),
+            _ => bail!("Expected a Uint8Array, Buffer, or ArrayBuffer for GBytes, got {value:?}"),
+        }
+    }
+
+    fn wrap(&self, bytes: glib::Bytes) -> value::Value {
+        value::Value::Bytes {
+            bytes,
+            zero_copy: self.zero_copy,
+        }
+    }
+}
+
+impl FfiEncoder for BytesType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        let Some(bytes) = Self::bytes_from_value(value)? else {
+            return Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()));
+        };
+
+        if self.ownership.is_full() {
+            let ptr: *mut glib::ffi::GBytes = bytes.to_glib_full();
+            return Ok(ffi::FfiValue::Ptr(ptr.cast()));
+        }
+
+        let ptr = bytes.as_ptr().cast();
+        Ok(ffi::FfiValue::Storage(FfiStorage::new(
+            ptr,
+            FfiStorageKind::GBytes(bytes),
+        )))
+    }
+
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn ref_for_transfer(&self, ptr: *mut c_void) -> anyhow::Result<*mut c_void> {
+        if self.ownership.is_full() && !ptr.is_null() {
+            return Ok(unsafe { glib::ffi::g_bytes_ref(ptr.cast()) }.cast());
+        }
+        Ok(ptr)
+    }
+}
+
+impl FfiDecoder for BytesType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let Some(ptr) = ffi_value.as_non_null_ptr("Bytes")? else {
+            return Ok(value::Value::Null);
+        };
+
+        let ptr = ptr.cast::<glib::ffi::GBytes>();
+        let bytes = if self.ownership.is_full() {
+            unsafe { glib::Bytes::from_glib_full(ptr) }
+        } else {
+            unsafe { glib::Bytes::from_glib_none(ptr) }
+        };
+        Ok(self.wrap(bytes))
+    }
+}
+
+impl RawPtrCodec for BytesType {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        null_guarded(ptr, |ptr| {
+            let bytes = unsafe { glib::Bytes::from_glib_none(ptr.cast::<glib::ffi::GBytes>()) };
+            Ok(self.wrap(bytes))
+        })
+    }
+
+    fn write_return_to_raw_ptr(
+        &self,
+        ret: *mut c_void,
+        value: &std::result::Result<value::Value, ()>,
+    ) {
+        let bytes = match value {
+            Ok(value) => Self::bytes_from_value(value).unwrap_or_else(|e| {
+                NativeErrorReporter::global().report(&e.context("GBytes return"));
+                None
+            }),
+            Err(()) => None,
+        };
+        let ptr: *mut glib::ffi::GBytes = bytes.to_glib_full();
+        unsafe { *ret.cast::<*mut c_void>() = ptr.cast() };
+    }
+
+    /// Stores a new reference in the field; the one it held before is not
+    /// released.
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        let bytes: *mut glib::ffi::GBytes = Self::bytes_from_value(value)?.to_glib_full();
+        unsafe { *ptr.cast::<*mut c_void>() = bytes.cast() };
+        Ok(())
+    }
+}
+
+impl GlibValueCodec for BytesType {
+    fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
+        Ok(Some(Self::bytes_from_value(val)?.to_value()))
+    }
+
+    fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        Ok(gvalue
+            .get::<Option<glib::Bytes>>()?
+            .map_or(value::Value::Null, |bytes| self.wrap(bytes)))
+    }
+}
+
+/// Builds a Node `Buffer` holding the contents of `bytes`, either copied or,
+/// with `zero_copy`, as an external view that owns a reference to `bytes`.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(crate) fn bytes_to_js(
+    env: &Env,
+    bytes: glib::Bytes,
+    zero_copy: bool,
+) -> napi::Result<Unknown<'_>> {
+    let buffer = if zero_copy && !bytes.is_empty() {
+        let (data, len) = (bytes.as_ptr_range().start.cast_mut(), bytes.len());
+        unsafe { BufferSlice::from_external(env, data, len, bytes, |_, bytes| drop(bytes)) }?
+    } else {
+        BufferSlice::copy_from(env, &*bytes)?
+    };
+    unsafe {
+        let raw = <&BufferSlice>::to_napi_value(env.raw(), &buffer)?;
+        Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+    }
+}
@@ -9,16 +9,19 @@ use napi::{Env, JsFunction, JsObject};
 
 use super::prelude::*;
 use crate::callback::ClosureGuard;
-use crate::dispatch::Mailbox;
+use crate::dispatch::{CallbackResult, Mailbox};
 use crate::error_reporter::NativeErrorReporter;
 use crate::ffi::FfiStorage;
 use crate::managed::{Boxed, NativeValue};
-use crate::types::Type;
+use crate::types::{GValueType, Type, VoidType};
 use crate::value::{Callback, JsRef};
 
 struct ClosureContext {
     js_func: Arc<JsRef<JsFunction>>,
     arg_types: Vec<Type>,
+    /// Type of the default returned when the callback yields no value, for
+    /// closures whose caller always expects one.
+    fallback_return: Option<glib::Type>,
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -27,6 +30,7 @@ impl ClosureContext {
         Self {
             js_func: callback.js_func.clone(),
             arg_types: callback_type.arg_types.clone(),
+            fallback_return: None,
         }
     }
 
@@ -39,62 +43,8 @@ impl ClosureContext {
             let _guard =
                 ClosureGuard::from_ptr(closure_holder_for_callback.load(Ordering::Acquire));
 
-            let args_values = match Self::convert_closure_args(args, &self.arg_types) {
-                Ok(v) => v,
-                Err(e) => {
-                    NativeErrorReporter::global()
-                        .report(&e.context("closure: failed to convert callback arguments"));
-                    return None;
-                }
-            };
-
-            let return_type_ref: Option<&Type> = Some(&return_type);
-
-            let ref_pointers: Vec<(*mut c_void, &Type)> = args
-                .iter()
-                .zip(self.arg_types.iter())
-                .filter_map(|(gval, ty)| {
-                    if let Type::Ref(ref_type) = ty {
-                        let ptr = unsafe {
-                            glib::gobject_ffi::g_value_get_pointer(
-                                gval.to_glib_none().0 as *const _,
-                            )
-                        };
-                        Some((ptr, &*ref_type.inner_type))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            let result = Mailbox::global().invoke_node_and_wait(&self.js_func, args_values, true);
-
-            match result {
-                Ok(value::Value::Array(arr)) if !ref_pointers.is_empty() => {
-                    for (i, (ptr, inner_type)) in ref_pointers.iter().enumerate() {
-                        if let Some(val) = arr.get(i + 1)
-                            && !(*ptr).is_null()
-                            && !matches!(val, value::Value::Null | value::Value::Undefined)
-                            && let Err(e) = inner_type.write_value_to_raw_ptr(*ptr, val)
-                        {
-                            NativeErrorReporter::global()
-                                .report(&e.context("closure: failed to write ref value"));
-                        }
-                    }
-                    let return_val = arr.into_iter().next().unwrap_or(value::Value::Undefined);
-                    value::Value::into_glib_value_with_default(return_val, return_type_ref)
-                }
-                Ok(value) => value::Value::into_glib_value_with_default(value, return_type_ref),
-                Err(ref e) => {
-                    NativeErrorReporter::global().report(&anyhow::anyhow!(
-                        "closure callback: JS callback error: {e:#}"
-                    ));
-                    value::Value::into_glib_value_with_default(
-                        value::Value::Undefined,
-                        return_type_ref,
-                    )
-                }
-            }
+            self.invoke(args, &return_type)
+                .or_else(|| self.fallback_return.map(glib::Value::from_type))
         });
 
         let closure_ptr: *mut gobject_ffi::GClosure = closure.to_glib_full();
@@ -103,6 +53,64 @@ impl ClosureContext {
         unsafe { glib::Closure::from_glib_full(closure_ptr) }
     }
 
+    fn invoke(&self, args: &[glib::Value], return_type: &Type) -> Option<glib::Value> {
+        let args_values = match Self::convert_closure_args(args, &self.arg_types) {
+            Ok(v) => v,
+            Err(e) => {
+                NativeErrorReporter::global()
+                    .report(&e.context("closure: failed to convert callback arguments"));
+                return None;
+            }
+        };
+
+        let return_type_ref: Option<&Type> = Some(return_type);
+
+        let ref_pointers: Vec<(*mut c_void, &Type)> = args
+            .iter()
+            .zip(self.arg_types.iter())
+            .filter_map(|(gval, ty)| {
+                if let Type::Ref(ref_type) = ty {
+                    let ptr = unsafe {
+                        glib::gobject_ffi::g_value_get_pointer(gval.to_glib_none().0 as *const _)
+                    };
+                    Some((ptr, &*ref_type.inner_type))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let result = Mailbox::global().invoke_node_and_wait(
+            &self.js_func,
+            args_values,
+            CallbackResult::Untyped,
+        );
+
+        match result {
+            Ok(value::Value::Array(arr)) if !ref_pointers.is_empty() => {
+                for (i, (ptr, inner_type)) in ref_pointers.iter().enumerate() {
+                    if let Some(val) = arr.get(i + 1)
+                        && !(*ptr).is_null()
+                        && !matches!(val, value::Value::Null | value::Value::Undefined)
+                        && let Err(e) = inner_type.write_value_to_raw_ptr(*ptr, val)
+                    {
+                        NativeErrorReporter::global()
+                            .report(&e.context("closure: failed to write ref value"));
+                    }
+                }
+                let return_val = arr.into_iter().next().unwrap_or(value::Value::Undefined);
+                value::Value::into_glib_value_with_default(return_val, return_type_ref)
+            }
+            Ok(value) => value::Value::into_glib_value_with_default(value, return_type_ref),
+            Err(ref e) => {
+                NativeErrorReporter::global().report(&anyhow::anyhow!(
+                    "closure callback: JS callback error: {e:#}"
+                ));
+                value::Value::into_glib_value_with_default(value::Value::Undefined, return_type_ref)
+            }
+        }
+    }
+
     fn convert_closure_args(
         args: &[glib::Value],
         arg_types: &[Type],
@@ -169,6 +177,38 @@ impl CallbackType {
     }
 }
 
+/// Builds a closure for a signal whose parameter and return `GType`s are only
+/// known at run time, as reported by `g_signal_query`.
+///
+/// `param_count` includes the emitting instance. Each argument is decoded by
+/// the type its `GValue` holds and the JS result is stored as `return_type`.
+/// When the callback fails or returns nothing, the closure returns that
+/// type's default value, since a signal with a return type always expects one.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(crate) fn signal_closure(
+    callback: &Callback,
+    param_count: usize,
+    return_type: glib::Type,
+) -> glib::Closure {
+    let untyped = || Type::GValue(GValueType::new(Ownership::Borrowed, None, None));
+    let returns_value = return_type != glib::Type::UNIT;
+    let ctx = ClosureContext {
+        js_func: callback.js_func.clone(),
+        arg_types: (0..param_count).map(|_| untyped()).collect(),
+        fallback_return: returns_value.then_some(return_type),
+    };
+    let return_type = if returns_value {
+        Type::GValue(GValueType::new(
+            Ownership::Borrowed,
+            None,
+            Some(return_type.name().to_owned()),
+        ))
+    } else {
+        Type::Void(VoidType)
+    };
+    ctx.build_closure_with_guard(Box::new(return_type))
+}
+
 impl FfiEncoder for CallbackType {
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn encode(&self, val: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
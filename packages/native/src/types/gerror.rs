@@ -0,0 +1,92 @@
+//! `GError**` out-parameter codec.
+//!
+//! A [`GErrorType`] argument occupies the trailing `GError**` slot of a
+//! throwing callable. It encodes to a pointer to a null `GError*` that the
+//! callee may fill in; after the call, [`GErrorType::take_error`] reads the
+//! slot back and, when it is set, takes ownership of the `GError` and returns
+//! its contents as a [`GErrorDetails`] so the call can fail with it.
+
+use anyhow::bail;
+use gtk4::glib::{
+    self,
+    translate::{FromGlibPtrFull as _, IntoGlib as _},
+};
+
+use super::prelude::*;
+use crate::ffi::{FfiStorage, FfiStorageKind};
+
+#[derive(Debug, Clone, Copy)]
+pub struct GErrorType;
+
+/// A `GError` reported by a native callable, decoded on the `GLib` thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GErrorDetails {
+    /// The error domain, as the string its quark was registered from.
+    pub domain: String,
+    /// The error domain quark.
+    pub domain_quark: u32,
+    /// The domain-specific error code.
+    pub code: i32,
+    pub message: String,
+}
+
+impl From<glib::Error> for GErrorDetails {
+    fn from(error: glib::Error) -> Self {
+        let domain = error.domain();
+        Self {
+            domain: domain.as_str().to_owned(),
+            domain_quark: domain.into_glib(),
+            code: error.code(),
+            message: error.message().to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for GErrorDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}, {})", self.message, self.domain, self.code)
+    }
+}
+
+impl std::error::Error for GErrorDetails {}
+
+impl GErrorType {
+    /// Takes the `GError` the callee stored in an encoded slot, if any.
+    ///
+    /// Resets the slot to null so the error is freed exactly once, by the
+    /// returned [`glib::Error`].
+    pub fn take_error(ffi_value: &ffi::FfiValue) -> anyhow::Result<Option<glib::Error>> {
+        let ffi::FfiValue::Storage(storage) = ffi_value else {
+            bail!("Expected a Storage ffi::FfiValue for GError, got {ffi_value:?}");
+        };
+        let slot = storage.ptr().cast::<*mut glib::ffi::GError>();
+        let error = unsafe { std::ptr::replace(slot, std::ptr::null_mut()) };
+        if error.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(unsafe { glib::Error::from_glib_full(error) }))
+    }
+}
+
+impl FfiEncoder for GErrorType {
+    fn encode(&self, _value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        let mut slot: Box<*mut c_void> = Box::new(std::ptr::null_mut());
+        let ptr = (&raw mut *slot).cast::<c_void>();
+        Ok(ffi::FfiValue::Storage(FfiStorage::new(
+            ptr,
+            FfiStorageKind::PtrStorage(slot),
+        )))
+    }
+
+    arg_only_call_cif!("GError types");
+}
+
+impl FfiDecoder for GErrorType {
+    fn decode(&self, _ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        Ok(value::Value::Null)
+    }
+}
+
+impl RawPtrCodec for GErrorType {}
+
+impl GlibValueCodec for GErrorType {}
@@ -0,0 +1,573 @@
+//! `GValue` codec.
+//!
+//! [`GValueType`] passes a `GValue*` to native code and decodes the `GValue`s
+//! it returns or fills in, so a call can take or produce a `GValue` without a
+//! round trip through JavaScript wrappers.
+//!
+//! An argument is converted into a `GValue` of the descriptor's `typeName`
+//! when it has one, otherwise by the [`GlibValueCodec`] of its `valueType`,
+//! otherwise by the JavaScript value's own type (a number becomes a
+//! `gdouble`). A native handle with neither is taken to be a `GValue*`
+//! already and is passed through unchanged. The `GValue` lives in storage the
+//! call owns and is unset when the call completes, unless ownership is
+//! transferred to the callee, in which case it is heap allocated for
+//! `g_boxed_free`.
+//!
+//! Decoding inspects `G_VALUE_TYPE` at run time, or uses the `valueType`
+//! codec when the descriptor has one. Wrapped in a `Ref`, a `GValue` is a
+//! caller-allocated out-parameter, and as the item type of an array it lays
+//! the `GValue`s out back to back (`const GValue *param_values`). Inside a
+//! record a `GValue` is embedded inline, like `GParameter`'s `value`.
+
+use std::ffi::{CStr, CString, c_long, c_ulong};
+
+use anyhow::{anyhow, bail};
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{
+        FromGlib as _, IntoGlib as _, ToGlibPtr as _, ToGlibPtrMut as _, Uninitialized as _,
+    },
+    types::StaticType as _,
+    value::ToValue as _,
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject, ValueType};
+
+use super::prelude::*;
+use super::{GObjectType, Type};
+use crate::ffi::FfiStorage;
+use crate::managed::{Boxed, Fundamental, NativeHandle, NativeValue, RefFn, UnrefFn};
+
+#[derive(Debug, Clone)]
+pub struct GValueType {
+    pub ownership: Ownership,
+    /// The type whose [`GlibValueCodec`] converts the value held.
+    pub value_type: Option<Box<Type>>,
+    /// The name of the `GType` a `GValue` argument is initialized to.
+    pub type_name: Option<String>,
+}
+
+impl GValueType {
+    #[must_use]
+    pub fn new(ownership: Ownership, value_type: Option<Type>, type_name: Option<String>) -> Self {
+        Self {
+            ownership,
+            value_type: value_type.map(Box::new),
+            type_name,
+        }
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let ownership = Ownership::from_js_value(obj, "gvalue")?;
+
+        let value_type_prop: Unknown<'_> = obj.get_named_property("valueType")?;
+        let value_type = match value_type_prop.get_type()? {
+            ValueType::Undefined | ValueType::Null => None,
+            _ => Some(Type::from_js_value(env, value_type_prop)?),
+        };
+        let type_name = obj.get_named_property::<Option<String>>("typeName")?;
+
+        Ok(Self::new(ownership, value_type, type_name))
+    }
+
+    /// The `GType` named by `type_name`, which must already be registered.
+    fn gtype(&self) -> anyhow::Result<Option<glib::Type>> {
+        self.type_name
+            .as_deref()
+            .map(|name| {
+                glib::Type::from_name(name).ok_or_else(|| anyhow!("Unknown GType '{name}'"))
+            })
+            .transpose()
+    }
+
+    /// Converts `value` into a `GValue`, or `None` for `null` and `undefined`
+    /// when nothing names the type to initialize it to.
+    pub fn to_gvalue(&self, value: &value::Value) -> anyhow::Result<Option<glib::Value>> {
+        if let Some(gtype) = self.gtype()? {
+            return value_to_gvalue(value, gtype).map(Some);
+        }
+        if let Some(ty) = &self.value_type
+            && let Some(gvalue) = ty.to_glib_value(value)?
+        {
+            return Ok(Some(gvalue));
+        }
+        match value {
+            value::Value::Null | value::Value::Undefined => Ok(None),
+            value::Value::Object(handle)
+                if self.value_type.is_none() && !handle.ptr().is_null() =>
+            {
+                Ok(Some(unsafe { gvalue_at(handle.ptr()) }.clone()))
+            }
+            _ => value.clone().to_glib_value().map(Some),
+        }
+    }
+
+    /// Like [`Self::to_gvalue`], but an untyped `null` becomes an
+    /// uninitialized `GValue` (`G_VALUE_INIT`) for native code to fill in.
+    fn new_gvalue(&self, value: &value::Value) -> anyhow::Result<glib::Value> {
+        Ok(self
+            .to_gvalue(value)?
+            .unwrap_or_else(|| unsafe { glib::Value::uninitialized() }))
+    }
+
+    /// Decodes the contents of `gvalue`. An uninitialized `GValue` decodes to
+    /// `undefined`.
+    pub fn read(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        if !gvalue.type_().is_valid() {
+            return Ok(value::Value::Undefined);
+        }
+        self.value_type
+            .as_deref()
+            .map_or_else(|| gvalue_to_value(gvalue), |ty| ty.from_glib_value(gvalue))
+    }
+
+    /// Decodes the `GValue` at `ptr`.
+    pub fn read_inline(&self, ptr: *const c_void) -> anyhow::Result<value::Value> {
+        self.read(unsafe { gvalue_at(ptr.cast()) })
+    }
+
+    /// Stores `value` in the `GValue` at `ptr`, unsetting the value it held.
+    /// `null` without a type leaves it unset.
+    pub fn write_inline(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        let gvalue = self.new_gvalue(value)?;
+        let slot = ptr.cast::<gobject_ffi::GValue>();
+        unsafe {
+            if (*slot).g_type != gobject_ffi::G_TYPE_INVALID {
+                gobject_ffi::g_value_unset(slot);
+            }
+            slot.write(gvalue.into_raw());
+        }
+        Ok(())
+    }
+
+    /// Builds the call-owned `GValue` a `Ref` out-parameter points at,
+    /// initialized from the `Ref`'s current value.
+    pub fn alloc(&self, value: &value::Value) -> anyhow::Result<FfiStorage> {
+        Ok(FfiStorage::from(vec![self.new_gvalue(value)?]))
+    }
+
+    /// Builds the call-owned, contiguous `GValue`s of an array.
+    pub(super) fn alloc_array(&self, items: &[value::Value]) -> anyhow::Result<FfiStorage> {
+        let gvalues = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                self.new_gvalue(item)
+                    .map_err(|e| e.context(format!("array element {i}")))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(FfiStorage::from(gvalues))
+    }
+
+    /// Moves `value` into a `g_malloc`ed `GValue` that its receiver releases
+    /// with `g_boxed_free`, or returns null for an untyped `null`.
+    pub fn to_owned_ptr(&self, value: &value::Value) -> anyhow::Result<*mut c_void> {
+        let Some(gvalue) = self.to_gvalue(value)? else {
+            return Ok(std::ptr::null_mut());
+        };
+        Ok(into_heap(gvalue))
+    }
+
+    fn is_untyped(&self) -> bool {
+        self.value_type.is_none() && self.type_name.is_none()
+    }
+}
+
+/// Borrows the `GValue` at `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must point to a `GValue` that is initialized or zeroed, and that
+/// outlives the returned reference.
+unsafe fn gvalue_at<'a>(ptr: *const c_void) -> &'a glib::Value {
+    unsafe { &*ptr.cast::<glib::Value>() }
+}
+
+fn into_heap(gvalue: glib::Value) -> *mut c_void {
+    unsafe {
+        let ptr =
+            glib::ffi::g_malloc0(size_of::<gobject_ffi::GValue>()).cast::<gobject_ffi::GValue>();
+        ptr.write(gvalue.into_raw());
+        ptr.cast()
+    }
+}
+
+/// The `GType` of a boxed `GValue`, `G_TYPE_VALUE`.
+fn value_gtype() -> glib::ffi::GType {
+    unsafe { gobject_ffi::g_value_get_type() }
+}
+
+unsafe extern "C" fn param_spec_ref(ptr: *mut c_void) -> *mut c_void {
+    unsafe { gobject_ffi::g_param_spec_ref(ptr.cast()).cast() }
+}
+
+unsafe extern "C" fn param_spec_unref(ptr: *mut c_void) {
+    unsafe { gobject_ffi::g_param_spec_unref(ptr.cast()) }
+}
+
+/// Converts the contents of `gvalue` by the `GType` it holds at run time.
+///
+/// Numbers of every width, enums and flags decode to numbers, strings and
+/// `GStrv`s to strings, and `GVariant`s to [`value::Value::Variant`]. Objects,
+/// param specs, other boxed types and raw pointers decode to handles. An
+/// uninitialized `GValue` decodes to `undefined`.
+pub(crate) fn gvalue_to_value(gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+    let gtype = gvalue.type_();
+    let raw: *const gobject_ffi::GValue = gvalue.to_glib_none().0;
+    let fundamental = unsafe { gobject_ffi::g_type_fundamental(gtype.into_glib()) };
+
+    let number = unsafe {
+        match fundamental {
+            gobject_ffi::G_TYPE_INVALID => return Ok(value::Value::Undefined),
+            gobject_ffi::G_TYPE_BOOLEAN => {
+                return Ok(value::Value::Boolean(
+                    gobject_ffi::g_value_get_boolean(raw) != glib::ffi::GFALSE,
+                ));
+            }
+            gobject_ffi::G_TYPE_CHAR => f64::from(gobject_ffi::g_value_get_schar(raw)),
+            gobject_ffi::G_TYPE_UCHAR => f64::from(gobject_ffi::g_value_get_uchar(raw)),
+            gobject_ffi::G_TYPE_INT => f64::from(gobject_ffi::g_value_get_int(raw)),
+            gobject_ffi::G_TYPE_UINT => f64::from(gobject_ffi::g_value_get_uint(raw)),
+            gobject_ffi::G_TYPE_LONG => gobject_ffi::g_value_get_long(raw) as f64,
+            gobject_ffi::G_TYPE_ULONG => gobject_ffi::g_value_get_ulong(raw) as f64,
+            gobject_ffi::G_TYPE_INT64 => gobject_ffi::g_value_get_int64(raw) as f64,
+            gobject_ffi::G_TYPE_UINT64 => gobject_ffi::g_value_get_uint64(raw) as f64,
+            gobject_ffi::G_TYPE_FLOAT => f64::from(gobject_ffi::g_value_get_float(raw)),
+            gobject_ffi::G_TYPE_DOUBLE => gobject_ffi::g_value_get_double(raw),
+            gobject_ffi::G_TYPE_ENUM => f64::from(gobject_ffi::g_value_get_enum(raw)),
+            gobject_ffi::G_TYPE_FLAGS => f64::from(gobject_ffi::g_value_get_flags(raw)),
+            gobject_ffi::G_TYPE_STRING => {
+                let ptr = gobject_ffi::g_value_get_string(raw);
+                return Ok(if ptr.is_null() {
+                    value::Value::Null
+                } else {
+                    value::Value::String(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+                });
+            }
+            gobject_ffi::G_TYPE_POINTER => {
+                let ptr = gobject_ffi::g_value_get_pointer(raw);
+                return Ok(if ptr.is_null() {
+                    value::Value::Null
+                } else {
+                    value::Value::Object(NativeHandle::borrowed(ptr))
+                });
+            }
+            gobject_ffi::G_TYPE_VARIANT => {
+                return Ok(gvalue
+                    .get::<Option<glib::Variant>>()?
+                    .map_or(value::Value::Null, value::Value::Variant));
+            }
+            gobject_ffi::G_TYPE_PARAM => {
+                let ptr = gobject_ffi::g_value_get_param(raw);
+                return Ok(if ptr.is_null() {
+                    value::Value::Null
+                } else {
+                    let pspec = Fundamental::from_glib_none(
+                        ptr.cast(),
+                        Some(param_spec_ref as RefFn),
+                        Some(param_spec_unref as UnrefFn),
+                    );
+                    value::Value::Object(NativeValue::Fundamental(pspec).into())
+                });
+            }
+            gobject_ffi::G_TYPE_OBJECT | gobject_ffi::G_TYPE_INTERFACE => {
+                return GObjectType {
+                    ownership: Ownership::Borrowed,
+                }
+                .from_glib_value(gvalue);
+            }
+            gobject_ffi::G_TYPE_BOXED => return boxed_to_value(gvalue, gtype),
+            _ => bail!("Cannot convert a GValue holding {}", gtype.name()),
+        }
+    };
+    Ok(value::Value::Number(number))
+}
+
+fn boxed_to_value(gvalue: &glib::Value, gtype: glib::Type) -> anyhow::Result<value::Value> {
+    let ptr = unsafe { gobject_ffi::g_value_get_boxed(gvalue.to_glib_none().0) };
+    if ptr.is_null() {
+        return Ok(value::Value::Null);
+    }
+    if gtype.into_glib() == value_gtype() {
+        return gvalue_to_value(unsafe { gvalue_at(ptr) });
+    }
+    if gtype == glib::StrV::static_type() {
+        let strings = gvalue.get::<Vec<String>>()?;
+        return Ok(value::Value::Array(
+            strings.into_iter().map(value::Value::String).collect(),
+        ));
+    }
+    let boxed = Boxed::from_glib_none(Some(gtype), ptr)?;
+    Ok(value::Value::Object(NativeValue::Boxed(boxed).into()))
+}
+
+/// Converts `value` into a `GValue` of type `gtype`, checking that the value
+/// fits it. `null` and `undefined` leave the type's default value.
+pub(crate) fn value_to_gvalue(
+    value: &value::Value,
+    gtype: glib::Type,
+) -> anyhow::Result<glib::Value> {
+    if unsafe { gobject_ffi::g_type_check_is_value_type(gtype.into_glib()) } == glib::ffi::GFALSE {
+        bail!("{} cannot be stored in a GValue", gtype.name());
+    }
+    if matches!(value, value::Value::Null | value::Value::Undefined) {
+        return Ok(glib::Value::from_type(gtype));
+    }
+    if gtype == glib::StrV::static_type() {
+        let value::Value::Array(items) = value else {
+            bail!("Expected an Array of strings for a GStrv GValue, got {value:?}");
+        };
+        let strings = items
+            .iter()
+            .map(|item| match item {
+                value::Value::String(s) => Ok(s.clone()),
+                _ => bail!("Expected a String in a GStrv GValue, got {item:?}"),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        return Ok(strings.to_value());
+    }
+
+    let mut gvalue = glib::Value::from_type(gtype);
+    let raw: *mut gobject_ffi::GValue = gvalue.to_glib_none_mut().0;
+    let fundamental = unsafe { gobject_ffi::g_type_fundamental(gtype.into_glib()) };
+    unsafe {
+        match (fundamental, value) {
+            (gobject_ffi::G_TYPE_BOOLEAN, value::Value::Boolean(b)) => {
+                gobject_ffi::g_value_set_boolean(raw, (*b).into_glib());
+            }
+            (gobject_ffi::G_TYPE_CHAR, _) => {
+                gobject_ffi::g_value_set_schar(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_UCHAR, _) => {
+                gobject_ffi::g_value_set_uchar(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_INT, _) => {
+                gobject_ffi::g_value_set_int(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_UINT, _) => {
+                gobject_ffi::g_value_set_uint(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_LONG, _) => {
+                gobject_ffi::g_value_set_long(raw, integer::<c_long>(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_ULONG, _) => {
+                gobject_ffi::g_value_set_ulong(raw, integer::<c_ulong>(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_INT64, _) => {
+                gobject_ffi::g_value_set_int64(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_UINT64, _) => {
+                gobject_ffi::g_value_set_uint64(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_ENUM, _) => {
+                gobject_ffi::g_value_set_enum(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_FLAGS, _) => {
+                gobject_ffi::g_value_set_flags(raw, integer(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_FLOAT, _) => {
+                gobject_ffi::g_value_set_float(raw, float(value, gtype)? as f32);
+            }
+            (gobject_ffi::G_TYPE_DOUBLE, _) => {
+                gobject_ffi::g_value_set_double(raw, float(value, gtype)?);
+            }
+            (gobject_ffi::G_TYPE_STRING, value::Value::String(s)) => {
+                let s = CString::new(s.as_str())?;
+                gobject_ffi::g_value_set_string(raw, s.as_ptr());
+            }
+            (gobject_ffi::G_TYPE_POINTER, value::Value::Object(handle)) => {
+                gobject_ffi::g_value_set_pointer(raw, handle.ptr());
+            }
+            (gobject_ffi::G_TYPE_VARIANT, value::Value::Variant(variant)) => {
+                gobject_ffi::g_value_set_variant(raw, variant.as_ptr());
+            }
+            (gobject_ffi::G_TYPE_VARIANT, value::Value::Object(handle)) => {
+                gobject_ffi::g_value_set_variant(raw, handle.ptr().cast());
+            }
+            (gobject_ffi::G_TYPE_PARAM, value::Value::Object(handle)) => {
+                gobject_ffi::g_value_set_param(raw, handle.ptr().cast());
+            }
+            (
+                gobject_ffi::G_TYPE_OBJECT | gobject_ffi::G_TYPE_INTERFACE,
+                value::Value::Object(handle),
+            ) => {
+                let ptr = handle.ptr();
+                if !ptr.is_null()
+                    && gobject_ffi::g_type_check_instance_is_a(ptr.cast(), gtype.into_glib())
+                        == glib::ffi::GFALSE
+                {
+                    let actual = glib::Type::from_glib(
+                        (*(*ptr.cast::<gobject_ffi::GTypeInstance>()).g_class).g_type,
+                    );
+                    bail!(
+                        "A {} cannot be stored in a GValue of type {}",
+                        actual.name(),
+                        gtype.name()
+                    );
+                }
+                gobject_ffi::g_value_set_object(raw, ptr.cast());
+            }
+            (gobject_ffi::G_TYPE_BOXED, value::Value::Object(handle)) => {
+                gobject_ffi::g_value_set_boxed(raw, handle.ptr());
+            }
+            (gobject_ffi::G_TYPE_BOXED, value::Value::Bytes { bytes, .. })
+                if gtype == glib::Bytes::static_type() =>
+            {
+                gobject_ffi::g_value_set_boxed(raw, bytes.as_ptr().cast());
+            }
+            _ => bail!(
+                "Cannot store {value:?} in a GValue of type {}",
+                gtype.name()
+            ),
+        }
+    }
+    Ok(gvalue)
+}
+
+/// Converts `value` into a `GValue` of type `gtype`, building it through
+/// `value_type` when one is given and transforming the result to `gtype`.
+///
+/// Used where the target `GType` comes from introspecting a property or
+/// signal, and a descriptor only refines how the JS value is built.
+pub(crate) fn value_to_gvalue_as(
+    value: &value::Value,
+    value_type: Option<&Type>,
+    gtype: glib::Type,
+) -> anyhow::Result<glib::Value> {
+    let Some(gvalue) = value_type
+        .map(|ty| ty.to_glib_value(value))
+        .transpose()?
+        .flatten()
+    else {
+        return value_to_gvalue(value, gtype);
+    };
+
+    let mut converted = glib::Value::from_type(gtype);
+    let transformed = unsafe {
+        gobject_ffi::g_value_transform(gvalue.to_glib_none().0, converted.to_glib_none_mut().0)
+    };
+    if transformed == glib::ffi::GFALSE {
+        bail!(
+            "A {} cannot be stored in a GValue of type {}",
+            gvalue.type_().name(),
+            gtype.name()
+        );
+    }
+    Ok(converted)
+}
+
+/// The integer `value` holds, converted to the C type of a `GValue` of type
+/// `gtype` and range-checked against it.
+fn integer<T: TryFrom<i128>>(value: &value::Value, gtype: glib::Type) -> anyhow::Result<T> {
+    let n = match value {
+        value::Value::Number(n) if n.is_finite() && n.fract() == 0.0 => *n as i128,
+        value::Value::BigInt(n) => *n,
+        _ => bail!(
+            "Expected an integer for a GValue of type {}, got {value:?}",
+            gtype.name()
+        ),
+    };
+    T::try_from(n).map_err(|_| {
+        anyhow!(
+            "Value {n} is out of range for a GValue of type {}",
+            gtype.name()
+        )
+    })
+}
+
+fn float(value: &value::Value, gtype: glib::Type) -> anyhow::Result<f64> {
+    match value {
+        value::Value::Number(n) => Ok(*n),
+        value::Value::BigInt(n) => Ok(*n as f64),
+        _ => bail!(
+            "Expected a Number for a GValue of type {}, got {value:?}",
+            gtype.name()
+        ),
+    }
+}
+
+impl FfiEncoder for GValueType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        if self.is_untyped()
+            && let value::Value::Object(handle) = value
+        {
+            return Ok(ffi::FfiValue::Ptr(self.ref_for_transfer(handle.ptr())?));
+        }
+        let Some(gvalue) = self.to_gvalue(value)? else {
+            return Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()));
+        };
+        if self.ownership.is_full() {
+            return Ok(ffi::FfiValue::Ptr(into_heap(gvalue)));
+        }
+        Ok(ffi::FfiValue::Storage(FfiStorage::from(vec![gvalue])))
+    }
+
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn ref_for_transfer(&self, ptr: *mut c_void) -> anyhow::Result<*mut c_void> {
+        if self.ownership.is_full() && !ptr.is_null() {
+            return Ok(unsafe { gobject_ffi::g_boxed_copy(value_gtype(), ptr) });
+        }
+        Ok(ptr)
+    }
+}
+
+impl FfiDecoder for GValueType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let Some(ptr) = ffi_value.as_non_null_ptr("GValue")? else {
+            return Ok(value::Value::Null);
+        };
+
+        let decoded = self.read_inline(ptr);
+        if self.ownership.is_full() {
+            unsafe { gobject_ffi::g_boxed_free(value_gtype(), ptr) };
+        }
+        decoded
+    }
+}
+
+impl RawPtrCodec for GValueType {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        null_guarded(ptr, |ptr| self.read_inline(ptr))
+    }
+
+    fn write_return_to_raw_ptr(
+        &self,
+        ret: *mut c_void,
+        value: &std::result::Result<value::Value, ()>,
+    ) {
+        let ptr = match value {
+            Ok(value) => self.to_owned_ptr(value).unwrap_or_else(|e| {
+                crate::error_reporter::NativeErrorReporter::global()
+                    .report(&e.context("GValue return"));
+                std::ptr::null_mut()
+            }),
+            Err(()) => std::ptr::null_mut(),
+        };
+        unsafe { *ret.cast::<*mut c_void>() = ptr };
+    }
+
+    /// Stores a pointer to a `g_malloc`ed `GValue` in the slot, which the
+    /// slot's owner must release with `g_boxed_free`.
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        let gvalue_ptr = self.to_owned_ptr(value)?;
+        unsafe { *ptr.cast::<*mut c_void>() = gvalue_ptr };
+        Ok(())
+    }
+}
+
+impl GlibValueCodec for GValueType {
+    fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
+        self.to_gvalue(val)
+    }
+
+    /// Decodes `gvalue` itself, or the `GValue` it holds when it is a boxed
+    /// `GValue`.
+    fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        if gvalue.type_().into_glib() == value_gtype() {
+            let ptr = unsafe { gobject_ffi::g_value_get_boxed(gvalue.to_glib_none().0) };
+            return null_guarded(ptr, |ptr| self.read_inline(ptr));
+        }
+        self.read(gvalue)
+    }
+}
@@ -7,13 +7,14 @@ use napi::{Env, JsObject};
 
 use super::prelude::*;
 use crate::ffi::{FfiStorage, FfiStorageKind, HashTableData};
-use crate::types::Type;
 use crate::types::array::ArrayKind;
+use crate::types::{BigIntKind, Type};
 
 #[derive(Clone, Debug)]
 pub enum HashTableEntryEncoder {
     String,
     Integer,
+    BigInt(BigIntKind),
     Boolean,
     Float,
     NativeHandle,
@@ -32,6 +33,7 @@ impl HashTableEntryEncoder {
         match ty {
             Type::String(_) => Some(Self::String),
             Type::Integer(_) => Some(Self::Integer),
+            Type::BigInt(kind) => Some(Self::BigInt(*kind)),
             Type::Boolean(_) => Some(Self::Boolean),
             Type::Float(_) => Some(Self::Float),
             Type::GObject(_) | Type::Boxed(_) | Type::Struct(_) | Type::Fundamental(_) => {
@@ -48,9 +50,11 @@ impl HashTableEntryEncoder {
         match self {
             Self::String => Some(glib::ffi::g_str_hash),
             Self::Float => Some(glib::ffi::g_double_hash),
-            Self::Integer | Self::Boolean | Self::NativeHandle | Self::PtrArray(_) => {
-                Some(glib::ffi::g_direct_hash)
-            }
+            Self::Integer
+            | Self::BigInt(_)
+            | Self::Boolean
+            | Self::NativeHandle
+            | Self::PtrArray(_) => Some(glib::ffi::g_direct_hash),
         }
     }
 
@@ -58,16 +62,18 @@ impl HashTableEntryEncoder {
         match self {
             Self::String => Some(glib::ffi::g_str_equal),
             Self::Float => Some(glib::ffi::g_double_equal),
-            Self::Integer | Self::Boolean | Self::NativeHandle | Self::PtrArray(_) => {
-                Some(glib::ffi::g_direct_equal)
-            }
+            Self::Integer
+            | Self::BigInt(_)
+            | Self::Boolean
+            | Self::NativeHandle
+            | Self::PtrArray(_) => Some(glib::ffi::g_direct_equal),
         }
     }
 
     pub fn free_func(&self) -> glib::ffi::GDestroyNotify {
         match self {
             Self::String | Self::Float => Some(glib::ffi::g_free),
-            Self::Integer | Self::Boolean | Self::NativeHandle => None,
+            Self::Integer | Self::BigInt(_) | Self::Boolean | Self::NativeHandle => None,
             Self::PtrArray(_) => Some(g_ptr_array_unref_wrapper),
         }
     }
@@ -86,6 +92,7 @@ impl HashTableEntryEncoder {
                 value::Value::Number(n) => Ok(*n as isize as *mut c_void),
                 _ => bail!("Expected number in GHashTable, got {val:?}"),
             },
+            Self::BigInt(kind) => kind.checked_to_ptr(kind.value_to_i128(val)?),
             Self::Boolean => match val {
                 value::Value::Boolean(b) => Ok(*b as isize as *mut c_void),
                 _ => bail!("Expected boolean in GHashTable, got {val:?}"),
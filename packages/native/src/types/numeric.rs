@@ -10,7 +10,7 @@ use napi::{Env, JsObject};
 
 use super::prelude::*;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum IntegerKind {
     U8,
@@ -54,6 +54,31 @@ macro_rules! impl_integer_kind_dispatch {
                 }
             }
 
+            /// Converts a `BigInt` straight to this kind, without a lossy
+            /// round trip through `f64`.
+            pub fn bigint_to_ffi_value(self, value: i128) -> anyhow::Result<ffi::FfiValue> {
+                match self {
+                    $(Self::$variant => <$ty>::try_from(value)
+                        .map(ffi::FfiValue::$variant)
+                        .map_err(|_| anyhow::anyhow!(
+                            "Value {value} is out of range for {} [{}, {}]",
+                            stringify!($ty),
+                            <$ty>::MIN,
+                            <$ty>::MAX
+                        ))),+
+                }
+            }
+
+            pub fn write_bigint_ptr(self, ptr: *mut u8, value: i128) -> anyhow::Result<()> {
+                self.bigint_to_ffi_value(value)?;
+                unsafe {
+                    match self {
+                        $(Self::$variant => ptr.cast::<$ty>().write_unaligned(value as $ty)),+
+                    }
+                }
+                Ok(())
+            }
+
             pub fn read_slice(self, ptr: *const u8, length: usize) -> Vec<f64> {
                 unsafe {
                     match self {
@@ -119,6 +144,7 @@ macro_rules! impl_numeric_codecs {
             ) -> anyhow::Result<ffi::FfiValue> {
                 let number = match value {
                     value::Value::Number(n) => *n,
+                    value::Value::BigInt(n) => return self.bigint_to_ffi_value(*n),
                     value::Value::Object(handle) => handle.ptr_as_usize() as f64,
                     value::Value::Null | value::Value::Undefined if optional => 0.0,
                     _ => bail!("Expected a Number for {} type, got {value:?}", $label),
@@ -166,6 +192,14 @@ macro_rules! impl_numeric_codecs {
             fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
                 let n = match value {
                     Ok(value::Value::Number(n)) => *n,
+                    Ok(value::Value::BigInt(n)) => {
+                        if let Err(e) = self.write_bigint_ptr(ret as *mut u8, *n) {
+                            crate::error_reporter::NativeErrorReporter::global()
+                                .report(&e.context(concat!($label, " return")));
+                            self.write_ptr(ret as *mut u8, 0.0);
+                        }
+                        return;
+                    }
                     _ => 0.0,
                 };
                 self.write_ptr(ret as *mut u8, n);
@@ -217,7 +251,7 @@ impl IntegerKind {
         }
     }
 
-    fn check_range(self, value: f64) -> anyhow::Result<()> {
+    pub(super) fn check_range(self, value: f64) -> anyhow::Result<()> {
         let (min, max, name) = match self {
             Self::I8 => (i8::MIN as f64, i8::MAX as f64, "i8"),
             Self::U8 => (0.0, u8::MAX as f64, "u8"),
@@ -324,8 +358,259 @@ impl From<IntegerKind> for libffi::Type {
     }
 }
 
+/// A 64-bit integer marshaled to and from a JavaScript `BigInt`.
+///
+/// Selected by the `bigint: true` flag on `int64`/`uint64` descriptors. Values
+/// travel as [`value::Value::BigInt`] rather than `f64`, so file sizes,
+/// microsecond timestamps and `GType`s above 2^53 round-trip losslessly. Safe
+/// integer `Number`s are still accepted when encoding.
 #[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
+pub enum BigIntKind {
+    I64,
+    U64,
+}
+
+impl BigIntKind {
+    #[must_use]
+    pub fn ffi_type(self) -> libffi::Type {
+        match self {
+            Self::I64 => libffi::Type::i64(),
+            Self::U64 => libffi::Type::u64(),
+        }
+    }
+
+    /// The [`IntegerKind`] with the same width and signedness.
+    #[must_use]
+    pub fn integer_kind(self) -> IntegerKind {
+        match self {
+            Self::I64 => IntegerKind::I64,
+            Self::U64 => IntegerKind::U64,
+        }
+    }
+
+    fn check_range(self, value: i128) -> anyhow::Result<()> {
+        let (min, max, name) = match self {
+            Self::I64 => (i128::from(i64::MIN), i128::from(i64::MAX), "i64"),
+            Self::U64 => (0, i128::from(u64::MAX), "u64"),
+        };
+        if !(min..=max).contains(&value) {
+            bail!("Value {value} is out of range for {name} [{min}, {max}]");
+        }
+        Ok(())
+    }
+
+    /// Extracts the integer carried by `value`: a `BigInt`, or a `Number`
+    /// that is a safe integer within this kind's range.
+    pub fn value_to_i128(self, value: &value::Value) -> anyhow::Result<i128> {
+        match value {
+            value::Value::BigInt(n) => Ok(*n),
+            value::Value::Number(n) => {
+                self.integer_kind().check_range(*n)?;
+                Ok(*n as i128)
+            }
+            _ => bail!("Expected a BigInt for {self:?} type, got {value:?}"),
+        }
+    }
+
+    #[must_use]
+    pub fn read_ptr(self, ptr: *const u8) -> i128 {
+        unsafe {
+            match self {
+                Self::I64 => ptr.cast::<i64>().read_unaligned().into(),
+                Self::U64 => ptr.cast::<u64>().read_unaligned().into(),
+            }
+        }
+    }
+
+    pub fn write_ptr(self, ptr: *mut u8, value: i128) {
+        unsafe {
+            match self {
+                Self::I64 => ptr.cast::<i64>().write_unaligned(value as i64),
+                Self::U64 => ptr.cast::<u64>().write_unaligned(value as u64),
+            }
+        }
+    }
+
+    pub fn checked_to_ffi_value(self, value: i128) -> anyhow::Result<ffi::FfiValue> {
+        self.check_range(value)?;
+        Ok(match self {
+            Self::I64 => ffi::FfiValue::I64(value as i64),
+            Self::U64 => ffi::FfiValue::U64(value as u64),
+        })
+    }
+
+    pub fn checked_to_ffi_storage(self, values: &[i128]) -> anyhow::Result<ffi::FfiStorage> {
+        for (i, &v) in values.iter().enumerate() {
+            if let Err(e) = self.check_range(v) {
+                bail!("Array element {i}: {e}");
+            }
+        }
+        Ok(match self {
+            Self::I64 => values.iter().map(|&v| v as i64).collect::<Vec<_>>().into(),
+            Self::U64 => values.iter().map(|&v| v as u64).collect::<Vec<_>>().into(),
+        })
+    }
+
+    #[must_use]
+    pub fn read_slice(self, ptr: *const u8, length: usize) -> Vec<i128> {
+        unsafe {
+            match self {
+                Self::I64 => std::slice::from_raw_parts(ptr.cast::<i64>(), length)
+                    .iter()
+                    .map(|&v| v.into())
+                    .collect(),
+                Self::U64 => std::slice::from_raw_parts(ptr.cast::<u64>(), length)
+                    .iter()
+                    .map(|&v| v.into())
+                    .collect(),
+            }
+        }
+    }
+
+    pub fn storage_to_vec(self, storage: &ffi::FfiStorage) -> anyhow::Result<Vec<i128>> {
+        match (storage.kind(), self) {
+            (ffi::FfiStorageKind::I64Vec(v), Self::I64) => {
+                Ok(v.iter().map(|&x| x.into()).collect())
+            }
+            (ffi::FfiStorageKind::U64Vec(v), Self::U64) => {
+                Ok(v.iter().map(|&x| x.into()).collect())
+            }
+            _ => bail!("FfiStorage does not match bigint kind {self:?}"),
+        }
+    }
+
+    /// Packs `value` into a pointer-sized word, the way `GHashTable` stores
+    /// integer keys and values.
+    pub fn checked_to_ptr(self, value: i128) -> anyhow::Result<*mut c_void> {
+        self.check_range(value)?;
+        Ok(match self {
+            Self::I64 => value as i64 as isize as *mut c_void,
+            Self::U64 => value as u64 as usize as *mut c_void,
+        })
+    }
+
+    #[must_use]
+    pub fn ptr_to_value_raw(self, ptr: *mut c_void) -> value::Value {
+        let n = match self {
+            Self::I64 => i128::from(ptr as i64),
+            Self::U64 => i128::from(ptr as u64),
+        };
+        value::Value::BigInt(n)
+    }
+}
+
+impl FfiEncoder for BigIntKind {
+    fn encode(&self, value: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        let n = match value {
+            value::Value::Null | value::Value::Undefined if optional => 0,
+            _ => self.value_to_i128(value)?,
+        };
+        self.checked_to_ffi_value(n)
+    }
+
+    fn libffi_type(&self) -> libffi::Type {
+        self.ffi_type()
+    }
+
+    fn call_cif(
+        &self,
+        cif: &libffi::Cif,
+        ptr: libffi::CodePtr,
+        args: &[libffi::Arg],
+    ) -> anyhow::Result<ffi::FfiValue> {
+        Ok(unsafe {
+            match self {
+                Self::I64 => ffi::FfiValue::I64(cif.call::<i64>(ptr, args)),
+                Self::U64 => ffi::FfiValue::U64(cif.call::<u64>(ptr, args)),
+            }
+        })
+    }
+}
+
+impl FfiDecoder for BigIntKind {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let n = match ffi_value {
+            ffi::FfiValue::I64(v) => i128::from(*v),
+            ffi::FfiValue::U64(v) => i128::from(*v),
+            _ => bail!("Expected a 64-bit integer ffi::FfiValue for {self:?}, got {ffi_value:?}"),
+        };
+        Ok(value::Value::BigInt(n))
+    }
+}
+
+impl RawPtrCodec for BigIntKind {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        Ok(self.ptr_to_value_raw(ptr))
+    }
+
+    fn read_from_raw_ptr(
+        &self,
+        ptr: *const c_void,
+        _context: &str,
+    ) -> anyhow::Result<value::Value> {
+        Ok(value::Value::BigInt(self.read_ptr(ptr as *const u8)))
+    }
+
+    fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
+        let n = match value {
+            Ok(value) => self
+                .value_to_i128(value)
+                .and_then(|n| self.check_range(n).map(|()| n))
+                .unwrap_or_else(|e| {
+                    crate::error_reporter::NativeErrorReporter::global()
+                        .report(&e.context("bigint return"));
+                    0
+                }),
+            Err(()) => 0,
+        };
+        self.write_ptr(ret as *mut u8, n);
+    }
+
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        let n = self.value_to_i128(value)?;
+        self.check_range(n)?;
+        self.write_ptr(ptr as *mut u8, n);
+        Ok(())
+    }
+}
+
+impl GlibValueCodec for BigIntKind {
+    fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
+        if !matches!(val, value::Value::BigInt(_) | value::Value::Number(_)) {
+            return Ok(None);
+        }
+        let n = self.value_to_i128(val)?;
+        self.check_range(n)?;
+        Ok(Some(match self {
+            Self::I64 => (n as i64).into(),
+            Self::U64 => (n as u64).into(),
+        }))
+    }
+
+    fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        let n: i128 = match self {
+            Self::I64 => gvalue
+                .get::<i64>()
+                .map_err(|e| anyhow::anyhow!("Failed to get i64 from GValue: {e}"))?
+                .into(),
+            Self::U64 => gvalue
+                .get::<u64>()
+                .map_err(|e| anyhow::anyhow!("Failed to get u64 from GValue: {e}"))?
+                .into(),
+        };
+        Ok(value::Value::BigInt(n))
+    }
+}
+
+impl From<BigIntKind> for libffi::Type {
+    fn from(kind: BigIntKind) -> Self {
+        kind.ffi_type()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum FloatKind {
     F32,
     F64,
@@ -379,6 +664,27 @@ impl FloatKind {
         }
     }
 
+    /// Converts a `BigInt` to this kind, rejecting integers it cannot
+    /// represent exactly.
+    pub fn bigint_to_ffi_value(self, value: i128) -> anyhow::Result<ffi::FfiValue> {
+        // The cast back saturates, so `i128::MAX` would compare equal to 2^127.
+        let exact = value != i128::MAX
+            && match self {
+                Self::F32 => value as f32 as i128 == value,
+                Self::F64 => value as f64 as i128 == value,
+            };
+        if !exact {
+            bail!("Value {value} cannot be represented exactly as {self:?}");
+        }
+        self.checked_to_ffi_value(value as f64)
+    }
+
+    pub fn write_bigint_ptr(self, ptr: *mut u8, value: i128) -> anyhow::Result<()> {
+        self.bigint_to_ffi_value(value)?;
+        self.write_ptr(ptr, value as f64);
+        Ok(())
+    }
+
     /// # Safety
     ///
     /// The caller must ensure:
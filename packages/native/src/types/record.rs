@@ -0,0 +1,350 @@
+//! Record codec.
+//!
+//! [`RecordType`] describes a C struct by the name, byte offset and type of
+//! each of its fields, and converts the whole struct to and from a plain
+//! JavaScript object in one request instead of one `read`/`write` per field.
+//!
+//! In a call a record is passed by pointer. An object argument is encoded into
+//! freshly allocated memory, which the call owns unless the descriptor
+//! transfers `full` ownership, in which case it is `g_malloc`ed for the callee
+//! to free. A native handle is passed through unchanged. A returned pointer
+//! is decoded into an object and, for `full` ownership, released with
+//! `g_free`. Wrapped in a `Ref`, a record is a caller-allocated out-parameter:
+//! the callee fills memory provided by the call, which is then decoded.
+//!
+//! Fields are embedded at their offset. A nested record or union is laid out
+//! inline as in a C struct (`GdkRectangle area`), as are an `inline` array
+//! (`double matrix[6]`) and a `GValue`; every other field type is read and
+//! written with the same codec as a `read`/`write` of that field.
+
+use anyhow::bail;
+use gtk4::glib;
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject, ValueType};
+
+use super::prelude::*;
+use super::{ArrayKind, FloatKind, StructLayout, Type};
+use crate::error_reporter::NativeErrorReporter;
+use crate::ffi::{AggregateBuffer, FfiStorage};
+
+/// One named member of a [`RecordType`].
+#[derive(Debug, Clone)]
+pub struct RecordField {
+    pub name: String,
+    /// Byte offset of the field from the start of the record.
+    pub offset: usize,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordType {
+    pub ownership: Ownership,
+    pub fields: Vec<RecordField>,
+    /// Size of the record in bytes, at least the end of its last field.
+    pub size: usize,
+}
+
+impl RecordType {
+    /// Builds a record from its fields. `size` defaults to the end of the
+    /// field that extends furthest, and may be larger to account for trailing
+    /// padding.
+    pub fn new(
+        ownership: Ownership,
+        fields: Vec<RecordField>,
+        size: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        let mut end = 0;
+        for field in &fields {
+            let Some(field_size) = embedded_size(&field.ty) else {
+                bail!(
+                    "{} cannot be the type of record field '{}'",
+                    field.ty,
+                    field.name
+                );
+            };
+            end = end.max(field.offset + field_size);
+        }
+
+        let size = size.unwrap_or(end);
+        if size < end {
+            bail!("Record size {size} is smaller than the {end} bytes its fields span");
+        }
+
+        Ok(Self {
+            ownership,
+            fields,
+            size,
+        })
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let ownership = Ownership::from_js_value(obj, "record")?;
+
+        let fields_prop: Unknown<'_> = obj.get_named_property("fields")?;
+        if !fields_prop.is_array()? {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                "'fields' property is required for record types",
+            ));
+        }
+        let fields_arr: Array = unsafe { Array::from_napi_value(env.raw(), fields_prop.raw())? };
+        let fields = crate::value::map_js_array(env, &fields_arr, |env, value| {
+            let field: JsObject = unsafe { JsObject::from_napi_value(env.raw(), value.raw())? };
+            let type_prop: Unknown<'_> = field.get_named_property("type")?;
+            let offset: f64 = field.get_named_property("offset")?;
+            Ok(RecordField {
+                name: field.get_named_property("name")?,
+                offset: offset as usize,
+                ty: Type::from_js_value(env, type_prop)?,
+            })
+        })?;
+
+        let size = obj
+            .get_named_property::<Option<f64>>("size")?
+            .map(|n| n as usize);
+
+        Self::new(ownership, fields, size)
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))
+    }
+
+    /// Converts a JavaScript argument into a [`value::Value`] for this type.
+    ///
+    /// Plain objects become a [`value::Value::Record`] holding the fields this
+    /// record declares, each converted by its own type; `null`, `undefined`
+    /// and native handles pass through unchanged.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn value_from_js(&self, env: &Env, value: Unknown<'_>) -> napi::Result<value::Value> {
+        match value.get_type()? {
+            ValueType::Null | ValueType::Undefined | ValueType::External => {
+                value::Value::from_js_value(env, value)
+            }
+            ValueType::Object if !value.is_array()? => {
+                let obj: JsObject = unsafe { JsObject::from_napi_value(env.raw(), value.raw())? };
+                let fields = self
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let prop: Unknown<'_> = obj.get_named_property(&field.name)?;
+                        let value = value::Value::from_js_value_typed(env, prop, &field.ty)?;
+                        Ok((field.name.clone(), value))
+                    })
+                    .collect::<napi::Result<Vec<_>>>()?;
+                Ok(value::Value::Record(fields))
+            }
+            other => Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Expected an object for record type, got {other:?}"),
+            )),
+        }
+    }
+
+    /// Decodes the record stored at `ptr` into a [`value::Value::Record`].
+    pub fn read_inline(&self, ptr: *const c_void) -> anyhow::Result<value::Value> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                let field_ptr = unsafe { ptr.byte_add(field.offset) };
+                let value = read_embedded(&field.ty, field_ptr)
+                    .map_err(|e| e.context(format!("reading record field '{}'", field.name)))?;
+                Ok((field.name.clone(), value))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(value::Value::Record(fields))
+    }
+
+    /// Writes `value` into the record stored at `ptr`.
+    ///
+    /// A [`value::Value::Record`] only overwrites the fields it holds a
+    /// defined value for, so a partial object updates the matching fields
+    /// and leaves the rest untouched. A native handle is copied byte for
+    /// byte.
+    pub fn write_inline(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        match value {
+            value::Value::Record(values) => {
+                for field in &self.fields {
+                    let Some((_, field_value)) =
+                        values.iter().find(|(name, _)| *name == field.name)
+                    else {
+                        continue;
+                    };
+                    let field_ptr = unsafe { ptr.byte_add(field.offset) };
+                    write_embedded(&field.ty, field_ptr, field_value)
+                        .map_err(|e| e.context(format!("writing record field '{}'", field.name)))?;
+                }
+                Ok(())
+            }
+            value::Value::Object(handle) if !handle.ptr().is_null() => {
+                unsafe {
+                    std::ptr::copy(
+                        handle.ptr().cast::<u8>().cast_const(),
+                        ptr.cast::<u8>(),
+                        self.size,
+                    );
+                }
+                Ok(())
+            }
+            _ => bail!("Expected an object for record type, got {value:?}"),
+        }
+    }
+
+    /// Encodes `value` into zeroed memory owned by the returned buffer.
+    /// `null` and `undefined` leave every field zero.
+    pub fn alloc(&self, value: &value::Value) -> anyhow::Result<AggregateBuffer> {
+        let mut buffer = AggregateBuffer::zeroed(self.size);
+        if !matches!(value, value::Value::Null | value::Value::Undefined) {
+            self.write_inline(buffer.as_mut_ptr(), value)?;
+        }
+        Ok(buffer)
+    }
+
+    /// Encodes `value` into zeroed memory from `g_malloc0`, for native code to
+    /// take ownership of.
+    fn malloc(&self, value: &value::Value) -> anyhow::Result<*mut c_void> {
+        let ptr = unsafe { glib::ffi::g_malloc0(self.size.max(1)) };
+        if let Err(e) = self.write_inline(ptr, value) {
+            unsafe { glib::ffi::g_free(ptr) };
+            return Err(e);
+        }
+        Ok(ptr)
+    }
+
+    /// The pointer a record value is stored as in a pointer-sized slot: a
+    /// handle's own pointer, or a `g_malloc`ed copy of an object.
+    fn to_owned_ptr(&self, value: &value::Value) -> anyhow::Result<*mut c_void> {
+        match value {
+            value::Value::Record(_) => self.malloc(value),
+            _ => value.object_ptr("Record"),
+        }
+    }
+}
+
+/// The number of bytes a field of type `ty` occupies inside a record, or
+/// `None` for types that cannot be record fields.
+pub(crate) fn embedded_size(ty: &Type) -> Option<usize> {
+    Some(match ty {
+        Type::Integer(kind) => kind.byte_size(),
+        Type::Tagged(tagged) => tagged.storage.byte_size(),
+        Type::BigInt(_) => size_of::<u64>(),
+        Type::Float(FloatKind::F32) => size_of::<f32>(),
+        Type::Float(FloatKind::F64) => size_of::<f64>(),
+        Type::Boolean(_) => size_of::<i32>(),
+        Type::Unichar(_) => size_of::<u32>(),
+        Type::GValue(_) => size_of::<glib::gobject_ffi::GValue>(),
+        Type::Record(record) => record.size,
+        Type::Union(union_type) => union_type.size,
+        Type::Boxed(boxed) => boxed
+            .by_value
+            .as_ref()
+            .map_or(size_of::<*mut c_void>(), StructLayout::size),
+        Type::Struct(struct_type) => struct_type
+            .by_value
+            .as_ref()
+            .map_or(size_of::<*mut c_void>(), StructLayout::size),
+        Type::Array(array) => match array.kind {
+            ArrayKind::Inline { size } => size * array.inline_stride().ok()?,
+            _ => size_of::<*mut c_void>(),
+        },
+        Type::String(_)
+        | Type::GObject(_)
+        | Type::Fundamental(_)
+        | Type::Variant(_)
+        | Type::Bytes(_)
+        | Type::HashTable(_) => size_of::<*mut c_void>(),
+        Type::Void(_)
+        | Type::Callback(_)
+        | Type::Trampoline(_)
+        | Type::Ref(_)
+        | Type::GError(_) => return None,
+    })
+}
+
+/// Reads a field of type `ty` embedded at `ptr`.
+pub(super) fn read_embedded(ty: &Type, ptr: *const c_void) -> anyhow::Result<value::Value> {
+    match ty {
+        Type::Record(record) => record.read_inline(ptr),
+        Type::Union(union_type) => union_type.read_inline(ptr),
+        Type::GValue(gvalue_type) => gvalue_type.read_inline(ptr),
+        _ => ty.read_from_raw_ptr(ptr, "record field"),
+    }
+}
+
+/// Writes `value` into a field of type `ty` embedded at `ptr`. `undefined`
+/// leaves the field untouched.
+pub(super) fn write_embedded(
+    ty: &Type,
+    ptr: *mut c_void,
+    value: &value::Value,
+) -> anyhow::Result<()> {
+    match (ty, value) {
+        (_, value::Value::Undefined) => Ok(()),
+        (Type::Record(record), _) => record.write_inline(ptr, value),
+        (Type::Union(union_type), _) => union_type.write_inline(ptr, value),
+        (Type::GValue(gvalue_type), _) => gvalue_type.write_inline(ptr, value),
+        _ => ty.write_value_to_raw_ptr(ptr, value),
+    }
+}
+
+impl FfiEncoder for RecordType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        match value {
+            value::Value::Record(_) if self.ownership.is_full() => {
+                Ok(ffi::FfiValue::Ptr(self.malloc(value)?))
+            }
+            value::Value::Record(_) => {
+                Ok(ffi::FfiValue::Storage(FfiStorage::from(self.alloc(value)?)))
+            }
+            _ => Ok(ffi::FfiValue::Ptr(value.object_ptr("Record")?)),
+        }
+    }
+}
+
+impl FfiDecoder for RecordType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let Some(ptr) = ffi_value.as_non_null_ptr("Record")? else {
+            return Ok(value::Value::Null);
+        };
+
+        let decoded = self.read_inline(ptr);
+        if self.ownership.is_full() {
+            unsafe { glib::ffi::g_free(ptr) };
+        }
+        decoded
+    }
+}
+
+impl RawPtrCodec for RecordType {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        null_guarded(ptr, |ptr| self.read_inline(ptr))
+    }
+
+    fn write_return_to_raw_ptr(
+        &self,
+        ret: *mut c_void,
+        value: &std::result::Result<value::Value, ()>,
+    ) {
+        let ptr = match value {
+            Ok(value) => self.to_owned_ptr(value).unwrap_or_else(|e| {
+                NativeErrorReporter::global().report(&e.context("record return"));
+                std::ptr::null_mut()
+            }),
+            Err(()) => std::ptr::null_mut(),
+        };
+        unsafe { *ret.cast::<*mut c_void>() = ptr };
+    }
+
+    /// Stores a pointer to the record in the slot: a handle's own pointer, or
+    /// a `g_malloc`ed copy of an object that the slot's owner must free.
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        let record_ptr = self.to_owned_ptr(value)?;
+        unsafe { *ptr.cast::<*mut c_void>() = record_ptr };
+        Ok(())
+    }
+}
+
+impl GlibValueCodec for RecordType {
+    fn from_glib_value(&self, _gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        bail!("Record types have no GType and cannot be stored in a GValue")
+    }
+}
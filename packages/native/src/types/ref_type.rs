@@ -44,28 +44,31 @@ impl FfiEncoder for RefType {
         };
 
         match &*self.inner_type {
-            Type::Boxed(_) | Type::Struct(_) | Type::GObject(_) | Type::Fundamental(_) => {
-                match &*ref_val.value {
-                    value::Value::Null | value::Value::Undefined => Ok(Self::null_ptr_storage()),
-                    _ => bail!(
-                        "Expected Null for Ref<Boxed/Struct/GObject/Fundamental>, got {:?}",
-                        ref_val.value
-                    ),
-                }
-            }
+            Type::Boxed(_)
+            | Type::Struct(_)
+            | Type::GObject(_)
+            | Type::Fundamental(_)
+            | Type::Variant(_)
+            | Type::Bytes(_) => match &*ref_val.value {
+                value::Value::Null | value::Value::Undefined => Ok(Self::null_ptr_storage()),
+                _ => bail!(
+                    "Expected Null for Ref<Boxed/Struct/GObject/Fundamental/Variant/Bytes>, got {:?}",
+                    ref_val.value
+                ),
+            },
             Type::Array(array_type) => match &*ref_val.value {
                 value::Value::Array(arr) if !arr.is_empty() => {
-                    let encoded = array_type.encode(&ref_val.value, false)?;
-                    match encoded {
-                        ffi::FfiValue::Storage(storage) => Ok(ffi::FfiValue::Storage(storage)),
-                        _ => bail!("Expected Storage from array encode for Ref<Array>"),
-                    }
+                    Self::encode_array_contents(array_type, &ref_val.value)
                 }
-                value::Value::Null | value::Value::Undefined | value::Value::Array(_) => {
-                    Ok(Self::null_ptr_storage())
+                value::Value::TypedArray(buffer) if !buffer.is_empty() => {
+                    Self::encode_array_contents(array_type, &ref_val.value)
                 }
+                value::Value::Null
+                | value::Value::Undefined
+                | value::Value::Array(_)
+                | value::Value::TypedArray(_) => Ok(Self::null_ptr_storage()),
                 _ => bail!(
-                    "Expected Array, Null, or Undefined for Ref<Array>, got {:?}",
+                    "Expected Array, TypedArray, Null, or Undefined for Ref<Array>, got {:?}",
                     ref_val.value
                 ),
             },
@@ -96,6 +99,15 @@ impl FfiEncoder for RefType {
                     FfiStorageKind::Buffer(buffer),
                 )))
             }
+            Type::Record(record) => Ok(ffi::FfiValue::Storage(FfiStorage::from(
+                record.alloc(&ref_val.value)?,
+            ))),
+            Type::Union(union_type) => Ok(ffi::FfiValue::Storage(FfiStorage::from(
+                union_type.alloc(&ref_val.value)?,
+            ))),
+            Type::GValue(gvalue_type) => {
+                Ok(ffi::FfiValue::Storage(gvalue_type.alloc(&ref_val.value)?))
+            }
             _ => {
                 let ref_arg = Arg::new(*self.inner_type.clone(), *ref_val.value.clone());
                 let ref_value = Box::new(ffi::FfiValue::try_from(ref_arg)?);
@@ -135,7 +147,12 @@ impl FfiDecoder for RefType {
         };
 
         match &*self.inner_type {
-            Type::GObject(_) | Type::Boxed(_) | Type::Fundamental(_) | Type::Struct(_) => {
+            Type::GObject(_)
+            | Type::Boxed(_)
+            | Type::Fundamental(_)
+            | Type::Struct(_)
+            | Type::Variant(_)
+            | Type::Bytes(_) => {
                 let actual_ptr = unsafe { *(storage.ptr() as *const *mut c_void) };
                 self.inner_type.decode(&ffi::FfiValue::Ptr(actual_ptr))
             }
@@ -143,6 +160,9 @@ impl FfiDecoder for RefType {
                 let number = int_type.read_ptr(storage.ptr() as *const u8);
                 Ok(value::Value::Number(number))
             }
+            Type::BigInt(kind) => Ok(value::Value::BigInt(
+                kind.read_ptr(storage.ptr() as *const u8),
+            )),
             Type::Tagged(tagged) => {
                 let number = tagged.storage.read_ptr(storage.ptr() as *const u8);
                 Ok(value::Value::Number(number))
@@ -152,6 +172,9 @@ impl FfiDecoder for RefType {
                 Ok(value::Value::Number(number))
             }
             Type::String(string_type) => Ok(Self::decode_ref_string(storage, string_type)),
+            Type::Record(record) => record.read_inline(storage.ptr()),
+            Type::Union(union_type) => union_type.read_inline(storage.ptr()),
+            Type::GValue(gvalue_type) => gvalue_type.read_inline(storage.ptr()),
             Type::Array(_) => {
                 bail!("Ref<Array> requires decode_with_context to get size from another parameter")
             }
@@ -182,7 +205,12 @@ impl RawPtrCodec for RefType {
         if inner_ptr.is_null() {
             return Ok(value::Value::Null);
         }
-        self.inner_type.read_from_raw_ptr(inner_ptr, "ref inner")
+        match &*self.inner_type {
+            Type::Record(record) => record.read_inline(inner_ptr),
+            Type::Union(union_type) => union_type.read_inline(inner_ptr),
+            Type::GValue(gvalue_type) => gvalue_type.read_inline(inner_ptr),
+            inner_type => inner_type.read_from_raw_ptr(inner_ptr, "ref inner"),
+        }
     }
 }
 
@@ -202,6 +230,7 @@ impl GlibValueCodec for RefType {
                 let val = int_kind.read_ptr(ptr as *const u8);
                 Ok(value::Value::Number(val))
             }
+            Type::BigInt(kind) => Ok(value::Value::BigInt(kind.read_ptr(ptr as *const u8))),
             Type::Tagged(tagged) => {
                 let val = tagged.storage.read_ptr(ptr as *const u8);
                 Ok(value::Value::Number(val))
@@ -261,6 +290,18 @@ impl RefType {
         self.decode(ffi_value)
     }
 
+    /// Encodes the initial contents of a `Ref<Array>`, which native code reads
+    /// and writes in place.
+    fn encode_array_contents(
+        array_type: &super::ArrayType,
+        contents: &value::Value,
+    ) -> anyhow::Result<ffi::FfiValue> {
+        match array_type.encode(contents, false)? {
+            ffi::FfiValue::Storage(storage) => Ok(ffi::FfiValue::Storage(storage)),
+            _ => bail!("Expected Storage from array encode for Ref<Array>"),
+        }
+    }
+
     /// Builds an [`ffi::FfiValue::Storage`] holding a heap-allocated null
     /// pointer, the out-parameter slot a native callee writes a result pointer
     /// into.
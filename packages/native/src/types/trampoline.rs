@@ -1,3 +1,4 @@
+use std::sync::Arc;
 use std::sync::atomic::{AtomicPtr, Ordering};
 
 use libffi::middle as libffi;
@@ -116,7 +117,7 @@ impl FfiEncoder for TrampolineType {
         let data = TrampolineData {
             js_func: callback.js_func.clone(),
             arg_types: self.arg_types.clone(),
-            return_type: (*self.return_type).clone(),
+            return_type: Arc::new((*self.return_type).clone()),
             user_data_index: self.user_data_index,
             is_oneshot,
             oneshot_state_ptr: AtomicPtr::new(std::ptr::null_mut()),
@@ -0,0 +1,552 @@
+//! Union codec.
+//!
+//! [`UnionType`] describes a C union by its size, alignment and named
+//! variants, all of which start at the union's first byte. Its JavaScript
+//! form is a tagged object, `{ tag, value }`, naming the active variant and
+//! holding that variant's value.
+//!
+//! A union with a [`UnionDiscriminant`] records its active variant in an
+//! integer inside its own bytes, like the `type` field that leads every
+//! member of a `GdkEvent`-style union. Reads decode the variant whose `tag`
+//! matches the discriminant, and writes store the chosen variant's tag after
+//! its value. Without a discriminant nothing in memory says which variant is
+//! active, so reads decode the first variant: describe the union with the
+//! variant you expect listed first.
+//!
+//! Like a record, a union is passed by pointer unless the descriptor sets
+//! `byValue`. libffi has no union types, so a by-value union is passed as a
+//! struct of the same size and alignment whose members are floating point
+//! where every variant holds a float of one kind and integers everywhere
+//! else. That classifies the union's bytes into the same registers the C
+//! compiler picks.
+
+use anyhow::bail;
+use gtk4::glib;
+use libffi::middle as libffi;
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject, ValueType};
+
+use super::prelude::*;
+use super::record::{read_embedded, write_embedded};
+use super::{ArrayKind, FloatKind, IntegerKind, Type, embedded_size};
+use crate::error_reporter::NativeErrorReporter;
+use crate::ffi::{AggregateBuffer, FfiStorage};
+
+/// One named member of a [`UnionType`].
+#[derive(Debug, Clone)]
+pub struct UnionVariant {
+    pub name: String,
+    pub ty: Type,
+    /// The discriminant value that selects this variant.
+    pub tag: Option<i64>,
+}
+
+/// The integer inside a union that records which variant is active.
+#[derive(Debug, Clone, Copy)]
+pub struct UnionDiscriminant {
+    /// Byte offset of the discriminant from the start of the union.
+    pub offset: usize,
+    pub kind: IntegerKind,
+}
+
+impl UnionDiscriminant {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let offset: f64 = obj.get_named_property("offset")?;
+        let type_prop: Unknown<'_> = obj.get_named_property("type")?;
+        let kind = match Type::from_js_value(env, type_prop)? {
+            Type::Integer(kind) => kind,
+            Type::Tagged(tagged) => tagged.storage,
+            other => {
+                return Err(napi::Error::new(
+                    napi::Status::InvalidArg,
+                    format!("A union discriminant must be an integer or enum, got {other}"),
+                ));
+            }
+        };
+        Ok(Self {
+            offset: offset as usize,
+            kind,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UnionType {
+    pub ownership: Ownership,
+    pub size: usize,
+    pub alignment: usize,
+    pub variants: Vec<UnionVariant>,
+    pub discriminant: Option<UnionDiscriminant>,
+    /// Whether the union is passed and returned by value rather than by
+    /// pointer.
+    pub by_value: bool,
+}
+
+impl UnionType {
+    /// Builds a union, checking that every variant and the discriminant fit
+    /// in `size` bytes and that `size` is a multiple of `alignment`.
+    pub fn new(
+        ownership: Ownership,
+        size: usize,
+        alignment: usize,
+        variants: Vec<UnionVariant>,
+        discriminant: Option<UnionDiscriminant>,
+        by_value: bool,
+    ) -> anyhow::Result<Self> {
+        if !matches!(alignment, 1 | 2 | 4 | 8) {
+            bail!("Union alignment must be 1, 2, 4 or 8, got {alignment}");
+        }
+        if size == 0 || !size.is_multiple_of(alignment) {
+            bail!("Union size {size} is not a positive multiple of its alignment {alignment}");
+        }
+        if variants.is_empty() {
+            bail!("A union needs at least one variant");
+        }
+        for variant in &variants {
+            let Some(variant_size) = embedded_size(&variant.ty) else {
+                bail!(
+                    "{} cannot be the type of union variant '{}'",
+                    variant.ty,
+                    variant.name
+                );
+            };
+            if variant_size > size {
+                bail!(
+                    "Union size {size} is smaller than the {variant_size} bytes of variant '{}'",
+                    variant.name
+                );
+            }
+        }
+        if let Some(discriminant) = discriminant
+            && discriminant.offset + discriminant.kind.byte_size() > size
+        {
+            bail!(
+                "Union discriminant at offset {} does not fit in {size} bytes",
+                discriminant.offset
+            );
+        }
+
+        Ok(Self {
+            ownership,
+            size,
+            alignment,
+            variants,
+            discriminant,
+            by_value,
+        })
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let invalid = |message: String| napi::Error::new(napi::Status::InvalidArg, message);
+
+        let ownership = Ownership::from_js_value(obj, "union")?;
+        let size: f64 = obj
+            .get_named_property::<Option<f64>>("size")?
+            .ok_or_else(|| invalid("'size' property is required for union types".into()))?;
+        let alignment: f64 = obj
+            .get_named_property::<Option<f64>>("alignment")?
+            .ok_or_else(|| invalid("'alignment' property is required for union types".into()))?;
+
+        let variants_prop: Unknown<'_> = obj.get_named_property("variants")?;
+        if !variants_prop.is_array()? {
+            return Err(invalid(
+                "'variants' property is required for union types".into(),
+            ));
+        }
+        let variants_arr: Array =
+            unsafe { Array::from_napi_value(env.raw(), variants_prop.raw())? };
+        let variants = crate::value::map_js_array(env, &variants_arr, |env, value| {
+            let variant: JsObject = unsafe { JsObject::from_napi_value(env.raw(), value.raw())? };
+            let type_prop: Unknown<'_> = variant.get_named_property("type")?;
+            Ok(UnionVariant {
+                name: variant.get_named_property("name")?,
+                ty: Type::from_js_value(env, type_prop)?,
+                tag: variant
+                    .get_named_property::<Option<f64>>("tag")?
+                    .map(|tag| tag as i64),
+            })
+        })?;
+
+        let discriminant = obj
+            .get_named_property::<Option<JsObject>>("discriminant")?
+            .map(|discriminant| UnionDiscriminant::from_js_value(env, &discriminant))
+            .transpose()?;
+
+        let by_value = obj
+            .get_named_property::<Option<bool>>("byValue")?
+            .unwrap_or(false);
+
+        Self::new(
+            ownership,
+            size as usize,
+            alignment as usize,
+            variants,
+            discriminant,
+            by_value,
+        )
+        .map_err(|e| invalid(e.to_string()))
+    }
+
+    /// Converts a JavaScript argument into a [`value::Value`] for this type.
+    ///
+    /// A `{ tag, value }` object becomes a [`value::Value::Record`] holding
+    /// the tag and the value converted by the tagged variant's type; `null`,
+    /// `undefined` and native handles pass through unchanged.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn value_from_js(&self, env: &Env, value: Unknown<'_>) -> napi::Result<value::Value> {
+        match value.get_type()? {
+            ValueType::Null | ValueType::Undefined | ValueType::External => {
+                value::Value::from_js_value(env, value)
+            }
+            ValueType::Object if !value.is_array()? => {
+                let obj: JsObject = unsafe { JsObject::from_napi_value(env.raw(), value.raw())? };
+                let tag: String = obj
+                    .get_named_property::<Option<String>>("tag")?
+                    .ok_or_else(|| {
+                        napi::Error::new(
+                            napi::Status::InvalidArg,
+                            "Expected a 'tag' naming the union variant",
+                        )
+                    })?;
+                let variant = self
+                    .variant(&tag)
+                    .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+                let prop: Unknown<'_> = obj.get_named_property("value")?;
+                let value = value::Value::from_js_value_typed(env, prop, &variant.ty)?;
+                Ok(value::Value::Record(vec![
+                    ("tag".to_owned(), value::Value::String(tag)),
+                    ("value".to_owned(), value),
+                ]))
+            }
+            other => Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Expected a {{ tag, value }} object for union type, got {other:?}"),
+            )),
+        }
+    }
+
+    fn variant(&self, name: &str) -> anyhow::Result<&UnionVariant> {
+        self.variants
+            .iter()
+            .find(|variant| variant.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Union has no variant named '{name}'"))
+    }
+
+    /// The variant active in the union stored at `ptr`.
+    fn active_variant(&self, ptr: *const c_void) -> anyhow::Result<&UnionVariant> {
+        let Some(discriminant) = self.discriminant else {
+            return Ok(&self.variants[0]);
+        };
+        let tag = discriminant
+            .kind
+            .read_ptr(unsafe { ptr.byte_add(discriminant.offset) }.cast());
+        self.variants
+            .iter()
+            .find(|variant| variant.tag.is_some_and(|t| t as f64 == tag))
+            .ok_or_else(|| anyhow::anyhow!("Union discriminant {tag} matches none of its variants"))
+    }
+
+    /// Decodes the union stored at `ptr` into a `{ tag, value }`
+    /// [`value::Value::Record`].
+    pub fn read_inline(&self, ptr: *const c_void) -> anyhow::Result<value::Value> {
+        let variant = self.active_variant(ptr)?;
+        let value = read_embedded(&variant.ty, ptr)
+            .map_err(|e| e.context(format!("reading union variant '{}'", variant.name)))?;
+        Ok(value::Value::Record(vec![
+            ("tag".to_owned(), value::Value::String(variant.name.clone())),
+            ("value".to_owned(), value),
+        ]))
+    }
+
+    /// Writes `value` into the union stored at `ptr`.
+    ///
+    /// A `{ tag, value }` [`value::Value::Record`] writes the tagged variant,
+    /// then the variant's tag into the discriminant. Bytes past the end of
+    /// the variant are left untouched. A native handle is copied byte for
+    /// byte.
+    pub fn write_inline(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        match value {
+            value::Value::Record(entries) => {
+                let entry = |key: &str| {
+                    entries
+                        .iter()
+                        .find(|(name, _)| name == key)
+                        .map(|(_, value)| value)
+                };
+                let Some(value::Value::String(tag)) = entry("tag") else {
+                    bail!("Expected a 'tag' naming the union variant, got {value:?}");
+                };
+                let variant = self.variant(tag)?;
+                write_embedded(
+                    &variant.ty,
+                    ptr,
+                    entry("value").unwrap_or(&value::Value::Undefined),
+                )
+                .map_err(|e| e.context(format!("writing union variant '{tag}'")))?;
+
+                if let Some(discriminant) = self.discriminant {
+                    let Some(variant_tag) = variant.tag else {
+                        bail!("Union variant '{tag}' has no discriminant tag");
+                    };
+                    discriminant.kind.write_ptr(
+                        unsafe { ptr.byte_add(discriminant.offset) }.cast(),
+                        variant_tag as f64,
+                    );
+                }
+                Ok(())
+            }
+            value::Value::Object(handle) if !handle.ptr().is_null() => {
+                unsafe {
+                    std::ptr::copy(
+                        handle.ptr().cast::<u8>().cast_const(),
+                        ptr.cast::<u8>(),
+                        self.size,
+                    );
+                }
+                Ok(())
+            }
+            _ => bail!("Expected a {{ tag, value }} object for union type, got {value:?}"),
+        }
+    }
+
+    /// Encodes `value` into zeroed memory owned by the returned buffer.
+    /// `null` and `undefined` leave the union zero.
+    pub fn alloc(&self, value: &value::Value) -> anyhow::Result<AggregateBuffer> {
+        let mut buffer = AggregateBuffer::zeroed(self.size);
+        if !matches!(value, value::Value::Null | value::Value::Undefined) {
+            self.write_inline(buffer.as_mut_ptr(), value)?;
+        }
+        Ok(buffer)
+    }
+
+    /// Encodes `value` into zeroed memory from `g_malloc0`, for native code to
+    /// take ownership of.
+    fn malloc(&self, value: &value::Value) -> anyhow::Result<*mut c_void> {
+        let ptr = unsafe { glib::ffi::g_malloc0(self.size) };
+        if let Err(e) = self.write_inline(ptr, value) {
+            unsafe { glib::ffi::g_free(ptr) };
+            return Err(e);
+        }
+        Ok(ptr)
+    }
+
+    /// The pointer a union value is stored as in a pointer-sized slot: a
+    /// handle's own pointer, or a `g_malloc`ed copy of a tagged object.
+    fn to_owned_ptr(&self, value: &value::Value) -> anyhow::Result<*mut c_void> {
+        match value {
+            value::Value::Record(_) => self.malloc(value),
+            _ => value.object_ptr("Union"),
+        }
+    }
+
+    /// The struct libffi passes a by-value union as: one member per
+    /// `alignment`-sized unit, floating point where every scalar of every
+    /// variant overlapping the unit is a float lying wholly inside it, and an
+    /// unsigned integer of the unit's size otherwise. Like an eightbyte of the
+    /// System V ABI, a unit of floats of both kinds still travels in a vector
+    /// register, so it becomes `double`s.
+    fn by_value_layout(&self) -> libffi::Type {
+        let mut scalars = Vec::new();
+        for variant in &self.variants {
+            collect_scalars(&variant.ty, 0, &mut scalars);
+        }
+
+        let unit = self.alignment;
+        let mut members = Vec::new();
+        for start in (0..self.size).step_by(unit) {
+            let end = start + unit;
+            let kinds = scalars
+                .iter()
+                .filter(|s| s.offset < end && s.offset + s.size > start)
+                .map(|s| {
+                    s.float
+                        .filter(|_| s.offset >= start && s.offset + s.size <= end)
+                })
+                .collect::<Option<Vec<_>>>();
+            match kinds {
+                Some(kinds) if !kinds.is_empty() => {
+                    let (kind, float_size) = if kinds.contains(&FloatKind::F64) {
+                        (FloatKind::F64, size_of::<f64>())
+                    } else {
+                        (FloatKind::F32, size_of::<f32>())
+                    };
+                    members.extend((0..unit / float_size).map(|_| kind.ffi_type()));
+                }
+                _ => members.push(match unit {
+                    1 => libffi::Type::u8(),
+                    2 => libffi::Type::u16(),
+                    4 => libffi::Type::u32(),
+                    _ => libffi::Type::u64(),
+                }),
+            }
+        }
+        libffi::Type::structure(members)
+    }
+}
+
+/// A scalar inside a union variant, as seen by the calling convention.
+struct Scalar {
+    offset: usize,
+    size: usize,
+    /// Set for floating-point scalars, which travel in vector registers.
+    float: Option<FloatKind>,
+}
+
+impl Scalar {
+    fn new(ty: &Type, offset: usize) -> Self {
+        Self {
+            offset,
+            size: embedded_size(ty).unwrap_or(0),
+            float: match ty {
+                Type::Float(kind) => Some(*kind),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Appends the scalars of a value of type `ty` stored at `offset`, looking
+/// through nested records, unions and inline arrays.
+fn collect_scalars(ty: &Type, offset: usize, out: &mut Vec<Scalar>) {
+    match ty {
+        Type::Record(record) => {
+            for field in &record.fields {
+                collect_scalars(&field.ty, offset + field.offset, out);
+            }
+        }
+        Type::Union(nested) => {
+            for variant in &nested.variants {
+                collect_scalars(&variant.ty, offset, out);
+            }
+        }
+        Type::Array(array) => match array.kind {
+            ArrayKind::Inline { size } => {
+                let stride = array.inline_stride().unwrap_or(0);
+                for i in 0..size {
+                    collect_scalars(&array.item_type, offset + i * stride, out);
+                }
+            }
+            _ => out.push(Scalar::new(ty, offset)),
+        },
+        _ => out.push(Scalar::new(ty, offset)),
+    }
+}
+
+impl FfiEncoder for UnionType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        if self.by_value {
+            return Ok(ffi::FfiValue::Aggregate(self.alloc(value)?));
+        }
+        match value {
+            value::Value::Record(_) if self.ownership.is_full() => {
+                Ok(ffi::FfiValue::Ptr(self.malloc(value)?))
+            }
+            value::Value::Record(_) => {
+                Ok(ffi::FfiValue::Storage(FfiStorage::from(self.alloc(value)?)))
+            }
+            _ => Ok(ffi::FfiValue::Ptr(value.object_ptr("Union")?)),
+        }
+    }
+
+    fn libffi_type(&self) -> libffi::Type {
+        if self.by_value {
+            self.by_value_layout()
+        } else {
+            libffi::Type::pointer()
+        }
+    }
+
+    fn call_cif(
+        &self,
+        cif: &libffi::Cif,
+        ptr: libffi::CodePtr,
+        args: &[libffi::Arg],
+    ) -> anyhow::Result<ffi::FfiValue> {
+        if self.by_value {
+            let mut buffer = AggregateBuffer::zeroed(self.size);
+            unsafe { cif.call_return_into(ptr, args, buffer.as_ret()) };
+            return Ok(ffi::FfiValue::Aggregate(buffer));
+        }
+        Ok(ffi::FfiValue::Ptr(unsafe {
+            cif.call::<*mut c_void>(ptr, args)
+        }))
+    }
+}
+
+impl FfiDecoder for UnionType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        if let ffi::FfiValue::Aggregate(buffer) = ffi_value {
+            return self.read_inline(buffer.as_ptr());
+        }
+
+        let Some(ptr) = ffi_value.as_non_null_ptr("Union")? else {
+            return Ok(value::Value::Null);
+        };
+
+        let decoded = self.read_inline(ptr);
+        if self.ownership.is_full() {
+            unsafe { glib::ffi::g_free(ptr) };
+        }
+        decoded
+    }
+}
+
+impl RawPtrCodec for UnionType {
+    /// A by-value union is stored inline, so `ptr` is the union itself
+    /// rather than a pointer to it.
+    fn read_from_raw_ptr(&self, ptr: *const c_void, context: &str) -> anyhow::Result<value::Value> {
+        if self.by_value {
+            return self.read_inline(ptr);
+        }
+        let inner_ptr = unsafe { *(ptr as *const *mut c_void) };
+        self.ptr_to_value(inner_ptr, context)
+    }
+
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        null_guarded(ptr, |ptr| self.read_inline(ptr))
+    }
+
+    fn write_return_to_raw_ptr(
+        &self,
+        ret: *mut c_void,
+        value: &std::result::Result<value::Value, ()>,
+    ) {
+        if self.by_value {
+            unsafe { std::ptr::write_bytes(ret.cast::<u8>(), 0, self.size) };
+            if let Ok(value) = value
+                && let Err(e) = self.write_inline(ret, value)
+            {
+                NativeErrorReporter::global().report(&e.context("by-value union return"));
+            }
+            return;
+        }
+        let ptr = match value {
+            Ok(value) => self.to_owned_ptr(value).unwrap_or_else(|e| {
+                NativeErrorReporter::global().report(&e.context("union return"));
+                std::ptr::null_mut()
+            }),
+            Err(()) => std::ptr::null_mut(),
+        };
+        unsafe { *ret.cast::<*mut c_void>() = ptr };
+    }
+
+    /// Writes a by-value union in place; otherwise stores a pointer to the
+    /// union, which for a tagged object is a `g_malloc`ed copy the slot's
+    /// owner must free.
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        if self.by_value {
+            return self.write_inline(ptr, value);
+        }
+        let union_ptr = self.to_owned_ptr(value)?;
+        unsafe { *ptr.cast::<*mut c_void>() = union_ptr };
+        Ok(())
+    }
+}
+
+impl GlibValueCodec for UnionType {
+    fn from_glib_value(&self, _gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        bail!("Union types have no GType and cannot be stored in a GValue")
+    }
+}
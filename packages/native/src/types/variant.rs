@@ -0,0 +1,458 @@
+//! `GVariant` codec.
+//!
+//! [`VariantType`] marshals `GVariant*` values as plain JavaScript values
+//! instead of the opaque handles a [`FundamentalType`](super::FundamentalType)
+//! produces. A JavaScript argument is built into a [`glib::Variant`] on the JS
+//! thread, driven by the descriptor's `signature`, and crosses to the `GLib`
+//! thread as [`value::Value::Variant`]. On the way back a variant is unpacked
+//! by its own type string, so decoding needs no signature:
+//!
+//! | `GVariant` type               | JavaScript value                            |
+//! |-------------------------------|---------------------------------------------|
+//! | `b`                           | `boolean`                                   |
+//! | `y` `n` `q` `i` `u` `h` `d`   | `number`                                    |
+//! | `x` `t`                       | `bigint` (a safe-integer `number` on input) |
+//! | `s` `o` `g`                   | `string`                                    |
+//! | `v`                           | `{ signature, value }`                      |
+//! | `m*`                          | the child value, or `null` for nothing      |
+//! | `ay`                          | `Uint8Array` (a number array on input)      |
+//! | `a{**}`                       | object keyed by the printed key             |
+//! | `a*`                          | array                                       |
+//! | `(*)`, `{**}`                 | array of the members                        |
+//!
+//! The JavaScript conversions ([`VariantType::value_from_js`] and
+//! [`variant_to_js`]) are bound to a live [`napi::Env`] and are excluded from
+//! coverage instrumentation.
+
+use anyhow::bail;
+use gtk4::glib::{
+    self,
+    translate::{FromGlibPtrFull as _, FromGlibPtrNone as _},
+    value::ToValue as _,
+    variant::{Handle, ObjectPath, Signature, ToVariant as _},
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject, ValueType};
+
+use super::IntegerKind;
+use super::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct VariantType {
+    pub ownership: Ownership,
+    /// Type of the variants built from JavaScript values. Only encoding needs
+    /// it; decoding reads the type of the variant itself.
+    pub signature: Option<glib::VariantType>,
+}
+
+impl VariantType {
+    #[must_use]
+    pub fn new(ownership: Ownership, signature: Option<glib::VariantType>) -> Self {
+        Self {
+            ownership,
+            signature,
+        }
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let ownership = Ownership::from_js_value(obj, "variant")?;
+        let signature = obj
+            .get_named_property::<Option<String>>("signature")
+            .ok()
+            .flatten()
+            .map(|signature| Self::parse_signature(&signature))
+            .transpose()
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+
+        Ok(Self::new(ownership, signature))
+    }
+
+    /// Parses a `GVariant` type string, rejecting indefinite types such as
+    /// `*` or `a?` that no concrete value can be built for.
+    pub fn parse_signature(signature: &str) -> anyhow::Result<glib::VariantType> {
+        let Ok(ty) = glib::VariantType::new(signature) else {
+            bail!("Invalid variant signature '{signature}'");
+        };
+        if !ty.is_definite() {
+            bail!("Variant signature '{signature}' is not a definite type");
+        }
+        Ok(ty)
+    }
+
+    /// Converts a JavaScript argument into a [`value::Value`] for this type.
+    ///
+    /// `null`, `undefined`, and native `GVariant` handles pass through
+    /// unchanged; any other value is built into a variant of the descriptor's
+    /// `signature`.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn value_from_js(&self, env: &Env, value: Unknown<'_>) -> napi::Result<value::Value> {
+        match value.get_type()? {
+            ValueType::Null | ValueType::Undefined | ValueType::External => {
+                value::Value::from_js_value(env, value)
+            }
+            _ => {
+                let Some(signature) = &self.signature else {
+                    return Err(napi::Error::new(
+                        napi::Status::InvalidArg,
+                        "'signature' property is required to encode variant values",
+                    ));
+                };
+                variant_from_js(env, value, signature).map(value::Value::Variant)
+            }
+        }
+    }
+
+    /// Wraps a non-null `GVariant*` into a [`value::Value::Variant`],
+    /// honoring `ownership`: a full transfer adopts the reference while a
+    /// borrowed one sinks or takes a fresh reference.
+    fn wrap_ptr(&self, ptr: *mut c_void) -> value::Value {
+        let ptr = ptr.cast::<glib::ffi::GVariant>();
+        let variant = if self.ownership.is_full() {
+            unsafe { glib::Variant::from_glib_full(ptr) }
+        } else {
+            unsafe { glib::Variant::from_glib_none(ptr) }
+        };
+        value::Value::Variant(variant)
+    }
+}
+
+impl FfiEncoder for VariantType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        let ptr = value.object_ptr("Variant")?;
+        self.ref_for_transfer(ptr).map(ffi::FfiValue::Ptr)
+    }
+
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn ref_for_transfer(&self, ptr: *mut c_void) -> anyhow::Result<*mut c_void> {
+        if self.ownership.is_full() && !ptr.is_null() {
+            return Ok(unsafe { glib::ffi::g_variant_ref(ptr.cast()) }.cast());
+        }
+        Ok(ptr)
+    }
+}
+
+impl FfiDecoder for VariantType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let Some(ptr) = ffi_value.as_non_null_ptr("Variant")? else {
+            return Ok(value::Value::Null);
+        };
+        Ok(self.wrap_ptr(ptr))
+    }
+}
+
+impl RawPtrCodec for VariantType {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        null_guarded(ptr, |ptr| {
+            let variant =
+                unsafe { glib::Variant::from_glib_none(ptr.cast::<glib::ffi::GVariant>()) };
+            Ok(value::Value::Variant(variant))
+        })
+    }
+
+    fn write_return_to_raw_ptr(
+        &self,
+        ret: *mut c_void,
+        value: &std::result::Result<value::Value, ()>,
+    ) {
+        write_return_object_ptr(ret, value, |ptr| unsafe {
+            glib::ffi::g_variant_ref(ptr.cast()).cast()
+        });
+    }
+
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        write_object_ptr(ptr, value, "Variant field write")
+    }
+}
+
+impl GlibValueCodec for VariantType {
+    fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
+        let variant = match val {
+            value::Value::Variant(variant) => Some(variant.clone()),
+            value::Value::Null | value::Value::Undefined => None,
+            value::Value::Object(handle) if !handle.ptr().is_null() => {
+                let ptr = handle.ptr().cast::<glib::ffi::GVariant>();
+                Some(unsafe { glib::Variant::from_glib_none(ptr) })
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(variant.to_value()))
+    }
+
+    fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        Ok(gvalue
+            .get::<Option<glib::Variant>>()?
+            .map_or(value::Value::Null, value::Value::Variant))
+    }
+}
+
+/// Builds a JS value of the shape described in the [module docs](self) from
+/// `variant`.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(crate) fn variant_to_js<'env>(
+    env: &'env Env,
+    variant: &glib::Variant,
+) -> napi::Result<Unknown<'env>> {
+    let ty = variant.type_();
+    match ty.as_str().as_bytes()[0] {
+        b'b' => to_unknown(env, unpack::<bool>(variant)?),
+        b'y' => to_unknown(env, f64::from(unpack::<u8>(variant)?)),
+        b'n' => to_unknown(env, f64::from(unpack::<i16>(variant)?)),
+        b'q' => to_unknown(env, f64::from(unpack::<u16>(variant)?)),
+        b'i' => to_unknown(env, f64::from(unpack::<i32>(variant)?)),
+        b'u' => to_unknown(env, f64::from(unpack::<u32>(variant)?)),
+        b'h' => to_unknown(env, f64::from(unpack::<Handle>(variant)?.0)),
+        b'x' => to_unknown(env, BigInt::from(unpack::<i64>(variant)?)),
+        b't' => to_unknown(env, BigInt::from(unpack::<u64>(variant)?)),
+        b'd' => to_unknown(env, unpack::<f64>(variant)?),
+        b's' | b'o' | b'g' => to_unknown(env, variant.str().unwrap_or_default()),
+        b'v' => {
+            let inner = unpack::<glib::Variant>(variant)?;
+            let mut tagged = Object::new(env)?;
+            tagged.set_named_property("signature", inner.type_().as_str())?;
+            tagged.set_named_property("value", variant_to_js(env, &inner)?)?;
+            to_unknown(env, tagged)
+        }
+        b'm' => match variant.as_maybe() {
+            Some(child) => variant_to_js(env, &child),
+            None => to_unknown(env, Null),
+        },
+        b'a' if ty == glib::VariantTy::BYTE_STRING => {
+            let bytes = variant
+                .fixed_array::<u8>()
+                .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;
+            to_unknown(env, Uint8Array::new(bytes.to_vec()))
+        }
+        b'a' if ty.element().is_dict_entry() => {
+            let mut object = Object::new(env)?;
+            for entry in variant.iter() {
+                let key = dict_key_to_string(&entry.child_value(0));
+                object.set_named_property(&key, variant_to_js(env, &entry.child_value(1))?)?;
+            }
+            to_unknown(env, object)
+        }
+        b'a' | b'(' | b'{' => {
+            let mut array = env.create_array(variant.n_children() as u32)?;
+            for (index, child) in variant.iter().enumerate() {
+                array.set(index as u32, variant_to_js(env, &child)?)?;
+            }
+            to_unknown(env, array)
+        }
+        _ => Err(napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Unsupported variant type '{}'", ty.as_str()),
+        )),
+    }
+}
+
+/// Builds a variant of type `ty` from a JS value of the shape described in
+/// the [module docs](self).
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn variant_from_js(
+    env: &Env,
+    value: Unknown<'_>,
+    ty: &glib::VariantTy,
+) -> napi::Result<glib::Variant> {
+    let variant = match ty.as_str().as_bytes()[0] {
+        b'b' => from_js::<bool>(env, &value, ty)?.to_variant(),
+        b'y' => (integer_from_js(env, &value, ty, IntegerKind::U8)? as u8).to_variant(),
+        b'n' => (integer_from_js(env, &value, ty, IntegerKind::I16)? as i16).to_variant(),
+        b'q' => (integer_from_js(env, &value, ty, IntegerKind::U16)? as u16).to_variant(),
+        b'i' => (integer_from_js(env, &value, ty, IntegerKind::I32)? as i32).to_variant(),
+        b'u' => (integer_from_js(env, &value, ty, IntegerKind::U32)? as u32).to_variant(),
+        b'h' => Handle(integer_from_js(env, &value, ty, IntegerKind::I32)? as i32).to_variant(),
+        b'x' => int64_from_js(env, &value, ty)?.to_variant(),
+        b't' => uint64_from_js(env, &value, ty)?.to_variant(),
+        b'd' => from_js::<f64>(env, &value, ty)?.to_variant(),
+        b's' => from_js::<String>(env, &value, ty)?.to_variant(),
+        b'o' => ObjectPath::try_from(from_js::<String>(env, &value, ty)?)
+            .map_err(|e| invalid_value(ty, e))?
+            .to_variant(),
+        b'g' => Signature::try_from(from_js::<String>(env, &value, ty)?)
+            .map_err(|e| invalid_value(ty, e))?
+            .to_variant(),
+        b'v' => tagged_variant_from_js(env, &value)?,
+        b'm' => match value.get_type()? {
+            ValueType::Null | ValueType::Undefined => glib::Variant::from_none(ty.element()),
+            _ => glib::Variant::from_some(&variant_from_js(env, value, ty.element())?),
+        },
+        b'a' => array_variant_from_js(env, &value, ty)?,
+        b'(' => glib::Variant::tuple_from_iter(members_from_js(env, &value, ty)?),
+        b'{' => {
+            let members = members_from_js(env, &value, ty)?;
+            glib::Variant::from_dict_entry(&members[0], &members[1])
+        }
+        _ => return Err(invalid_value(ty, "unsupported variant type")),
+    };
+    Ok(variant)
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn tagged_variant_from_js(env: &Env, value: &Unknown<'_>) -> napi::Result<glib::Variant> {
+    let tagged: Object<'_> = from_js(env, value, glib::VariantTy::VARIANT)?;
+    let signature: String = tagged.get_named_property("signature").map_err(|_| {
+        invalid_value(
+            glib::VariantTy::VARIANT,
+            "expected an object with a 'signature' string",
+        )
+    })?;
+    let inner_ty = VariantType::parse_signature(&signature)
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+    let inner: Unknown<'_> = tagged.get_named_property("value")?;
+    Ok(glib::Variant::from_variant(&variant_from_js(
+        env, inner, &inner_ty,
+    )?))
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn array_variant_from_js(
+    env: &Env,
+    value: &Unknown<'_>,
+    ty: &glib::VariantTy,
+) -> napi::Result<glib::Variant> {
+    let element = ty.element();
+
+    if element == glib::VariantTy::BYTE && value.is_typedarray()? {
+        let bytes: Uint8Array = from_js(env, value, ty)?;
+        return Ok(glib::Variant::array_from_fixed_array(&bytes[..]));
+    }
+
+    if element.is_dict_entry() && !value.is_array()? {
+        let object: Object<'_> = from_js(env, value, ty)?;
+        let mut entries = Vec::new();
+        for key in Object::keys(&object)? {
+            let entry_value: Unknown<'_> = object.get_named_property(&key)?;
+            entries.push(glib::Variant::from_dict_entry(
+                &dict_key_from_str(&key, element.key())?,
+                &variant_from_js(env, entry_value, element.value())?,
+            ));
+        }
+        return Ok(glib::Variant::array_from_iter_with_type(element, entries));
+    }
+
+    let array: Array<'_> = from_js(env, value, ty)?;
+    let items =
+        crate::value::map_js_array(env, &array, |env, item| variant_from_js(env, item, element))?;
+    Ok(glib::Variant::array_from_iter_with_type(element, items))
+}
+
+/// Builds the members of a tuple or dictionary entry type from a JS array
+/// holding exactly one value per member.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn members_from_js(
+    env: &Env,
+    value: &Unknown<'_>,
+    ty: &glib::VariantTy,
+) -> napi::Result<Vec<glib::Variant>> {
+    let array: Array<'_> = from_js(env, value, ty)?;
+    let expected = ty.n_items();
+    if array.len() as usize != expected {
+        return Err(invalid_value(
+            ty,
+            format!("expected {expected} members, got {}", array.len()),
+        ));
+    }
+    let mut member_types = ty.tuple_types();
+    crate::value::map_js_array(env, &array, |env, item| {
+        let member_ty = member_types
+            .next()
+            .ok_or_else(|| invalid_value(ty, "too many members"))?;
+        variant_from_js(env, item, member_ty)
+    })
+}
+
+/// Renders a dictionary key as a JS property name: strings as-is, every
+/// other basic type in `GVariant` text format.
+fn dict_key_to_string(key: &glib::Variant) -> String {
+    key.str()
+        .map_or_else(|| key.print(false).to_string(), str::to_owned)
+}
+
+/// Parses a JS property name back into a dictionary key of type `ty`; the
+/// inverse of [`dict_key_to_string`].
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn dict_key_from_str(key: &str, ty: &glib::VariantTy) -> napi::Result<glib::Variant> {
+    match ty.as_str() {
+        "s" => Ok(key.to_variant()),
+        "o" => ObjectPath::try_from(key)
+            .map(|path| path.to_variant())
+            .map_err(|e| invalid_value(ty, e)),
+        "g" => Signature::try_from(key)
+            .map(|signature| signature.to_variant())
+            .map_err(|e| invalid_value(ty, e)),
+        _ => glib::Variant::parse(Some(ty), key).map_err(|e| invalid_value(ty, e)),
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn integer_from_js(
+    env: &Env,
+    value: &Unknown<'_>,
+    ty: &glib::VariantTy,
+    kind: IntegerKind,
+) -> napi::Result<f64> {
+    let number: f64 = from_js(env, value, ty)?;
+    kind.check_range(number).map_err(|e| invalid_value(ty, e))?;
+    Ok(number)
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn int64_from_js(env: &Env, value: &Unknown<'_>, ty: &glib::VariantTy) -> napi::Result<i64> {
+    if value.get_type()? != ValueType::BigInt {
+        return integer_from_js(env, value, ty, IntegerKind::I64).map(|n| n as i64);
+    }
+    let bigint: BigInt = from_js(env, value, ty)?;
+    match bigint.get_i64() {
+        (n, true) => Ok(n),
+        _ => Err(invalid_value(ty, "bigint is out of range for int64")),
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn uint64_from_js(env: &Env, value: &Unknown<'_>, ty: &glib::VariantTy) -> napi::Result<u64> {
+    if value.get_type()? != ValueType::BigInt {
+        return integer_from_js(env, value, ty, IntegerKind::U64).map(|n| n as u64);
+    }
+    let bigint: BigInt = from_js(env, value, ty)?;
+    match bigint.get_u64() {
+        (false, n, true) => Ok(n),
+        _ => Err(invalid_value(ty, "bigint is out of range for uint64")),
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn from_js<T: FromNapiValue>(
+    env: &Env,
+    value: &Unknown<'_>,
+    ty: &glib::VariantTy,
+) -> napi::Result<T> {
+    unsafe { T::from_napi_value(env.raw(), napi::JsValue::raw(value)) }
+        .map_err(|e| invalid_value(ty, e.reason))
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn to_unknown<T: ToNapiValue>(env: &Env, value: T) -> napi::Result<Unknown<'_>> {
+    unsafe {
+        let raw = T::to_napi_value(env.raw(), value)?;
+        Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+    }
+}
+
+fn unpack<T: glib::variant::FromVariant>(variant: &glib::Variant) -> napi::Result<T> {
+    variant.get().ok_or_else(|| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            format!(
+                "Cannot unpack variant of type '{}'",
+                variant.type_().as_str()
+            ),
+        )
+    })
+}
+
+fn invalid_value(ty: &glib::VariantTy, detail: impl std::fmt::Display) -> napi::Error {
+    napi::Error::new(
+        napi::Status::InvalidArg,
+        format!("Invalid value for variant type '{}': {detail}", ty.as_str()),
+    )
+}
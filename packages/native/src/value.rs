@@ -6,13 +6,17 @@
 //! via the [`ffi`] module.
 //!
 //! The [`Value`] enum supports all types that can be passed through the FFI:
-//! - Primitives: numbers, strings, booleans
+//! - Primitives: numbers, 64-bit `BigInt`s, strings, booleans
 //! - Objects: `GObjects`, boxed types, structs
 //! - Callbacks: JavaScript functions invocable from native code
-//! - Arrays and references
+//! - Arrays, typed arrays, and references
+//! - `GVariant`s, built from and unpacked into plain JavaScript values
+//! - `GBytes`, read from and returned as Node `Buffer`s
 //!
 //! [`JsRef`], [`Callback`], [`Ref`], and the [`napi::Env`]-bound conversions
-//! ([`Value::from_js_value`], [`Value::to_js_value`], [`map_js_array`]) wrap
+//! ([`Value::from_js_value`], [`Value::from_js_value_typed`],
+//! [`Value::to_js_value`], [`TypedBuffer::from_js_value`],
+//! [`TypedBuffer::to_js_value`], [`map_js_array`]) wrap
 //! live JavaScript references, so they are excluded from coverage
 //! instrumentation — a `cargo test` process has no JavaScript runtime to
 //! exercise them against.
@@ -29,7 +33,9 @@ use napi::{Env, JsFunction, JsObject, NapiRaw, NapiValue, ValueType};
 
 use crate::error_reporter::NativeErrorReporter;
 use crate::managed::NativeHandle;
-use crate::types::{FfiDecoder, GlibValueCodec, Type};
+use crate::types::{
+    FfiDecoder, FloatKind, GlibValueCodec, IntegerKind, Type, bytes_to_js, variant_to_js,
+};
 use crate::{arg::Arg, ffi};
 
 /// Send-safe napi reference to a JavaScript value of type `T`.
@@ -183,27 +189,331 @@ impl Ref {
     }
 
     pub fn from_js_value(env: &Env, value: Unknown<'_>) -> napi::Result<Self> {
+        Self::from_js_object(env, value, Value::from_js_value)
+    }
+
+    /// Like [`Self::from_js_value`], converting the current value with
+    /// [`Value::from_js_value_typed`] for `inner_type`.
+    pub fn from_js_value_typed(
+        env: &Env,
+        value: Unknown<'_>,
+        inner_type: &Type,
+    ) -> napi::Result<Self> {
+        Self::from_js_object(env, value, |env, value_prop| {
+            Value::from_js_value_typed(env, value_prop, inner_type)
+        })
+    }
+
+    fn from_js_object(
+        env: &Env,
+        value: Unknown<'_>,
+        convert: impl FnOnce(&Env, Unknown<'_>) -> napi::Result<Value>,
+    ) -> napi::Result<Self> {
         let obj: JsObject = unsafe { JsObject::from_raw_unchecked(env.raw(), value.raw()) };
         let value_prop: Unknown<'_> = obj.get_named_property("value")?;
-        let inner = Value::from_js_value(env, value_prop)?;
+        let inner = convert(env, value_prop)?;
         let js_obj_ref = JsRef::from_js_value(env, &obj)?;
 
         Ok(Self::new(inner, Arc::new(js_obj_ref)))
     }
 }
 
+/// The elements of a JavaScript `TypedArray`, or the bytes of an
+/// `ArrayBuffer`.
+///
+/// Built with a single bulk copy out of the JS heap (or out of native memory
+/// when decoding), so numeric arrays never pass through one [`Value::Number`]
+/// per element. The buffer is reference-counted so encoding can hand its
+/// pointer to native code without copying again.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum TypedBuffer {
+    U8(Arc<Vec<u8>>),
+    I8(Arc<Vec<i8>>),
+    U16(Arc<Vec<u16>>),
+    I16(Arc<Vec<i16>>),
+    U32(Arc<Vec<u32>>),
+    I32(Arc<Vec<i32>>),
+    U64(Arc<Vec<u64>>),
+    I64(Arc<Vec<i64>>),
+    F32(Arc<Vec<f32>>),
+    F64(Arc<Vec<f64>>),
+}
+
+macro_rules! with_typed_buffer {
+    ($buffer:expr, $data:ident => $body:expr) => {
+        match $buffer {
+            TypedBuffer::U8($data) => $body,
+            TypedBuffer::I8($data) => $body,
+            TypedBuffer::U16($data) => $body,
+            TypedBuffer::I16($data) => $body,
+            TypedBuffer::U32($data) => $body,
+            TypedBuffer::I32($data) => $body,
+            TypedBuffer::U64($data) => $body,
+            TypedBuffer::I64($data) => $body,
+            TypedBuffer::F32($data) => $body,
+            TypedBuffer::F64($data) => $body,
+        }
+    };
+}
+
+/// Copies `len` elements of `T` starting at `data`, tolerating the null
+/// pointer JS engines report for empty buffers.
+///
+/// # Safety
+///
+/// When `len` is non-zero, `data` must point to `len` initialized, aligned
+/// elements of `T`.
+unsafe fn copy_elements<T: Copy>(data: *const c_void, len: usize) -> Arc<Vec<T>> {
+    if len == 0 || data.is_null() {
+        return Arc::new(Vec::new());
+    }
+    Arc::new(unsafe { std::slice::from_raw_parts(data.cast::<T>(), len) }.to_vec())
+}
+
+impl TypedBuffer {
+    /// Copies `len` contiguous integers of `kind` starting at `data`.
+    ///
+    /// # Safety
+    ///
+    /// When `len` is non-zero, `data` must point to `len` initialized
+    /// elements of `kind`.
+    #[must_use]
+    pub unsafe fn from_integers(kind: IntegerKind, data: *const c_void, len: usize) -> Self {
+        macro_rules! dispatch {
+            ($($variant:ident : $ty:ident : $vec_variant:ident),+ $(,)?) => {
+                match kind {
+                    $(IntegerKind::$variant => Self::$variant(unsafe { copy_elements::<$ty>(data, len) })),+
+                }
+            };
+        }
+        with_integer_kinds!(dispatch)
+    }
+
+    /// Copies `len` contiguous floats of `kind` starting at `data`.
+    ///
+    /// # Safety
+    ///
+    /// When `len` is non-zero, `data` must point to `len` initialized
+    /// elements of `kind`.
+    #[must_use]
+    pub unsafe fn from_floats(kind: FloatKind, data: *const c_void, len: usize) -> Self {
+        match kind {
+            FloatKind::F32 => Self::F32(unsafe { copy_elements(data, len) }),
+            FloatKind::F64 => Self::F64(unsafe { copy_elements(data, len) }),
+        }
+    }
+
+    /// The integer element type, or `None` for a float buffer.
+    #[must_use]
+    pub fn integer_kind(&self) -> Option<IntegerKind> {
+        Some(match self {
+            Self::U8(_) => IntegerKind::U8,
+            Self::I8(_) => IntegerKind::I8,
+            Self::U16(_) => IntegerKind::U16,
+            Self::I16(_) => IntegerKind::I16,
+            Self::U32(_) => IntegerKind::U32,
+            Self::I32(_) => IntegerKind::I32,
+            Self::U64(_) => IntegerKind::U64,
+            Self::I64(_) => IntegerKind::I64,
+            Self::F32(_) | Self::F64(_) => return None,
+        })
+    }
+
+    /// The float element type, or `None` for an integer buffer.
+    #[must_use]
+    pub fn float_kind(&self) -> Option<FloatKind> {
+        match self {
+            Self::F32(_) => Some(FloatKind::F32),
+            Self::F64(_) => Some(FloatKind::F64),
+            _ => None,
+        }
+    }
+
+    /// The JavaScript constructor name matching this buffer's element type.
+    #[must_use]
+    pub fn js_type_name(&self) -> &'static str {
+        match self {
+            Self::U8(_) => "Uint8Array",
+            Self::I8(_) => "Int8Array",
+            Self::U16(_) => "Uint16Array",
+            Self::I16(_) => "Int16Array",
+            Self::U32(_) => "Uint32Array",
+            Self::I32(_) => "Int32Array",
+            Self::U64(_) => "BigUint64Array",
+            Self::I64(_) => "BigInt64Array",
+            Self::F32(_) => "Float32Array",
+            Self::F64(_) => "Float64Array",
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        with_typed_buffer!(self, data => data.len())
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn byte_len(&self) -> usize {
+        with_typed_buffer!(self, data => size_of_val(data.as_slice()))
+    }
+
+    /// Pointer to the first element, valid for as long as any clone of this
+    /// buffer is alive.
+    #[must_use]
+    pub fn as_ptr(&self) -> *const c_void {
+        with_typed_buffer!(self, data => data.as_ptr().cast())
+    }
+
+    /// Reads a JS `TypedArray` or `ArrayBuffer`, or returns `None` when
+    /// `value` is neither.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value(env: &Env, value: &Unknown<'_>) -> napi::Result<Option<Self>> {
+        let raw = unsafe { value.raw() };
+        let mut is_typed_array = false;
+        check_napi(unsafe { sys::napi_is_typedarray(env.raw(), raw, &mut is_typed_array) })?;
+        if is_typed_array {
+            let mut array_type = 0;
+            let mut len = 0;
+            let mut data = std::ptr::null_mut();
+            check_napi(unsafe {
+                sys::napi_get_typedarray_info(
+                    env.raw(),
+                    raw,
+                    &mut array_type,
+                    &mut len,
+                    &mut data,
+                    std::ptr::null_mut(),
+                    std::ptr::null_mut(),
+                )
+            })?;
+            let data = data.cast_const();
+            let buffer = unsafe {
+                match array_type {
+                    sys::TypedarrayType::int8_array => Self::I8(copy_elements(data, len)),
+                    sys::TypedarrayType::uint8_array | sys::TypedarrayType::uint8_clamped_array => {
+                        Self::U8(copy_elements(data, len))
+                    }
+                    sys::TypedarrayType::int16_array => Self::I16(copy_elements(data, len)),
+                    sys::TypedarrayType::uint16_array => Self::U16(copy_elements(data, len)),
+                    sys::TypedarrayType::int32_array => Self::I32(copy_elements(data, len)),
+                    sys::TypedarrayType::uint32_array => Self::U32(copy_elements(data, len)),
+                    sys::TypedarrayType::float32_array => Self::F32(copy_elements(data, len)),
+                    sys::TypedarrayType::float64_array => Self::F64(copy_elements(data, len)),
+                    sys::TypedarrayType::bigint64_array => Self::I64(copy_elements(data, len)),
+                    sys::TypedarrayType::biguint64_array => Self::U64(copy_elements(data, len)),
+                    other => {
+                        return Err(napi::Error::new(
+                            napi::Status::InvalidArg,
+                            format!("Unsupported TypedArray type: {other}"),
+                        ));
+                    }
+                }
+            };
+            return Ok(Some(buffer));
+        }
+
+        let mut is_array_buffer = false;
+        check_napi(unsafe { sys::napi_is_arraybuffer(env.raw(), raw, &mut is_array_buffer) })?;
+        if is_array_buffer {
+            let mut len = 0;
+            let mut data = std::ptr::null_mut();
+            check_napi(unsafe {
+                sys::napi_get_arraybuffer_info(env.raw(), raw, &mut data, &mut len)
+            })?;
+            return Ok(Some(Self::U8(unsafe {
+                copy_elements(data.cast_const(), len)
+            })));
+        }
+
+        Ok(None)
+    }
+
+    /// Converts the buffer into the matching JS `TypedArray`, handing the
+    /// allocation over without a copy when this is its only owner.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn to_js_value(self, env: &Env) -> napi::Result<Unknown<'_>> {
+        fn take<T: Clone>(data: Arc<Vec<T>>) -> Vec<T> {
+            Arc::try_unwrap(data).unwrap_or_else(|shared| (*shared).clone())
+        }
+
+        let raw = unsafe {
+            match self {
+                Self::U8(data) => Uint8Array::to_napi_value(env.raw(), Uint8Array::new(take(data))),
+                Self::I8(data) => Int8Array::to_napi_value(env.raw(), Int8Array::new(take(data))),
+                Self::U16(data) => {
+                    Uint16Array::to_napi_value(env.raw(), Uint16Array::new(take(data)))
+                }
+                Self::I16(data) => {
+                    Int16Array::to_napi_value(env.raw(), Int16Array::new(take(data)))
+                }
+                Self::U32(data) => {
+                    Uint32Array::to_napi_value(env.raw(), Uint32Array::new(take(data)))
+                }
+                Self::I32(data) => {
+                    Int32Array::to_napi_value(env.raw(), Int32Array::new(take(data)))
+                }
+                Self::U64(data) => {
+                    BigUint64Array::to_napi_value(env.raw(), BigUint64Array::new(take(data)))
+                }
+                Self::I64(data) => {
+                    BigInt64Array::to_napi_value(env.raw(), BigInt64Array::new(take(data)))
+                }
+                Self::F32(data) => {
+                    Float32Array::to_napi_value(env.raw(), Float32Array::new(take(data)))
+                }
+                Self::F64(data) => {
+                    Float64Array::to_napi_value(env.raw(), Float64Array::new(take(data)))
+                }
+            }?
+        };
+        Ok(unsafe { Unknown::from_raw_unchecked(env.raw(), raw) })
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn check_napi(status: sys::napi_status) -> napi::Result<()> {
+    if status == sys::Status::napi_ok {
+        Ok(())
+    } else {
+        Err(napi::Error::new(
+            napi::Status::GenericFailure,
+            "Failed to read TypedArray contents",
+        ))
+    }
+}
+
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum Value {
     Number(f64),
+    /// A JavaScript `BigInt`, wide enough to hold every `i64` and `u64`.
+    BigInt(i128),
     String(String),
     Boolean(bool),
     Object(NativeHandle),
     Null,
     Undefined,
     Array(Vec<Self>),
+    /// A JavaScript `TypedArray` or `ArrayBuffer`, kept as one contiguous
+    /// buffer instead of per-element values.
+    TypedArray(TypedBuffer),
     Callback(Callback),
     Ref(Ref),
+    Variant(glib::Variant),
+    /// A `GBytes`, which reaches JavaScript as a Node `Buffer`. With
+    /// `zero_copy` the `Buffer` views the `GBytes` memory instead of a copy.
+    Bytes {
+        bytes: glib::Bytes,
+        zero_copy: bool,
+    },
+    /// A C struct decoded field by field, which reaches JavaScript as a plain
+    /// object. Fields keep the order of their record descriptor.
+    Record(Vec<(String, Self)>),
 }
 
 impl Value {
@@ -211,6 +521,8 @@ impl Value {
     pub fn result_to_ptr(result: &std::result::Result<Self, ()>) -> *mut c_void {
         match result {
             Ok(Self::Object(handle)) => handle.ptr(),
+            Ok(Self::Variant(variant)) => variant.as_ptr().cast(),
+            Ok(Self::Bytes { bytes, .. }) => bytes.as_ptr().cast(),
             _ => std::ptr::null_mut(),
         }
     }
@@ -228,13 +540,18 @@ impl Value {
     pub fn object_ptr(&self, type_name: &str) -> anyhow::Result<*mut c_void> {
         match self {
             Self::Object(handle) => Ok(handle.ptr()),
+            Self::Variant(variant) => Ok(variant.as_ptr().cast()),
+            Self::Bytes { bytes, .. } => Ok(bytes.as_ptr().cast()),
             Self::Null | Self::Undefined => Ok(std::ptr::null_mut()),
             Self::Number(_)
+            | Self::BigInt(_)
             | Self::String(_)
             | Self::Boolean(_)
             | Self::Array(_)
+            | Self::TypedArray(_)
             | Self::Callback(_)
-            | Self::Ref(_) => {
+            | Self::Ref(_)
+            | Self::Record(_) => {
                 anyhow::bail!("Expected an Object for {type_name} type, got {self:?}")
             }
         }
@@ -257,7 +574,8 @@ impl Value {
                 let default = match ty {
                     Type::Boolean(_) => Self::Boolean(false),
                     Type::Integer(_) | Type::Tagged(_) | Type::Float(_) => Self::Number(0.0),
-                    Type::String(_) | Type::GObject(_) => Self::Null,
+                    Type::BigInt(_) => Self::BigInt(0),
+                    Type::String(_) | Type::GObject(_) | Type::Bytes(_) => Self::Null,
                     _ => return None,
                 };
                 match ty.to_glib_value(&default) {
@@ -270,13 +588,18 @@ impl Value {
                 }
             }
             Self::Number(_)
+            | Self::BigInt(_)
             | Self::String(_)
             | Self::Boolean(_)
             | Self::Object(_)
             | Self::Null
             | Self::Array(_)
+            | Self::TypedArray(_)
             | Self::Callback(_)
-            | Self::Ref(_) => match self.to_glib_value_typed(return_type) {
+            | Self::Ref(_)
+            | Self::Variant(_)
+            | Self::Bytes { .. }
+            | Self::Record(_) => match self.to_glib_value_typed(return_type) {
                 Ok(v) => Some(v),
                 Err(e) => {
                     NativeErrorReporter::global()
@@ -299,6 +622,10 @@ impl Value {
         }
         match self {
             Self::Number(n) => Ok(n.into()),
+            Self::BigInt(n) => match i64::try_from(n) {
+                Ok(n) => Ok(n.into()),
+                Err(_) => Ok(u64::try_from(n)?.into()),
+            },
             Self::String(s) => Ok(s.into()),
             Self::Boolean(b) => Ok(b.into()),
             Self::Object(handle) => {
@@ -312,10 +639,16 @@ impl Value {
                     Ok(obj.to_value())
                 }
             }
+            Self::Variant(variant) => Ok(variant.to_value()),
+            Self::Bytes { bytes, .. } => Ok(bytes.to_value()),
             Self::Null | Self::Undefined => {
                 bail!("Cannot convert Null/Undefined to glib::Value without a type hint")
             }
-            Self::Array(_) | Self::Callback(_) | Self::Ref(_) => {
+            Self::Array(_)
+            | Self::TypedArray(_)
+            | Self::Callback(_)
+            | Self::Ref(_)
+            | Self::Record(_) => {
                 bail!("Unsupported Value type for glib::Value conversion: {self:?}")
             }
         }
@@ -330,6 +663,16 @@ impl Value {
                 let n = unsafe { f64::from_napi_value(env.raw(), value.raw())? };
                 Ok(Self::Number(n))
             }
+            ValueType::BigInt => {
+                let bigint = unsafe { BigInt::from_napi_value(env.raw(), value.raw())? };
+                match bigint.get_i128() {
+                    (n, true) => Ok(Self::BigInt(n)),
+                    _ => Err(napi::Error::new(
+                        napi::Status::InvalidArg,
+                        "BigInt value does not fit in 128 bits",
+                    )),
+                }
+            }
             ValueType::String => {
                 let s = unsafe { String::from_napi_value(env.raw(), value.raw())? };
                 Ok(Self::String(s))
@@ -350,7 +693,9 @@ impl Value {
                 Ok(Self::Callback(cb))
             }
             ValueType::Object => {
-                if value.is_array()? {
+                if let Some(buffer) = TypedBuffer::from_js_value(env, &value)? {
+                    Ok(Self::TypedArray(buffer))
+                } else if value.is_array()? {
                     let arr: Array = unsafe { Array::from_napi_value(env.raw(), value.raw())? };
                     Ok(Self::Array(map_js_array(env, &arr, Self::from_js_value)?))
                 } else {
@@ -365,6 +710,43 @@ impl Value {
         }
     }
 
+    /// Converts a JavaScript value into a [`Value`] shaped by its descriptor.
+    ///
+    /// Variant-typed values are built into a [`glib::Variant`] from the
+    /// descriptor's signature, and record-typed objects and union-typed
+    /// `{ tag, value }` objects into a [`Value::Record`]. The items of an array and the value of a `Ref` are
+    /// converted by their inner type, so records and variants nest inside
+    /// them, as is the value of a `GValue` with a `valueType`. Every other
+    /// type goes through [`Value::from_js_value`].
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_js_value_typed(env: &Env, value: Unknown<'_>, ty: &Type) -> napi::Result<Self> {
+        match ty {
+            Type::Variant(variant_type) => variant_type.value_from_js(env, value),
+            Type::Record(record_type) => record_type.value_from_js(env, value),
+            Type::Union(union_type) => union_type.value_from_js(env, value),
+            Type::GValue(gvalue_type) => gvalue_type.value_type.as_deref().map_or_else(
+                || Self::from_js_value(env, value),
+                |inner_type| Self::from_js_value_typed(env, value, inner_type),
+            ),
+            Type::Array(array_type) if value.is_array()? => {
+                let arr: Array = unsafe { Array::from_napi_value(env.raw(), value.raw())? };
+                Ok(Self::Array(map_js_array(env, &arr, |env, item| {
+                    Self::from_js_value_typed(env, item, &array_type.item_type)
+                })?))
+            }
+            Type::Ref(ref_type)
+                if value.get_type()? == ValueType::Object && !value.is_array()? =>
+            {
+                Ok(Self::Ref(Ref::from_js_value_typed(
+                    env,
+                    value,
+                    &ref_type.inner_type,
+                )?))
+            }
+            _ => Self::from_js_value(env, value),
+        }
+    }
+
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub fn to_js_value(self, env: &Env) -> napi::Result<Unknown<'_>> {
         match self {
@@ -372,6 +754,10 @@ impl Value {
                 let raw = f64::to_napi_value(env.raw(), n)?;
                 Ok(Unknown::from_raw_unchecked(env.raw(), raw))
             },
+            Self::BigInt(n) => unsafe {
+                let raw = BigInt::to_napi_value(env.raw(), BigInt::from(n))?;
+                Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+            },
             Self::String(s) => unsafe {
                 let raw = String::to_napi_value(env.raw(), s)?;
                 Ok(Unknown::from_raw_unchecked(env.raw(), raw))
@@ -404,6 +790,19 @@ impl Value {
                 let raw = napi::bindgen_prelude::Undefined::to_napi_value(env.raw(), ())?;
                 Ok(Unknown::from_raw_unchecked(env.raw(), raw))
             },
+            Self::TypedArray(buffer) => buffer.to_js_value(env),
+            Self::Variant(variant) => variant_to_js(env, &variant),
+            Self::Bytes { bytes, zero_copy } => bytes_to_js(env, bytes, zero_copy),
+            Self::Record(fields) => {
+                let mut object = Object::new(env)?;
+                for (name, value) in fields {
+                    object.set_named_property(&name, value.to_js_value(env)?)?;
+                }
+                unsafe {
+                    let raw = Object::to_napi_value(env.raw(), object)?;
+                    Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+                }
+            }
             Self::Callback(_) | Self::Ref(_) => Err(napi::Error::new(
                 napi::Status::InvalidArg,
                 format!("Unsupported Value type for JS conversion: {self:?}"),
@@ -15,11 +15,11 @@ use native::types::{
 use native::value::Value;
 
 fn struct_item_type() -> Type {
-    Type::Struct(StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_string(),
-        size: Some(size_of::<gtk4::gdk::ffi::GdkRGBA>()),
-    })
+    Type::Struct(StructType::new(
+        Ownership::Borrowed,
+        "TestStruct".to_string(),
+        Some(size_of::<gtk4::gdk::ffi::GdkRGBA>()),
+    ))
 }
 
 fn string_item_type(ownership: Ownership) -> Type {
@@ -44,6 +44,7 @@ fn array_type(item: Type, kind: ArrayKind, ownership: Ownership) -> ArrayType {
         kind,
         ownership,
         element_size: None,
+        typed: false,
     }
 }
 
@@ -74,6 +75,10 @@ fn array_kind_from_str_parses_every_variant() {
         "fixed".parse::<ArrayKind>().unwrap(),
         ArrayKind::Fixed { size: 0 }
     );
+    assert_eq!(
+        "inline".parse::<ArrayKind>().unwrap(),
+        ArrayKind::Inline { size: 0 }
+    );
     assert!("bogus".parse::<ArrayKind>().is_err());
 }
 
@@ -1391,3 +1396,74 @@ fn trait_methods_delegate_to_inherent_implementations() {
         assert!(matches!(from_ptr, Value::Array(items) if items.len() == 1));
     });
 }
+
+#[test]
+fn inline_array_reads_elements_in_place() {
+    let ty = array_type(
+        Type::Float(FloatKind::F64),
+        ArrayKind::Inline { size: 3 },
+        Ownership::Borrowed,
+    );
+    let matrix = [1.5f64, -2.0, 4.25];
+
+    let value = ty
+        .read_from_raw_ptr(matrix.as_ptr() as *const c_void, "ctx")
+        .unwrap();
+    let Value::Array(items) = value else {
+        panic!("expected Value::Array, got {value:?}");
+    };
+    let items: Vec<f64> = items.iter().map(|v| v.as_number().unwrap()).collect();
+    assert_eq!(items, matrix);
+}
+
+#[test]
+fn inline_array_writes_elements_in_place() {
+    let mut ty = array_type(
+        Type::Integer(IntegerKind::U8),
+        ArrayKind::Inline { size: 4 },
+        Ownership::Borrowed,
+    );
+    let mut name = [b'x'; 4];
+
+    let value = Value::Array(vec![Value::Number(104.0), Value::Number(105.0)]);
+    ty.write_value_to_raw_ptr(name.as_mut_ptr() as *mut c_void, &value)
+        .unwrap();
+    assert_eq!(&name, b"hixx");
+
+    ty.element_size = Some(2);
+    let value = Value::Array(vec![Value::Number(0.0), Value::Number(0.0)]);
+    ty.write_value_to_raw_ptr(name.as_mut_ptr() as *mut c_void, &value)
+        .unwrap();
+    assert_eq!(&name, b"\0i\0x");
+}
+
+#[test]
+fn inline_array_write_rejects_too_many_elements() {
+    let ty = array_type(
+        Type::Integer(IntegerKind::I32),
+        ArrayKind::Inline { size: 1 },
+        Ownership::Borrowed,
+    );
+    let mut slot = [0i32; 2];
+    let value = Value::Array(vec![Value::Number(1.0), Value::Number(2.0)]);
+
+    let err = ty
+        .write_value_to_raw_ptr(slot.as_mut_ptr() as *mut c_void, &value)
+        .unwrap_err();
+    assert!(err.to_string().contains("cannot hold 2 elements"));
+    assert_eq!(slot, [0, 0]);
+}
+
+#[test]
+fn only_inline_arrays_can_be_written_to_a_raw_pointer() {
+    let ty = array_type(
+        Type::Integer(IntegerKind::I32),
+        ArrayKind::Fixed { size: 1 },
+        Ownership::Borrowed,
+    );
+    let mut slot = 0i32;
+    let err = ty
+        .write_value_to_raw_ptr((&raw mut slot).cast(), &Value::Array(Vec::new()))
+        .unwrap_err();
+    assert!(err.to_string().contains("Only inline arrays"));
+}
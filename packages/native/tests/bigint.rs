@@ -0,0 +1,258 @@
+//! Coverage tests for [`native::types::BigIntKind`] and its lossless
+//! round trips through arrays, refs and hash tables.
+
+mod common;
+
+use std::ffi::c_void;
+
+use libffi::middle;
+use native::ffi;
+use native::types::{
+    ArrayKind, ArrayType, BigIntKind, FfiDecoder, FfiEncoder, GlibValueCodec, HashTableType,
+    IntegerKind, Ownership, RawPtrCodec, RefType, Type,
+};
+use native::value::Value;
+
+const BEYOND_SAFE: i128 = 9_007_199_254_740_993;
+
+fn expect_bigint(value: &Value) -> i128 {
+    match value {
+        Value::BigInt(n) => *n,
+        other => panic!("expected Value::BigInt, got {other:?}"),
+    }
+}
+
+#[test]
+fn encode_is_lossless_at_the_64_bit_limits() {
+    let max = FfiEncoder::encode(&BigIntKind::U64, &Value::BigInt(u64::MAX.into()), false).unwrap();
+    assert!(matches!(max, ffi::FfiValue::U64(u64::MAX)));
+
+    let min = FfiEncoder::encode(&BigIntKind::I64, &Value::BigInt(i64::MIN.into()), false).unwrap();
+    assert!(matches!(min, ffi::FfiValue::I64(i64::MIN)));
+}
+
+#[test]
+fn encode_accepts_safe_numbers_and_optional_null() {
+    let from_number = FfiEncoder::encode(&BigIntKind::I64, &Value::Number(-5.0), false).unwrap();
+    assert!(matches!(from_number, ffi::FfiValue::I64(-5)));
+
+    let optional = FfiEncoder::encode(&BigIntKind::U64, &Value::Null, true).unwrap();
+    assert!(matches!(optional, ffi::FfiValue::U64(0)));
+}
+
+#[test]
+fn encode_rejects_out_of_range_and_unsafe_values() {
+    let negative = FfiEncoder::encode(&BigIntKind::U64, &Value::BigInt(-1), false);
+    assert!(
+        negative
+            .unwrap_err()
+            .to_string()
+            .contains("out of range for u64")
+    );
+
+    let too_big = FfiEncoder::encode(
+        &BigIntKind::I64,
+        &Value::BigInt(i64::MAX as i128 + 1),
+        false,
+    );
+    assert!(too_big.is_err());
+
+    assert!(FfiEncoder::encode(&BigIntKind::U64, &Value::Number(1e300), false).is_err());
+    assert!(FfiEncoder::encode(&BigIntKind::U64, &Value::Boolean(true), false).is_err());
+}
+
+#[test]
+fn decode_preserves_values_beyond_safe_integers() {
+    let decoded = FfiDecoder::decode(&BigIntKind::U64, &ffi::FfiValue::U64(u64::MAX)).unwrap();
+    assert_eq!(expect_bigint(&decoded), u64::MAX.into());
+
+    let signed =
+        FfiDecoder::decode(&BigIntKind::I64, &ffi::FfiValue::I64(-(BEYOND_SAFE as i64))).unwrap();
+    assert_eq!(expect_bigint(&signed), -BEYOND_SAFE);
+
+    assert!(FfiDecoder::decode(&BigIntKind::I64, &ffi::FfiValue::Void).is_err());
+}
+
+extern "C" fn ret_u64_max() -> u64 {
+    u64::MAX
+}
+
+#[test]
+fn call_cif_returns_the_full_width() {
+    let kind = BigIntKind::U64;
+    let cif = middle::Cif::new(Vec::new(), kind.ffi_type());
+    let code = ret_u64_max as *mut c_void;
+    let result = FfiEncoder::call_cif(&kind, &cif, middle::CodePtr(code), &[]).unwrap();
+    assert!(matches!(result, ffi::FfiValue::U64(u64::MAX)));
+}
+
+#[test]
+fn raw_ptr_codec_round_trips() {
+    let kind = BigIntKind::I64;
+    let mut slot: i64 = 0;
+    let ptr = (&raw mut slot).cast::<c_void>();
+
+    kind.write_value_to_raw_ptr(ptr, &Value::BigInt(BEYOND_SAFE))
+        .unwrap();
+    assert_eq!(slot, BEYOND_SAFE as i64);
+    let read = kind.read_from_raw_ptr(ptr.cast_const(), "ctx").unwrap();
+    assert_eq!(expect_bigint(&read), BEYOND_SAFE);
+
+    assert!(
+        BigIntKind::U64
+            .write_value_to_raw_ptr(ptr, &Value::BigInt(-1))
+            .is_err()
+    );
+
+    kind.write_return_to_raw_ptr(ptr, &Err(()));
+    assert_eq!(slot, 0);
+
+    let from_ptr = BigIntKind::U64
+        .ptr_to_value(usize::MAX as *mut c_void, "ctx")
+        .unwrap();
+    assert_eq!(expect_bigint(&from_ptr), u64::MAX.into());
+}
+
+#[test]
+fn return_writes_zero_for_out_of_range_and_wrong_type_values() {
+    let mut slot: u64 = 7;
+    let ptr = (&raw mut slot).cast::<c_void>();
+
+    BigIntKind::U64.write_return_to_raw_ptr(ptr, &Ok(Value::BigInt(u64::MAX.into())));
+    assert_eq!(slot, u64::MAX);
+
+    BigIntKind::U64.write_return_to_raw_ptr(ptr, &Ok(Value::BigInt(-1)));
+    assert_eq!(slot, 0);
+
+    slot = 7;
+    BigIntKind::I64.write_return_to_raw_ptr(ptr, &Ok(Value::BigInt(i128::from(i64::MAX) + 1)));
+    assert_eq!(slot, 0);
+
+    slot = 7;
+    BigIntKind::U64.write_return_to_raw_ptr(ptr, &Ok(Value::Boolean(true)));
+    assert_eq!(slot, 0);
+
+    slot = 7;
+    BigIntKind::U64.write_return_to_raw_ptr(ptr, &Ok(Value::Number(0.5)));
+    assert_eq!(slot, 0);
+}
+
+#[test]
+fn glib_value_round_trips() {
+    common::run(|| {
+        let gvalue = BigIntKind::U64
+            .to_glib_value(&Value::BigInt(u64::MAX.into()))
+            .unwrap()
+            .expect("a bigint converts to a GValue");
+        let decoded = BigIntKind::U64.from_glib_value(&gvalue).unwrap();
+        assert_eq!(expect_bigint(&decoded), u64::MAX.into());
+
+        assert!(
+            BigIntKind::I64
+                .to_glib_value(&Value::String("1".into()))
+                .unwrap()
+                .is_none()
+        );
+    });
+}
+
+#[test]
+fn integer_kinds_accept_bigint_values() {
+    let encoded = FfiEncoder::encode(&IntegerKind::I32, &Value::BigInt(-3), false).unwrap();
+    assert!(matches!(encoded, ffi::FfiValue::I32(-3)));
+}
+
+#[test]
+fn array_storage_round_trips() {
+    let array_type = ArrayType {
+        item_type: Box::new(Type::BigInt(BigIntKind::U64)),
+        kind: ArrayKind::Array,
+        ownership: Ownership::Borrowed,
+        element_size: None,
+        typed: false,
+    };
+    let values = Value::Array(vec![
+        Value::BigInt(u64::MAX.into()),
+        Value::BigInt(BEYOND_SAFE),
+    ]);
+
+    let encoded = array_type.encode(&values, false).unwrap();
+    let decoded = array_type.decode(&encoded).unwrap();
+    let Value::Array(items) = decoded else {
+        panic!("expected Value::Array");
+    };
+    assert_eq!(expect_bigint(&items[0]), u64::MAX.into());
+    assert_eq!(expect_bigint(&items[1]), BEYOND_SAFE);
+}
+
+#[test]
+fn array_rejects_out_of_range_elements() {
+    let array_type = ArrayType {
+        item_type: Box::new(Type::BigInt(BigIntKind::I64)),
+        kind: ArrayKind::Array,
+        ownership: Ownership::Borrowed,
+        element_size: None,
+        typed: false,
+    };
+    let values = Value::Array(vec![Value::BigInt(0), Value::BigInt(u64::MAX.into())]);
+    let err = array_type.encode(&values, false).unwrap_err();
+    assert!(err.to_string().contains("element 1"));
+}
+
+#[test]
+fn garray_round_trips() {
+    common::run(|| {
+        let array_type = ArrayType {
+            item_type: Box::new(Type::BigInt(BigIntKind::I64)),
+            kind: ArrayKind::GArray,
+            ownership: Ownership::Borrowed,
+            element_size: None,
+            typed: false,
+        };
+        let values = Value::Array(vec![Value::BigInt(-BEYOND_SAFE), Value::BigInt(1)]);
+
+        let encoded = array_type.encode(&values, false).unwrap();
+        let Value::Array(items) = array_type.decode(&encoded).unwrap() else {
+            panic!("expected Value::Array");
+        };
+        assert_eq!(expect_bigint(&items[0]), -BEYOND_SAFE);
+        assert_eq!(expect_bigint(&items[1]), 1);
+    });
+}
+
+#[test]
+fn ref_out_parameter_decodes_a_bigint() {
+    let ref_type = RefType::new(Type::BigInt(BigIntKind::U64));
+    let storage = ffi::FfiStorage::from(vec![u64::MAX]);
+    let decoded = ref_type.decode(&ffi::FfiValue::Storage(storage)).unwrap();
+    assert_eq!(expect_bigint(&decoded), u64::MAX.into());
+}
+
+#[test]
+fn hash_table_round_trips_bigint_values() {
+    common::run(|| {
+        let table_type = HashTableType {
+            key_type: Box::new(Type::Integer(IntegerKind::I32)),
+            value_type: Box::new(Type::BigInt(BigIntKind::U64)),
+            ownership: Ownership::Borrowed,
+        };
+        let entries = Value::Array(vec![Value::Array(vec![
+            Value::Number(1.0),
+            Value::BigInt(u64::MAX.into()),
+        ])]);
+
+        let encoded = table_type.encode(&entries, false).unwrap();
+        let Value::Array(pairs) = table_type.decode(&encoded).unwrap() else {
+            panic!("expected Value::Array");
+        };
+        let Value::Array(pair) = &pairs[0] else {
+            panic!("expected a [key, value] pair");
+        };
+        assert_eq!(expect_bigint(&pair[1]), u64::MAX.into());
+    });
+}
+
+#[test]
+fn display_names_the_kind() {
+    assert_eq!(Type::BigInt(BigIntKind::U64).to_string(), "BigInt(U64)");
+}
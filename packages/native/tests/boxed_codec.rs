@@ -22,20 +22,11 @@ fn rgba_type_name() -> String {
 }
 
 fn boxed(ownership: Ownership) -> BoxedType {
-    BoxedType {
-        ownership,
-        type_name: rgba_type_name(),
-        library: None,
-        get_type_fn: None,
-    }
+    BoxedType::new(ownership, rgba_type_name(), None, None)
 }
 
 fn struct_type(ownership: Ownership, size: Option<usize>) -> StructType {
-    StructType {
-        ownership,
-        type_name: "PlainStruct".to_owned(),
-        size,
-    }
+    StructType::new(ownership, "PlainStruct".to_owned(), size)
 }
 
 #[test]
@@ -49,12 +40,12 @@ fn gtype_resolves_from_registered_name() {
 #[test]
 fn gtype_resolves_via_library_lookup() {
     common::run(|| {
-        let bytes_type = BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "GBytes".to_owned(),
-            library: Some("libgobject-2.0.so.0".to_owned()),
-            get_type_fn: Some("g_bytes_get_type".to_owned()),
-        };
+        let bytes_type = BoxedType::new(
+            Ownership::Borrowed,
+            "GBytes".to_owned(),
+            Some("libgobject-2.0.so.0".to_owned()),
+            Some("g_bytes_get_type".to_owned()),
+        );
         let resolved = bytes_type.gtype();
         assert_eq!(resolved, Some(glib::Bytes::static_type()));
     });
@@ -63,12 +54,12 @@ fn gtype_resolves_via_library_lookup() {
 #[test]
 fn gtype_unknown_without_library_yields_none() {
     common::run(|| {
-        let unknown = BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "CompletelyUnknownBoxed".to_owned(),
-            library: None,
-            get_type_fn: None,
-        };
+        let unknown = BoxedType::new(
+            Ownership::Borrowed,
+            "CompletelyUnknownBoxed".to_owned(),
+            None,
+            None,
+        );
         assert!(unknown.gtype().is_none());
     });
 }
@@ -76,12 +67,12 @@ fn gtype_unknown_without_library_yields_none() {
 #[test]
 fn gtype_unknown_with_library_but_no_get_type_fn_yields_none() {
     common::run(|| {
-        let unknown = BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "AnotherUnknownBoxed".to_owned(),
-            library: Some("libgobject-2.0.so.0".to_owned()),
-            get_type_fn: None,
-        };
+        let unknown = BoxedType::new(
+            Ownership::Borrowed,
+            "AnotherUnknownBoxed".to_owned(),
+            Some("libgobject-2.0.so.0".to_owned()),
+            None,
+        );
         assert!(unknown.gtype().is_none());
     });
 }
@@ -89,12 +80,12 @@ fn gtype_unknown_with_library_but_no_get_type_fn_yields_none() {
 #[test]
 fn gtype_with_missing_symbol_reports_error_and_yields_none() {
     common::run(|| {
-        let bad = BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "BadSymbolBoxed".to_owned(),
-            library: Some("libgobject-2.0.so.0".to_owned()),
-            get_type_fn: Some("definitely_not_a_real_symbol_xyz".to_owned()),
-        };
+        let bad = BoxedType::new(
+            Ownership::Borrowed,
+            "BadSymbolBoxed".to_owned(),
+            Some("libgobject-2.0.so.0".to_owned()),
+            Some("definitely_not_a_real_symbol_xyz".to_owned()),
+        );
         assert!(bad.gtype().is_none());
     });
 }
@@ -228,12 +219,12 @@ fn decode_borrowed_copies_boxed() {
 fn decode_borrowed_unknown_gtype_bails() {
     common::run(|| {
         let raw = unsafe { glib::ffi::g_malloc0(64) };
-        let unknown = BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "DecodeUnknownBoxed".to_owned(),
-            library: None,
-            get_type_fn: None,
-        };
+        let unknown = BoxedType::new(
+            Ownership::Borrowed,
+            "DecodeUnknownBoxed".to_owned(),
+            None,
+            None,
+        );
         let result = unknown.decode(&ffi::FfiValue::Ptr(raw));
         assert!(result.is_err());
 
@@ -386,12 +377,12 @@ fn to_glib_value_unknown_gtype_yields_none() {
     common::run(|| {
         let gtype = gdk::RGBA::static_type();
         let original = common::allocate_test_boxed(gtype);
-        let unknown = BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "GtypeUnknownBoxed".to_owned(),
-            library: None,
-            get_type_fn: None,
-        };
+        let unknown = BoxedType::new(
+            Ownership::Borrowed,
+            "GtypeUnknownBoxed".to_owned(),
+            None,
+            None,
+        );
         let result = unknown
             .to_glib_value(&Value::Object(NativeHandle::borrowed(original)))
             .expect("to_glib_value should succeed");
@@ -0,0 +1,187 @@
+//! Coverage tests for [`native::types::BytesType`] codec implementations.
+
+mod common;
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use gtk4::glib::{
+    self,
+    translate::{IntoGlibPtr as _, ToGlibPtr as _},
+};
+
+use native::ffi::{self, FfiStorageKind};
+use native::managed::NativeHandle;
+use native::types::{
+    BytesType, FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec, Type,
+};
+use native::value::{TypedBuffer, Value};
+
+fn expect_bytes(value: Value) -> (glib::Bytes, bool) {
+    match value {
+        Value::Bytes { bytes, zero_copy } => (bytes, zero_copy),
+        other => panic!("expected Value::Bytes, got {other:?}"),
+    }
+}
+
+fn js_bytes(data: &[u8]) -> Value {
+    Value::TypedArray(TypedBuffer::U8(Arc::new(data.to_vec())))
+}
+
+#[test]
+fn encode_borrowed_shares_the_js_buffer() {
+    let data = Arc::new(vec![1u8, 2, 3]);
+    let value = Value::TypedArray(TypedBuffer::U8(Arc::clone(&data)));
+
+    let encoded = BytesType::new(Ownership::Borrowed, false)
+        .encode(&value, false)
+        .unwrap();
+    let ffi::FfiValue::Storage(storage) = &encoded else {
+        panic!("expected FfiValue::Storage, got {encoded:?}");
+    };
+    let FfiStorageKind::GBytes(bytes) = storage.kind() else {
+        panic!("expected GBytes storage");
+    };
+    assert_eq!(storage.ptr(), bytes.as_ptr().cast::<c_void>());
+    assert_eq!(&**bytes, &[1, 2, 3]);
+    assert_eq!(bytes.as_ptr_range().start, data.as_ptr());
+}
+
+#[test]
+fn encode_full_hands_over_a_reference() {
+    let encoded = BytesType::new(Ownership::Full, false)
+        .encode(&js_bytes(b"abc"), false)
+        .unwrap();
+    let ffi::FfiValue::Ptr(ptr) = encoded else {
+        panic!("expected FfiValue::Ptr, got {encoded:?}");
+    };
+    let bytes: glib::Bytes =
+        unsafe { glib::translate::from_glib_full(ptr.cast::<glib::ffi::GBytes>()) };
+    assert_eq!(&*bytes, b"abc");
+}
+
+#[test]
+fn encode_accepts_native_handles_and_null() {
+    let bytes = glib::Bytes::from_static(b"native");
+    let handle = NativeHandle::borrowed(bytes.to_glib_none().0.cast());
+
+    let encoded = BytesType::new(Ownership::Borrowed, false)
+        .encode(&Value::Object(handle), false)
+        .unwrap();
+    let ffi::FfiValue::Storage(storage) = &encoded else {
+        panic!("expected FfiValue::Storage, got {encoded:?}");
+    };
+    assert_eq!(storage.ptr(), bytes.as_ptr().cast::<c_void>());
+
+    let null = BytesType::new(Ownership::Full, false)
+        .encode(&Value::Null, true)
+        .unwrap();
+    assert!(matches!(null, ffi::FfiValue::Ptr(ptr) if ptr.is_null()));
+}
+
+#[test]
+fn encode_rejects_non_byte_values() {
+    let bytes_type = BytesType::new(Ownership::Borrowed, false);
+
+    let wide = Value::TypedArray(TypedBuffer::U16(Arc::new(vec![1])));
+    let err = bytes_type.encode(&wide, false).unwrap_err();
+    assert!(err.to_string().contains("Uint16Array"));
+
+    assert!(
+        bytes_type
+            .encode(&Value::String("abc".into()), false)
+            .is_err()
+    );
+}
+
+#[test]
+fn decode_honors_ownership_and_zero_copy() {
+    let source = glib::Bytes::from_owned(vec![9u8, 8, 7]);
+
+    let full_ptr: *mut glib::ffi::GBytes = source.clone().into_glib_ptr();
+    let decoded = BytesType::new(Ownership::Full, true)
+        .decode(&ffi::FfiValue::Ptr(full_ptr.cast()))
+        .unwrap();
+    let (bytes, zero_copy) = expect_bytes(decoded);
+    assert!(zero_copy);
+    assert_eq!(bytes, source);
+
+    let borrowed = BytesType::new(Ownership::Borrowed, false)
+        .decode(&ffi::FfiValue::Ptr(source.as_ptr().cast()))
+        .unwrap();
+    let (bytes, zero_copy) = expect_bytes(borrowed);
+    assert!(!zero_copy);
+    assert_eq!(&*bytes, &[9, 8, 7]);
+
+    let null = BytesType::new(Ownership::Full, false)
+        .decode(&ffi::FfiValue::Ptr(std::ptr::null_mut()))
+        .unwrap();
+    assert!(matches!(null, Value::Null));
+}
+
+#[test]
+fn raw_ptr_codec_round_trips() {
+    let bytes_type = BytesType::new(Ownership::Borrowed, false);
+    let mut slot: *mut c_void = std::ptr::null_mut();
+    let slot_ptr = (&raw mut slot).cast::<c_void>();
+
+    bytes_type
+        .write_value_to_raw_ptr(slot_ptr, &js_bytes(b"field"))
+        .unwrap();
+    let read = bytes_type
+        .read_from_raw_ptr(slot_ptr.cast_const(), "ctx")
+        .unwrap();
+    assert_eq!(&*expect_bytes(read).0, b"field");
+    unsafe { glib::ffi::g_bytes_unref(slot.cast()) };
+
+    bytes_type.write_return_to_raw_ptr(slot_ptr, &Ok(js_bytes(b"ret")));
+    let returned: glib::Bytes =
+        unsafe { glib::translate::from_glib_full(slot.cast::<glib::ffi::GBytes>()) };
+    assert_eq!(&*returned, b"ret");
+
+    bytes_type.write_return_to_raw_ptr(slot_ptr, &Err(()));
+    assert!(slot.is_null());
+
+    bytes_type.write_return_to_raw_ptr(slot_ptr, &Ok(Value::Boolean(true)));
+    assert!(slot.is_null());
+}
+
+#[test]
+fn glib_value_round_trips() {
+    common::run(|| {
+        let bytes_type = BytesType::new(Ownership::Borrowed, true);
+        let gvalue = bytes_type
+            .to_glib_value(&js_bytes(b"gvalue"))
+            .unwrap()
+            .expect("a byte array converts to a GValue");
+        let (bytes, zero_copy) = expect_bytes(bytes_type.from_glib_value(&gvalue).unwrap());
+        assert!(zero_copy);
+        assert_eq!(&*bytes, b"gvalue");
+
+        let err = bytes_type
+            .to_glib_value(&Value::Number(1.0))
+            .expect_err("a number is not a byte array");
+        assert!(
+            err.to_string()
+                .starts_with("Expected a Uint8Array, Buffer, or ArrayBuffer for GBytes")
+        );
+    });
+}
+
+#[test]
+fn value_exposes_the_gbytes_pointer() {
+    let bytes = glib::Bytes::from_static(b"ptr");
+    let value = Value::Bytes {
+        bytes: bytes.clone(),
+        zero_copy: false,
+    };
+    let expected = bytes.as_ptr().cast::<c_void>();
+    assert_eq!(value.object_ptr("Bytes").unwrap(), expected);
+    assert_eq!(Value::result_to_ptr(&Ok(value)), expected);
+}
+
+#[test]
+fn display_names_the_type() {
+    let ty = Type::Bytes(BytesType::new(Ownership::Full, false));
+    assert_eq!(ty.to_string(), "Bytes");
+}
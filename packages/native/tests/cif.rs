@@ -239,6 +239,7 @@ fn try_from_array_u8() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value::Value::Array(vec![
             value::Value::Number(1.0),
@@ -262,6 +263,7 @@ fn try_from_array_i32() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value::Value::Array(vec![
             value::Value::Number(-10.0),
@@ -285,6 +287,7 @@ fn try_from_array_f64() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value::Value::Array(vec![value::Value::Number(1.1), value::Value::Number(2.2)]),
     );
@@ -308,6 +311,7 @@ fn try_from_array_string() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value::Value::Array(vec![
             value::Value::String("foo".to_string()),
@@ -334,6 +338,7 @@ fn try_from_array_boolean() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value::Value::Array(vec![
             value::Value::Boolean(true),
@@ -404,11 +409,8 @@ fn value_to_libffi_arg_owned_ptr() {
 
 #[test]
 fn try_from_struct_null() {
-    let struct_type = native::types::StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_string(),
-        size: Some(16),
-    };
+    let struct_type =
+        native::types::StructType::new(Ownership::Borrowed, "TestStruct".to_string(), Some(16));
     let arg = Arg::new(Type::Struct(struct_type), value::Value::Null);
 
     let ptr = expect_variant!(arg, Ptr);
@@ -417,11 +419,7 @@ fn try_from_struct_null() {
 
 #[test]
 fn try_from_struct_undefined() {
-    let struct_type = native::types::StructType {
-        ownership: Ownership::Full,
-        type_name: "TestRect".to_string(),
-        size: None,
-    };
+    let struct_type = native::types::StructType::new(Ownership::Full, "TestRect".to_string(), None);
     let arg = Arg::new(Type::Struct(struct_type), value::Value::Undefined);
 
     let ptr = expect_variant!(arg, Ptr);
@@ -430,11 +428,8 @@ fn try_from_struct_undefined() {
 
 #[test]
 fn try_from_struct_invalid_type() {
-    let struct_type = native::types::StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_string(),
-        size: Some(16),
-    };
+    let struct_type =
+        native::types::StructType::new(Ownership::Borrowed, "TestStruct".to_string(), Some(16));
     let arg = Arg::new(
         Type::Struct(struct_type),
         value::Value::String("invalid".to_string()),
@@ -445,11 +440,8 @@ fn try_from_struct_invalid_type() {
 
 #[test]
 fn try_from_struct_invalid_number() {
-    let struct_type = native::types::StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_string(),
-        size: Some(16),
-    };
+    let struct_type =
+        native::types::StructType::new(Ownership::Borrowed, "TestStruct".to_string(), Some(16));
     let arg = Arg::new(Type::Struct(struct_type), value::Value::Number(42.0));
 
     assert!(FfiValue::try_from(arg).is_err());
@@ -457,11 +449,8 @@ fn try_from_struct_invalid_number() {
 
 #[test]
 fn try_from_struct_invalid_boolean() {
-    let struct_type = native::types::StructType {
-        ownership: Ownership::Full,
-        type_name: "TestRect".to_string(),
-        size: Some(8),
-    };
+    let struct_type =
+        native::types::StructType::new(Ownership::Full, "TestRect".to_string(), Some(8));
     let arg = Arg::new(Type::Struct(struct_type), value::Value::Boolean(true));
 
     assert!(FfiValue::try_from(arg).is_err());
@@ -475,6 +464,7 @@ fn try_from_array_optional_null_yields_null_ptr() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value: value::Value::Null,
         optional: true,
@@ -494,6 +484,7 @@ fn try_from_array_propagates_encode_error() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value::Value::Number(1.0),
     );
@@ -509,6 +500,7 @@ fn try_from_array_f32_storage_converts_to_libffi_arg() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         }),
         value::Value::Array(vec![value::Value::Number(0.5)]),
     );
@@ -519,16 +511,10 @@ fn try_from_array_f32_storage_converts_to_libffi_arg() {
 
 #[test]
 fn try_from_struct_transfer_none_vs_full() {
-    let transfer_none_type = native::types::StructType {
-        ownership: Ownership::Full,
-        type_name: "TestStruct".to_string(),
-        size: Some(16),
-    };
-    let transfer_full_type = native::types::StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_string(),
-        size: Some(16),
-    };
+    let transfer_none_type =
+        native::types::StructType::new(Ownership::Full, "TestStruct".to_string(), Some(16));
+    let transfer_full_type =
+        native::types::StructType::new(Ownership::Borrowed, "TestStruct".to_string(), Some(16));
 
     let transfer_none_arg = Arg::new(Type::Struct(transfer_none_type), value::Value::Null);
 
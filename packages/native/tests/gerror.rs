@@ -0,0 +1,82 @@
+mod common;
+
+use std::ffi::c_void;
+
+use gtk4::glib::{self, translate::IntoGlibPtr as _};
+use libffi::middle;
+use native::ffi;
+use native::types::{FfiDecoder, FfiEncoder, GErrorDetails, GErrorType};
+use native::value::Value;
+
+extern "C" fn ret_void() {}
+
+fn slot_ptr(encoded: &ffi::FfiValue) -> *mut *mut glib::ffi::GError {
+    match encoded {
+        ffi::FfiValue::Storage(storage) => storage.ptr().cast(),
+        other => panic!("expected a Storage ffi::FfiValue, got {other:?}"),
+    }
+}
+
+#[test]
+fn encode_yields_a_null_slot() {
+    let encoded = FfiEncoder::encode(&GErrorType, &Value::Null, false).unwrap();
+    let slot = slot_ptr(&encoded);
+    assert!(!slot.is_null());
+    assert!(unsafe { *slot }.is_null());
+}
+
+#[test]
+fn take_error_returns_none_for_an_untouched_slot() {
+    let encoded = FfiEncoder::encode(&GErrorType, &Value::Undefined, false).unwrap();
+    assert!(GErrorType::take_error(&encoded).unwrap().is_none());
+}
+
+#[test]
+fn take_error_takes_a_stored_error_and_clears_the_slot() {
+    let encoded = FfiEncoder::encode(&GErrorType, &Value::Null, false).unwrap();
+    let slot = slot_ptr(&encoded);
+    let error = glib::Error::new(glib::FileError::Noent, "missing file");
+    unsafe { *slot = error.into_glib_ptr() };
+
+    let taken = GErrorType::take_error(&encoded)
+        .unwrap()
+        .expect("the stored error should be taken");
+    assert!(taken.matches(glib::FileError::Noent));
+    assert!(unsafe { *slot }.is_null());
+    assert!(GErrorType::take_error(&encoded).unwrap().is_none());
+}
+
+#[test]
+fn take_error_rejects_a_non_storage_value() {
+    assert!(GErrorType::take_error(&ffi::FfiValue::Ptr(std::ptr::null_mut())).is_err());
+}
+
+#[test]
+fn details_decode_domain_code_and_message() {
+    let error = glib::Error::new(glib::FileError::Noent, "missing file");
+    let details = GErrorDetails::from(error);
+    assert_eq!(details.domain, "g-file-error-quark");
+    assert_ne!(details.domain_quark, 0);
+    assert_eq!(details.code, glib::ffi::G_FILE_ERROR_NOENT);
+    assert_eq!(details.message, "missing file");
+    assert_eq!(details.to_string(), "missing file (g-file-error-quark, 4)");
+}
+
+#[test]
+fn decode_yields_null() {
+    let encoded = FfiEncoder::encode(&GErrorType, &Value::Null, false).unwrap();
+    let decoded = FfiDecoder::decode(&GErrorType, &encoded).unwrap();
+    assert!(matches!(decoded, Value::Null));
+}
+
+#[test]
+fn call_cif_rejects_gerror_as_a_return_type() {
+    let cif = middle::Cif::new(Vec::new(), middle::Type::pointer());
+    let result = FfiEncoder::call_cif(
+        &GErrorType,
+        &cif,
+        middle::CodePtr(ret_void as *mut c_void),
+        &[],
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,241 @@
+//! Coverage tests for [`native::types::GValueType`] codec implementations.
+
+mod common;
+
+use std::ffi::c_void;
+
+use gtk4::glib::{
+    self,
+    translate::{FromGlib as _, ToGlibPtr as _, ToGlibPtrMut as _},
+    value::ToValue as _,
+};
+
+use native::ffi::{self, FfiStorageKind};
+use native::managed::NativeHandle;
+use native::types::{
+    FfiDecoder, FfiEncoder, GValueType, GlibValueCodec, IntegerKind, Ownership, RawPtrCodec, Type,
+};
+use native::value::Value;
+
+fn typed(ownership: Ownership, type_name: &str) -> GValueType {
+    GValueType::new(ownership, None, Some(type_name.to_owned()))
+}
+
+fn untyped(ownership: Ownership) -> GValueType {
+    GValueType::new(ownership, None, None)
+}
+
+fn encoded_gvalue(encoded: &ffi::FfiValue) -> &glib::Value {
+    let ffi::FfiValue::Storage(storage) = encoded else {
+        panic!("expected FfiValue::Storage, got {encoded:?}");
+    };
+    let FfiStorageKind::GValues(gvalues) = storage.kind() else {
+        panic!("expected GValue storage");
+    };
+    assert_eq!(storage.ptr(), gvalues.as_ptr().cast_mut().cast::<c_void>());
+    &gvalues[0]
+}
+
+fn expect_number(value: Value) -> f64 {
+    match value {
+        Value::Number(n) => n,
+        other => panic!("expected Value::Number, got {other:?}"),
+    }
+}
+
+#[test]
+fn encode_type_name_stores_the_named_type() {
+    common::run(|| {
+        let encoded = typed(Ownership::Borrowed, "gint")
+            .encode(&Value::Number(-42.0), false)
+            .unwrap();
+        let gvalue = encoded_gvalue(&encoded);
+        assert_eq!(gvalue.type_(), glib::Type::I32);
+        assert_eq!(gvalue.get::<i32>().unwrap(), -42);
+    });
+}
+
+#[test]
+fn encode_value_type_uses_its_codec() {
+    common::run(|| {
+        let ty = GValueType::new(
+            Ownership::Borrowed,
+            Some(Type::Integer(IntegerKind::U8)),
+            None,
+        );
+        let encoded = ty.encode(&Value::Number(200.0), false).unwrap();
+        let gvalue = encoded_gvalue(&encoded);
+        assert_eq!(gvalue.type_(), glib::Type::U8);
+        assert_eq!(gvalue.get::<u8>().unwrap(), 200);
+    });
+}
+
+#[test]
+fn encode_infers_the_type_from_the_js_value() {
+    common::run(|| {
+        let encoded = untyped(Ownership::Borrowed)
+            .encode(&Value::String("hello".into()), false)
+            .unwrap();
+        let gvalue = encoded_gvalue(&encoded);
+        assert_eq!(gvalue.get::<String>().unwrap(), "hello");
+    });
+}
+
+#[test]
+fn encode_rejects_out_of_range_integers() {
+    common::run(|| {
+        let err = typed(Ownership::Borrowed, "guchar")
+            .encode(&Value::Number(256.0), false)
+            .unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+
+        let err = typed(Ownership::Borrowed, "gint")
+            .encode(&Value::Number(1.5), false)
+            .unwrap_err();
+        assert!(err.to_string().contains("Expected an integer"), "{err}");
+    });
+}
+
+#[test]
+fn encode_rejects_unknown_type_names() {
+    common::run(|| {
+        let err = typed(Ownership::Borrowed, "NoSuchType")
+            .encode(&Value::Number(1.0), false)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Unknown GType 'NoSuchType'"),
+            "{err}"
+        );
+    });
+}
+
+#[test]
+fn encode_untyped_handle_passes_the_gvalue_through() {
+    common::run(|| {
+        let mut gvalue = 7i32.to_value();
+        let ptr: *mut c_void = gvalue.to_glib_none_mut().0.cast();
+
+        let encoded = untyped(Ownership::Borrowed)
+            .encode(&Value::Object(NativeHandle::borrowed(ptr)), false)
+            .unwrap();
+        assert!(matches!(encoded, ffi::FfiValue::Ptr(p) if p == ptr));
+    });
+}
+
+#[test]
+fn encode_full_hands_over_a_heap_gvalue() {
+    common::run(|| {
+        let ty = typed(Ownership::Full, "gdouble");
+        let encoded = ty.encode(&Value::Number(2.5), false).unwrap();
+        let ffi::FfiValue::Ptr(ptr) = encoded else {
+            panic!("expected FfiValue::Ptr, got {encoded:?}");
+        };
+        assert!(!ptr.is_null());
+        assert_eq!(
+            expect_number(ty.decode(&ffi::FfiValue::Ptr(ptr)).unwrap()),
+            2.5
+        );
+    });
+}
+
+#[test]
+fn decode_reads_the_runtime_type() {
+    common::run(|| {
+        let ty = untyped(Ownership::Borrowed);
+        let read = |gvalue: &glib::Value| {
+            let ptr: *const glib::gobject_ffi::GValue = gvalue.to_glib_none().0;
+            ty.decode(&ffi::FfiValue::Ptr(ptr.cast_mut().cast()))
+                .unwrap()
+        };
+
+        assert_eq!(expect_number(read(&u64::MAX.to_value())), u64::MAX as f64);
+        assert!(matches!(read(&true.to_value()), Value::Boolean(true)));
+        assert!(matches!(read(&"text".to_value()), Value::String(s) if s == "text"));
+        assert!(matches!(read(&None::<String>.to_value()), Value::Null));
+        assert!(matches!(
+            read(&vec!["a".to_owned(), "b".to_owned()].to_value()),
+            Value::Array(items) if items.len() == 2
+        ));
+        assert!(matches!(
+            read(&glib::Variant::from(3u32).to_value()),
+            Value::Variant(v) if v.get::<u32>() == Some(3)
+        ));
+    });
+}
+
+#[test]
+fn decode_null_and_unset_gvalues() {
+    common::run(|| {
+        let ty = untyped(Ownership::Borrowed);
+        assert!(matches!(
+            ty.decode(&ffi::FfiValue::Ptr(std::ptr::null_mut()))
+                .unwrap(),
+            Value::Null
+        ));
+
+        let mut unset: glib::gobject_ffi::GValue = unsafe { std::mem::zeroed() };
+        let decoded = ty.ptr_to_value((&raw mut unset).cast(), "test").unwrap();
+        assert!(matches!(decoded, Value::Undefined));
+    });
+}
+
+#[test]
+fn write_inline_replaces_the_held_value() {
+    common::run(|| {
+        let mut gvalue = "old".to_value();
+        let ptr: *mut c_void = gvalue.to_glib_none_mut().0.cast();
+
+        typed(Ownership::Borrowed, "guint")
+            .write_inline(ptr, &Value::Number(9.0))
+            .unwrap();
+        assert_eq!(gvalue.type_(), glib::Type::U32);
+        assert_eq!(gvalue.get::<u32>().unwrap(), 9);
+    });
+}
+
+#[test]
+fn from_glib_value_unwraps_a_boxed_gvalue() {
+    common::run(|| {
+        let inner = 5i64.to_value();
+        let mut boxed = glib::Value::from_type(unsafe {
+            glib::Type::from_glib(glib::gobject_ffi::g_value_get_type())
+        });
+        unsafe {
+            glib::gobject_ffi::g_value_set_boxed(
+                boxed.to_glib_none_mut().0,
+                inner.to_glib_none().0.cast(),
+            );
+        }
+        let decoded = untyped(Ownership::Borrowed)
+            .from_glib_value(&boxed)
+            .unwrap();
+        assert_eq!(expect_number(decoded), 5.0);
+    });
+}
+
+#[test]
+fn alloc_array_lays_gvalues_out_contiguously() {
+    common::run(|| {
+        let encoded = native::types::ArrayType {
+            item_type: Box::new(Type::GValue(untyped(Ownership::Borrowed))),
+            kind: native::types::ArrayKind::Array,
+            ownership: Ownership::Borrowed,
+            element_size: None,
+            typed: false,
+        }
+        .encode(
+            &Value::Array(vec![Value::Number(1.0), Value::String("two".into())]),
+            false,
+        )
+        .unwrap();
+        let ffi::FfiValue::Storage(storage) = &encoded else {
+            panic!("expected FfiValue::Storage, got {encoded:?}");
+        };
+        let FfiStorageKind::GValues(gvalues) = storage.kind() else {
+            panic!("expected GValue storage");
+        };
+        assert_eq!(gvalues.len(), 2);
+        assert_eq!(gvalues[0].get::<f64>().unwrap(), 1.0);
+        assert_eq!(gvalues[1].get::<String>().unwrap(), "two");
+    });
+}
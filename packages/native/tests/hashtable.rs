@@ -15,11 +15,11 @@ use native::types::{FfiDecoder, FfiEncoder, RawPtrCodec};
 use native::value::Value;
 
 fn struct_type() -> Type {
-    Type::Struct(StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_string(),
-        size: Some(size_of::<gtk4::gdk::ffi::GdkRGBA>()),
-    })
+    Type::Struct(StructType::new(
+        Ownership::Borrowed,
+        "TestStruct".to_string(),
+        Some(size_of::<gtk4::gdk::ffi::GdkRGBA>()),
+    ))
 }
 
 fn gptrarray_type() -> Type {
@@ -28,6 +28,7 @@ fn gptrarray_type() -> Type {
         kind: ArrayKind::GPtrArray,
         ownership: Ownership::Borrowed,
         element_size: None,
+        typed: false,
     })
 }
 
@@ -258,11 +259,11 @@ fn ptr_to_value_float() {
 
 #[test]
 fn ptr_to_value_struct_null() {
-    let ty = Type::Struct(StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_string(),
-        size: Some(16),
-    });
+    let ty = Type::Struct(StructType::new(
+        Ownership::Borrowed,
+        "TestStruct".to_string(),
+        Some(16),
+    ));
 
     let value = ty
         .ptr_to_value(std::ptr::null_mut(), "test")
@@ -277,11 +278,11 @@ fn ptr_to_value_struct_null() {
 #[test]
 fn ptr_to_value_struct_non_null() {
     common::run(|| {
-        let ty = Type::Struct(StructType {
-            ownership: Ownership::Borrowed,
-            type_name: "TestStruct".to_string(),
-            size: Some(16),
-        });
+        let ty = Type::Struct(StructType::new(
+            Ownership::Borrowed,
+            "TestStruct".to_string(),
+            Some(16),
+        ));
 
         let ptr = unsafe { glib::ffi::g_malloc0(16) };
 
@@ -514,6 +515,7 @@ fn encoder_from_type_unsupported_returns_none() {
         kind: ArrayKind::Array,
         ownership: Ownership::Full,
         element_size: None,
+        typed: false,
     });
     assert_eq!(HashTableEntryEncoder::from_type(&non_ptr_array), None);
 }
@@ -0,0 +1,81 @@
+import { describe, expect, it } from "vitest";
+import { call, callAsyncPair, createRef, NativeError } from "../../index.js";
+import { BOOLEAN, GIO_LIB, GOBJECT, GOBJECT_BORROWED, INT32, STRING_BORROWED, VOID } from "./utils.js";
+
+const fileForPath = (path: string) =>
+    call(GIO_LIB, "g_file_new_for_path", [{ type: STRING_BORROWED, value: path }], GOBJECT);
+
+const queryInfo = (file: unknown, cancellable?: unknown) =>
+    callAsyncPair(GIO_LIB, "g_file_query_info_async", "g_file_query_info_finish", {
+        args: [
+            { type: GOBJECT_BORROWED, value: file },
+            { type: STRING_BORROWED, value: "standard::name" },
+            { type: INT32, value: 0 },
+            { type: INT32, value: 0 },
+        ],
+        cancellable,
+        finishArgs: [{ type: GOBJECT_BORROWED, value: file }],
+        returnType: GOBJECT,
+    });
+
+describe("callAsyncPair", () => {
+    it("resolves with the finish return value", async () => {
+        const info = await queryInfo(fileForPath("/"));
+
+        const name = call(GIO_LIB, "g_file_info_get_name", [{ type: GOBJECT_BORROWED, value: info }], STRING_BORROWED);
+        expect(name).toBe("/");
+    });
+
+    it("rejects with a NativeError carrying the GError domain and code", async () => {
+        const file = fileForPath("/nonexistent/gtkx/path");
+
+        const error = await queryInfo(file).catch((e: unknown) => e);
+
+        expect(error).toBeInstanceOf(NativeError);
+        expect(error).toMatchObject({ domainName: "g-io-error-quark", code: 1 });
+    });
+
+    it("rejects with an AbortError when cancelled", async () => {
+        const cancellable = call(GIO_LIB, "g_cancellable_new", [], GOBJECT);
+        call(GIO_LIB, "g_cancellable_cancel", [{ type: GOBJECT_BORROWED, value: cancellable }], VOID);
+
+        await expect(queryInfo(fileForPath("/"), cancellable)).rejects.toMatchObject({ name: "AbortError" });
+    });
+
+    it("rejects when the start symbol is unknown", async () => {
+        const pending = callAsyncPair(GIO_LIB, "nonexistent_async_xyz", "g_file_query_info_finish", {
+            args: [],
+            returnType: GOBJECT,
+        });
+
+        await expect(pending).rejects.toThrow("Error during async FFI call");
+    });
+
+    it("rejects once the operation finishes when a start arg cannot be written back", async () => {
+        const file = fileForPath("/");
+
+        // A false `Ref<boolean>` reads as an empty attribute list, so the
+        // operation starts, but `Ref<boolean>` has no write-back decoding.
+        const pending = callAsyncPair(GIO_LIB, "g_file_query_info_async", "g_file_query_info_finish", {
+            args: [
+                { type: GOBJECT_BORROWED, value: file },
+                { type: { type: "ref", innerType: BOOLEAN }, value: createRef(false) },
+                { type: INT32, value: 0 },
+                { type: INT32, value: 0 },
+            ],
+            finishArgs: [{ type: GOBJECT_BORROWED, value: file }],
+            returnType: GOBJECT,
+        });
+
+        await expect(pending).rejects.toThrow("Unsupported ref inner type for reading");
+    });
+
+    it("throws synchronously on an invalid return type", () => {
+        expect(() =>
+            callAsyncPair(GIO_LIB, "g_file_query_info_async", "g_file_query_info_finish", {
+                args: [],
+                returnType: { type: "ref", innerType: INT32 },
+            }),
+        ).toThrow("cannot be used as a function return type");
+    });
+});
@@ -0,0 +1,113 @@
+import { describe, expect, it } from "vitest";
+import { callBatch, createRef } from "../../index.js";
+import { createLabel, GOBJECT, GOBJECT_BORROWED, GTK_LIB, INT32, STRING_BORROWED, VOID } from "./utils.js";
+
+describe("callBatch", () => {
+    it("returns an empty array for an empty batch", () => {
+        expect(callBatch([])).toEqual([]);
+    });
+
+    it("runs calls in order and returns each result", () => {
+        const label = createLabel("Initial");
+
+        const results = callBatch([
+            {
+                library: GTK_LIB,
+                symbol: "gtk_label_set_text",
+                args: [
+                    { type: GOBJECT_BORROWED, value: label },
+                    { type: STRING_BORROWED, value: "Batched" },
+                ],
+                returnType: VOID,
+            },
+            {
+                library: GTK_LIB,
+                symbol: "gtk_label_get_text",
+                args: [{ type: GOBJECT_BORROWED, value: label }],
+                returnType: STRING_BORROWED,
+            },
+        ]);
+
+        expect(results).toEqual([undefined, "Batched"]);
+    });
+
+    it("returns object handles", () => {
+        const [label] = callBatch([
+            {
+                library: GTK_LIB,
+                symbol: "gtk_label_new",
+                args: [{ type: STRING_BORROWED, value: "New" }],
+                returnType: GOBJECT,
+            },
+        ]);
+
+        expect(label).not.toBeNull();
+    });
+
+    it("writes back ref out-parameters", () => {
+        const label = createLabel("Test");
+        const width = createRef(0);
+        const height = createRef(0);
+
+        callBatch([
+            {
+                library: GTK_LIB,
+                symbol: "gtk_widget_set_size_request",
+                args: [
+                    { type: GOBJECT_BORROWED, value: label },
+                    { type: INT32, value: 120 },
+                    { type: INT32, value: 40 },
+                ],
+                returnType: VOID,
+            },
+            {
+                library: GTK_LIB,
+                symbol: "gtk_widget_get_size_request",
+                args: [
+                    { type: GOBJECT_BORROWED, value: label },
+                    { type: { type: "ref", innerType: INT32 }, value: width },
+                    { type: { type: "ref", innerType: INT32 }, value: height },
+                ],
+                returnType: VOID,
+            },
+        ]);
+
+        expect(width.value).toBe(120);
+        expect(height.value).toBe(40);
+    });
+
+    it("stops at the first failing call and reports its index", () => {
+        const label = createLabel("Untouched");
+
+        expect(() =>
+            callBatch([
+                {
+                    library: GTK_LIB,
+                    symbol: "gtk_widget_get_visible",
+                    args: [{ type: GOBJECT_BORROWED, value: label }],
+                    returnType: { type: "boolean" },
+                },
+                { library: GTK_LIB, symbol: "nonexistent_function_xyz", args: [], returnType: VOID },
+                {
+                    library: GTK_LIB,
+                    symbol: "gtk_label_set_text",
+                    args: [
+                        { type: GOBJECT_BORROWED, value: label },
+                        { type: STRING_BORROWED, value: "Changed" },
+                    ],
+                    returnType: VOID,
+                },
+            ]),
+        ).toThrow("batch call 1 (nonexistent_function_xyz) failed");
+
+        const [text] = callBatch([
+            {
+                library: GTK_LIB,
+                symbol: "gtk_label_get_text",
+                args: [{ type: GOBJECT_BORROWED, value: label }],
+                returnType: STRING_BORROWED,
+            },
+        ]);
+        expect(text).toBe("Untouched");
+    });
+});
@@ -0,0 +1,75 @@
+import { describe, expect, it } from "vitest";
+import { callPromise, createRef } from "../../index.js";
+import { connectCancelledSignal } from "./call/_helpers.js";
+import { createLabel, GIO_LIB, GOBJECT, GOBJECT_BORROWED, GTK_LIB, INT32, STRING_BORROWED, VOID } from "./utils.js";
+
+describe("callPromise", () => {
+    it("returns a promise", () => {
+        const result = callPromise(GTK_LIB, "gtk_get_major_version", [], { type: "uint32" });
+
+        expect(result).toBeInstanceOf(Promise);
+    });
+
+    it("resolves with the return value", async () => {
+        await expect(callPromise(GTK_LIB, "gtk_get_major_version", [], { type: "uint32" })).resolves.toBe(4);
+    });
+
+    it("resolves with an object handle", async () => {
+        const label = await callPromise(
+            GTK_LIB,
+            "gtk_label_new",
+            [{ type: STRING_BORROWED, value: "Async" }],
+            GOBJECT,
+        );
+
+        const text = await callPromise(
+            GTK_LIB,
+            "gtk_label_get_text",
+            [{ type: GOBJECT_BORROWED, value: label }],
+            STRING_BORROWED,
+        );
+        expect(text).toBe("Async");
+    });
+
+    it("writes back ref out-parameters before resolving", async () => {
+        const label = createLabel("Test");
+        const width = createRef(0);
+        const height = createRef(0);
+
+        await callPromise(
+            GTK_LIB,
+            "gtk_widget_get_size_request",
+            [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: { type: "ref", innerType: INT32 }, value: width },
+                { type: { type: "ref", innerType: INT32 }, value: height },
+            ],
+            VOID,
+        );
+
+        expect(width.value).toBe(-1);
+        expect(height.value).toBe(-1);
+    });
+
+    it("runs callbacks triggered by the call", async () => {
+        const cancellable = await callPromise(GIO_LIB, "g_cancellable_new", [], GOBJECT);
+        let cancelled = false;
+        connectCancelledSignal(cancellable, () => {
+            cancelled = true;
+        });
+
+        await callPromise(GIO_LIB, "g_cancellable_cancel", [{ type: GOBJECT_BORROWED, value: cancellable }], VOID);
+
+        expect(cancelled).toBe(true);
+    });
+
+    it("rejects on an unknown symbol", async () => {
+        await expect(callPromise(GTK_LIB, "nonexistent_function_xyz", [], VOID)).rejects.toThrow();
+    });
+
+    it("throws synchronously on an invalid return type", () => {
+        expect(() => callPromise(GTK_LIB, "gtk_get_major_version", [], { type: "ref", innerType: INT32 })).toThrow(
+            "cannot be used as a function return type",
+        );
+    });
+});
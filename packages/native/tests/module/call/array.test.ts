@@ -1,17 +1,35 @@
 import { describe, expect, it } from "vitest";
 import { call } from "../../../index.js";
+import type { ArrayType, Type } from "../../../types.js";
 import {
     createLabel,
+    createRef,
+    FLOAT64,
     forceGC,
     GOBJECT_BORROWED,
     GTK_LIB,
     getRefCount,
+    INT32,
     STRING,
     STRING_ARRAY,
+    STRING_BORROWED,
     startMemoryMeasurement,
+    UINT8,
+    UINT32,
+    UINT64,
     VOID,
 } from "../utils.js";
 
+const GLIB_LIB = "libglib-2.0.so.0";
+const G_CHECKSUM_MD5 = 0;
+
+const numericArray = (
+    itemType: Type,
+    kind: "array" | "garray" | "gbytearray",
+    ownership: "full" | "borrowed",
+    typed = false,
+): ArrayType => ({ type: "array", itemType, kind, ownership, typed });
+
 describe("call - array types - string arrays basic", () => {
     it("passes string array argument", () => {
         const label = createLabel("Test");
@@ -438,3 +456,114 @@ describe("call - array types - edge cases duplicates and special", () => {
         expect(result).toContain("valid-class");
     });
 });
+
+describe("call - array types - typed arrays", () => {
+    const md5 = (data: unknown): string =>
+        call(
+            GLIB_LIB,
+            "g_compute_checksum_for_data",
+            [
+                { type: INT32, value: G_CHECKSUM_MD5 },
+                { type: numericArray(UINT8, "array", "borrowed"), value: data },
+                { type: UINT64, value: 3 },
+            ],
+            STRING,
+        ) as string;
+
+    it("passes a Uint8Array, an ArrayBuffer, or a number array as the same bytes", () => {
+        const abc = new Uint8Array([97, 98, 99]);
+
+        expect(md5(abc)).toBe("900150983cd24fb0d6963f7d28e17f72");
+        expect(md5(abc.buffer)).toBe("900150983cd24fb0d6963f7d28e17f72");
+        expect(md5(Array.from(abc))).toBe("900150983cd24fb0d6963f7d28e17f72");
+    });
+
+    it("passes a typed array view at its byte offset", () => {
+        const padded = new TextEncoder().encode("xxabc");
+
+        expect(md5(padded.subarray(2))).toBe("900150983cd24fb0d6963f7d28e17f72");
+    });
+
+    it("rejects a typed array whose element type does not match", () => {
+        expect(() => md5(new Float32Array([1, 2, 3]))).toThrow("Float32Array");
+        expect(() => md5(new Uint32Array([1, 2, 3]))).toThrow("Uint32Array");
+    });
+
+    it("returns a sized array as a Uint8Array when typed", () => {
+        const length = createRef(0);
+        const returnType: Type = {
+            type: "array",
+            itemType: UINT8,
+            kind: "sized",
+            sizeParamIndex: 1,
+            ownership: "full",
+            typed: true,
+        };
+
+        const decoded = call(
+            GLIB_LIB,
+            "g_base64_decode",
+            [
+                { type: STRING_BORROWED, value: "AQID/w==" },
+                { type: { type: "ref", innerType: UINT64 }, value: length },
+            ],
+            returnType,
+        );
+
+        expect(decoded).toBeInstanceOf(Uint8Array);
+        expect(Array.from(decoded as Uint8Array)).toEqual([1, 2, 3, 255]);
+    });
+
+    it("appends to a GArray from a Float64Array and returns it typed", () => {
+        const result = call(
+            GLIB_LIB,
+            "g_array_append_vals",
+            [
+                { type: numericArray(FLOAT64, "garray", "full"), value: new Float64Array([1.5, 2.5]) },
+                { type: numericArray(FLOAT64, "array", "borrowed"), value: new Float64Array([3.5]) },
+                { type: UINT32, value: 1 },
+            ],
+            numericArray(FLOAT64, "garray", "full", true),
+        );
+
+        expect(result).toBeInstanceOf(Float64Array);
+        expect(Array.from(result as Float64Array)).toEqual([1.5, 2.5, 3.5]);
+    });
+
+    it("returns a GArray as a number array unless typed", () => {
+        const result = call(
+            GLIB_LIB,
+            "g_array_append_vals",
+            [
+                { type: numericArray(FLOAT64, "garray", "full"), value: new Float64Array([0.25]) },
+                { type: numericArray(FLOAT64, "array", "borrowed"), value: [0.5] },
+                { type: UINT32, value: 1 },
+            ],
+            numericArray(FLOAT64, "garray", "full"),
+        );
+
+        expect(result).toEqual([0.25, 0.5]);
+    });
+
+    it("appends to a GByteArray from a Uint8Array and returns it typed", () => {
+        const result = call(
+            GLIB_LIB,
+            "g_byte_array_append",
+            [
+                { type: numericArray(UINT8, "gbytearray", "full"), value: new Uint8Array([1, 2]) },
+                { type: numericArray(UINT8, "array", "borrowed"), value: new Uint8Array([3]) },
+                { type: UINT32, value: 1 },
+            ],
+            numericArray(UINT8, "gbytearray", "full", true),
+        );
+
+        expect(result).toBeInstanceOf(Uint8Array);
+        expect(Array.from(result as Uint8Array)).toEqual([1, 2, 3]);
+    });
+
+    it("rejects typed decoding of non-numeric items", () => {
+        const returnType: Type = { type: "array", itemType: STRING, kind: "array", ownership: "full", typed: true };
+
+        expect(() => call(GLIB_LIB, "g_get_system_data_dirs", [], returnType)).toThrow("'typed' requires");
+    });
+});
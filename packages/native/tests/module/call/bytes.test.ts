@@ -0,0 +1,66 @@
+import { describe, expect, it } from "vitest";
+import { call } from "../../../index.js";
+import type { Type } from "../../../types.js";
+import { STRING, UINT32, UINT64 } from "../utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+const G_CHECKSUM_MD5 = 0;
+
+const bytesOf = (ownership: "full" | "borrowed", zeroCopy = false): Type => ({ type: "bytes", ownership, zeroCopy });
+
+const md5 = (value: unknown): string =>
+    call(
+        GLIB_LIB,
+        "g_compute_checksum_for_bytes",
+        [
+            { type: UINT32, value: G_CHECKSUM_MD5 },
+            { type: bytesOf("borrowed"), value },
+        ],
+        STRING,
+    ) as string;
+
+const slice = (value: unknown, offset: number, length: number, zeroCopy = false): Buffer =>
+    call(
+        GLIB_LIB,
+        "g_bytes_new_from_bytes",
+        [
+            { type: bytesOf("borrowed"), value },
+            { type: UINT64, value: offset },
+            { type: UINT64, value: length },
+        ],
+        bytesOf("full", zeroCopy),
+    ) as Buffer;
+
+describe("call - bytes types", () => {
+    it("encodes a Buffer, Uint8Array or ArrayBuffer as GBytes", () => {
+        const expected = "900150983cd24fb0d6963f7d28e17f72";
+        expect(md5(Buffer.from("abc"))).toBe(expected);
+        expect(md5(new Uint8Array([0x61, 0x62, 0x63]))).toBe(expected);
+        expect(md5(new Uint8Array([0x61, 0x62, 0x63]).buffer)).toBe(expected);
+    });
+
+    it("decodes a returned GBytes as a copied Buffer", () => {
+        const result = slice(new Uint8Array([1, 2, 3, 4, 5]), 1, 3);
+        expect(Buffer.isBuffer(result)).toBe(true);
+        expect([...result]).toEqual([2, 3, 4]);
+    });
+
+    it("decodes a returned GBytes as a zero-copy Buffer", () => {
+        const result = slice(Buffer.from("hello world"), 6, 5, true);
+        expect(Buffer.isBuffer(result)).toBe(true);
+        expect(result.toString()).toBe("world");
+    });
+
+    it("decodes an empty GBytes as an empty Buffer", () => {
+        expect(slice(new Uint8Array([1, 2]), 0, 0, true).length).toBe(0);
+    });
+
+    it("passes a returned GBytes back to native code", () => {
+        const result = slice(Buffer.from("xabc"), 1, 3, true);
+        expect(md5(result)).toBe("900150983cd24fb0d6963f7d28e17f72");
+    });
+
+    it("rejects typed arrays wider than a byte", () => {
+        expect(() => md5(new Int16Array([1]))).toThrow("Int16Array");
+    });
+});
@@ -0,0 +1,113 @@
+import { describe, expect, it } from "vitest";
+import { call, callBatch, callPrepared, callPromise, NativeError, prepare } from "../../../index.js";
+import { STRING, STRING_BORROWED } from "../utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+const GERROR = { type: "gerror" as const };
+const G_CONVERT_ERROR_NOT_ABSOLUTE_PATH = 5;
+
+const filenameToUriArgs = (filename: string) => [
+    { type: STRING_BORROWED, value: filename },
+    { type: STRING_BORROWED, value: null, optional: true },
+    { type: GERROR, value: null },
+];
+
+const catchError = (fn: () => unknown): unknown => {
+    try {
+        fn();
+    } catch (error) {
+        return error;
+    }
+    throw new Error("expected the call to throw");
+};
+
+describe("call - gerror", () => {
+    it("returns normally when the callee leaves the error unset", () => {
+        const uri = call(GLIB_LIB, "g_filename_to_uri", filenameToUriArgs("/tmp"), STRING);
+
+        expect(uri).toBe("file:///tmp");
+    });
+
+    it("throws a NativeError when the callee sets the error", () => {
+        const error = catchError(() =>
+            call(GLIB_LIB, "g_filename_to_uri", filenameToUriArgs("relative/path"), STRING),
+        );
+
+        expect(error).toBeInstanceOf(NativeError);
+        expect(error).toBeInstanceOf(Error);
+    });
+
+    it("carries the domain quark, domain name, code, and message", () => {
+        const error = catchError(() =>
+            call(GLIB_LIB, "g_filename_to_uri", filenameToUriArgs("relative/path"), STRING),
+        ) as NativeError;
+
+        expect(error.name).toBe("NativeError");
+        expect(error.domain).toBeGreaterThan(0);
+        expect(error.domainName).toBe("g_convert_error");
+        expect(error.code).toBe(G_CONVERT_ERROR_NOT_ABSOLUTE_PATH);
+        expect(error.message).toContain("relative/path");
+    });
+
+    it("throws from prepared calls", () => {
+        const prepared = prepare(
+            GLIB_LIB,
+            "g_filename_to_uri",
+            [{ type: STRING_BORROWED }, { type: STRING_BORROWED, optional: true }, { type: GERROR }],
+            STRING,
+        );
+
+        expect(() => callPrepared(prepared, ["relative/path", null, null])).toThrow(NativeError);
+        expect(callPrepared(prepared, ["/tmp", null, null])).toBe("file:///tmp");
+    });
+
+    it("throws from batched calls", () => {
+        expect(() =>
+            callBatch([
+                {
+                    library: GLIB_LIB,
+                    symbol: "g_filename_to_uri",
+                    args: filenameToUriArgs("relative/path"),
+                    returnType: STRING,
+                },
+            ]),
+        ).toThrow(NativeError);
+    });
+
+    it("reports which batched call set the error", () => {
+        const batchCall = (filename: string) => ({
+            library: GLIB_LIB,
+            symbol: "g_filename_to_uri",
+            args: filenameToUriArgs(filename),
+            returnType: STRING,
+        });
+
+        const error = catchError(() =>
+            callBatch([batchCall("/tmp"), batchCall("relative/path"), batchCall("/tmp")]),
+        ) as NativeError;
+
+        expect(error).toBeInstanceOf(NativeError);
+        expect(error.code).toBe(G_CONVERT_ERROR_NOT_ABSOLUTE_PATH);
+        expect(error.batchIndex).toBe(1);
+    });
+
+    it("leaves batchIndex unset outside a batch", () => {
+        const error = catchError(() =>
+            call(GLIB_LIB, "g_filename_to_uri", filenameToUriArgs("relative/path"), STRING),
+        ) as NativeError;
+
+        expect(error.batchIndex).toBeUndefined();
+    });
+
+    it("rejects promise calls with a NativeError", async () => {
+        const pending = callPromise(GLIB_LIB, "g_filename_to_uri", filenameToUriArgs("relative/path"), STRING);
+
+        await expect(pending).rejects.toBeInstanceOf(NativeError);
+    });
+
+    it("rejects gerror as a return type", () => {
+        expect(() => call(GLIB_LIB, "g_filename_to_uri", filenameToUriArgs("/tmp"), GERROR)).toThrow(
+            "cannot be used as a function return type",
+        );
+    });
+});
@@ -0,0 +1,122 @@
+import { describe, expect, it } from "vitest";
+import { call } from "../../../index.js";
+import type { Type } from "../../../types.js";
+import {
+    BOOLEAN,
+    createRef,
+    FLOAT64,
+    GIO_LIB,
+    GOBJECT,
+    GOBJECT_BORROWED,
+    GOBJECT_LIB,
+    INT32,
+    POINTER,
+    STRING,
+    STRING_BORROWED,
+    UINT32,
+    VOID,
+} from "../utils.js";
+
+const G_TYPE_INT = 6 << 2;
+const G_TYPE_DOUBLE = 15 << 2;
+
+const GVALUE = { type: "gvalue" as const, ownership: "borrowed" as const };
+
+const gvalueOf = (typeName: string): Type => ({ ...GVALUE, typeName });
+
+const contents = (type: Type, value: unknown): string =>
+    call(GOBJECT_LIB, "g_strdup_value_contents", [{ type, value }], STRING) as string;
+
+describe("call - gvalue types", () => {
+    it("stores a value as the named type", () => {
+        const value = call(GOBJECT_LIB, "g_value_get_int", [{ type: gvalueOf("gint"), value: 42 }], INT32);
+        expect(value).toBe(42);
+        expect(contents(gvalueOf("guint"), 7)).toBe("7");
+    });
+
+    it("stores a value through its valueType", () => {
+        const type: Type = { ...GVALUE, valueType: FLOAT64 };
+        expect(call(GOBJECT_LIB, "g_value_get_double", [{ type, value: 1.5 }], FLOAT64)).toBe(1.5);
+    });
+
+    it("infers the type from the JS value", () => {
+        expect(contents(GVALUE, "hello")).toBe('"hello"');
+        expect(contents(GVALUE, true)).toBe("TRUE");
+        expect(contents(GVALUE, 2.5)).toBe("2.500000");
+    });
+
+    it("rejects values that do not fit the named type", () => {
+        expect(() => contents(gvalueOf("guchar"), 300)).toThrow(/out of range/);
+        expect(() => contents(gvalueOf("gint"), "text")).toThrow(/Expected an integer/);
+        expect(() => contents(gvalueOf("NoSuchType"), 1)).toThrow(/Unknown GType 'NoSuchType'/);
+    });
+
+    it("decodes an out-parameter GValue by its runtime type", () => {
+        const ref = createRef<unknown>(null);
+        call(
+            GOBJECT_LIB,
+            "g_value_init",
+            [
+                { type: { type: "ref", innerType: GVALUE }, value: ref },
+                { type: POINTER, value: G_TYPE_DOUBLE },
+            ],
+            POINTER,
+        );
+        expect(ref.value).toBe(0);
+    });
+
+    it("writes into an initialized out-parameter GValue", () => {
+        const ref = createRef<unknown>(0);
+        call(
+            GOBJECT_LIB,
+            "g_value_copy",
+            [
+                { type: gvalueOf("gint"), value: -9 },
+                { type: { type: "ref", innerType: gvalueOf("gint") }, value: ref },
+            ],
+            VOID,
+        );
+        expect(ref.value).toBe(-9);
+    });
+
+    it("passes an array of GValues laid out contiguously", () => {
+        const gtype = call(GIO_LIB, "g_simple_action_get_type", [], POINTER);
+        const action = call(
+            GOBJECT_LIB,
+            "g_object_new_with_properties",
+            [
+                { type: POINTER, value: gtype },
+                { type: UINT32, value: 2 },
+                {
+                    type: { type: "array", itemType: STRING_BORROWED, kind: "array", ownership: "borrowed" },
+                    value: ["name", "enabled"],
+                },
+                {
+                    type: { type: "array", itemType: GVALUE, kind: "sized", ownership: "borrowed", sizeParamIndex: 1 },
+                    value: ["open", false],
+                },
+            ],
+            GOBJECT,
+        );
+
+        const actionArg = [{ type: GOBJECT_BORROWED, value: action }];
+        expect(call(GIO_LIB, "g_action_get_name", actionArg, STRING_BORROWED)).toBe("open");
+        expect(call(GIO_LIB, "g_action_get_enabled", actionArg, BOOLEAN)).toBe(false);
+    });
+
+    it("checks the stored type with G_VALUE_HOLDS", () => {
+        const holds = (type: Type, value: unknown, gtype: number): boolean =>
+            call(
+                GOBJECT_LIB,
+                "g_type_check_value_holds",
+                [
+                    { type, value },
+                    { type: POINTER, value: gtype },
+                ],
+                BOOLEAN,
+            ) as boolean;
+
+        expect(holds(gvalueOf("gint"), 1, G_TYPE_INT)).toBe(true);
+        expect(holds(gvalueOf("gint"), 1, G_TYPE_DOUBLE)).toBe(false);
+    });
+});
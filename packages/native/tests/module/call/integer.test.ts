@@ -16,14 +16,21 @@ import {
     INT16,
     INT32,
     INT64,
+    INT64_BIGINT,
+    POINTER,
+    STRING_BORROWED,
     UINT8,
     UINT16,
     UINT32,
     UINT64,
+    UINT64_BIGINT,
     VOID,
 } from "../utils.js";
 import { setAndGetLabelMaxWidthChars } from "./_helpers.js";
 
+const GLIB_LIB = "libglib-2.0.so.0";
+const VARIANT_BORROWED = { type: "variant" as const, ownership: "borrowed" as const };
+
 describe("call - integer types - 8-bit signed", () => {
     it("handles signed 8-bit as part of boolean-like values", () => {
         const label = createLabel("Test");
@@ -261,6 +268,80 @@ describe("call - integer types - 64-bit unsigned disconnect", () => {
     });
 });
 
+describe("call - integer types - 64-bit bigint", () => {
+    const parseUnsigned = (text: string) =>
+        call(
+            GLIB_LIB,
+            "g_ascii_strtoull",
+            [
+                { type: STRING_BORROWED, value: text },
+                { type: POINTER, value: 0 },
+                { type: INT32, value: 10 },
+            ],
+            UINT64_BIGINT,
+        );
+
+    it("returns unsigned values above 2^53 losslessly", () => {
+        expect(parseUnsigned("18446744073709551615")).toBe(18446744073709551615n);
+        expect(parseUnsigned("9007199254740993")).toBe(9007199254740993n);
+    });
+
+    it("returns signed values below -2^53 losslessly", () => {
+        const value = call(
+            GLIB_LIB,
+            "g_ascii_strtoll",
+            [
+                { type: STRING_BORROWED, value: "-9223372036854775808" },
+                { type: POINTER, value: 0 },
+                { type: INT32, value: 10 },
+            ],
+            INT64_BIGINT,
+        );
+
+        expect(value).toBe(-9223372036854775808n);
+    });
+
+    it("passes bigint arguments through without rounding", () => {
+        const value = call(
+            GLIB_LIB,
+            "g_variant_new_uint64",
+            [{ type: UINT64_BIGINT, value: 18446744073709551614n }],
+            VARIANT_BORROWED,
+        );
+
+        expect(value).toBe(18446744073709551614n);
+    });
+
+    it("accepts safe integer numbers for bigint slots", () => {
+        const value = call(GLIB_LIB, "g_variant_new_int64", [{ type: INT64_BIGINT, value: -42 }], VARIANT_BORROWED);
+
+        expect(value).toBe(-42n);
+    });
+
+    it("rejects bigints outside the descriptor range", () => {
+        expect(() =>
+            call(GLIB_LIB, "g_variant_new_uint64", [{ type: UINT64_BIGINT, value: -1n }], VARIANT_BORROWED),
+        ).toThrow("out of range for u64");
+    });
+
+    it("keeps returning numbers when the flag is absent", () => {
+        expect(typeof parseUnsigned("1")).toBe("bigint");
+
+        const value = call(
+            GLIB_LIB,
+            "g_ascii_strtoull",
+            [
+                { type: STRING_BORROWED, value: "42" },
+                { type: POINTER, value: 0 },
+                { type: INT32, value: 10 },
+            ],
+            UINT64,
+        );
+
+        expect(value).toBe(42);
+    });
+});
+
 describe("call - integer types - edge cases simultaneous", () => {
     it("handles integer as argument and return type simultaneously", () => {
         const label = createLabel("Test");
@@ -0,0 +1,141 @@
+import { describe, expect, it } from "vitest";
+import { alloc, call, createRef, type NativeHandle, read, write } from "../../../index.js";
+import { BOOLEAN, FLOAT32, GDK_LIB, INT32, STRING, STRING_BORROWED } from "../utils.js";
+
+const RGBA = {
+    type: "record" as const,
+    ownership: "borrowed" as const,
+    fields: [
+        { name: "red", offset: 0, type: FLOAT32 },
+        { name: "green", offset: 4, type: FLOAT32 },
+        { name: "blue", offset: 8, type: FLOAT32 },
+        { name: "alpha", offset: 12, type: FLOAT32 },
+    ],
+};
+const RGBA_FULL = { ...RGBA, ownership: "full" as const };
+const DIV_T = {
+    type: "record" as const,
+    ownership: "borrowed" as const,
+    fields: [
+        { name: "quot", offset: 0, type: INT32 },
+        { name: "rem", offset: 4, type: INT32 },
+    ],
+};
+
+const LIBC = "libc.so.6";
+
+type Rgba = { red: number; green: number; blue: number; alpha: number };
+
+describe("call - record types", () => {
+    it("passes a plain object as a struct pointer", () => {
+        const text = call(
+            GDK_LIB,
+            "gdk_rgba_to_string",
+            [{ type: RGBA, value: { red: 1, green: 0, blue: 0.5, alpha: 1 } }],
+            STRING,
+        );
+
+        expect(text).toBe("rgb(255,0,128)");
+    });
+
+    it("decodes a caller-allocated out-parameter", () => {
+        const rgba = createRef<Rgba | null>(null);
+        const ok = call(
+            GDK_LIB,
+            "gdk_rgba_parse",
+            [
+                { type: { type: "ref", innerType: RGBA }, value: rgba },
+                { type: STRING_BORROWED, value: "rgba(0, 255, 0, 0.5)" },
+            ],
+            BOOLEAN,
+        );
+
+        expect(ok).toBe(true);
+        expect(rgba.value).toEqual({ red: 0, green: 1, blue: 0, alpha: 0.5 });
+    });
+
+    it("decodes a returned struct pointer", () => {
+        const copy = call(
+            GDK_LIB,
+            "gdk_rgba_copy",
+            [{ type: RGBA, value: { red: 0.25, green: 0.5, blue: 0.75, alpha: 1 } }],
+            RGBA_FULL,
+        );
+
+        expect(copy).toEqual({ red: 0.25, green: 0.5, blue: 0.75, alpha: 1 });
+    });
+
+    it("passes a native handle through unchanged", () => {
+        const handle = alloc(16);
+        write(handle, RGBA, 0, { red: 0, green: 0, blue: 1, alpha: 1 });
+
+        const text = call(GDK_LIB, "gdk_rgba_to_string", [{ type: RGBA, value: handle }], STRING);
+
+        expect(text).toBe("rgb(0,0,255)");
+    });
+
+    it("throws on a non-object value", () => {
+        expect(() => call(GDK_LIB, "gdk_rgba_to_string", [{ type: RGBA, value: 1 }], STRING)).toThrow(
+            "Expected an object for record type",
+        );
+    });
+});
+
+describe("read/write - record types", () => {
+    it("reads a whole struct at an offset", () => {
+        const handle = alloc(16);
+        write(handle, INT32, 8, 17);
+        write(handle, INT32, 12, -3);
+
+        expect(read(handle, DIV_T, 8)).toEqual({ quot: 17, rem: -3 });
+    });
+
+    it("writes only the fields an object holds", () => {
+        const handle = alloc(16);
+        write(handle, RGBA, 0, { red: 1, green: 1, blue: 1, alpha: 1 });
+        write(handle, RGBA, 0, { blue: 0 });
+
+        expect(read(handle, RGBA, 0)).toEqual({ red: 1, green: 1, blue: 0, alpha: 1 });
+    });
+
+    it("embeds nested records and fixed arrays inline", () => {
+        const PAIR = {
+            type: "record" as const,
+            ownership: "borrowed" as const,
+            fields: [
+                { name: "result", offset: 0, type: DIV_T },
+                {
+                    name: "history",
+                    offset: 8,
+                    type: {
+                        type: "array" as const,
+                        itemType: INT32,
+                        kind: "inline" as const,
+                        fixedSize: 2,
+                        ownership: "borrowed" as const,
+                    },
+                },
+            ],
+        };
+        const handle = alloc(16);
+        write(handle, PAIR, 0, { result: { quot: 4, rem: 1 }, history: [9, 10] });
+
+        expect(read(handle, INT32, 4)).toBe(1);
+        expect(read(handle, INT32, 12)).toBe(10);
+        expect(read(handle, PAIR, 0)).toEqual({ result: { quot: 4, rem: 1 }, history: [9, 10] });
+    });
+
+    it("reads a struct returned by value through its handle", () => {
+        const result = call(
+            LIBC,
+            "div",
+            [
+                { type: INT32, value: 17 },
+                { type: INT32, value: 5 },
+            ],
+            { type: "struct", innerType: "div_t", ownership: "full", byValue: true, fields: [INT32, INT32] },
+        ) as NativeHandle;
+
+        expect(read(result, DIV_T, 0)).toEqual({ quot: 3, rem: 2 });
+    });
+});
@@ -0,0 +1,105 @@
+import { describe, expect, it } from "vitest";
+import { alloc, call, chainUp, type NativeHandle, read, registerClass, write } from "../../../index.js";
+import { GOBJECT_LIB, INT32, STRING_BORROWED, UINT8, UINT32, UINT64 } from "../utils.js";
+
+const LIBC = "libc.so.6";
+
+const DIV_T = {
+    type: "struct" as const,
+    innerType: "div_t",
+    ownership: "full" as const,
+    byValue: true,
+    fields: [INT32, INT32],
+};
+const IN_ADDR = {
+    type: "struct" as const,
+    innerType: "in_addr",
+    ownership: "borrowed" as const,
+    byValue: true,
+    fields: [UINT32],
+};
+
+const ipv4 = (octets: number[]): NativeHandle => {
+    const addr = alloc(4);
+    octets.forEach((octet, i) => {
+        write(addr, UINT8, i, octet);
+    });
+    return addr;
+};
+
+describe("call - struct types - by value", () => {
+    it("returns a struct by value as a new handle", () => {
+        const result = call(
+            LIBC,
+            "div",
+            [
+                { type: INT32, value: 17 },
+                { type: INT32, value: 5 },
+            ],
+            DIV_T,
+        ) as NativeHandle;
+
+        expect(read(result, INT32, 0)).toBe(3);
+        expect(read(result, INT32, 4)).toBe(2);
+    });
+
+    it("passes a struct by value", () => {
+        const address = call(LIBC, "inet_ntoa", [{ type: IN_ADDR, value: ipv4([127, 0, 0, 1]) }], STRING_BORROWED);
+
+        expect(address).toBe("127.0.0.1");
+    });
+
+    it("copies the struct at call time", () => {
+        const addr = ipv4([10, 1, 2, 3]);
+        const before = call(LIBC, "inet_ntoa", [{ type: IN_ADDR, value: addr }], STRING_BORROWED);
+        write(addr, UINT8, 0, 192);
+        const after = call(LIBC, "inet_ntoa", [{ type: IN_ADDR, value: addr }], STRING_BORROWED);
+
+        expect(before).toBe("10.1.2.3");
+        expect(after).toBe("192.1.2.3");
+    });
+
+    it("throws when a by-value struct has no fields", () => {
+        expect(() =>
+            call(
+                LIBC,
+                "div",
+                [
+                    { type: INT32, value: 1 },
+                    { type: INT32, value: 1 },
+                ],
+                { type: "struct", innerType: "div_t", ownership: "full", byValue: true },
+            ),
+        ).toThrow("'fields' property is required");
+    });
+});
+
+/** Offset of the first `GObjectClass.pdummy` slot, which GObject never calls. */
+const GOBJECT_CLASS_PDUMMY = 112;
+
+describe("call - struct types - by value through a trampoline", () => {
+    it("passes and returns a struct by value to and from a JS callback", () => {
+        const gobject = Number(
+            call(GOBJECT_LIB, "g_type_from_name", [{ type: STRING_BORROWED, value: "GObject" }], UINT64),
+        );
+        const swap = (pair: unknown): NativeHandle => {
+            const swapped = alloc(8);
+            write(swapped, INT32, 0, read(pair as NativeHandle, INT32, 4));
+            write(swapped, INT32, 4, read(pair as NativeHandle, INT32, 0));
+            return swapped;
+        };
+        const vfunc = { byteOffset: GOBJECT_CLASS_PDUMMY, argTypes: [DIV_T], returnType: DIV_T };
+        const base = registerClass(`GtkxByValueBase${process.pid}`, gobject, { vfuncs: [{ ...vfunc, fn: swap }] });
+        const derived = registerClass(`GtkxByValueDerived${process.pid}`, base, {
+            vfuncs: [{ ...vfunc, fn: (pair: unknown) => pair }],
+        });
+        const pair = alloc(8);
+        write(pair, INT32, 0, 17);
+        write(pair, INT32, 4, -5);
+
+        const result = chainUp(derived, GOBJECT_CLASS_PDUMMY, [pair]) as NativeHandle;
+
+        expect(read(result, INT32, 0)).toBe(-5);
+        expect(read(result, INT32, 4)).toBe(17);
+    });
+});
@@ -0,0 +1,116 @@
+import { describe, expect, it } from "vitest";
+import { alloc, call, read, write } from "../../../index.js";
+import { FLOAT64, GOBJECT_LIB, INT32, INT64, POINTER, UINT32, VOID } from "../utils.js";
+
+const G_TYPE_INT = 6 << 2;
+const G_TYPE_DOUBLE = 15 << 2;
+const GVALUE_DATA_OFFSET = 8;
+
+const GVALUE_PTR = { type: "struct" as const, innerType: "GValue", ownership: "borrowed" as const };
+
+const GVALUE_DATA = {
+    type: "union" as const,
+    ownership: "borrowed" as const,
+    size: 8,
+    alignment: 8,
+    variants: [
+        { name: "v_double", type: FLOAT64 },
+        { name: "v_int", type: INT32 },
+        { name: "v_int64", type: INT64 },
+    ],
+};
+
+const EVENT = {
+    type: "union" as const,
+    ownership: "borrowed" as const,
+    size: 16,
+    alignment: 8,
+    discriminant: { offset: 0, type: INT32 },
+    variants: [
+        {
+            name: "button",
+            tag: 1,
+            type: {
+                type: "record" as const,
+                ownership: "borrowed" as const,
+                fields: [{ name: "x", offset: 8, type: FLOAT64 }],
+            },
+        },
+        {
+            name: "key",
+            tag: 2,
+            type: {
+                type: "record" as const,
+                ownership: "borrowed" as const,
+                fields: [{ name: "keyval", offset: 4, type: UINT32 }],
+            },
+        },
+    ],
+};
+
+const initGValue = (gtype: number) => {
+    const gvalue = alloc(24);
+    call(
+        GOBJECT_LIB,
+        "g_value_init",
+        [
+            { type: GVALUE_PTR, value: gvalue },
+            { type: POINTER, value: gtype },
+        ],
+        POINTER,
+    );
+    return gvalue;
+};
+
+describe("read/write - union types", () => {
+    it("reads the first variant when there is no discriminant", () => {
+        const gvalue = initGValue(G_TYPE_DOUBLE);
+        call(
+            GOBJECT_LIB,
+            "g_value_set_double",
+            [
+                { type: GVALUE_PTR, value: gvalue },
+                { type: FLOAT64, value: 2.5 },
+            ],
+            VOID,
+        );
+
+        expect(read(gvalue, GVALUE_DATA, GVALUE_DATA_OFFSET)).toEqual({ tag: "v_double", value: 2.5 });
+    });
+
+    it("writes the tagged variant", () => {
+        const gvalue = initGValue(G_TYPE_INT);
+
+        write(gvalue, GVALUE_DATA, GVALUE_DATA_OFFSET, { tag: "v_int", value: -7 });
+
+        expect(call(GOBJECT_LIB, "g_value_get_int", [{ type: GVALUE_PTR, value: gvalue }], INT32)).toBe(-7);
+    });
+
+    it("decodes the variant its discriminant selects", () => {
+        const handle = alloc(16);
+        write(handle, INT32, 0, 2);
+        write(handle, UINT32, 4, 65);
+
+        expect(read(handle, EVENT, 0)).toEqual({ tag: "key", value: { keyval: 65 } });
+    });
+
+    it("stores the discriminant of the written variant", () => {
+        const handle = alloc(16);
+
+        write(handle, EVENT, 0, { tag: "button", value: { x: 1.5 } });
+
+        expect(read(handle, INT32, 0)).toBe(1);
+        expect(read(handle, EVENT, 0)).toEqual({ tag: "button", value: { x: 1.5 } });
+    });
+
+    it("rejects an unknown tag", () => {
+        expect(() => write(alloc(16), EVENT, 0, { tag: "touch", value: {} })).toThrow("no variant named 'touch'");
+    });
+
+    it("rejects a discriminant that matches no variant", () => {
+        const handle = alloc(16);
+        write(handle, INT32, 0, 9);
+
+        expect(() => read(handle, EVENT, 0)).toThrow("matches none of its variants");
+    });
+});
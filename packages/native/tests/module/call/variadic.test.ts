@@ -0,0 +1,123 @@
+import { describe, expect, it } from "vitest";
+import { call } from "../../../index.js";
+import {
+    BOOLEAN,
+    FLOAT32,
+    GOBJECT,
+    GOBJECT_BORROWED,
+    GOBJECT_LIB,
+    GTK_LIB,
+    INT8,
+    INT32,
+    POINTER,
+    STRING,
+    STRING_BORROWED,
+    UINT8,
+    UINT16,
+} from "../utils.js";
+import { getLabelSelectable } from "./_helpers.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+
+describe("call - variadic functions", () => {
+    it("passes trailing arguments through varargs", () => {
+        const result = call(
+            GLIB_LIB,
+            "g_strdup_printf",
+            [
+                { type: STRING_BORROWED, value: "%s has %d items" },
+                { type: STRING_BORROWED, value: "list" },
+                { type: INT32, value: 3 },
+            ],
+            STRING,
+            { variadic: { fixedArgs: 1 } },
+        );
+
+        expect(result).toBe("list has 3 items");
+    });
+
+    it("promotes narrow integers and floats", () => {
+        const result = call(
+            GLIB_LIB,
+            "g_strdup_printf",
+            [
+                { type: STRING_BORROWED, value: "%d %u %.2f" },
+                { type: INT8, value: -7 },
+                { type: UINT16, value: 65535 },
+                { type: FLOAT32, value: 0.25 },
+            ],
+            STRING,
+            { variadic: { fixedArgs: 1 } },
+        );
+
+        expect(result).toBe("-7 65535 0.25");
+    });
+
+    it("rejects a narrow argument outside its declared range", () => {
+        expect(() =>
+            call(
+                GLIB_LIB,
+                "g_strdup_printf",
+                [
+                    { type: STRING_BORROWED, value: "%u" },
+                    { type: UINT8, value: 300 },
+                ],
+                STRING,
+                { variadic: { fixedArgs: 1 } },
+            ),
+        ).toThrow("Value 300 is out of range for u8");
+    });
+
+    it("narrows a float32 argument before promoting it", () => {
+        const result = call(
+            GLIB_LIB,
+            "g_strdup_printf",
+            [
+                { type: STRING_BORROWED, value: "%.10f" },
+                { type: FLOAT32, value: 0.1 },
+            ],
+            STRING,
+            { variadic: { fixedArgs: 1 } },
+        );
+
+        expect(result).toBe("0.1000000015");
+    });
+
+    it("calls a variadic function without trailing arguments", () => {
+        const result = call(GLIB_LIB, "g_strdup_printf", [{ type: STRING_BORROWED, value: "plain" }], STRING, {
+            variadic: { fixedArgs: 1 },
+        });
+
+        expect(result).toBe("plain");
+    });
+
+    it("constructs an object with g_object_new", () => {
+        const labelType = call(GTK_LIB, "gtk_label_get_type", [], POINTER);
+        const label = call(
+            GOBJECT_LIB,
+            "g_object_new",
+            [
+                { type: POINTER, value: labelType },
+                { type: STRING_BORROWED, value: "label" },
+                { type: STRING_BORROWED, value: "Hello" },
+                { type: STRING_BORROWED, value: "selectable" },
+                { type: BOOLEAN, value: true },
+                { type: POINTER, value: 0 },
+            ],
+            GOBJECT,
+            { variadic: { fixedArgs: 2 } },
+        );
+
+        const text = call(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED);
+        expect(text).toBe("Hello");
+        expect(getLabelSelectable(label)).toBe(true);
+    });
+
+    it("throws when fixedArgs exceeds the argument count", () => {
+        expect(() =>
+            call(GLIB_LIB, "g_strdup_printf", [{ type: STRING_BORROWED, value: "plain" }], STRING, {
+                variadic: { fixedArgs: 2 },
+            }),
+        ).toThrow("declares 2 fixed argument(s)");
+    });
+});
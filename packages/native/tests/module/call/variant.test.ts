@@ -0,0 +1,156 @@
+import { describe, expect, it } from "vitest";
+import { call, callPrepared, prepare } from "../../../index.js";
+import type { Type } from "../../../types.js";
+import { BOOLEAN, GIO_LIB, GOBJECT, GOBJECT_BORROWED, POINTER, STRING, STRING_BORROWED } from "../utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+const VARIANT_FULL = { type: "variant" as const, ownership: "full" as const };
+
+const variantOf = (signature: string): Type => ({ type: "variant", ownership: "borrowed", signature });
+
+const print = (signature: string, value: unknown): string =>
+    call(
+        GLIB_LIB,
+        "g_variant_print",
+        [
+            { type: variantOf(signature), value },
+            { type: BOOLEAN, value: true },
+        ],
+        STRING,
+    ) as string;
+
+const parse = (text: string): unknown =>
+    call(
+        GLIB_LIB,
+        "g_variant_parse",
+        [
+            { type: POINTER, value: 0 },
+            { type: STRING_BORROWED, value: text },
+            { type: POINTER, value: 0 },
+            { type: POINTER, value: 0 },
+            { type: { type: "gerror" }, value: null },
+        ],
+        VARIANT_FULL,
+    );
+
+describe("call - variant encoding", () => {
+    it("encodes basic types from their signature", () => {
+        expect(print("b", true)).toBe("true");
+        expect(print("i", -7)).toBe("-7");
+        expect(print("u", 7)).toBe("uint32 7");
+        expect(print("d", 1.5)).toBe("1.5");
+        expect(print("s", "hello")).toBe("'hello'");
+        expect(print("o", "/org/gtkx")).toBe("objectpath '/org/gtkx'");
+    });
+
+    it("encodes 64-bit integers from bigints and safe numbers", () => {
+        expect(print("x", -(2n ** 62n))).toBe("int64 -4611686018427387904");
+        expect(print("t", 2n ** 64n - 1n)).toBe("uint64 18446744073709551615");
+        expect(print("t", 42)).toBe("uint64 42");
+    });
+
+    it("encodes byte arrays from a Uint8Array or a number array", () => {
+        expect(print("ay", new Uint8Array([1, 2, 255]))).toBe("[byte 0x01, 0x02, 0xff]");
+        expect(print("ay", [1, 2])).toBe("[byte 0x01, 0x02]");
+    });
+
+    it("encodes a{sv} from an object of tagged values", () => {
+        const printed = print("a{sv}", {
+            name: { signature: "s", value: "gtkx" },
+            size: { signature: "u", value: 3 },
+        });
+
+        expect(printed).toBe("{'name': <'gtkx'>, 'size': <uint32 3>}");
+    });
+
+    it("encodes dictionaries with non-string keys", () => {
+        expect(print("a{is}", { 1: "one", 2: "two" })).toBe("{1: 'one', 2: 'two'}");
+    });
+
+    it("encodes tuples, maybes, and nested arrays", () => {
+        expect(print("(sib)", ["a", 1, false])).toBe("('a', 1, false)");
+        expect(print("ms", null)).toBe("@ms nothing");
+        expect(print("ms", "x")).toBe("@ms 'x'");
+        expect(print("aas", [["a"], ["b", "c"]])).toBe("[['a'], ['b', 'c']]");
+    });
+
+    it("rejects values that do not match the signature", () => {
+        expect(() => print("i", "nope")).toThrow("Invalid value for variant type 'i'");
+        expect(() => print("y", 256)).toThrow("Invalid value for variant type 'y'");
+        expect(() => print("(si)", ["only one"])).toThrow("expected 2 members, got 1");
+        expect(() => print("t", -1n)).toThrow("out of range for uint64");
+    });
+
+    it("rejects indefinite or malformed signatures", () => {
+        expect(() => print("a*", [])).toThrow("not a definite type");
+        expect(() => print("a{s", {})).toThrow("Invalid variant signature");
+    });
+
+    it("requires a signature to encode a JS value", () => {
+        const args = [
+            { type: { type: "variant" as const, ownership: "borrowed" as const }, value: 1 },
+            { type: BOOLEAN, value: true },
+        ];
+
+        expect(() => call(GLIB_LIB, "g_variant_print", args, STRING)).toThrow("'signature' property is required");
+    });
+
+    it("encodes prepared call values from the slot signature", () => {
+        const prepared = prepare(GLIB_LIB, "g_variant_print", [{ type: variantOf("(ss)") }, { type: BOOLEAN }], STRING);
+
+        expect(callPrepared(prepared, [["a", "b"], false])).toBe("('a', 'b')");
+    });
+});
+
+describe("call - variant decoding", () => {
+    it("decodes basic types", () => {
+        expect(parse("true")).toBe(true);
+        expect(parse("int16 -3")).toBe(-3);
+        expect(parse("2.5")).toBe(2.5);
+        expect(parse("'text'")).toBe("text");
+        expect(parse("objectpath '/a/b'")).toBe("/a/b");
+    });
+
+    it("decodes 64-bit integers as bigints", () => {
+        expect(parse("int64 -9007199254740993")).toBe(-9007199254740993n);
+        expect(parse("uint64 18446744073709551615")).toBe(18446744073709551615n);
+    });
+
+    it("decodes byte arrays as a Uint8Array", () => {
+        const bytes = parse("[byte 0x01, 0x02, 0x03]");
+
+        expect(bytes).toBeInstanceOf(Uint8Array);
+        expect(Array.from(bytes as Uint8Array)).toEqual([1, 2, 3]);
+    });
+
+    it("decodes a{sv} as an object of tagged values", () => {
+        expect(parse("{'name': <'gtkx'>, 'count': <int32 2>}")).toEqual({
+            name: { signature: "s", value: "gtkx" },
+            count: { signature: "i", value: 2 },
+        });
+    });
+
+    it("decodes tuples, maybes, and arrays", () => {
+        expect(parse("('a', 1, [true, false])")).toEqual(["a", 1, [true, false]]);
+        expect(parse("@mi nothing")).toBeNull();
+        expect(parse("@mi 5")).toBe(5);
+        expect(parse("@a{ii} {1: 2}")).toEqual({ 1: 2 });
+    });
+
+    it("round-trips through a GSimpleAction state", () => {
+        const action = call(
+            GIO_LIB,
+            "g_simple_action_new_stateful",
+            [
+                { type: STRING_BORROWED, value: "zoom" },
+                { type: POINTER, value: 0 },
+                { type: variantOf("(sd)"), value: ["level", 1.25] },
+            ],
+            GOBJECT,
+        );
+
+        const state = call(GIO_LIB, "g_action_get_state", [{ type: GOBJECT_BORROWED, value: action }], VARIANT_FULL);
+
+        expect(state).toEqual(["level", 1.25]);
+    });
+});
@@ -0,0 +1,117 @@
+import { describe, expect, it } from "vitest";
+import {
+    blockSignal,
+    call,
+    connectSignal,
+    disconnectSignal,
+    getNativeId,
+    type NativeHandle,
+    setProperties,
+    unblockSignal,
+} from "../../index.js";
+import type { Type } from "../../types.js";
+import { GIO_LIB, GOBJECT, GOBJECT_BORROWED, STRING_BORROWED, VOID } from "./utils.js";
+
+const STRING_VARIANT: Type = { type: "variant", ownership: "borrowed", signature: "s" };
+
+/** `parameterType` doubles as the `GVariantType*`, as `G_VARIANT_TYPE` does in C. */
+const createAction = (parameterType: string | null): NativeHandle =>
+    call(
+        GIO_LIB,
+        "g_simple_action_new",
+        [
+            { type: STRING_BORROWED, value: "test-action" },
+            { type: STRING_BORROWED, value: parameterType },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+const activate = (action: NativeHandle, parameter: string | null = null): void => {
+    call(
+        GIO_LIB,
+        "g_action_activate",
+        [
+            { type: GOBJECT_BORROWED, value: action },
+            { type: STRING_VARIANT, value: parameter },
+        ],
+        VOID,
+    );
+};
+
+describe("connectSignal", () => {
+    it("passes the instance and decoded parameters to the handler", () => {
+        const action = createAction("s");
+        const received: unknown[][] = [];
+
+        const handlerId = connectSignal(action, "activate", (...args) => {
+            received.push(args);
+        });
+        activate(action, "hello");
+
+        expect(handlerId).toBeGreaterThan(0);
+        expect(received).toHaveLength(1);
+        expect(getNativeId(received[0]?.[0] as NativeHandle)).toBe(getNativeId(action));
+        expect(received[0]?.[1]).toBe("hello");
+    });
+
+    it("connects to a detailed signal", () => {
+        const action = createAction(null);
+        const notified: string[] = [];
+
+        connectSignal(action, "notify::enabled", () => notified.push("enabled"));
+        connectSignal(action, "notify::state", () => notified.push("state"));
+        setProperties(action, { enabled: false });
+
+        expect(notified).toEqual(["enabled"]);
+    });
+
+    it("runs handlers connected with after last", () => {
+        const action = createAction(null);
+        const order: string[] = [];
+
+        connectSignal(action, "activate", () => order.push("after"), { after: true });
+        connectSignal(action, "activate", () => order.push("before"));
+        activate(action);
+
+        expect(order).toEqual(["before", "after"]);
+    });
+
+    it("throws for an unknown signal", () => {
+        const action = createAction(null);
+
+        expect(() => connectSignal(action, "no-such-signal", () => {})).toThrow(
+            /GSimpleAction has no signal 'no-such-signal'/,
+        );
+    });
+});
+
+describe("signal handler ids", () => {
+    it("blocks, unblocks and disconnects a handler", () => {
+        const action = createAction(null);
+        let count = 0;
+        const handlerId = connectSignal(action, "activate", () => {
+            count++;
+        });
+
+        blockSignal(action, handlerId);
+        activate(action);
+        expect(count).toBe(0);
+
+        unblockSignal(action, handlerId);
+        activate(action);
+        expect(count).toBe(1);
+
+        disconnectSignal(action, handlerId);
+        activate(action);
+        expect(count).toBe(1);
+    });
+
+    it("throws for a handler that is not connected", () => {
+        const action = createAction(null);
+        const handlerId = connectSignal(action, "activate", () => {});
+        disconnectSignal(action, handlerId);
+
+        expect(() => disconnectSignal(action, handlerId)).toThrow(/no signal handler/);
+        expect(() => blockSignal(action, handlerId)).toThrow(/no signal handler/);
+    });
+});
@@ -0,0 +1,84 @@
+import { describe, expect, it } from "vitest";
+import { call, connectSignal, emitSignal, type NativeHandle } from "../../index.js";
+import type { Type } from "../../types.js";
+import { GIO_LIB, GOBJECT, POINTER, STRING_BORROWED, UINT32 } from "./utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+
+const STRING_VARIANT: Type = { type: "variant", ownership: "borrowed", signature: "s" };
+
+const VARIANT_DICT: Type = {
+    type: "boxed",
+    ownership: "full",
+    innerType: "GVariantDict",
+    library: GLIB_LIB,
+    getTypeFn: "g_variant_dict_get_type",
+};
+
+const createAction = (parameterType: string | null): NativeHandle =>
+    call(
+        GIO_LIB,
+        "g_simple_action_new",
+        [
+            { type: STRING_BORROWED, value: "test-action" },
+            { type: STRING_BORROWED, value: parameterType },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+const createApplication = (): NativeHandle =>
+    call(
+        GIO_LIB,
+        "g_application_new",
+        [
+            { type: STRING_BORROWED, value: null },
+            { type: UINT32, value: 0 },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+const handleLocalOptions = (application: NativeHandle): unknown => {
+    const options = call(GLIB_LIB, "g_variant_dict_new", [{ type: POINTER, value: 0 }], VARIANT_DICT);
+    return emitSignal(application, "handle-local-options", [options]);
+};
+
+describe("emitSignal", () => {
+    it("emits a signal without a return value", () => {
+        const action = createAction(null);
+        let count = 0;
+        connectSignal(action, "activate", () => {
+            count++;
+        });
+
+        expect(emitSignal(action, "activate", [null])).toBeUndefined();
+        expect(count).toBe(1);
+    });
+
+    it("builds arguments through their descriptors", () => {
+        const action = createAction("s");
+        const received: unknown[] = [];
+        connectSignal(action, "activate", (_action, parameter) => {
+            received.push(parameter);
+        });
+
+        emitSignal(action, "activate", ["hello"], [STRING_VARIANT]);
+
+        expect(received).toEqual(["hello"]);
+    });
+
+    it("returns the signal's return value", () => {
+        const application = createApplication();
+        expect(handleLocalOptions(application)).toBe(-1);
+
+        connectSignal(application, "handle-local-options", () => 0);
+        expect(handleLocalOptions(application)).toBe(0);
+    });
+
+    it("throws for a wrong argument count or type", () => {
+        const action = createAction(null);
+
+        expect(() => emitSignal(action, "activate")).toThrow(/takes 1 arguments, got 0/);
+        expect(() => emitSignal(action, "activate", [42])).toThrow(/signal argument 0/);
+        expect(() => emitSignal(action, "no-such-signal")).toThrow(/has no signal 'no-such-signal'/);
+    });
+});
@@ -0,0 +1,71 @@
+import { describe, expect, it } from "vitest";
+import { call, createRef, enqueue, flush } from "../../index.js";
+import { createLabel, GOBJECT_BORROWED, GTK_LIB, INT32, STRING_BORROWED } from "./utils.js";
+
+const getLabelText = (label: unknown): unknown =>
+    call(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED);
+
+describe("enqueue", () => {
+    it("runs the call once flushed", () => {
+        const label = createLabel("Initial");
+
+        enqueue(GTK_LIB, "gtk_label_set_text", [
+            { type: GOBJECT_BORROWED, value: label },
+            { type: STRING_BORROWED, value: "Enqueued" },
+        ]);
+        flush();
+
+        expect(getLabelText(label)).toBe("Enqueued");
+    });
+
+    it("runs enqueued calls in order", () => {
+        const label = createLabel("Initial");
+
+        for (const text of ["One", "Two", "Three"]) {
+            enqueue(GTK_LIB, "gtk_label_set_text", [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: STRING_BORROWED, value: text },
+            ]);
+        }
+        flush();
+
+        expect(getLabelText(label)).toBe("Three");
+    });
+
+    it("runs before a synchronous call made after it", () => {
+        const label = createLabel("Initial");
+
+        enqueue(GTK_LIB, "gtk_label_set_text", [
+            { type: GOBJECT_BORROWED, value: label },
+            { type: STRING_BORROWED, value: "Ordered" },
+        ]);
+
+        expect(getLabelText(label)).toBe("Ordered");
+    });
+
+    it("rejects calls that return a value", () => {
+        const label = createLabel("Test");
+
+        expect(() =>
+            enqueue(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED),
+        ).toThrow("only void calls can be enqueued");
+    });
+
+    it("rejects calls with ref arguments", () => {
+        const label = createLabel("Test");
+
+        expect(() =>
+            enqueue(GTK_LIB, "gtk_widget_get_size_request", [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: { type: "ref", innerType: INT32 }, value: createRef(0) },
+                { type: { type: "ref", innerType: INT32 }, value: createRef(0) },
+            ]),
+        ).toThrow("calls with out-parameters cannot be enqueued");
+    });
+});
+
+describe("flush", () => {
+    it("returns immediately when nothing is queued", () => {
+        expect(() => flush()).not.toThrow();
+    });
+});
@@ -0,0 +1,85 @@
+import { describe, expect, it } from "vitest";
+import { call, getNativeId, getProperty, type NativeHandle, setProperties } from "../../index.js";
+import type { Type } from "../../types.js";
+import { createButton, createLabel, GIO_LIB, GOBJECT, POINTER, STRING_BORROWED } from "./utils.js";
+
+const GTK_ALIGN_CENTER = 3;
+
+const INT32_VARIANT: Type = { type: "variant", ownership: "borrowed", signature: "i" };
+
+const createStatefulAction = (state: number): NativeHandle =>
+    call(
+        GIO_LIB,
+        "g_simple_action_new_stateful",
+        [
+            { type: STRING_BORROWED, value: "test-action" },
+            { type: POINTER, value: 0 },
+            { type: INT32_VARIANT, value: state },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+describe("getProperty", () => {
+    it("decodes a property by its type", () => {
+        const label = createLabel("Hello") as NativeHandle;
+
+        expect(getProperty(label, "label")).toBe("Hello");
+        expect(getProperty(label, "selectable")).toBe(false);
+        expect(getProperty(label, "max-width-chars")).toBe(-1);
+    });
+
+    it("decodes object and variant properties", () => {
+        const label = createLabel("Hello") as NativeHandle;
+        expect(getProperty(label, "mnemonic-widget")).toBeNull();
+
+        const action = createStatefulAction(4);
+        expect(getProperty(action, "state")).toBe(4);
+    });
+
+    it("throws for an unknown property", () => {
+        const label = createLabel("Hello") as NativeHandle;
+
+        expect(() => getProperty(label, "no-such-property")).toThrow(/GtkLabel has no property 'no-such-property'/);
+    });
+});
+
+describe("setProperties", () => {
+    it("sets several properties at once", () => {
+        const label = createLabel("Hello") as NativeHandle;
+        const button = createButton("Open") as NativeHandle;
+
+        setProperties(label, {
+            label: "_Open",
+            "use-underline": true,
+            halign: GTK_ALIGN_CENTER,
+            "mnemonic-widget": button,
+        });
+
+        expect(getProperty(label, "label")).toBe("_Open");
+        expect(getProperty(label, "use-underline")).toBe(true);
+        expect(getProperty(label, "halign")).toBe(GTK_ALIGN_CENTER);
+        expect(getNativeId(getProperty(label, "mnemonic-widget") as NativeHandle)).toBe(getNativeId(button));
+    });
+
+    it("builds a property value through its descriptor", () => {
+        const action = createStatefulAction(1);
+
+        setProperties(action, { state: 9 }, { state: INT32_VARIANT });
+
+        expect(getProperty(action, "state")).toBe(9);
+    });
+
+    it("leaves the object unchanged when a value is rejected", () => {
+        const label = createLabel("Hello") as NativeHandle;
+
+        expect(() => setProperties(label, { label: "Changed", "max-width-chars": -5 })).toThrow(/out of range/);
+        expect(() => setProperties(label, { label: "Changed", selectable: "yes" })).toThrow(/property 'selectable'/);
+        expect(getProperty(label, "label")).toBe("Hello");
+    });
+
+    it("throws for an unknown property", () => {
+        const label = createLabel("Hello") as NativeHandle;
+
+        expect(() => setProperties(label, { "no-such-property": 1 })).toThrow(/no property 'no-such-property'/);
+    });
+});
@@ -0,0 +1,94 @@
+import { describe, expect, it } from "vitest";
+import { callPrepared, createRef, prepare } from "../../index.js";
+import { createLabel, GOBJECT, GOBJECT_BORROWED, GTK_LIB, INT32, STRING, STRING_BORROWED, VOID } from "./utils.js";
+
+describe("prepare", () => {
+    it("returns a handle for a known symbol", () => {
+        const prepared = prepare(GTK_LIB, "gtk_label_new", [{ type: STRING_BORROWED }], GOBJECT);
+
+        expect(prepared).toBeDefined();
+    });
+
+    it("throws on an unknown symbol", () => {
+        expect(() => prepare(GTK_LIB, "nonexistent_function_xyz", [], VOID)).toThrow();
+    });
+
+    it("throws on an unknown library", () => {
+        expect(() => prepare("libnonexistent.so.1", "some_function", [], VOID)).toThrow();
+    });
+
+    it("rejects a return type that cannot occupy the return slot", () => {
+        expect(() => prepare(GTK_LIB, "gtk_get_major_version", [], { type: "ref", innerType: INT32 })).toThrow(
+            "cannot be used as a function return type",
+        );
+    });
+});
+
+describe("callPrepared", () => {
+    it("calls the prepared function repeatedly", () => {
+        const label = createLabel("Initial");
+        const setText = prepare(
+            GTK_LIB,
+            "gtk_label_set_text",
+            [{ type: GOBJECT_BORROWED }, { type: STRING_BORROWED }],
+            VOID,
+        );
+        const getText = prepare(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED }], STRING_BORROWED);
+
+        for (const text of ["One", "Two", "Three"]) {
+            callPrepared(setText, [label, text]);
+            expect(callPrepared(getText, [label])).toBe(text);
+        }
+    });
+
+    it("returns an object handle", () => {
+        const labelNew = prepare(GTK_LIB, "gtk_label_new", [{ type: STRING_BORROWED }], GOBJECT);
+
+        const label = callPrepared(labelNew, ["Prepared"]);
+        const text = callPrepared(
+            prepare(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED }], STRING_BORROWED),
+            [label],
+        );
+
+        expect(text).toBe("Prepared");
+    });
+
+    it("accepts null for optional arguments", () => {
+        const labelNew = prepare(GTK_LIB, "gtk_label_new", [{ type: STRING, optional: true }], GOBJECT);
+
+        expect(callPrepared(labelNew, [null])).not.toBeNull();
+    });
+
+    it("writes back ref out-parameters", () => {
+        const label = createLabel("Test");
+        const getSize = prepare(
+            GTK_LIB,
+            "gtk_widget_get_size_request",
+            [
+                { type: GOBJECT_BORROWED },
+                { type: { type: "ref", innerType: INT32 } },
+                { type: { type: "ref", innerType: INT32 } },
+            ],
+            VOID,
+        );
+        const width = createRef(0);
+        const height = createRef(0);
+
+        callPrepared(getSize, [label, width, height]);
+
+        expect(width.value).toBe(-1);
+        expect(height.value).toBe(-1);
+    });
+
+    it("throws when the argument count does not match", () => {
+        const getText = prepare(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED }], STRING_BORROWED);
+
+        expect(() => callPrepared(getText, [])).toThrow("expects 1 argument(s), got 0");
+    });
+
+    it("throws when an argument cannot be encoded", () => {
+        const labelNew = prepare(GTK_LIB, "gtk_label_new", [{ type: STRING_BORROWED }], GOBJECT);
+
+        expect(() => callPrepared(labelNew, [42])).toThrow("encoding arg 0 of gtk_label_new");
+    });
+});
@@ -0,0 +1,93 @@
+import { describe, expect, it } from "vitest";
+import { alloc, read, readMany, write, writeMany } from "../../index.js";
+import { BOOLEAN, INT8, INT32, UINT32 } from "./utils.js";
+
+const INT32_INLINE_4 = {
+    type: "array" as const,
+    itemType: INT32,
+    kind: "inline" as const,
+    fixedSize: 4,
+    ownership: "borrowed" as const,
+};
+
+describe("read/write - bitfields", () => {
+    it("round-trips a bitfield without touching neighbouring bits", () => {
+        const handle = alloc(4);
+        write(handle, UINT32, 0, 0xffffffff);
+
+        write(handle, UINT32, 0, 0, { bitOffset: 8, bitWidth: 4 });
+
+        expect(read(handle, UINT32, 0, { bitOffset: 8, bitWidth: 4 })).toBe(0);
+        expect(read(handle, UINT32, 0, { bitOffset: 0, bitWidth: 8 })).toBe(0xff);
+        expect(read(handle, UINT32, 0, { bitOffset: 12, bitWidth: 20 })).toBe(0xfffff);
+    });
+
+    it("sign-extends signed bitfields", () => {
+        const handle = alloc(1);
+
+        write(handle, INT8, 0, -2, { bitWidth: 3 });
+
+        expect(read(handle, INT8, 0, { bitWidth: 3 })).toBe(-2);
+    });
+
+    it("reads and writes boolean bitfields", () => {
+        const handle = alloc(4);
+
+        write(handle, BOOLEAN, 0, true, { bitOffset: 1, bitWidth: 1 });
+
+        expect(read(handle, BOOLEAN, 0, { bitOffset: 1, bitWidth: 1 })).toBe(true);
+        expect(read(handle, BOOLEAN, 0, { bitOffset: 0, bitWidth: 1 })).toBe(false);
+    });
+
+    it("rejects values wider than the bitfield", () => {
+        const handle = alloc(4);
+
+        expect(() => write(handle, UINT32, 0, 8, { bitWidth: 3 })).toThrow("out of range for a 3-bit bitfield");
+    });
+
+    it("rejects a bitfield that overflows its unit", () => {
+        expect(() => read(alloc(1), INT8, 0, { bitOffset: 6, bitWidth: 4 })).toThrow("does not fit");
+    });
+
+    it("accepts bitfields in readMany and writeMany", () => {
+        const handle = alloc(4);
+
+        writeMany(handle, [
+            { offset: 0, type: UINT32, bitWidth: 3, value: 5 },
+            { offset: 0, type: BOOLEAN, bitOffset: 3, bitWidth: 1, value: true },
+        ]);
+
+        expect(
+            readMany(handle, [
+                { offset: 0, type: UINT32, bitWidth: 3 },
+                { offset: 0, type: BOOLEAN, bitOffset: 3, bitWidth: 1 },
+                { offset: 0, type: UINT32, bitOffset: 4, bitWidth: 28 },
+            ]),
+        ).toEqual([5, true, 0]);
+    });
+});
+
+describe("read/write - inline arrays", () => {
+    it("reads elements embedded at the offset", () => {
+        const handle = alloc(20);
+        for (let i = 0; i < 4; i++) {
+            write(handle, INT32, 4 + i * 4, i * 10);
+        }
+
+        expect(read(handle, INT32_INLINE_4, 4)).toEqual([0, 10, 20, 30]);
+    });
+
+    it("writes elements in place", () => {
+        const handle = alloc(16);
+
+        write(handle, INT32_INLINE_4, 0, [7, 8]);
+
+        expect(read(handle, INT32, 0)).toBe(7);
+        expect(read(handle, INT32, 4)).toBe(8);
+        expect(read(handle, INT32, 8)).toBe(0);
+    });
+
+    it("rejects more elements than the array holds", () => {
+        expect(() => write(alloc(16), INT32_INLINE_4, 0, [1, 2, 3, 4, 5])).toThrow("cannot hold 5 elements");
+    });
+});
@@ -0,0 +1,81 @@
+import { describe, expect, it } from "vitest";
+import { alloc, read, readMany, write, writeMany } from "../../index.js";
+import { FLOAT32, GDK_LIB, INT32, STRING, UINT8 } from "./utils.js";
+
+describe("readMany and writeMany", () => {
+    it("writes and reads several fields at once", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+
+        writeMany(rgba, [
+            { offset: 0, type: FLOAT32, value: 1.0 },
+            { offset: 4, type: FLOAT32, value: 0.5 },
+            { offset: 8, type: FLOAT32, value: 0.25 },
+            { offset: 12, type: FLOAT32, value: 0.75 },
+        ]);
+
+        expect(
+            readMany(rgba, [
+                { offset: 0, type: FLOAT32 },
+                { offset: 4, type: FLOAT32 },
+                { offset: 8, type: FLOAT32 },
+                { offset: 12, type: FLOAT32 },
+            ]),
+        ).toEqual([1.0, 0.5, 0.25, 0.75]);
+    });
+
+    it("matches individual reads of mixed types", () => {
+        const handle = alloc(16);
+        write(handle, INT32, 0, -42);
+        write(handle, STRING, 8, "hello");
+
+        const [number, text] = readMany(handle, [
+            { offset: 0, type: INT32 },
+            { offset: 8, type: STRING },
+        ]);
+
+        expect(number).toBe(read(handle, INT32, 0));
+        expect(text).toBe("hello");
+    });
+
+    it("returns an empty array for no fields", () => {
+        expect(readMany(alloc(4), [])).toEqual([]);
+    });
+
+    it("applies overlapping writes in order", () => {
+        const handle = alloc(4);
+
+        writeMany(handle, [
+            { offset: 0, type: INT32, value: 0 },
+            { offset: 1, type: UINT8, value: 7 },
+        ]);
+
+        expect(read(handle, UINT8, 0)).toBe(0);
+        expect(read(handle, UINT8, 1)).toBe(7);
+    });
+
+    it("leaves memory untouched when any value is rejected", () => {
+        const handle = alloc(8);
+        write(handle, INT32, 0, 1);
+
+        expect(() =>
+            writeMany(handle, [
+                { offset: 0, type: INT32, value: 2 },
+                { offset: 4, type: INT32, value: "not a number" },
+            ]),
+        ).toThrow("field 1");
+        expect(read(handle, INT32, 0)).toBe(1);
+    });
+
+    it("rejects fields that allocate memory", () => {
+        const handle = alloc(16);
+        write(handle, INT32, 0, 1);
+
+        expect(() =>
+            writeMany(handle, [
+                { offset: 0, type: INT32, value: 2 },
+                { offset: 8, type: STRING, value: "hello" },
+            ]),
+        ).toThrow("field 1: String fields allocate memory and cannot be written by writeMany");
+        expect(read(handle, INT32, 0)).toBe(1);
+    });
+});
@@ -0,0 +1,77 @@
+import { describe, expect, it } from "vitest";
+import { call, chainUp, type NativeHandle, registerClass } from "../../index.js";
+import { GOBJECT, GOBJECT_BORROWED, GOBJECT_LIB, POINTER, STRING_BORROWED, UINT32, UINT64, VOID } from "./utils.js";
+
+/** Offsets of `GObjectClass.notify` and `GObjectClass.constructed`. */
+const GOBJECT_CLASS_NOTIFY = 64;
+const GOBJECT_CLASS_CONSTRUCTED = 72;
+
+const typeFromName = (name: string): number =>
+    Number(call(GOBJECT_LIB, "g_type_from_name", [{ type: STRING_BORROWED, value: name }], UINT64));
+
+let uniqueSuffix = 0;
+const uniqueName = (prefix: string): string => `${prefix}ChainUpTest${process.pid}_${++uniqueSuffix}`;
+
+const newObject = (gtype: number): NativeHandle =>
+    call(
+        GOBJECT_LIB,
+        "g_object_new_with_properties",
+        [
+            { type: POINTER, value: gtype },
+            { type: UINT32, value: 0 },
+            { type: POINTER, value: 0 },
+            { type: POINTER, value: 0 },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+describe("chainUp", () => {
+    it("calls the implementation each override replaced", () => {
+        const order: string[] = [];
+        const constructedOverride = (label: string, gtype: () => number) => ({
+            byteOffset: GOBJECT_CLASS_CONSTRUCTED,
+            argTypes: [GOBJECT_BORROWED],
+            returnType: VOID,
+            fn: (instance: unknown) => {
+                order.push(`${label}:before`);
+                chainUp(gtype(), GOBJECT_CLASS_CONSTRUCTED, [instance]);
+                order.push(`${label}:after`);
+            },
+        });
+        const base: number = registerClass(uniqueName("GtkxChainUpBase"), typeFromName("GObject"), {
+            vfuncs: [constructedOverride("A", () => base)],
+        });
+        const derived: number = registerClass(uniqueName("GtkxChainUpDerived"), base, {
+            vfuncs: [constructedOverride("B", () => derived)],
+        });
+
+        newObject(derived);
+
+        expect(order).toEqual(["B:before", "A:before", "A:after", "B:after"]);
+    });
+
+    it("rejects a slot the parent class leaves empty", () => {
+        const gtype = registerClass(uniqueName("GtkxChainUpNotify"), typeFromName("GObject"), {
+            vfuncs: [
+                {
+                    byteOffset: GOBJECT_CLASS_NOTIFY,
+                    argTypes: [GOBJECT_BORROWED, POINTER],
+                    returnType: VOID,
+                    fn: () => {},
+                },
+            ],
+        });
+
+        expect(() => chainUp(gtype, GOBJECT_CLASS_NOTIFY, [newObject(gtype), 0])).toThrow(
+            /has no implementation at byte offset 64/,
+        );
+    });
+
+    it("rejects a slot the class does not override", () => {
+        const gtype = registerClass(uniqueName("GtkxChainUpNone"), typeFromName("GObject"));
+
+        expect(() => chainUp(gtype, GOBJECT_CLASS_CONSTRUCTED, [newObject(gtype)])).toThrow(
+            /has no vfunc override at byte offset 72/,
+        );
+    });
+});
@@ -0,0 +1,145 @@
+import { describe, expect, it } from "vitest";
+import { call, getNativeId, type NativeHandle, registerClass } from "../../index.js";
+import {
+    BOOLEAN,
+    GIO_LIB,
+    GOBJECT,
+    GOBJECT_BORROWED,
+    GOBJECT_LIB,
+    POINTER,
+    STRING_BORROWED,
+    UINT32,
+    UINT64,
+} from "./utils.js";
+
+/** Offsets of the `GListModelInterface` vfuncs, after the 16-byte `GTypeInterface`. */
+const LIST_MODEL_GET_ITEM_TYPE = 16;
+const LIST_MODEL_GET_N_ITEMS = 24;
+const LIST_MODEL_GET_ITEM = 32;
+
+const typeFromName = (name: string): number =>
+    Number(call(GOBJECT_LIB, "g_type_from_name", [{ type: STRING_BORROWED, value: name }], UINT64));
+
+const getType = (library: string, getTypeFn: string): number => Number(call(library, getTypeFn, [], UINT64));
+
+const isA = (gtype: number, ancestor: number): boolean =>
+    call(
+        GOBJECT_LIB,
+        "g_type_is_a",
+        [
+            { type: UINT64, value: gtype },
+            { type: UINT64, value: ancestor },
+        ],
+        BOOLEAN,
+    ) as boolean;
+
+let uniqueSuffix = 0;
+const uniqueName = (prefix: string): string => `${prefix}InterfaceTest${process.pid}_${++uniqueSuffix}`;
+
+const newObject = (gtype: number): NativeHandle =>
+    call(
+        GOBJECT_LIB,
+        "g_object_new_with_properties",
+        [
+            { type: POINTER, value: gtype },
+            { type: UINT32, value: 0 },
+            { type: POINTER, value: 0 },
+            { type: POINTER, value: 0 },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+const listModelCall = (list: NativeHandle, fn: string, returnType: typeof UINT32 | typeof UINT64): number =>
+    Number(call(GIO_LIB, fn, [{ type: GOBJECT_BORROWED, value: list }], returnType));
+
+describe("registerClass - implements", () => {
+    it("implements an interface the parent lacks", () => {
+        const listModel = getType(GIO_LIB, "g_list_model_get_type");
+        const objectType = typeFromName("GObject");
+        const items = [newObject(objectType), newObject(objectType)];
+
+        const gtype = registerClass(uniqueName("GtkxListModel"), objectType, {
+            implements: [
+                {
+                    gtype: listModel,
+                    vfuncs: [
+                        {
+                            byteOffset: LIST_MODEL_GET_ITEM_TYPE,
+                            argTypes: [GOBJECT_BORROWED],
+                            returnType: UINT64,
+                            fn: () => objectType,
+                        },
+                        {
+                            byteOffset: LIST_MODEL_GET_N_ITEMS,
+                            argTypes: [GOBJECT_BORROWED],
+                            returnType: UINT32,
+                            fn: () => items.length,
+                        },
+                        {
+                            byteOffset: LIST_MODEL_GET_ITEM,
+                            argTypes: [GOBJECT_BORROWED, UINT32],
+                            returnType: GOBJECT,
+                            fn: (_list, position) => items[position as number] ?? null,
+                        },
+                    ],
+                },
+            ],
+        });
+        const list = newObject(gtype);
+
+        expect(isA(gtype, listModel)).toBe(true);
+        expect(listModelCall(list, "g_list_model_get_item_type", UINT64)).toBe(objectType);
+        expect(listModelCall(list, "g_list_model_get_n_items", UINT32)).toBe(2);
+        const item = call(
+            GIO_LIB,
+            "g_list_model_get_item",
+            [
+                { type: GOBJECT_BORROWED, value: list },
+                { type: UINT32, value: 1 },
+            ],
+            GOBJECT,
+        ) as NativeHandle;
+        expect(getNativeId(item)).toBe(getNativeId(items[1] as NativeHandle));
+    });
+
+    it("rejects a vfunc offset past the end of the interface vtable", () => {
+        const listModel = getType(GIO_LIB, "g_list_model_get_type");
+
+        expect(() =>
+            registerClass(uniqueName("GtkxListModel"), typeFromName("GObject"), {
+                implements: [
+                    {
+                        gtype: listModel,
+                        vfuncs: [
+                            {
+                                byteOffset: LIST_MODEL_GET_ITEM + 8,
+                                argTypes: [GOBJECT_BORROWED],
+                                returnType: UINT32,
+                                fn: () => 0,
+                            },
+                        ],
+                    },
+                ],
+            }),
+        ).toThrow(/interface vfunc byte_offset 40 exceeds class size 40/);
+    });
+
+    it("rejects an interface the parent already implements", () => {
+        const listModel = getType(GIO_LIB, "g_list_model_get_type");
+        const listStore = getType(GIO_LIB, "g_list_store_get_type");
+
+        expect(() =>
+            registerClass(uniqueName("GtkxListStore"), listStore, { implements: [{ gtype: listModel, vfuncs: [] }] }),
+        ).toThrow(/GListStore already implements GListModel/);
+    });
+
+    it("rejects an interface whose prerequisites are missing", () => {
+        const pollable = getType(GIO_LIB, "g_pollable_input_stream_get_type");
+
+        expect(() =>
+            registerClass(uniqueName("GtkxPollable"), typeFromName("GObject"), {
+                implements: [{ gtype: pollable, vfuncs: [] }],
+            }),
+        ).toThrow(/requires GInputStream/);
+    });
+});
@@ -0,0 +1,101 @@
+import { describe, expect, it } from "vitest";
+import { call, getNativeId, type NativeHandle, registerClass } from "../../index.js";
+import { BOOLEAN, GOBJECT, GOBJECT_LIB, POINTER, STRING_BORROWED, UINT32, UINT64, VOID } from "./utils.js";
+
+/** Offset of `GObjectClass.dispose`, after the `GTypeClass` and four pointers. */
+const GOBJECT_CLASS_DISPOSE = 40;
+
+const typeFromName = (name: string): number =>
+    Number(call(GOBJECT_LIB, "g_type_from_name", [{ type: STRING_BORROWED, value: name }], UINT64));
+
+let uniqueSuffix = 0;
+const uniqueName = (prefix: string): string => `${prefix}LifecycleTest${process.pid}_${++uniqueSuffix}`;
+
+const newObjectArgs = (gtype: number) => [
+    { type: POINTER, value: gtype },
+    { type: UINT32, value: 0 },
+    { type: POINTER, value: 0 },
+    { type: POINTER, value: 0 },
+];
+
+const newObject = (gtype: number): NativeHandle =>
+    call(GOBJECT_LIB, "g_object_new_with_properties", newObjectArgs(gtype), GOBJECT) as NativeHandle;
+
+/** Creates an instance without a handle, so that it is freed by the matching {@link unref}. */
+const newRawObject = (gtype: number): number =>
+    Number(call(GOBJECT_LIB, "g_object_new_with_properties", newObjectArgs(gtype), POINTER));
+
+const unref = (object: number): void => {
+    call(GOBJECT_LIB, "g_object_unref", [{ type: POINTER, value: object }], VOID);
+};
+
+describe("registerClass - lifecycle hooks", () => {
+    it("runs instanceInit and constructed for each new instance, ancestors first", () => {
+        const order: string[] = [];
+        const instances: number[] = [];
+        const base = registerClass(uniqueName("GtkxLifecycleBase"), typeFromName("GObject"), {
+            instanceInit: (instance) => {
+                instances.push(getNativeId(instance));
+                order.push("init:A");
+            },
+            constructed: () => order.push("constructed:A"),
+        });
+        const derived = registerClass(uniqueName("GtkxLifecycleDerived"), base, {
+            instanceInit: () => order.push("init:B"),
+            constructed: () => order.push("constructed:B"),
+        });
+
+        const object = newObject(derived);
+
+        expect(order).toEqual(["init:A", "init:B", "constructed:A", "constructed:B"]);
+        expect(instances).toEqual([getNativeId(object)]);
+    });
+
+    it("leaves a GInitiallyUnowned instance floating after its hooks take handles", () => {
+        const handles: NativeHandle[] = [];
+        const gtype = registerClass(uniqueName("GtkxFloating"), typeFromName("GInitiallyUnowned"), {
+            instanceInit: (instance) => handles.push(instance),
+            constructed: (instance) => handles.push(instance),
+        });
+
+        const object = newRawObject(gtype);
+        const isFloating = call(GOBJECT_LIB, "g_object_is_floating", [{ type: POINTER, value: object }], BOOLEAN);
+
+        expect(handles.map(getNativeId)).toEqual([object, object]);
+        expect(isFloating).toBe(true);
+        call(GOBJECT_LIB, "g_object_ref_sink", [{ type: POINTER, value: object }], POINTER);
+        unref(object);
+    });
+
+    it("chains dispose and finalize up to the parent after the hook", () => {
+        const order: string[] = [];
+        const ids = new Set<number>();
+        const record = (stage: string) => (nativeId: number) => {
+            ids.add(nativeId);
+            order.push(stage);
+        };
+        const base = registerClass(uniqueName("GtkxTeardownBase"), typeFromName("GObject"), {
+            dispose: record("dispose:A"),
+            finalize: record("finalize:A"),
+        });
+        const derived = registerClass(uniqueName("GtkxTeardownDerived"), base, {
+            dispose: record("dispose:B"),
+            finalize: record("finalize:B"),
+        });
+
+        const object = newRawObject(derived);
+        unref(object);
+
+        expect(order).toEqual(["dispose:B", "dispose:A", "finalize:B", "finalize:A"]);
+        expect([...ids]).toEqual([object]);
+    });
+
+    it("rejects a hook whose slot a vfunc also overrides", () => {
+        expect(() =>
+            registerClass(uniqueName("GtkxDoubleDispose"), typeFromName("GObject"), {
+                vfuncs: [{ byteOffset: GOBJECT_CLASS_DISPOSE, argTypes: [POINTER], returnType: VOID, fn: () => {} }],
+                dispose: () => {},
+            }),
+        ).toThrow(/'dispose' hook and a vfunc both override byte offset 40/);
+    });
+});
@@ -0,0 +1,174 @@
+import { describe, expect, it } from "vitest";
+import {
+    call,
+    connectSignal,
+    getNativeId,
+    getProperty,
+    type NativeHandle,
+    registerClass,
+    type RegisterClassPropertyDefinition,
+    setProperties,
+} from "../../index.js";
+import { GOBJECT, GOBJECT_BORROWED, GOBJECT_LIB, POINTER, STRING_BORROWED, UINT32, UINT64, VOID } from "./utils.js";
+
+const G_PARAM_READABLE = 1;
+const G_PARAM_READWRITE = 3;
+
+/** Offset of `GObjectClass.get_property`. */
+const GOBJECT_CLASS_GET_PROPERTY = 32;
+
+const typeFromName = (name: string): number =>
+    Number(call(GOBJECT_LIB, "g_type_from_name", [{ type: STRING_BORROWED, value: name }], UINT64));
+
+let uniqueSuffix = 0;
+const uniqueName = (prefix: string): string => `${prefix}PropertyTest${process.pid}_${++uniqueSuffix}`;
+
+const newObject = (gtype: number): NativeHandle =>
+    call(
+        GOBJECT_LIB,
+        "g_object_new_with_properties",
+        [
+            { type: POINTER, value: gtype },
+            { type: UINT32, value: 0 },
+            { type: POINTER, value: 0 },
+            { type: POINTER, value: 0 },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+/** Registers a GObject subclass whose properties are stored per instance in `store`. */
+const registerWithStore = (
+    store: Map<number, Record<string, unknown>>,
+    properties: readonly RegisterClassPropertyDefinition[],
+): number =>
+    registerClass(uniqueName("GtkxStored"), typeFromName("GObject"), {
+        properties: properties.map((property) => ({
+            ...property,
+            get: (instance: NativeHandle) => store.get(getNativeId(instance))?.[property.name],
+            set: (instance: NativeHandle, value: unknown) => {
+                const id = getNativeId(instance);
+                store.set(id, { ...store.get(id), [property.name]: value });
+            },
+        })),
+    });
+
+describe("registerClass - properties", () => {
+    it("routes reads and writes to the property handlers", () => {
+        const store = new Map<number, Record<string, unknown>>();
+        const gtype = registerWithStore(store, [
+            { name: "count", type: "int", minimum: 0, maximum: 10 },
+            { name: "title", type: "string" },
+        ]);
+        const object = newObject(gtype);
+
+        setProperties(object, { count: 7, title: "Hello" });
+
+        expect(store.get(getNativeId(object))).toEqual({ count: 7, title: "Hello" });
+        expect(getProperty(object, "count")).toBe(7);
+        expect(getProperty(object, "title")).toBe("Hello");
+    });
+
+    it("validates writes against the declared range", () => {
+        const store = new Map<number, Record<string, unknown>>();
+        const gtype = registerWithStore(store, [{ name: "count", type: "int", minimum: 0, maximum: 10 }]);
+        const object = newObject(gtype);
+
+        expect(() => setProperties(object, { count: 11 })).toThrow(/out of range/);
+        expect(store.has(getNativeId(object))).toBe(false);
+    });
+
+    it("emits notify for a declared property", () => {
+        const store = new Map<number, Record<string, unknown>>();
+        const gtype = registerWithStore(store, [{ name: "enabled", type: "boolean" }]);
+        const object = newObject(gtype);
+        const notified: string[] = [];
+
+        connectSignal(object, "notify::enabled", () => notified.push("enabled"));
+        setProperties(object, { enabled: true });
+
+        expect(notified).toEqual(["enabled"]);
+    });
+
+    it("works with g_object_bind_property", () => {
+        const store = new Map<number, Record<string, unknown>>();
+        const gtype = registerWithStore(store, [{ name: "ratio", type: "double", default: 0.5 }]);
+        const source = newObject(gtype);
+        const target = newObject(gtype);
+
+        call(
+            GOBJECT_LIB,
+            "g_object_bind_property",
+            [
+                { type: GOBJECT_BORROWED, value: source },
+                { type: STRING_BORROWED, value: "ratio" },
+                { type: GOBJECT_BORROWED, value: target },
+                { type: STRING_BORROWED, value: "ratio" },
+                { type: UINT32, value: 0 },
+            ],
+            GOBJECT_BORROWED,
+        );
+        setProperties(source, { ratio: 0.25 });
+
+        expect(getProperty(target, "ratio")).toBe(0.25);
+    });
+
+    it("reads a property without a getter as its default value", () => {
+        const gtype = registerClass(uniqueName("GtkxDefaults"), typeFromName("GObject"), {
+            properties: [
+                { name: "label", type: "string", default: "untitled", flags: G_PARAM_READABLE },
+                { name: "count", type: "int", minimum: 1, maximum: 5, flags: G_PARAM_READABLE },
+            ],
+        });
+        const object = newObject(gtype);
+
+        expect(getProperty(object, "label")).toBe("untitled");
+        expect(getProperty(object, "count")).toBe(1);
+    });
+
+    it("builds a variant property's value from the getter's plain value", () => {
+        const gtype = registerClass(uniqueName("GtkxVariant"), typeFromName("GObject"), {
+            properties: [
+                {
+                    name: "state",
+                    type: "variant",
+                    signature: "(si)",
+                    flags: G_PARAM_READABLE,
+                    get: () => ["on", 2],
+                },
+            ],
+        });
+
+        expect(getProperty(newObject(gtype), "state")).toEqual(["on", 2]);
+    });
+
+    it("rejects an invalid property descriptor", () => {
+        const parent = typeFromName("GObject");
+
+        expect(() =>
+            registerClass(uniqueName("GtkxNoSetter"), parent, {
+                properties: [{ name: "count", type: "int", flags: G_PARAM_READWRITE }],
+            }),
+        ).toThrow(/property 'count' is writable but has no 'set' handler/);
+        expect(() =>
+            registerClass(uniqueName("GtkxBadDefault"), parent, {
+                properties: [{ name: "count", type: "int", maximum: 3, default: 4, flags: G_PARAM_READABLE }],
+            }),
+        ).toThrow(/default 4 is outside/);
+    });
+
+    it("rejects a vfunc over the get_property slot the properties use", () => {
+        expect(() =>
+            registerClass(uniqueName("GtkxGetPropertyVfunc"), typeFromName("GObject"), {
+                properties: [{ name: "enabled", type: "boolean", flags: G_PARAM_READABLE, get: () => true }],
+                vfuncs: [
+                    {
+                        byteOffset: GOBJECT_CLASS_GET_PROPERTY,
+                        argTypes: [GOBJECT_BORROWED, UINT32, POINTER, POINTER],
+                        returnType: VOID,
+                        fn: () => {},
+                    },
+                ],
+            }),
+        ).toThrow(/the properties and a vfunc both override get_property at byte offset 32/);
+    });
+});
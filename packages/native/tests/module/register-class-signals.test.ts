@@ -0,0 +1,118 @@
+import { describe, expect, it } from "vitest";
+import {
+    call,
+    connectSignal,
+    emitSignal,
+    getNativeId,
+    type NativeHandle,
+    registerClass,
+    type RegisterClassSignalDefinition,
+} from "../../index.js";
+import { GOBJECT, GOBJECT_LIB, POINTER, STRING_BORROWED, UINT32, UINT64 } from "./utils.js";
+
+const G_SIGNAL_RUN_FIRST = 1;
+const G_SIGNAL_RUN_LAST = 2;
+const G_SIGNAL_DETAILED = 16;
+
+const typeFromName = (name: string): number =>
+    Number(call(GOBJECT_LIB, "g_type_from_name", [{ type: STRING_BORROWED, value: name }], UINT64));
+
+let uniqueSuffix = 0;
+const uniqueName = (prefix: string): string => `${prefix}SignalTest${process.pid}_${++uniqueSuffix}`;
+
+const newObject = (gtype: number): NativeHandle =>
+    call(
+        GOBJECT_LIB,
+        "g_object_new_with_properties",
+        [
+            { type: POINTER, value: gtype },
+            { type: UINT32, value: 0 },
+            { type: POINTER, value: 0 },
+            { type: POINTER, value: 0 },
+        ],
+        GOBJECT,
+    ) as NativeHandle;
+
+const registerWithSignals = (prefix: string, signals: readonly RegisterClassSignalDefinition[]): NativeHandle =>
+    newObject(registerClass(uniqueName(prefix), typeFromName("GObject"), { signals }));
+
+describe("registerClass - signals", () => {
+    it("runs the class handler with the instance and parameters", () => {
+        const received: unknown[] = [];
+        const object = registerWithSignals("GtkxClassHandler", [
+            {
+                name: "item-activated",
+                paramTypes: [typeFromName("gint"), typeFromName("gchararray")],
+                returnType: typeFromName("gint"),
+                classHandler: (instance, position, label) => {
+                    received.push(getNativeId(instance), position, label);
+                    return (position as number) * 2;
+                },
+            },
+        ]);
+
+        expect(emitSignal(object, "item-activated", [21, "row"])).toBe(42);
+        expect(received).toEqual([getNativeId(object), 21, "row"]);
+    });
+
+    it("orders the class handler by its run stage", () => {
+        const order: string[] = [];
+        const object = registerWithSignals("GtkxRunStages", [
+            { name: "first", flags: G_SIGNAL_RUN_FIRST, classHandler: () => order.push("first:class") },
+            { name: "last", flags: G_SIGNAL_RUN_LAST, classHandler: () => order.push("last:class") },
+        ]);
+        connectSignal(object, "first", () => order.push("first:handler"));
+        connectSignal(object, "last", () => order.push("last:handler"));
+
+        emitSignal(object, "first");
+        emitSignal(object, "last");
+
+        expect(order).toEqual(["first:class", "first:handler", "last:handler", "last:class"]);
+    });
+
+    it("stops the emission with the true-handled accumulator", () => {
+        let classHandlerRan = false;
+        const object = registerWithSignals("GtkxTrueHandled", [
+            {
+                name: "close-request",
+                returnType: typeFromName("gboolean"),
+                accumulator: "true-handled",
+                classHandler: () => {
+                    classHandlerRan = true;
+                    return false;
+                },
+            },
+        ]);
+        connectSignal(object, "close-request", () => true);
+
+        expect(emitSignal(object, "close-request")).toBe(true);
+        expect(classHandlerRan).toBe(false);
+    });
+
+    it("delivers a detailed signal to handlers of that detail", () => {
+        const details: string[] = [];
+        const object = registerWithSignals("GtkxDetailed", [
+            { name: "changed", flags: G_SIGNAL_RUN_LAST | G_SIGNAL_DETAILED },
+        ]);
+        connectSignal(object, "changed::title", () => details.push("title"));
+        connectSignal(object, "changed", () => details.push("any"));
+
+        emitSignal(object, "changed::title");
+        emitSignal(object, "changed::count");
+
+        expect(details).toEqual(["title", "any", "any"]);
+    });
+
+    it("rejects an invalid signal descriptor", () => {
+        const parent = typeFromName("GObject");
+
+        expect(() =>
+            registerClass(uniqueName("GtkxInherited"), parent, { signals: [{ name: "notify" }] }),
+        ).toThrow(/signal 'notify' already exists on the parent type/);
+        expect(() =>
+            registerClass(uniqueName("GtkxBadAccumulator"), parent, {
+                signals: [{ name: "changed", returnType: typeFromName("gint"), accumulator: "true-handled" }],
+            }),
+        ).toThrow(/must return gboolean/);
+    });
+});
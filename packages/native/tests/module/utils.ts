@@ -18,6 +18,8 @@ export const UINT8 = { type: "uint8" as const };
 export const UINT16 = { type: "uint16" as const };
 export const UINT32 = { type: "uint32" as const };
 export const UINT64 = { type: "uint64" as const };
+export const INT64_BIGINT = { type: "int64" as const, bigint: true };
+export const UINT64_BIGINT = { type: "uint64" as const, bigint: true };
 export const FLOAT32 = { type: "float32" as const };
 export const FLOAT64 = { type: "float64" as const };
 export const BOOLEAN = { type: "boolean" as const };
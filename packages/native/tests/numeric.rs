@@ -238,6 +238,55 @@ fn integer_encode_rejects_wrong_value() {
     assert!(FfiEncoder::encode(&IntegerKind::I32, &Value::Null, false).is_err());
 }
 
+#[test]
+fn integer_encode_converts_bigint_without_precision_loss() {
+    let above_2_53 = (1i64 << 53) + 1;
+    let encoded =
+        FfiEncoder::encode(&IntegerKind::I64, &Value::BigInt(above_2_53.into()), false).unwrap();
+    assert!(matches!(encoded, ffi::FfiValue::I64(n) if n == above_2_53));
+
+    let max =
+        FfiEncoder::encode(&IntegerKind::U64, &Value::BigInt(u64::MAX.into()), false).unwrap();
+    assert!(matches!(max, ffi::FfiValue::U64(u64::MAX)));
+
+    let err = FfiEncoder::encode(&IntegerKind::U8, &Value::BigInt(256), false)
+        .expect_err("256 does not fit in u8");
+    assert_eq!(err.to_string(), "Value 256 is out of range for u8 [0, 255]");
+    assert!(FfiEncoder::encode(&IntegerKind::U32, &Value::BigInt(-1), false).is_err());
+}
+
+#[test]
+fn integer_return_writes_bigint_without_precision_loss() {
+    let above_2_53 = (1i64 << 53) + 1;
+    let mut slot: i64 = 0;
+    let ret = &mut slot as *mut i64 as *mut c_void;
+    RawPtrCodec::write_return_to_raw_ptr(
+        &IntegerKind::I64,
+        ret,
+        &Ok(Value::BigInt(above_2_53.into())),
+    );
+    assert_eq!(slot, above_2_53);
+
+    RawPtrCodec::write_return_to_raw_ptr(&IntegerKind::I64, ret, &Ok(Value::BigInt(i128::MAX)));
+    assert_eq!(slot, 0);
+}
+
+#[test]
+fn float_encode_rejects_inexact_bigint() {
+    let exact = FfiEncoder::encode(&FloatKind::F64, &Value::BigInt(1 << 40), false).unwrap();
+    assert!(matches!(exact, ffi::FfiValue::F64(n) if n == (1u64 << 40) as f64));
+
+    let err = FfiEncoder::encode(&FloatKind::F64, &Value::BigInt((1 << 53) + 1), false)
+        .expect_err("2^53 + 1 has no exact f64");
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Value {} cannot be represented exactly as F64",
+            (1i128 << 53) + 1
+        )
+    );
+}
+
 #[test]
 fn integer_libffi_type_matches_ffi_type() {
     for kind in INTEGER_KINDS {
@@ -13,11 +13,11 @@ use native::value::Value;
 use native::{NativeHandle, value};
 
 fn struct_type() -> StructType {
-    StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_owned(),
-        size: Some(std::mem::size_of::<u64>()),
-    }
+    StructType::new(
+        Ownership::Borrowed,
+        "TestStruct".to_owned(),
+        Some(std::mem::size_of::<u64>()),
+    )
 }
 
 #[test]
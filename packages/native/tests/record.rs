@@ -0,0 +1,340 @@
+//! Coverage tests for [`native::types::RecordType`] descriptors, which convert
+//! C structs to and from [`Value::Record`].
+
+use std::ffi::c_void;
+use std::mem::offset_of;
+
+use gtk4::glib;
+
+use native::ffi::{FfiStorageKind, FfiValue};
+use native::types::{
+    ArrayKind, ArrayType, BooleanType, FfiDecoder, FfiEncoder, FloatKind, IntegerKind, Ownership,
+    RawPtrCodec, RecordField, RecordType, RefType, Type, VoidType,
+};
+use native::value::Value;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Inner {
+    a: u8,
+    b: i16,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Outer {
+    id: i32,
+    inner: Inner,
+    values: [f64; 3],
+    flag: i32,
+}
+
+fn field(name: &str, offset: usize, ty: Type) -> RecordField {
+    RecordField {
+        name: name.to_owned(),
+        offset,
+        ty,
+    }
+}
+
+fn inner_type() -> RecordType {
+    RecordType::new(
+        Ownership::Borrowed,
+        vec![
+            field("a", offset_of!(Inner, a), Type::Integer(IntegerKind::U8)),
+            field("b", offset_of!(Inner, b), Type::Integer(IntegerKind::I16)),
+        ],
+        None,
+    )
+    .unwrap()
+}
+
+fn outer_fields() -> Vec<RecordField> {
+    vec![
+        field("id", offset_of!(Outer, id), Type::Integer(IntegerKind::I32)),
+        field(
+            "inner",
+            offset_of!(Outer, inner),
+            Type::Record(inner_type()),
+        ),
+        field(
+            "values",
+            offset_of!(Outer, values),
+            Type::Array(ArrayType {
+                item_type: Box::new(Type::Float(FloatKind::F64)),
+                kind: ArrayKind::Inline { size: 3 },
+                ownership: Ownership::Borrowed,
+                element_size: None,
+                typed: false,
+            }),
+        ),
+        field("flag", offset_of!(Outer, flag), Type::Boolean(BooleanType)),
+    ]
+}
+
+fn outer_type(ownership: Ownership) -> RecordType {
+    RecordType::new(ownership, outer_fields(), Some(size_of::<Outer>())).unwrap()
+}
+
+fn sample() -> Outer {
+    Outer {
+        id: 7,
+        inner: Inner { a: 200, b: -300 },
+        values: [0.5, 1.5, -2.0],
+        flag: 1,
+    }
+}
+
+fn sample_value() -> Value {
+    Value::Record(vec![
+        ("id".into(), Value::Number(7.0)),
+        (
+            "inner".into(),
+            Value::Record(vec![
+                ("a".into(), Value::Number(200.0)),
+                ("b".into(), Value::Number(-300.0)),
+            ]),
+        ),
+        (
+            "values".into(),
+            Value::Array(vec![
+                Value::Number(0.5),
+                Value::Number(1.5),
+                Value::Number(-2.0),
+            ]),
+        ),
+        ("flag".into(), Value::Boolean(true)),
+    ])
+}
+
+/// Looks up a field of a decoded record.
+fn get<'a>(value: &'a Value, name: &str) -> &'a Value {
+    let Value::Record(fields) = value else {
+        panic!("expected Value::Record, got {value:?}");
+    };
+    fields
+        .iter()
+        .find(|(field_name, _)| field_name == name)
+        .map(|(_, value)| value)
+        .unwrap_or_else(|| panic!("record has no field '{name}'"))
+}
+
+fn number(value: &Value) -> f64 {
+    value.as_number().expect("expected a number")
+}
+
+fn assert_is_sample(value: &Value) {
+    assert!((number(get(value, "id")) - 7.0).abs() < f64::EPSILON);
+    let inner = get(value, "inner");
+    assert!((number(get(inner, "a")) - 200.0).abs() < f64::EPSILON);
+    assert!((number(get(inner, "b")) + 300.0).abs() < f64::EPSILON);
+    let Value::Array(values) = get(value, "values") else {
+        panic!("expected an inline array");
+    };
+    let values: Vec<f64> = values.iter().map(number).collect();
+    assert_eq!(values, vec![0.5, 1.5, -2.0]);
+    assert!(matches!(get(value, "flag"), Value::Boolean(true)));
+}
+
+#[test]
+fn size_defaults_to_the_end_of_the_furthest_field() {
+    let record = RecordType::new(Ownership::Borrowed, outer_fields(), None).unwrap();
+    assert_eq!(record.size, offset_of!(Outer, flag) + size_of::<i32>());
+    assert_eq!(inner_type().size, size_of::<Inner>());
+}
+
+#[test]
+fn new_rejects_a_size_smaller_than_the_fields() {
+    let err = RecordType::new(Ownership::Borrowed, outer_fields(), Some(8)).unwrap_err();
+    assert!(err.to_string().contains("smaller than"));
+}
+
+#[test]
+fn new_rejects_fields_that_cannot_be_embedded() {
+    let err = RecordType::new(
+        Ownership::Borrowed,
+        vec![field("nothing", 0, Type::Void(VoidType))],
+        None,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("'nothing'"));
+}
+
+#[test]
+fn read_inline_decodes_nested_records_and_inline_arrays() {
+    let outer = sample();
+    let value = outer_type(Ownership::Borrowed)
+        .read_inline((&raw const outer).cast())
+        .unwrap();
+    assert_is_sample(&value);
+}
+
+#[test]
+fn write_inline_round_trips() {
+    let mut outer = Outer::default();
+    outer_type(Ownership::Borrowed)
+        .write_inline((&raw mut outer).cast(), &sample_value())
+        .unwrap();
+    assert_eq!(outer, sample());
+}
+
+#[test]
+fn write_inline_leaves_missing_fields_untouched() {
+    let mut outer = sample();
+    let partial = Value::Record(vec![
+        ("id".into(), Value::Number(9.0)),
+        (
+            "inner".into(),
+            Value::Record(vec![("b".into(), Value::Number(12.0))]),
+        ),
+        ("flag".into(), Value::Undefined),
+    ]);
+    outer_type(Ownership::Borrowed)
+        .write_inline((&raw mut outer).cast(), &partial)
+        .unwrap();
+
+    assert_eq!(outer.id, 9);
+    assert_eq!(outer.inner, Inner { a: 200, b: 12 });
+    assert_eq!(outer.values, sample().values);
+    assert_eq!(outer.flag, 1);
+}
+
+#[test]
+fn write_inline_rejects_an_overlong_inline_array() {
+    let mut outer = Outer::default();
+    let value = Value::Record(vec![(
+        "values".into(),
+        Value::Array(vec![Value::Number(0.0); 4]),
+    )]);
+    let err = outer_type(Ownership::Borrowed)
+        .write_inline((&raw mut outer).cast(), &value)
+        .unwrap_err();
+    assert!(format!("{err:#}").contains("cannot hold 4 elements"));
+}
+
+#[test]
+fn encode_borrowed_lends_call_owned_memory() {
+    let encoded = outer_type(Ownership::Borrowed)
+        .encode(&sample_value(), false)
+        .unwrap();
+    let FfiValue::Storage(storage) = &encoded else {
+        panic!("expected FfiValue::Storage, got {encoded:?}");
+    };
+    assert!(matches!(storage.kind(), FfiStorageKind::Aggregate(_)));
+    assert_eq!(unsafe { *storage.ptr().cast::<Outer>() }, sample());
+}
+
+#[test]
+fn encode_full_transfers_malloced_memory_and_decode_frees_it() {
+    let record = outer_type(Ownership::Full);
+    let encoded = record.encode(&sample_value(), false).unwrap();
+    let FfiValue::Ptr(ptr) = encoded else {
+        panic!("expected FfiValue::Ptr, got {encoded:?}");
+    };
+    assert_eq!(unsafe { *ptr.cast::<Outer>() }, sample());
+
+    let decoded = record.decode(&FfiValue::Ptr(ptr)).unwrap();
+    assert_is_sample(&decoded);
+}
+
+#[test]
+fn encode_passes_handles_through() {
+    let mut outer = sample();
+    let ptr = (&raw mut outer).cast::<c_void>();
+    let handle = Value::Object(native::managed::NativeHandle::borrowed(ptr));
+    let encoded = outer_type(Ownership::Borrowed)
+        .encode(&handle, false)
+        .unwrap();
+    assert!(matches!(encoded, FfiValue::Ptr(p) if p == ptr));
+}
+
+#[test]
+fn decode_returns_null_for_a_null_pointer() {
+    let record = outer_type(Ownership::Full);
+    let decoded = record.decode(&FfiValue::Ptr(std::ptr::null_mut())).unwrap();
+    assert!(matches!(decoded, Value::Null));
+    let read = record.ptr_to_value(std::ptr::null_mut(), "test").unwrap();
+    assert!(matches!(read, Value::Null));
+}
+
+#[test]
+fn write_value_to_raw_ptr_stores_a_malloced_copy() {
+    let record = outer_type(Ownership::Borrowed);
+    let mut slot: *mut c_void = std::ptr::null_mut();
+    record
+        .write_value_to_raw_ptr((&raw mut slot).cast(), &sample_value())
+        .unwrap();
+    assert_eq!(unsafe { *slot.cast::<Outer>() }, sample());
+
+    assert_is_sample(
+        &record
+            .read_from_raw_ptr((&raw const slot).cast(), "test")
+            .unwrap(),
+    );
+    unsafe { glib::ffi::g_free(slot) };
+}
+
+#[test]
+fn ref_record_decodes_the_filled_out_parameter() {
+    let record = outer_type(Ownership::Borrowed);
+    let encoded = FfiValue::Storage(record.alloc(&Value::Null).unwrap().into());
+    let FfiValue::Storage(storage) = &encoded else {
+        unreachable!();
+    };
+    unsafe { *storage.ptr().cast::<Outer>() = sample() };
+
+    let decoded = RefType::new(Type::Record(record)).decode(&encoded).unwrap();
+    assert_is_sample(&decoded);
+}
+
+#[test]
+fn array_of_records_is_laid_out_contiguously() {
+    let array = ArrayType {
+        item_type: Box::new(Type::Record(inner_type())),
+        kind: ArrayKind::Fixed { size: 2 },
+        ownership: Ownership::Borrowed,
+        element_size: None,
+        typed: false,
+    };
+    let items = Value::Array(vec![
+        Value::Record(vec![
+            ("a".into(), Value::Number(1.0)),
+            ("b".into(), Value::Number(2.0)),
+        ]),
+        Value::Record(vec![
+            ("a".into(), Value::Number(3.0)),
+            ("b".into(), Value::Number(4.0)),
+        ]),
+    ]);
+
+    let encoded = array.encode(&items, false).unwrap();
+    let FfiValue::Storage(storage) = &encoded else {
+        panic!("expected FfiValue::Storage, got {encoded:?}");
+    };
+    let laid_out = unsafe { *storage.ptr().cast::<[Inner; 2]>() };
+    assert_eq!(laid_out, [Inner { a: 1, b: 2 }, Inner { a: 3, b: 4 }]);
+
+    let Value::Array(decoded) = array.decode(&encoded).unwrap() else {
+        panic!("expected Value::Array");
+    };
+    assert_eq!(decoded.len(), 2);
+    assert!((number(get(&decoded[1], "b")) - 4.0).abs() < f64::EPSILON);
+
+    let Value::Array(from_ptr) = array.decode(&FfiValue::Ptr(storage.ptr())).unwrap() else {
+        panic!("expected Value::Array");
+    };
+    assert!((number(get(&from_ptr[0], "a")) - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn array_of_records_rejects_list_kinds() {
+    let array = ArrayType {
+        item_type: Box::new(Type::Record(inner_type())),
+        kind: ArrayKind::GList,
+        ownership: Ownership::Borrowed,
+        element_size: None,
+        typed: false,
+    };
+    let err = array.encode(&Value::Array(Vec::new()), false).unwrap_err();
+    assert!(err.to_string().contains("cannot be encoded"));
+}
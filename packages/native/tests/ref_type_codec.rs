@@ -39,6 +39,7 @@ fn u8_array_ref_type() -> RefType {
         kind: ArrayKind::Array,
         ownership: Ownership::Borrowed,
         element_size: None,
+        typed: false,
     }))
 }
 
@@ -333,6 +334,7 @@ fn decode_with_context_array_string_items_not_freed_by_ref() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         };
         let ref_type = RefType::new(Type::Array(array_type));
         let decoded = ref_type
@@ -354,6 +356,7 @@ fn decode_with_context_array_non_string_items_freed_by_ref() {
             kind: ArrayKind::Fixed { size: 0 },
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         };
         let ref_type = RefType::new(Type::Array(array_type));
         let decoded = ref_type
@@ -377,6 +380,7 @@ fn decode_with_context_array_non_ptr_storage_uses_storage_pointer() {
             kind: ArrayKind::Array,
             ownership: Ownership::Borrowed,
             element_size: None,
+            typed: false,
         };
         let ref_type = RefType::new(Type::Array(array_type));
         let decoded = ref_type
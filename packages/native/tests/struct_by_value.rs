@@ -0,0 +1,263 @@
+//! Coverage tests for by-value [`native::types::StructType`] and
+//! [`native::types::BoxedType`] descriptors built on
+//! [`native::types::StructLayout`].
+
+mod common;
+
+use std::ffi::c_void;
+
+use gtk4::gdk;
+use gtk4::prelude::StaticType as _;
+use libffi::middle;
+
+use native::ffi::{AggregateBuffer, FfiValue};
+use native::managed::NativeHandle;
+use native::types::{
+    BoxedType, FfiDecoder, FfiEncoder, FloatKind, IntegerKind, Ownership, RawPtrCodec,
+    StructLayout, StructType, Type,
+};
+use native::value::Value;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rect {
+    origin: Point,
+    width: i32,
+    height: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mixed {
+    tag: u8,
+    value: f64,
+    count: u16,
+}
+
+fn by_value_struct(name: &str, fields: Vec<Type>) -> StructType {
+    let layout = StructLayout::new(fields).unwrap();
+    StructType {
+        ownership: Ownership::Full,
+        type_name: name.to_owned(),
+        size: Some(layout.size()),
+        by_value: Some(layout),
+    }
+}
+
+fn point_type() -> StructType {
+    by_value_struct(
+        "Point",
+        vec![Type::Float(FloatKind::F32), Type::Float(FloatKind::F32)],
+    )
+}
+
+fn rect_type() -> StructType {
+    by_value_struct(
+        "Rect",
+        vec![
+            Type::Struct(point_type()),
+            Type::Integer(IntegerKind::I32),
+            Type::Integer(IntegerKind::I32),
+        ],
+    )
+}
+
+fn mixed_type() -> StructType {
+    by_value_struct(
+        "Mixed",
+        vec![
+            Type::Integer(IntegerKind::U8),
+            Type::Float(FloatKind::F64),
+            Type::Integer(IntegerKind::U16),
+        ],
+    )
+}
+
+fn handle_to<T>(value: &mut T) -> Value {
+    Value::Object(NativeHandle::borrowed((value as *mut T).cast::<c_void>()))
+}
+
+/// Reads the struct a decoded by-value handle owns.
+fn read_decoded<T: Copy>(value: &Value) -> T {
+    let Value::Object(handle) = value else {
+        panic!("expected Value::Object, got {value:?}");
+    };
+    unsafe { *handle.ptr().cast::<T>() }
+}
+
+extern "C" fn scale_point(point: Point, factor: f32) -> Point {
+    Point {
+        x: point.x * factor,
+        y: point.y * factor,
+    }
+}
+
+extern "C" fn rect_area(rect: Rect) -> i32 {
+    rect.width * rect.height
+}
+
+extern "C" fn make_mixed(tag: u8) -> Mixed {
+    Mixed {
+        tag,
+        value: 2.5,
+        count: 700,
+    }
+}
+
+#[test]
+fn layout_matches_the_c_compiler() {
+    assert_eq!(point_type().by_value.unwrap().size(), size_of::<Point>());
+    assert_eq!(rect_type().by_value.unwrap().size(), size_of::<Rect>());
+    assert_eq!(mixed_type().by_value.unwrap().size(), size_of::<Mixed>());
+}
+
+#[test]
+fn layout_rejects_empty_and_void_fields() {
+    assert!(StructLayout::new(Vec::new()).is_err());
+
+    let err = StructLayout::new(vec![Type::Void(native::types::VoidType)]).unwrap_err();
+    assert!(err.to_string().contains("Void"));
+}
+
+#[test]
+fn encode_copies_the_struct_bytes() {
+    let mut point = Point { x: 1.5, y: -2.0 };
+    let encoded = point_type().encode(&handle_to(&mut point), false).unwrap();
+
+    point.x = 100.0;
+    let FfiValue::Aggregate(buffer) = &encoded else {
+        panic!("expected FfiValue::Aggregate, got {encoded:?}");
+    };
+    assert_eq!(buffer.len(), size_of::<Point>());
+    assert_eq!(
+        unsafe { *buffer.as_ptr().cast::<Point>() },
+        Point { x: 1.5, y: -2.0 }
+    );
+}
+
+#[test]
+fn encode_rejects_null() {
+    let err = point_type().encode(&Value::Null, true).unwrap_err();
+    assert!(err.to_string().contains("cannot be null"));
+}
+
+#[test]
+fn call_passes_and_returns_a_struct() {
+    let point_type = point_type();
+    let cif = middle::Cif::new(
+        vec![point_type.libffi_type(), FloatKind::F32.libffi_type()],
+        point_type.libffi_type(),
+    );
+
+    let mut point = Point { x: 3.0, y: 4.0 };
+    let args = [
+        point_type.encode(&handle_to(&mut point), false).unwrap(),
+        FfiValue::F32(2.0),
+    ];
+    let mut ffi_args = Vec::new();
+    for arg in &args {
+        arg.append_libffi_args(&mut ffi_args);
+    }
+
+    let code = middle::CodePtr(scale_point as *mut c_void);
+    let result = point_type.call_cif(&cif, code, &ffi_args).unwrap();
+    let decoded = point_type.decode(&result).unwrap();
+    assert_eq!(read_decoded::<Point>(&decoded), Point { x: 6.0, y: 8.0 });
+}
+
+#[test]
+fn call_passes_a_nested_struct() {
+    let rect_type = rect_type();
+    let cif = middle::Cif::new(
+        vec![rect_type.libffi_type()],
+        IntegerKind::I32.libffi_type(),
+    );
+
+    let mut rect = Rect {
+        origin: Point { x: 1.0, y: 1.0 },
+        width: 6,
+        height: 7,
+    };
+    let arg = rect_type.encode(&handle_to(&mut rect), false).unwrap();
+    let mut ffi_args = Vec::new();
+    arg.append_libffi_args(&mut ffi_args);
+
+    let code = middle::CodePtr(rect_area as *mut c_void);
+    let result = IntegerKind::I32.call_cif(&cif, code, &ffi_args).unwrap();
+    assert!(matches!(result, FfiValue::I32(42)));
+}
+
+#[test]
+fn call_returns_a_padded_struct() {
+    let mixed_type = mixed_type();
+    let cif = middle::Cif::new(
+        vec![IntegerKind::U8.libffi_type()],
+        mixed_type.libffi_type(),
+    );
+
+    let arg = FfiValue::U8(9);
+    let mut ffi_args = Vec::new();
+    arg.append_libffi_args(&mut ffi_args);
+
+    let code = middle::CodePtr(make_mixed as *mut c_void);
+    let result = mixed_type.call_cif(&cif, code, &ffi_args).unwrap();
+    let decoded = mixed_type.decode(&result).unwrap();
+    assert_eq!(
+        read_decoded::<Mixed>(&decoded),
+        Mixed {
+            tag: 9,
+            value: 2.5,
+            count: 700,
+        }
+    );
+}
+
+#[test]
+fn raw_ptr_codec_reads_and_writes_inline() {
+    let point_type = point_type();
+    let mut slot = Point { x: 0.0, y: 0.0 };
+    let slot_ptr = (&raw mut slot).cast::<c_void>();
+
+    let mut source = Point { x: 7.0, y: 8.0 };
+    point_type
+        .write_value_to_raw_ptr(slot_ptr, &handle_to(&mut source))
+        .unwrap();
+    assert_eq!(slot, source);
+
+    let read = point_type
+        .read_from_raw_ptr(slot_ptr.cast_const(), "ctx")
+        .unwrap();
+    slot.x = -1.0;
+    assert_eq!(read_decoded::<Point>(&read), Point { x: 7.0, y: 8.0 });
+
+    point_type.write_return_to_raw_ptr(slot_ptr, &Ok(handle_to(&mut source)));
+    assert_eq!(slot, source);
+    point_type.write_return_to_raw_ptr(slot_ptr, &Err(()));
+    assert_eq!(slot, Point { x: 0.0, y: 0.0 });
+}
+
+#[test]
+fn boxed_return_is_copied_through_its_gtype() {
+    common::run(|| {
+        let float = || Type::Float(FloatKind::F32);
+        let rgba_type = BoxedType {
+            ownership: Ownership::Full,
+            type_name: gdk::RGBA::static_type().name().to_owned(),
+            library: None,
+            get_type_fn: None,
+            by_value: Some(StructLayout::new(vec![float(), float(), float(), float()]).unwrap()),
+        };
+
+        let source = [0.25f32, 0.5, 0.75, 1.0];
+        let buffer = unsafe { AggregateBuffer::copy_from(source.as_ptr().cast(), 16) };
+        let decoded = rgba_type.decode(&FfiValue::Aggregate(buffer)).unwrap();
+        assert_eq!(read_decoded::<[f32; 4]>(&decoded), source);
+    });
+}
@@ -0,0 +1,239 @@
+//! Coverage tests for [`native::value::TypedBuffer`] and its bulk encode and
+//! decode paths through [`native::types::ArrayType`].
+
+mod common;
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use native::ffi::{self, FfiStorageKind};
+use native::types::{
+    ArrayKind, ArrayType, BigIntKind, BooleanType, FloatKind, IntegerKind, Ownership, Type,
+};
+use native::value::{TypedBuffer, Value};
+
+fn array_type(item: Type, kind: ArrayKind, typed: bool) -> ArrayType {
+    ArrayType {
+        item_type: Box::new(item),
+        kind,
+        ownership: Ownership::Borrowed,
+        element_size: None,
+        typed,
+    }
+}
+
+fn storage(value: &ffi::FfiValue) -> &ffi::FfiStorage {
+    match value {
+        ffi::FfiValue::Storage(storage) => storage,
+        other => panic!("expected FfiValue::Storage, got {other:?}"),
+    }
+}
+
+#[test]
+fn buffer_reports_its_layout() {
+    let buffer = TypedBuffer::F32(Arc::new(vec![1.0, 2.0, 3.0]));
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.byte_len(), 12);
+    assert!(!buffer.is_empty());
+    assert_eq!(buffer.float_kind(), Some(FloatKind::F32));
+    assert_eq!(buffer.integer_kind(), None);
+    assert_eq!(buffer.js_type_name(), "Float32Array");
+
+    let bytes = TypedBuffer::U8(Arc::new(Vec::new()));
+    assert!(bytes.is_empty());
+    assert_eq!(bytes.integer_kind(), Some(IntegerKind::U8));
+}
+
+#[test]
+fn from_integers_copies_native_memory_once() {
+    let source: [i16; 3] = [-1, 0, 300];
+    let buffer = unsafe { TypedBuffer::from_integers(IntegerKind::I16, source.as_ptr().cast(), 3) };
+    let TypedBuffer::I16(data) = buffer else {
+        panic!("expected an I16 buffer");
+    };
+    assert_eq!(*data, vec![-1, 0, 300]);
+
+    let empty = unsafe { TypedBuffer::from_floats(FloatKind::F64, std::ptr::null(), 0) };
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn encode_shares_the_buffer_with_native_code() {
+    let array_type = array_type(Type::Integer(IntegerKind::I32), ArrayKind::Array, false);
+    let buffer = TypedBuffer::I32(Arc::new(vec![7, -8, 9]));
+
+    let encoded = array_type
+        .encode(&Value::TypedArray(buffer.clone()), false)
+        .unwrap();
+    let storage = storage(&encoded);
+    assert!(matches!(storage.kind(), FfiStorageKind::TypedBuffer(_)));
+    assert_eq!(storage.ptr().cast_const(), buffer.as_ptr());
+    assert_eq!(storage.numeric_len(), Some(3));
+}
+
+#[test]
+fn encode_rejects_a_mismatched_element_type() {
+    let array_type = array_type(Type::Integer(IntegerKind::I32), ArrayKind::Array, false);
+    let buffer = TypedBuffer::F32(Arc::new(vec![1.0]));
+
+    let err = array_type
+        .encode(&Value::TypedArray(buffer), false)
+        .unwrap_err();
+    assert!(err.to_string().contains("Float32Array"));
+
+    let booleans = array_type(Type::Boolean(BooleanType), ArrayKind::Array, false);
+    let err = booleans
+        .encode(
+            &Value::TypedArray(TypedBuffer::I32(Arc::new(vec![1]))),
+            false,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("Int32Array"));
+}
+
+#[test]
+fn storage_decodes_as_numbers_unless_typed() {
+    let buffer = TypedBuffer::U16(Arc::new(vec![1, 65535]));
+    let plain = array_type(Type::Integer(IntegerKind::U16), ArrayKind::Array, false);
+    let encoded = plain
+        .encode(&Value::TypedArray(buffer.clone()), false)
+        .unwrap();
+
+    let Value::Array(items) = plain.decode(&encoded).unwrap() else {
+        panic!("expected Value::Array");
+    };
+    assert!(
+        matches!(items.as_slice(), [Value::Number(a), Value::Number(b)] if *a == 1.0 && *b == 65535.0)
+    );
+
+    let typed = array_type(Type::Integer(IntegerKind::U16), ArrayKind::Array, true);
+    let Value::TypedArray(TypedBuffer::U16(data)) = typed.decode(&encoded).unwrap() else {
+        panic!("expected a U16 Value::TypedArray");
+    };
+    assert_eq!(*data, vec![1, 65535]);
+}
+
+#[test]
+fn typed_decode_also_covers_number_array_input() {
+    let array_type = array_type(Type::Float(FloatKind::F64), ArrayKind::Array, true);
+    let encoded = array_type
+        .encode(
+            &Value::Array(vec![Value::Number(0.5), Value::Number(-2.0)]),
+            false,
+        )
+        .unwrap();
+
+    let Value::TypedArray(TypedBuffer::F64(data)) = array_type.decode(&encoded).unwrap() else {
+        panic!("expected an F64 Value::TypedArray");
+    };
+    assert_eq!(*data, vec![0.5, -2.0]);
+}
+
+#[test]
+fn fixed_array_decodes_typed_from_native_memory() {
+    let source: [f32; 3] = [1.5, 2.5, 3.5];
+    let array_type = array_type(
+        Type::Float(FloatKind::F32),
+        ArrayKind::Fixed { size: 3 },
+        true,
+    );
+
+    let decoded = array_type
+        .decode_with_context(
+            &ffi::FfiValue::Ptr(source.as_ptr().cast_mut().cast::<c_void>()),
+            &[],
+            &[],
+        )
+        .unwrap();
+    let Value::TypedArray(TypedBuffer::F32(data)) = decoded else {
+        panic!("expected an F32 Value::TypedArray");
+    };
+    assert_eq!(*data, source.to_vec());
+
+    let null = array_type
+        .decode_with_context(&ffi::FfiValue::Ptr(std::ptr::null_mut()), &[], &[])
+        .unwrap();
+    assert!(matches!(null, Value::TypedArray(TypedBuffer::F32(data)) if data.is_empty()));
+}
+
+#[test]
+fn bigint_items_accept_64_bit_typed_arrays() {
+    let array_type = array_type(Type::BigInt(BigIntKind::U64), ArrayKind::Array, false);
+    let buffer = TypedBuffer::U64(Arc::new(vec![u64::MAX]));
+
+    let encoded = array_type
+        .encode(&Value::TypedArray(buffer), false)
+        .unwrap();
+    let Value::Array(items) = array_type.decode(&encoded).unwrap() else {
+        panic!("expected Value::Array");
+    };
+    assert!(matches!(items[0], Value::BigInt(n) if n == u64::MAX.into()));
+
+    let signed = TypedBuffer::I64(Arc::new(vec![1]));
+    assert!(
+        array_type
+            .encode(&Value::TypedArray(signed), false)
+            .is_err()
+    );
+}
+
+#[test]
+fn garray_round_trips_in_bulk() {
+    common::run(|| {
+        let array_type = array_type(Type::Float(FloatKind::F64), ArrayKind::GArray, true);
+        let buffer = TypedBuffer::F64(Arc::new(vec![1.25, -4.0, 1e300]));
+
+        let encoded = array_type
+            .encode(&Value::TypedArray(buffer), false)
+            .unwrap();
+        let Value::TypedArray(TypedBuffer::F64(data)) = array_type.decode(&encoded).unwrap() else {
+            panic!("expected an F64 Value::TypedArray");
+        };
+        assert_eq!(*data, vec![1.25, -4.0, 1e300]);
+    });
+}
+
+#[test]
+fn garray_rejects_a_conflicting_element_size() {
+    common::run(|| {
+        let array_type = ArrayType {
+            element_size: Some(8),
+            ..array_type(Type::Integer(IntegerKind::I32), ArrayKind::GArray, false)
+        };
+        let buffer = TypedBuffer::I32(Arc::new(vec![1, 2]));
+
+        let err = array_type
+            .encode(&Value::TypedArray(buffer), false)
+            .unwrap_err();
+        assert!(err.to_string().contains("elementSize"));
+    });
+}
+
+#[test]
+fn gbytearray_round_trips_bytes() {
+    common::run(|| {
+        let array_type = array_type(Type::Integer(IntegerKind::U8), ArrayKind::GByteArray, true);
+        let buffer = TypedBuffer::U8(Arc::new(vec![0, 127, 255]));
+
+        let encoded = array_type
+            .encode(&Value::TypedArray(buffer), false)
+            .unwrap();
+        let Value::TypedArray(TypedBuffer::U8(data)) = array_type.decode(&encoded).unwrap() else {
+            panic!("expected a U8 Value::TypedArray");
+        };
+        assert_eq!(*data, vec![0, 127, 255]);
+
+        let signed = TypedBuffer::I8(Arc::new(vec![-1]));
+        let err = array_type
+            .encode(&Value::TypedArray(signed), false)
+            .unwrap_err();
+        assert!(err.to_string().contains("Int8Array"));
+    });
+}
+
+#[test]
+fn typed_arrays_are_not_objects_or_glib_values() {
+    let value = Value::TypedArray(TypedBuffer::U8(Arc::new(vec![1])));
+    assert!(value.object_ptr("GObject").is_err());
+    assert!(value.to_glib_value().is_err());
+}
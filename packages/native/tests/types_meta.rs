@@ -65,20 +65,11 @@ fn gobject_type() -> GObjectType {
 }
 
 fn boxed_type() -> BoxedType {
-    BoxedType {
-        ownership: Ownership::Borrowed,
-        type_name: "GdkRGBA".to_owned(),
-        library: None,
-        get_type_fn: None,
-    }
+    BoxedType::new(Ownership::Borrowed, "GdkRGBA".to_owned(), None, None)
 }
 
 fn struct_type() -> StructType {
-    StructType {
-        ownership: Ownership::Borrowed,
-        type_name: "TestStruct".to_owned(),
-        size: Some(8),
-    }
+    StructType::new(Ownership::Borrowed, "TestStruct".to_owned(), Some(8))
 }
 
 fn fundamental_type() -> FundamentalType {
@@ -97,6 +88,7 @@ fn array_type() -> ArrayType {
         kind: ArrayKind::Array,
         ownership: Ownership::Borrowed,
         element_size: Some(4),
+        typed: false,
     }
 }
 
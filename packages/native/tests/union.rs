@@ -0,0 +1,441 @@
+//! Coverage tests for [`native::types::UnionType`] descriptors, which convert
+//! C unions to and from `{ tag, value }` objects.
+
+use std::ffi::c_void;
+use std::mem::offset_of;
+
+use libffi::middle;
+
+use native::ffi::FfiValue;
+use native::types::{
+    ArrayKind, ArrayType, FfiDecoder, FfiEncoder, FloatKind, IntegerKind, Ownership, RawPtrCodec,
+    RecordField, RecordType, Type, UnionDiscriminant, UnionType, UnionVariant, VoidType,
+};
+use native::value::Value;
+
+const BUTTON: i32 = 1;
+const KEY: i32 = 2;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Button {
+    kind: i32,
+    x: f64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Key {
+    kind: i32,
+    keyval: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union Event {
+    kind: i32,
+    button: Button,
+    key: Key,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union Number {
+    d: f64,
+    i: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union Floats {
+    f: f32,
+    pair: [f32; 2],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union MixedFloats {
+    f: [f32; 2],
+    d: f64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Holder {
+    id: u8,
+    number: Number,
+}
+
+extern "C" fn sum_floats(floats: Floats) -> f32 {
+    unsafe { floats.pair[0] + floats.pair[1] }
+}
+
+extern "C" fn make_number(d: f64) -> Number {
+    Number { d }
+}
+
+extern "C" fn sum_mixed_floats(floats: MixedFloats) -> f32 {
+    unsafe { floats.f[0] + floats.f[1] }
+}
+
+fn variant(name: &str, tag: Option<i64>, ty: Type) -> UnionVariant {
+    UnionVariant {
+        name: name.to_owned(),
+        ty,
+        tag,
+    }
+}
+
+fn record(fields: Vec<(&str, usize, Type)>) -> RecordType {
+    let fields = fields
+        .into_iter()
+        .map(|(name, offset, ty)| RecordField {
+            name: name.to_owned(),
+            offset,
+            ty,
+        })
+        .collect();
+    RecordType::new(Ownership::Borrowed, fields, None).unwrap()
+}
+
+fn event_type() -> UnionType {
+    UnionType::new(
+        Ownership::Borrowed,
+        size_of::<Event>(),
+        align_of::<Event>(),
+        vec![
+            variant(
+                "button",
+                Some(BUTTON.into()),
+                Type::Record(record(vec![(
+                    "x",
+                    offset_of!(Button, x),
+                    Type::Float(FloatKind::F64),
+                )])),
+            ),
+            variant(
+                "key",
+                Some(KEY.into()),
+                Type::Record(record(vec![(
+                    "keyval",
+                    offset_of!(Key, keyval),
+                    Type::Integer(IntegerKind::U32),
+                )])),
+            ),
+        ],
+        Some(UnionDiscriminant {
+            offset: 0,
+            kind: IntegerKind::I32,
+        }),
+        false,
+    )
+    .unwrap()
+}
+
+fn number_type(by_value: bool) -> UnionType {
+    UnionType::new(
+        Ownership::Borrowed,
+        size_of::<Number>(),
+        align_of::<Number>(),
+        vec![
+            variant("d", None, Type::Float(FloatKind::F64)),
+            variant("i", None, Type::Integer(IntegerKind::I64)),
+        ],
+        None,
+        by_value,
+    )
+    .unwrap()
+}
+
+fn floats_type() -> UnionType {
+    UnionType::new(
+        Ownership::Borrowed,
+        size_of::<Floats>(),
+        align_of::<Floats>(),
+        vec![
+            variant("f", None, Type::Float(FloatKind::F32)),
+            variant(
+                "pair",
+                None,
+                Type::Array(ArrayType {
+                    item_type: Box::new(Type::Float(FloatKind::F32)),
+                    kind: ArrayKind::Inline { size: 2 },
+                    ownership: Ownership::Borrowed,
+                    element_size: None,
+                    typed: false,
+                }),
+            ),
+        ],
+        None,
+        true,
+    )
+    .unwrap()
+}
+
+fn tagged(tag: &str, value: Value) -> Value {
+    Value::Record(vec![
+        ("tag".into(), Value::String(tag.to_owned())),
+        ("value".into(), value),
+    ])
+}
+
+/// Looks up an entry of a decoded record or tagged union.
+fn get<'a>(value: &'a Value, name: &str) -> &'a Value {
+    let Value::Record(fields) = value else {
+        panic!("expected Value::Record, got {value:?}");
+    };
+    fields
+        .iter()
+        .find(|(field_name, _)| field_name == name)
+        .map(|(_, value)| value)
+        .unwrap_or_else(|| panic!("record has no field '{name}'"))
+}
+
+fn tag_of(value: &Value) -> &str {
+    let Value::String(tag) = get(value, "tag") else {
+        panic!("expected a string tag in {value:?}");
+    };
+    tag
+}
+
+fn number(value: &Value) -> f64 {
+    value.as_number().expect("expected a number")
+}
+
+/// The number a decoded tagged union holds.
+fn number_value(value: &Value) -> f64 {
+    number(get(value, "value"))
+}
+
+#[test]
+fn new_rejects_inconsistent_layouts() {
+    let f64_variant = || vec![variant("d", None, Type::Float(FloatKind::F64))];
+
+    let err = UnionType::new(Ownership::Borrowed, 8, 3, f64_variant(), None, false).unwrap_err();
+    assert!(err.to_string().contains("alignment"));
+
+    let err = UnionType::new(Ownership::Borrowed, 4, 4, f64_variant(), None, false).unwrap_err();
+    assert!(err.to_string().contains("smaller than"));
+
+    let err = UnionType::new(Ownership::Borrowed, 8, 8, Vec::new(), None, false).unwrap_err();
+    assert!(err.to_string().contains("at least one variant"));
+
+    let void = vec![variant("nothing", None, Type::Void(VoidType))];
+    let err = UnionType::new(Ownership::Borrowed, 8, 8, void, None, false).unwrap_err();
+    assert!(err.to_string().contains("'nothing'"));
+
+    let discriminant = UnionDiscriminant {
+        offset: 6,
+        kind: IntegerKind::I32,
+    };
+    let err = UnionType::new(
+        Ownership::Borrowed,
+        8,
+        8,
+        f64_variant(),
+        Some(discriminant),
+        false,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("does not fit"));
+}
+
+#[test]
+fn read_inline_decodes_the_variant_the_discriminant_selects() {
+    let event = Event {
+        key: Key {
+            kind: KEY,
+            keyval: 65,
+        },
+    };
+    let value = event_type().read_inline((&raw const event).cast()).unwrap();
+
+    assert_eq!(tag_of(&value), "key");
+    assert!((number(get(get(&value, "value"), "keyval")) - 65.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn read_inline_rejects_an_unknown_discriminant() {
+    let event = Event { kind: 99 };
+    let err = event_type()
+        .read_inline((&raw const event).cast())
+        .unwrap_err();
+    assert!(err.to_string().contains("99 matches none"));
+}
+
+#[test]
+fn write_inline_writes_the_variant_and_its_tag() {
+    let mut event = Event { kind: 0 };
+    let button = tagged(
+        "button",
+        Value::Record(vec![("x".into(), Value::Number(2.5))]),
+    );
+    event_type()
+        .write_inline((&raw mut event).cast(), &button)
+        .unwrap();
+
+    let written = unsafe { event.button };
+    assert_eq!(written.kind, BUTTON);
+    assert!((written.x - 2.5).abs() < f64::EPSILON);
+}
+
+#[test]
+fn write_inline_rejects_an_unknown_tag() {
+    let mut event = Event { kind: 0 };
+    let err = event_type()
+        .write_inline((&raw mut event).cast(), &tagged("touch", Value::Null))
+        .unwrap_err();
+    assert!(err.to_string().contains("no variant named 'touch'"));
+}
+
+#[test]
+fn reads_without_a_discriminant_decode_the_first_variant() {
+    let union_type = number_type(false);
+    let mut number = Number { d: 0.0 };
+    union_type
+        .write_inline((&raw mut number).cast(), &tagged("i", Value::Number(-1.0)))
+        .unwrap();
+    assert_eq!(unsafe { number.i }, -1);
+
+    let value = union_type.read_inline((&raw const number).cast()).unwrap();
+    assert_eq!(tag_of(&value), "d");
+    assert!(number_value(&value).is_nan());
+}
+
+#[test]
+fn encode_borrowed_lends_call_owned_memory() {
+    let encoded = event_type()
+        .encode(&tagged("key", Value::Record(Vec::new())), false)
+        .unwrap();
+    let FfiValue::Storage(storage) = &encoded else {
+        panic!("expected FfiValue::Storage, got {encoded:?}");
+    };
+    assert_eq!(unsafe { storage.ptr().cast::<Event>().read().kind }, KEY);
+}
+
+#[test]
+fn by_value_float_unions_are_passed_in_float_registers() {
+    let union_type = floats_type();
+    let cif = middle::Cif::new(vec![union_type.libffi_type()], FloatKind::F32.libffi_type());
+
+    let arg = union_type
+        .encode(
+            &tagged(
+                "pair",
+                Value::Array(vec![Value::Number(1.5), Value::Number(2.0)]),
+            ),
+            false,
+        )
+        .unwrap();
+    assert!(matches!(arg, FfiValue::Aggregate(_)));
+    let mut ffi_args = Vec::new();
+    arg.append_libffi_args(&mut ffi_args);
+
+    let code = middle::CodePtr(sum_floats as *mut c_void);
+    let result = FloatKind::F32.call_cif(&cif, code, &ffi_args).unwrap();
+    assert!(matches!(result, FfiValue::F32(sum) if (sum - 3.5).abs() < f32::EPSILON));
+}
+
+#[test]
+fn by_value_unions_of_both_float_kinds_are_passed_in_float_registers() {
+    let union_type = UnionType::new(
+        Ownership::Borrowed,
+        size_of::<MixedFloats>(),
+        align_of::<MixedFloats>(),
+        vec![
+            variant(
+                "f",
+                None,
+                Type::Array(ArrayType {
+                    item_type: Box::new(Type::Float(FloatKind::F32)),
+                    kind: ArrayKind::Inline { size: 2 },
+                    ownership: Ownership::Borrowed,
+                    element_size: None,
+                    typed: false,
+                }),
+            ),
+            variant("d", None, Type::Float(FloatKind::F64)),
+        ],
+        None,
+        true,
+    )
+    .unwrap();
+    let cif = middle::Cif::new(vec![union_type.libffi_type()], FloatKind::F32.libffi_type());
+
+    let arg = union_type
+        .encode(
+            &tagged(
+                "f",
+                Value::Array(vec![Value::Number(0.5), Value::Number(4.0)]),
+            ),
+            false,
+        )
+        .unwrap();
+    let mut ffi_args = Vec::new();
+    arg.append_libffi_args(&mut ffi_args);
+
+    let code = middle::CodePtr(sum_mixed_floats as *mut c_void);
+    let result = FloatKind::F32.call_cif(&cif, code, &ffi_args).unwrap();
+    assert!(matches!(result, FfiValue::F32(sum) if (sum - 4.5).abs() < f32::EPSILON));
+}
+
+#[test]
+fn by_value_mixed_unions_are_returned_in_integer_registers() {
+    let union_type = number_type(true);
+    let cif = middle::Cif::new(vec![FloatKind::F64.libffi_type()], union_type.libffi_type());
+
+    let arg = FfiValue::F64(0.75);
+    let mut ffi_args = Vec::new();
+    arg.append_libffi_args(&mut ffi_args);
+
+    let code = middle::CodePtr(make_number as *mut c_void);
+    let result = union_type.call_cif(&cif, code, &ffi_args).unwrap();
+    let decoded = union_type.decode(&result).unwrap();
+    assert_eq!(tag_of(&decoded), "d");
+    assert!((number_value(&decoded) - 0.75).abs() < f64::EPSILON);
+}
+
+#[test]
+fn unions_embed_inline_in_records() {
+    let holder_type = record(vec![
+        ("id", offset_of!(Holder, id), Type::Integer(IntegerKind::U8)),
+        (
+            "number",
+            offset_of!(Holder, number),
+            Type::Union(number_type(false)),
+        ),
+    ]);
+    assert_eq!(holder_type.size, size_of::<Holder>());
+
+    let mut holder = Holder {
+        id: 0,
+        number: Number { i: 0 },
+    };
+    let value = Value::Record(vec![
+        ("id".into(), Value::Number(3.0)),
+        ("number".into(), tagged("d", Value::Number(1.25))),
+    ]);
+    holder_type
+        .write_inline((&raw mut holder).cast(), &value)
+        .unwrap();
+    assert_eq!(holder.id, 3);
+    assert!((unsafe { holder.number.d } - 1.25).abs() < f64::EPSILON);
+
+    let read = holder_type.read_inline((&raw const holder).cast()).unwrap();
+    assert!((number_value(get(&read, "number")) - 1.25).abs() < f64::EPSILON);
+}
+
+#[test]
+fn by_value_unions_are_read_and_written_in_place() {
+    let union_type = number_type(true);
+    let mut number = Number { i: 0 };
+    let slot = (&raw mut number).cast::<c_void>();
+
+    union_type
+        .write_value_to_raw_ptr(slot, &tagged("d", Value::Number(-4.0)))
+        .unwrap();
+    assert!((unsafe { number.d } + 4.0).abs() < f64::EPSILON);
+
+    let value = union_type.read_from_raw_ptr(slot, "test").unwrap();
+    assert!((number_value(&value) + 4.0).abs() < f64::EPSILON);
+}
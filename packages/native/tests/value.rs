@@ -196,12 +196,7 @@ fn boxed_transfer_none_creates_copy() {
         let gtype = gdk::RGBA::static_type();
         let original_ptr = common::allocate_test_boxed(gtype);
 
-        let boxed_type = BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "GdkRGBA".to_string(),
-            library: None,
-            get_type_fn: None,
-        };
+        let boxed_type = BoxedType::new(Ownership::Borrowed, "GdkRGBA".to_string(), None, None);
         let type_ = Type::Boxed(boxed_type);
 
         let cif_value = ffi::FfiValue::Ptr(original_ptr);
@@ -223,12 +218,7 @@ fn boxed_full_transfer_takes_ownership() {
         let gtype = gdk::RGBA::static_type();
         let ptr = common::allocate_test_boxed(gtype);
 
-        let boxed_type = BoxedType {
-            ownership: Ownership::Full,
-            type_name: "GdkRGBA".to_string(),
-            library: None,
-            get_type_fn: None,
-        };
+        let boxed_type = BoxedType::new(Ownership::Full, "GdkRGBA".to_string(), None, None);
         let type_ = Type::Boxed(boxed_type);
 
         let cif_value = ffi::FfiValue::Ptr(ptr);
@@ -241,12 +231,7 @@ fn boxed_full_transfer_takes_ownership() {
 #[test]
 fn boxed_null_returns_null_value() {
     common::run(|| {
-        let boxed_type = BoxedType {
-            ownership: Ownership::Full,
-            type_name: "GdkRGBA".to_string(),
-            library: None,
-            get_type_fn: None,
-        };
+        let boxed_type = BoxedType::new(Ownership::Full, "GdkRGBA".to_string(), None, None);
         let type_ = Type::Boxed(boxed_type);
 
         let cif_value = ffi::FfiValue::Ptr(std::ptr::null_mut());
@@ -278,6 +263,7 @@ fn glist_transfer_none_does_not_free_list() {
             kind: ArrayKind::GList,
             ownership: Ownership::Borrowed,
             element_size: None,
+            typed: false,
         };
         let type_ = Type::Array(array_type);
 
@@ -329,6 +315,7 @@ fn glist_full_transfer_frees_list() {
             kind: ArrayKind::GList,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         };
         let type_ = Type::Array(array_type);
 
@@ -355,6 +342,7 @@ fn glist_null_returns_empty_array() {
             kind: ArrayKind::GList,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         };
         let type_ = Type::Array(array_type);
 
@@ -391,6 +379,7 @@ fn strv_transfer_none_does_not_free() {
             kind: ArrayKind::Array,
             ownership: Ownership::Borrowed,
             element_size: None,
+            typed: false,
         };
         let type_ = Type::Array(array_type);
 
@@ -442,6 +431,7 @@ fn strv_full_transfer_frees_strings() {
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
             element_size: None,
+            typed: false,
         };
         let type_ = Type::Array(array_type);
 
@@ -835,12 +825,12 @@ fn from_cif_value_ref_boxed() {
             ffi::FfiStorage::new(storage_ptr, ffi::FfiStorageKind::PtrStorage(ptr_storage));
         let cif_value = ffi::FfiValue::Storage(storage);
 
-        let ref_type = native::types::RefType::new(Type::Boxed(BoxedType {
-            ownership: Ownership::Borrowed,
-            type_name: "GdkRGBA".to_string(),
-            library: None,
-            get_type_fn: None,
-        }));
+        let ref_type = native::types::RefType::new(Type::Boxed(BoxedType::new(
+            Ownership::Borrowed,
+            "GdkRGBA".to_string(),
+            None,
+            None,
+        )));
         let type_ = Type::Ref(ref_type);
 
         let result = type_.decode(&cif_value).expect("Ref<Boxed> decode failed");
@@ -948,6 +938,7 @@ fn glist_with_string_items() {
             kind: ArrayKind::GList,
             ownership: Ownership::Borrowed,
             element_size: None,
+            typed: false,
         };
         let type_ = Type::Array(array_type);
 
@@ -978,11 +969,8 @@ fn from_cif_value_struct_transfer_none_logs_warning() {
     common::run(|| {
         let struct_ptr = unsafe { glib::ffi::g_malloc0(16) };
 
-        let struct_type = native::types::StructType {
-            ownership: Ownership::Borrowed,
-            type_name: "TestRect".to_string(),
-            size: Some(16),
-        };
+        let struct_type =
+            native::types::StructType::new(Ownership::Borrowed, "TestRect".to_string(), Some(16));
         let type_ = Type::Struct(struct_type);
 
         let cif_value = ffi::FfiValue::Ptr(struct_ptr);
@@ -1005,11 +993,8 @@ fn from_cif_value_struct_full_transfer() {
     common::run(|| {
         let struct_ptr = unsafe { glib::ffi::g_malloc0(32) };
 
-        let struct_type = native::types::StructType {
-            ownership: Ownership::Full,
-            type_name: "CustomStruct".to_string(),
-            size: Some(32),
-        };
+        let struct_type =
+            native::types::StructType::new(Ownership::Full, "CustomStruct".to_string(), Some(32));
         let type_ = Type::Struct(struct_type);
 
         let cif_value = ffi::FfiValue::Ptr(struct_ptr);
@@ -1026,11 +1011,8 @@ fn from_cif_value_struct_full_transfer() {
 #[test]
 fn from_cif_value_struct_null_returns_null_value() {
     common::run(|| {
-        let struct_type = native::types::StructType {
-            ownership: Ownership::Borrowed,
-            type_name: "TestStruct".to_string(),
-            size: Some(16),
-        };
+        let struct_type =
+            native::types::StructType::new(Ownership::Borrowed, "TestStruct".to_string(), Some(16));
         let type_ = Type::Struct(struct_type);
 
         let cif_value = ffi::FfiValue::Ptr(std::ptr::null_mut());
@@ -1046,11 +1028,11 @@ fn from_glib_value_struct_fails() {
     common::run(|| {
         let gvalue: glib::Value = glib::Value::from_type(glib::types::Type::POINTER);
 
-        let struct_type = native::types::StructType {
-            ownership: Ownership::Borrowed,
-            type_name: "PlainStruct".to_string(),
-            size: Some(16),
-        };
+        let struct_type = native::types::StructType::new(
+            Ownership::Borrowed,
+            "PlainStruct".to_string(),
+            Some(16),
+        );
         let type_ = Type::Struct(struct_type);
 
         let result = Value::from_glib_value(&gvalue, &type_);
@@ -1064,11 +1046,11 @@ fn from_cif_value_struct_transfer_none_without_size_creates_unowned() {
     common::run(|| {
         let struct_ptr = unsafe { glib::ffi::g_malloc0(24) };
 
-        let struct_type = native::types::StructType {
-            ownership: Ownership::Borrowed,
-            type_name: "UnknownSizeStruct".to_string(),
-            size: None,
-        };
+        let struct_type = native::types::StructType::new(
+            Ownership::Borrowed,
+            "UnknownSizeStruct".to_string(),
+            None,
+        );
         let type_ = Type::Struct(struct_type);
 
         let cif_value = ffi::FfiValue::Ptr(struct_ptr);
@@ -1091,11 +1073,8 @@ fn from_cif_value_struct_owned_without_size() {
     common::run(|| {
         let struct_ptr = unsafe { glib::ffi::g_malloc0(24) };
 
-        let struct_type = native::types::StructType {
-            ownership: Ownership::Full,
-            type_name: "UnknownSizeStruct".to_string(),
-            size: None,
-        };
+        let struct_type =
+            native::types::StructType::new(Ownership::Full, "UnknownSizeStruct".to_string(), None);
         let type_ = Type::Struct(struct_type);
 
         let cif_value = ffi::FfiValue::Ptr(struct_ptr);
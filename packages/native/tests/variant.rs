@@ -0,0 +1,209 @@
+//! Coverage tests for [`native::types::VariantType`] codec implementations.
+
+mod common;
+
+use std::ffi::c_void;
+
+use gtk4::glib::{
+    self,
+    translate::{FromGlibPtrFull as _, IntoGlibPtr as _, ToGlibPtr as _},
+};
+
+use native::ffi;
+use native::types::{
+    FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec, Type, VariantType,
+};
+use native::value::Value;
+
+fn variant_type(ownership: Ownership) -> VariantType {
+    VariantType::new(ownership, None)
+}
+
+fn expect_variant(value: Value) -> glib::Variant {
+    match value {
+        Value::Variant(variant) => variant,
+        other => panic!("expected Value::Variant, got {other:?}"),
+    }
+}
+
+fn encoded_ptr(encoded: &ffi::FfiValue) -> *mut c_void {
+    match encoded {
+        ffi::FfiValue::Ptr(ptr) => *ptr,
+        other => panic!("expected FfiValue::Ptr, got {other:?}"),
+    }
+}
+
+#[test]
+fn parse_signature_accepts_definite_types() {
+    let ty = VariantType::parse_signature("a{sv}").unwrap();
+    assert_eq!(ty.as_str(), "a{sv}");
+}
+
+#[test]
+fn parse_signature_rejects_malformed_types() {
+    let err = VariantType::parse_signature("a{s").unwrap_err();
+    assert!(err.to_string().contains("Invalid variant signature"));
+}
+
+#[test]
+fn parse_signature_rejects_indefinite_types() {
+    let err = VariantType::parse_signature("a*").unwrap_err();
+    assert!(err.to_string().contains("not a definite type"));
+}
+
+#[test]
+fn encode_borrowed_passes_the_variant_pointer() {
+    let variant = glib::Variant::from("hello");
+    let encoded = variant_type(Ownership::Borrowed)
+        .encode(&Value::Variant(variant.clone()), false)
+        .unwrap();
+    assert_eq!(encoded_ptr(&encoded), variant.as_ptr().cast::<c_void>());
+}
+
+#[test]
+fn encode_full_transfers_an_extra_reference() {
+    let variant = glib::Variant::from(42i32);
+    let encoded = variant_type(Ownership::Full)
+        .encode(&Value::Variant(variant.clone()), false)
+        .unwrap();
+    let ptr = encoded_ptr(&encoded);
+    assert_eq!(ptr, variant.as_ptr().cast::<c_void>());
+
+    let transferred = unsafe { glib::Variant::from_glib_full(ptr.cast::<glib::ffi::GVariant>()) };
+    drop(transferred);
+    assert_eq!(variant.get::<i32>(), Some(42));
+}
+
+#[test]
+fn encode_null_yields_a_null_pointer() {
+    let encoded = variant_type(Ownership::Full)
+        .encode(&Value::Null, true)
+        .unwrap();
+    assert!(encoded_ptr(&encoded).is_null());
+}
+
+#[test]
+fn encode_rejects_non_pointer_values() {
+    let result = variant_type(Ownership::Borrowed).encode(&Value::Number(1.0), false);
+    assert!(result.is_err());
+}
+
+#[test]
+fn decode_full_adopts_the_returned_reference() {
+    let original = glib::Variant::from(7u64);
+    let ptr: *mut glib::ffi::GVariant = original.to_glib_full();
+
+    let decoded = variant_type(Ownership::Full)
+        .decode(&ffi::FfiValue::Ptr(ptr.cast()))
+        .unwrap();
+    assert_eq!(expect_variant(decoded), original);
+}
+
+#[test]
+fn decode_borrowed_sinks_a_floating_reference() {
+    let ptr = unsafe { glib::ffi::g_variant_new_int32(-3) };
+    let decoded = variant_type(Ownership::Borrowed)
+        .decode(&ffi::FfiValue::Ptr(ptr.cast()))
+        .unwrap();
+    let variant = expect_variant(decoded);
+    assert_eq!(variant.get::<i32>(), Some(-3));
+    assert_eq!(
+        unsafe { glib::ffi::g_variant_is_floating(variant.as_ptr()) },
+        0
+    );
+}
+
+#[test]
+fn decode_null_yields_null() {
+    let decoded = variant_type(Ownership::Full)
+        .decode(&ffi::FfiValue::Ptr(std::ptr::null_mut()))
+        .unwrap();
+    assert!(matches!(decoded, Value::Null));
+}
+
+#[test]
+fn ptr_to_value_takes_a_fresh_reference() {
+    let variant = glib::Variant::from("borrowed");
+    let value = variant_type(Ownership::Full)
+        .ptr_to_value(variant.as_ptr().cast(), "test")
+        .unwrap();
+    assert_eq!(expect_variant(value), variant);
+}
+
+#[test]
+fn write_return_to_raw_ptr_returns_an_owned_reference() {
+    let variant = glib::Variant::from(true);
+    let mut slot: *mut c_void = std::ptr::null_mut();
+    variant_type(Ownership::Borrowed)
+        .write_return_to_raw_ptr((&raw mut slot).cast(), &Ok(Value::Variant(variant.clone())));
+    assert_eq!(slot, variant.as_ptr().cast::<c_void>());
+
+    let returned = unsafe { glib::Variant::from_glib_full(slot.cast::<glib::ffi::GVariant>()) };
+    assert_eq!(returned, variant);
+}
+
+#[test]
+fn write_value_to_raw_ptr_stores_the_variant_pointer() {
+    let variant = glib::Variant::from(1.5f64);
+    let mut slot: *mut c_void = std::ptr::null_mut();
+    variant_type(Ownership::Borrowed)
+        .write_value_to_raw_ptr((&raw mut slot).cast(), &Value::Variant(variant.clone()))
+        .unwrap();
+    assert_eq!(slot, variant.as_ptr().cast::<c_void>());
+}
+
+#[test]
+fn glib_value_round_trips_a_variant() {
+    let variant = glib::Variant::from(("key", 3u32));
+    let codec = variant_type(Ownership::Borrowed);
+
+    let gvalue = codec
+        .to_glib_value(&Value::Variant(variant.clone()))
+        .unwrap()
+        .expect("a variant converts to a GValue");
+    assert!(gvalue.type_().is_a(glib::types::Type::VARIANT));
+
+    let decoded = codec.from_glib_value(&gvalue).unwrap();
+    assert_eq!(expect_variant(decoded), variant);
+}
+
+#[test]
+fn glib_value_round_trips_null() {
+    let codec = variant_type(Ownership::Borrowed);
+    let gvalue = codec
+        .to_glib_value(&Value::Null)
+        .unwrap()
+        .expect("null converts to an empty variant GValue");
+    assert!(matches!(
+        codec.from_glib_value(&gvalue).unwrap(),
+        Value::Null
+    ));
+}
+
+#[test]
+fn glib_value_accepts_a_native_variant_handle() {
+    common::run(|| {
+        let variant = glib::Variant::from("handle");
+        let ptr: *mut glib::ffi::GVariant = variant.clone().into_glib_ptr();
+        let handle = native::managed::NativeHandle::borrowed(ptr.cast());
+
+        let gvalue = variant_type(Ownership::Borrowed)
+            .to_glib_value(&Value::Object(handle))
+            .unwrap()
+            .expect("a native handle converts to a GValue");
+        assert_eq!(gvalue.get::<glib::Variant>().unwrap(), variant);
+
+        unsafe { glib::ffi::g_variant_unref(ptr) };
+    });
+}
+
+#[test]
+fn display_includes_the_signature() {
+    let signature = VariantType::parse_signature("a{sv}").unwrap();
+    let ty = Type::Variant(VariantType::new(Ownership::Borrowed, Some(signature)));
+    assert_eq!(ty.to_string(), "Variant(a{sv})");
+    assert_eq!(
+        Type::Variant(variant_type(Ownership::Borrowed)).to_string(),
+        "Variant"
+    );
+}
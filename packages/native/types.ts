@@ -6,7 +6,40 @@ import type { NativeHandle } from "./index.js";
  * Returned by `call()` and `read()` where the concrete type
  * depends on the type descriptor passed to the function.
  */
-export type FfiValue = NativeHandle | number | string | boolean | FfiValue[] | null | undefined;
+export type FfiValue =
+    | NativeHandle
+    | number
+    | bigint
+    | string
+    | boolean
+    | NumericTypedArray
+    | FfiValue[]
+    | { [key: string]: FfiValue }
+    | null
+    | undefined;
+
+/**
+ * A typed array whose elements map one-to-one onto a native numeric array.
+ * Accepted wherever an array of numbers is, and returned by `typed` arrays.
+ */
+export type NumericTypedArray =
+    | Int8Array
+    | Uint8Array
+    | Uint8ClampedArray
+    | Int16Array
+    | Uint16Array
+    | Int32Array
+    | Uint32Array
+    | BigInt64Array
+    | BigUint64Array
+    | Float32Array
+    | Float64Array;
+
+/**
+ * The JS form of a `v`-typed `GVariant`: the child's type string and its
+ * unpacked value.
+ */
+export type TaggedVariant = { signature: string; value: FfiValue };
 
 type Int8Type = { type: "int8" };
 type Uint8Type = { type: "uint8" };
@@ -14,8 +47,12 @@ type Int16Type = { type: "int16" };
 type Uint16Type = { type: "uint16" };
 type Int32Type = { type: "int32" };
 type Uint32Type = { type: "uint32" };
-type Int64Type = { type: "int64" };
-type Uint64Type = { type: "uint64" };
+/**
+ * 64-bit integers decode to `number` by default. Set `bigint` to exchange
+ * them as `bigint` instead, which is lossless above 2^53.
+ */
+type Int64Type = { type: "int64"; bigint?: boolean };
+type Uint64Type = { type: "uint64"; bigint?: boolean };
 
 type Float32Type = { type: "float32" };
 type Float64Type = { type: "float64" };
@@ -31,9 +68,22 @@ type StringType = { type: "string"; ownership: Ownership; length?: number };
 
 type GObjectType = { type: "gobject"; ownership: Ownership };
 
-type BoxedType = { type: "boxed"; ownership: Ownership; innerType: string; library?: string; getTypeFn?: string };
+/**
+ * Boxed types and structs are passed by pointer unless `byValue` is set, in
+ * which case `fields` lists the member types in declaration order and the
+ * struct itself is copied into arguments and out of return values.
+ */
+type ByValueLayout = { byValue?: boolean; fields?: Type[] };
+
+type BoxedType = {
+    type: "boxed";
+    ownership: Ownership;
+    innerType: string;
+    library?: string;
+    getTypeFn?: string;
+} & ByValueLayout;
 
-type StructType = { type: "struct"; ownership: Ownership; innerType: string; size?: number };
+type StructType = { type: "struct"; ownership: Ownership; innerType: string; size?: number } & ByValueLayout;
 
 type FundamentalType = {
     type: "fundamental";
@@ -44,14 +94,79 @@ type FundamentalType = {
     typeName?: string;
 };
 
+/**
+ * A `GVariant` converted to and from a plain JS value. `signature` is the
+ * variant type string JS values are built into; returned variants are
+ * unpacked by their own type.
+ */
+type VariantType = { type: "variant"; ownership: Ownership; signature?: string };
+
+/**
+ * A `GBytes` passed from a `Uint8Array`, `Buffer` or `ArrayBuffer` and returned
+ * as a `Buffer`. Set `zeroCopy` to return a `Buffer` backed by the `GBytes`
+ * memory instead of a copy; it must then be treated as read-only.
+ */
+type BytesType = { type: "bytes"; ownership: Ownership; zeroCopy?: boolean };
+
+/**
+ * A `GValue` passed and returned by pointer. JS values are stored as
+ * `typeName` (a registered `GType` name such as `"gint"`) or through
+ * `valueType`, and are otherwise inferred from the JS value; a native handle
+ * with neither is passed through as an existing `GValue*`. Returned values are
+ * decoded by the type they hold at run time unless `valueType` is set.
+ */
+type GValueType = { type: "gvalue"; ownership: Ownership; valueType?: Type; typeName?: string };
+
+type RecordField = { name: string; offset: number; type: Type };
+
+/**
+ * A C struct converted to and from a plain object keyed by field name. Each
+ * field sits at its byte `offset`; nested records, unions and `inline` arrays
+ * are embedded in place. `size` defaults to the end of the furthest field. Passed
+ * by pointer; wrap in a `ref` for a caller-allocated out-parameter.
+ */
+type RecordType = { type: "record"; ownership: Ownership; fields: RecordField[]; size?: number };
+
+/** A union member; `tag` is the discriminant value that selects it. */
+type UnionVariant = { name: string; type: Type; tag?: number };
+
+/**
+ * A C union converted to and from a `{ tag, value }` object naming the active
+ * variant. With a `discriminant` (an integer or enum at a byte offset inside
+ * the union), reads decode the variant whose `tag` it holds and writes store
+ * the written variant's tag; without one, reads decode the first variant.
+ * Passed by pointer unless `byValue` is set.
+ */
+type UnionType = {
+    type: "union";
+    ownership: Ownership;
+    size: number;
+    alignment: number;
+    variants: UnionVariant[];
+    discriminant?: { offset: number; type: Type };
+    byValue?: boolean;
+};
+
+/** The JS form of a union value. */
+export type TaggedUnion = { tag: string; value: FfiValue };
+
 export type ArrayType = {
     type: "array";
     itemType: Type;
-    kind: "array" | "glist" | "gslist" | "gptrarray" | "garray" | "gbytearray" | "sized" | "fixed";
+    /**
+     * `fixed` is a pointer to `fixedSize` elements; `inline` is a C array
+     * member (`gint values[4]`) whose `fixedSize` elements sit in place.
+     */
+    kind: "array" | "glist" | "gslist" | "gptrarray" | "garray" | "gbytearray" | "sized" | "fixed" | "inline";
     ownership: Ownership;
     elementSize?: number;
     sizeParamIndex?: number;
     fixedSize?: number;
+    /**
+     * Return numeric elements as a typed array matching `itemType` (e.g. a
+     * `Float32Array` for `float32`) instead of a `number[]`.
+     */
+    typed?: boolean;
 };
 
 export type HashTableType = {
@@ -67,6 +182,12 @@ type UnicharType = { type: "unichar" };
 
 type VoidType = { type: "void" };
 
+/**
+ * The trailing `GError**` out-parameter of a throwing callable. A call that
+ * sets it throws a `NativeError` instead of returning.
+ */
+type GErrorType = { type: "gerror" };
+
 export type CallbackType = {
     type: "callback";
     kind: "closure";
@@ -107,13 +228,19 @@ export type Type =
     | BoxedType
     | StructType
     | FundamentalType
+    | VariantType
+    | BytesType
+    | GValueType
+    | RecordType
+    | UnionType
     | ArrayType
     | HashTableType
     | RefType
     | CallbackType
     | TrampolineType
     | UnicharType
-    | VoidType;
+    | VoidType
+    | GErrorType;
 
 /**
  * An argument for an FFI call.
@@ -129,6 +256,88 @@ export type Arg = {
     optional?: boolean;
 };
 
+/**
+ * Options for a `call()`.
+ */
+export type CallOptions = {
+    /**
+     * Marks the callee as a C variadic function. The first `fixedArgs`
+     * arguments are its named parameters; the rest are passed through `...`
+     * after the C default argument promotions (`int8`/`uint8`/`int16`/`uint16`
+     * to `int`, `float32` to `double`).
+     */
+    variadic?: { fixedArgs: number };
+};
+
+/**
+ * One entry of a `callBatch()` submission.
+ */
+export type BatchCall = {
+    /** Shared library name (e.g., "libgtk-4.so.1") */
+    library: string;
+    /** Function symbol name */
+    symbol: string;
+    /** Function arguments with type information */
+    args: Arg[];
+    /** Expected return type */
+    returnType: Type;
+};
+
+/**
+ * A C bitfield inside the integer at a field's offset. Bits are counted in
+ * the order the C compiler allocates them, starting at `bitOffset` (default 0).
+ */
+export type BitField = {
+    bitOffset?: number;
+    bitWidth: number;
+};
+
+/**
+ * One field of a `readMany()` request.
+ */
+export type FieldRead = Partial<BitField> & {
+    /** Byte offset from the handle pointer */
+    offset: number;
+    /** Type of value to read */
+    type: Type;
+};
+
+/**
+ * One field of a `writeMany()` request.
+ */
+export type FieldWrite = FieldRead & {
+    /** Value to write */
+    value: unknown;
+};
+
+/**
+ * Describes a GIO-style asynchronous operation for `callAsyncPair()`.
+ */
+export type AsyncPairOptions = {
+    /** Arguments of the `_async` callable preceding its `GCancellable*` slot */
+    args: Arg[];
+    /** Arguments of the `_async` callable between the `GCancellable*` slot and the callback */
+    trailingArgs?: Arg[];
+    /** `GCancellable` handle, or `null` for an uncancellable operation */
+    cancellable?: unknown;
+    /** Arguments of the `_finish` callable preceding its `GAsyncResult*` */
+    finishArgs?: Arg[];
+    /** Arguments of the `_finish` callable between the `GAsyncResult*` and the `GError**` */
+    finishTrailingArgs?: Arg[];
+    /** Return type of the `_finish` callable */
+    returnType: Type;
+};
+
+/**
+ * Argument type descriptor without a value, as passed to `prepare()`.
+ */
+export type ArgType = {
+    /** Type descriptor for marshaling */
+    type: Type;
+    /** Whether the argument can be null/undefined */
+    optional?: boolean;
+};
+
 /**
  * A mutable reference wrapper for out-parameters.
  *